@@ -16,17 +16,22 @@ use crate::simulation::{CpuSimulation, SimEdge, SimNode};
 /// Canvas background; a hollow arrowhead is filled with this so the
 /// edge line doesn't show through its interior before the outline is
 /// stroked. Keep in sync with the `fill_rect` clear in `render`.
-const CANVAS_BG: &str = "#1a1a2e";
+pub(crate) const CANVAS_BG: &str = "#1a1a2e";
 /// Amber — the "a rule touches this" accent: the persistent rule ring and
 /// the pronounced rule-hover participant ring.
 pub(crate) const AMBER: &str = "rgba(251, 191, 36, 1.0)";
+/// Legend text, section-header and swatch-outline colors, shared with
+/// the static SVG export's legend.
+pub(crate) const LEGEND_TEXT: &str = "rgba(232, 232, 244, 0.95)";
+pub(crate) const LEGEND_HEADER: &str = "rgba(150, 150, 178, 0.95)";
+pub(crate) const LEGEND_BORDER: &str = "rgba(255, 255, 255, 0.6)";
 /// Blue — the selection ring.
 const SELECTION_BLUE: &str = "rgba(59, 130, 246, 1.0)";
 /// Ring stroke widths (device px), shared by the graph and the legend so
 /// their thickness can't drift. A node touched by a rule wears the thin
 /// persistent ring at rest; hovering a rule thickens its participants; the
 /// selection ring sits between the two.
-const RING_W_RULE: f64 = 2.0;
+pub(crate) const RING_W_RULE: f64 = 2.0;
 const RING_W_HOVER: f64 = 3.5;
 const RING_W_SELECTED: f64 = 3.0;
 /// Ring radius offsets (device px) past the node rim. The amber rule ring
@@ -34,7 +39,7 @@ const RING_W_SELECTED: f64 = 3.0;
 /// ring, but is pushed outside the rule ring when the node has both, so a
 /// selected rule node shows an inner amber ring and an outer blue one
 /// instead of the blue burying the amber.
-const RING_OFF_RULE: f64 = 3.0;
+pub(crate) const RING_OFF_RULE: f64 = 3.0;
 const RING_OFF_SELECTED: f64 = 4.0;
 const RING_OFF_SELECTED_WITH_RULE: f64 = 7.0;
 
@@ -55,7 +60,7 @@ fn selection_ring_offset(in_rule: bool) -> f64 {
 /// tints — desaturated so the colored nodes still pop. Color is
 /// reinforcing only; line style + head shape carry the distinction in
 /// grayscale.
-fn edge_rgb(kind: EdgeType) -> (u8, u8, u8) {
+pub(crate) fn edge_rgb(kind: EdgeType) -> (u8, u8, u8) {
     match kind {
         EdgeType::SubclassOf | EdgeType::Mixin => (160, 160, 185),
         EdgeType::Domain => (120, 165, 170),
@@ -70,19 +75,19 @@ fn edge_rgb(kind: EdgeType) -> (u8, u8, u8) {
 
 /// Dashed line for `mixin` (UML realization analog) and `inverse`
 /// (symmetric, not a single solid direction); solid otherwise.
-fn edge_dashed(kind: EdgeType) -> bool {
+pub(crate) fn edge_dashed(kind: EdgeType) -> bool {
     matches!(kind, EdgeType::Mixin | EdgeType::Inverse)
 }
 
 /// Hollow-triangle head (UML generalization) for the inheritance
 /// kinds; a filled arrow for the referential kinds.
-fn edge_hollow_head(kind: EdgeType) -> bool {
+pub(crate) fn edge_hollow_head(kind: EdgeType) -> bool {
     matches!(kind, EdgeType::SubclassOf | EdgeType::Mixin)
 }
 
 /// `inverse` is symmetric — draw a head at both ends; every other
 /// kind points at its target only.
-fn edge_both_ends(kind: EdgeType) -> bool {
+pub(crate) fn edge_both_ends(kind: EdgeType) -> bool {
     matches!(kind, EdgeType::Inverse)
 }
 
@@ -113,13 +118,13 @@ fn label_font_size(base: f64, scale: f64, hovered: bool, min_readable: f64) -> O
 /// `node.radius * mult * scale` and the label stays proportional to the
 /// rendered node (small at the fit view, growing as you zoom in) rather
 /// than an arbitrary multiple of the zoom alone.
-const NODE_LABEL_RADIUS_MULT: f64 = 1.3;
+pub(crate) const NODE_LABEL_RADIUS_MULT: f64 = 1.3;
 /// Edge-label multiple of an endpoint node's world radius — a touch
 /// smaller than node labels so relation names don't dominate.
-const EDGE_LABEL_RADIUS_MULT: f64 = 1.1;
+pub(crate) const EDGE_LABEL_RADIUS_MULT: f64 = 1.1;
 
 /// CSS `rgba(...)` string from a normalized `[r, g, b, a]` color.
-fn rgba(c: [f32; 4]) -> String {
+pub(crate) fn rgba(c: [f32; 4]) -> String {
     format!(
         "rgba({}, {}, {}, {})",
         (c[0] * 255.0) as u8,
@@ -142,7 +147,7 @@ pub(crate) enum NodeShape {
 /// circle, `Slot` → pill, `Enum` → diamond; a node with no
 /// `KindMetadata` is a `Type` node → rectangle (the only metadata-less
 /// kind the graph emits).
-fn node_shape(kind: Option<&KindMetadata>) -> NodeShape {
+pub(crate) fn node_shape(kind: Option<&KindMetadata>) -> NodeShape {
     match kind {
         Some(KindMetadata::Class { .. }) => NodeShape::Circle,
         Some(KindMetadata::Slot { .. }) => NodeShape::Pill,
@@ -881,9 +886,6 @@ impl Canvas2DRenderer {
     /// with no rows are omitted entirely, header included.
     pub fn render_legend_spec(&self, dpr: f64, spec: &crate::legend::LegendSpec) {
         let lw = |w: f64| w / dpr;
-        const TEXT: &str = "rgba(232, 232, 244, 0.95)";
-        const HEADER: &str = "rgba(150, 150, 178, 0.95)";
-        const BORDER: &str = "rgba(255, 255, 255, 0.6)";
         const BODY_FONT: &str = "12px system-ui, -apple-system, sans-serif";
         const HEADER_FONT: &str = "bold 11px system-ui, -apple-system, sans-serif";
         let label_x = 64.0;
@@ -905,7 +907,7 @@ impl Canvas2DRenderer {
         let node_rows = crate::legend::node_rows_for(spec);
         if !node_rows.is_empty() {
             self.ctx.set_font(HEADER_FONT);
-            self.ctx.set_fill_style_str(HEADER);
+            self.ctx.set_fill_style_str(LEGEND_HEADER);
             let _ = self.ctx.fill_text("Nodes", 12.0, y);
             y += row;
         }
@@ -915,11 +917,11 @@ impl Canvas2DRenderer {
             self.ctx.set_fill_style_str(&rgba(*fill));
             self.ctx.fill();
             self.set_dash(*dashed);
-            self.ctx.set_stroke_style_str(BORDER);
+            self.ctx.set_stroke_style_str(LEGEND_BORDER);
             self.ctx.set_line_width(lw(if *dashed { 1.5 } else { 1.0 }));
             self.ctx.stroke();
             self.set_dash(false);
-            self.ctx.set_fill_style_str(TEXT);
+            self.ctx.set_fill_style_str(LEGEND_TEXT);
             let _ = self.ctx.fill_text(label, label_x, y);
             y += row;
         }
@@ -929,7 +931,7 @@ impl Canvas2DRenderer {
         if !edges.is_empty() {
             y += crate::legend::legend_metrics::SECTION_GAP;
             self.ctx.set_font(HEADER_FONT);
-            self.ctx.set_fill_style_str(HEADER);
+            self.ctx.set_fill_style_str(LEGEND_HEADER);
             let _ = self.ctx.fill_text("Edges", 12.0, y);
             y += row;
         }
@@ -951,7 +953,7 @@ impl Canvas2DRenderer {
             if edge_both_ends(*kind) {
                 self.draw_head(x2, y, x1 - 6.0, y, 6.0, &color, hollow);
             }
-            self.ctx.set_fill_style_str(TEXT);
+            self.ctx.set_fill_style_str(LEGEND_TEXT);
             let _ = self.ctx.fill_text(label, label_x, y);
             y += row;
         }
@@ -960,7 +962,7 @@ impl Canvas2DRenderer {
         if spec.cardinality {
             y += crate::legend::legend_metrics::SECTION_GAP;
             self.ctx.set_font(HEADER_FONT);
-            self.ctx.set_fill_style_str(HEADER);
+            self.ctx.set_fill_style_str(LEGEND_HEADER);
             let _ = self.ctx.fill_text("Cardinality (range edges)", 12.0, y);
             y += row;
             self.ctx.set_font(BODY_FONT);
@@ -982,7 +984,7 @@ impl Canvas2DRenderer {
                 self.ctx.line_to(tip_x, y);
                 self.ctx.stroke();
                 self.draw_cardinality(cx1, y, tip_x + tr, y, tr, glyph, &ccolor);
-                self.ctx.set_fill_style_str(TEXT);
+                self.ctx.set_fill_style_str(LEGEND_TEXT);
                 let _ = self.ctx.fill_text(label, label_x, y);
                 y += row;
             }
@@ -993,7 +995,7 @@ impl Canvas2DRenderer {
         if !ring_rows.is_empty() {
             y += crate::legend::legend_metrics::SECTION_GAP;
             self.ctx.set_font(HEADER_FONT);
-            self.ctx.set_fill_style_str(HEADER);
+            self.ctx.set_fill_style_str(LEGEND_HEADER);
             let _ = self.ctx.fill_text("Rings", 12.0, y);
             y += row;
         }
@@ -1010,7 +1012,7 @@ impl Canvas2DRenderer {
             self.ctx.set_fill_style_str(&rgba(fill));
             self.ctx.fill();
             self.draw_ring(glyph_x, y, ring_r, color, lw(width));
-            self.ctx.set_fill_style_str(TEXT);
+            self.ctx.set_fill_style_str(LEGEND_TEXT);
             let _ = self.ctx.fill_text(label, label_x, y);
             y += row;
         }
@@ -1630,8 +1632,12 @@ mod tests {
             min: None,
             max: None,
             pattern: None,
+            unit: None,
             identifier: false,
             any_of: vec![],
+            all_of: vec![],
+            exactly_one_of: vec![],
+            none_of: vec![],
         };
         let enum_kind = KindMetadata::Enum {
             permissible_values: vec![],
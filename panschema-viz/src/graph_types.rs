@@ -179,10 +179,19 @@ pub enum KindMetadata {
         max: Option<u32>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pattern: Option<String>,
+        /// The slot's unit of measure, rendered readably (`kg (kilogram)`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<String>,
         #[serde(default, skip_serializing_if = "std::ops::Not::not")]
         identifier: bool,
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         any_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        all_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        exactly_one_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        none_of: Vec<String>,
     },
     /// Permissible values for a LinkML enum, in declaration order —
     /// each with its optional description and curie-expanded meaning.
@@ -775,6 +775,27 @@ fn sgd_component(
     (positions, width)
 }
 
+/// Final positions from a static (non-force-directed) layout, already
+/// rescaled into the world range by [`scale_to_world`]. `None` for
+/// [`LayoutAlgorithm::ForceDirected`], whose positions come from ticking
+/// the simulation, and for the unimplemented variants.
+pub fn static_positions(
+    algorithm: LayoutAlgorithm,
+    graph: &GraphData,
+    aspect_w: f32,
+    aspect_h: f32,
+) -> Option<Vec<(f32, f32)>> {
+    let mut positions = match algorithm {
+        LayoutAlgorithm::KamadaKawai => kamada_kawai(graph, aspect_w, aspect_h),
+        LayoutAlgorithm::Hierarchical => hierarchical(graph, aspect_w, aspect_h),
+        LayoutAlgorithm::Stress => stress_majorization(graph, aspect_w, aspect_h),
+        LayoutAlgorithm::Sgd => sgd(graph, aspect_w, aspect_h),
+        _ => return None,
+    };
+    scale_to_world(&mut positions, WORLD_TARGET_DIMENSION);
+    Some(positions)
+}
+
 /// Default target for [`scale_to_world`] in world units. Sized so the
 /// rendered layout fills the in-tree `CpuSimulation`'s world bounding
 /// box without clipping against its `MAX_RADIUS = 800` safety net.
@@ -7,6 +7,8 @@
 //!
 //! - **CPU Fallback**: 2D Canvas rendering with CPU force simulation (default)
 //! - **WebGPU** (optional): GPU-accelerated 3D rendering (with `webgpu` feature)
+//! - **Static SVG** ([`svg`]): the same notation drawn natively to an SVG
+//!   document, for `panschema generate --format svg`
 
 pub mod camera;
 mod canvas2d;
@@ -17,6 +19,7 @@ pub mod layout;
 mod legend;
 mod sim_common;
 mod simulation;
+pub mod svg;
 
 #[cfg(all(feature = "webgpu", target_arch = "wasm32"))]
 pub mod camera3d;
@@ -168,30 +171,10 @@ impl Visualization {
         // the per-tick physics; the simulation acts as a position
         // container plus drag/hover state. Aspect bias is baked into
         // the algorithm output (not the per-tick forces).
-        let mut static_positions: Option<Vec<(f32, f32)>> = match algorithm {
-            layout::LayoutAlgorithm::KamadaKawai => Some(layout::kamada_kawai(
-                &graph,
-                aspect_w as f32,
-                aspect_h as f32,
-            )),
-            layout::LayoutAlgorithm::Hierarchical => Some(layout::hierarchical(
-                &graph,
-                aspect_w as f32,
-                aspect_h as f32,
-            )),
-            layout::LayoutAlgorithm::Stress => Some(layout::stress_majorization(
-                &graph,
-                aspect_w as f32,
-                aspect_h as f32,
-            )),
-            layout::LayoutAlgorithm::Sgd => {
-                Some(layout::sgd(&graph, aspect_w as f32, aspect_h as f32))
-            }
-            _ => None,
-        };
+        let static_positions =
+            layout::static_positions(algorithm, &graph, aspect_w as f32, aspect_h as f32);
         let is_static_layout = static_positions.is_some();
-        if let Some(positions) = static_positions.as_mut() {
-            layout::scale_to_world(positions, layout::WORLD_TARGET_DIMENSION);
+        if let Some(positions) = &static_positions {
             simulation.freeze_at(positions);
         }
 
@@ -1280,8 +1263,12 @@ mod details_json_tests {
                 min: None,
                 max: None,
                 pattern: Some("^[A-Z]".into()),
+                unit: Some("kg (kilogram)".into()),
                 identifier: true,
                 any_of: vec!["Person".into(), "Organization".into()],
+                all_of: vec![],
+                exactly_one_of: vec![],
+                none_of: vec![],
             },
         );
         let json: serde_json::Value =
@@ -1298,6 +1285,7 @@ mod details_json_tests {
         // small; the JS card treats absent as `false`.
         assert!(km.get("multivalued").is_none());
         assert_eq!(km["pattern"], "^[A-Z]");
+        assert_eq!(km["unit"], "kg (kilogram)");
         assert_eq!(km["identifier"], true);
         assert_eq!(km["anyOf"], serde_json::json!(["Person", "Organization"]));
     }
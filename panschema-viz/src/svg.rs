@@ -0,0 +1,766 @@
+//! Static SVG export of the schema graph
+//!
+//! Lays the graph out on the CPU with one of the [`LayoutAlgorithm`]s and
+//! draws it in the canvas renderer's notation (ADR-005): the same node
+//! shapes and fills, edge hues, dashes and heads, crow's-foot terminators
+//! on `range` edges, amber rings on rule participants, and the adaptive
+//! legend beside the graph. Nothing here touches a browser, and the
+//! static layouts are deterministic, so one graph renders to the same
+//! bytes every time — an image a docs build can regenerate and a PR can
+//! diff.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use crate::canvas2d::{
+    AMBER, CANVAS_BG, CardinalityGlyph, EDGE_LABEL_RADIUS_MULT, LEGEND_BORDER, LEGEND_HEADER,
+    LEGEND_TEXT, NODE_LABEL_RADIUS_MULT, NodeShape, RING_OFF_RULE, RING_W_RULE, arrowhead_points,
+    cardinality_glyph, edge_both_ends, edge_dashed, edge_hollow_head, edge_rgb, node_shape, rgba,
+    unit,
+};
+use crate::graph_types::{EdgeType, GraphData, KindMetadata, NodeType};
+use crate::layout::{self, LayoutAlgorithm};
+use crate::legend::{self, legend_metrics};
+use crate::simulation::{CpuSimulation, SimNode};
+
+/// Image pixels per world unit. World layouts span about
+/// [`layout::WORLD_TARGET_DIMENSION`], so the graph comes out near
+/// 1200 px across with the canvas's 4-unit nodes at an 8 px radius.
+const SCALE: f64 = 2.0;
+/// Space around the graph, and between the graph and the legend.
+const MARGIN: f64 = 40.0;
+/// Tick budget for a force-directed layout; it normally settles well
+/// before this.
+const MAX_TICKS: usize = 3000;
+/// Node labels sit this far right of the node's rim, as on the canvas.
+const LABEL_GAP: f64 = 4.0;
+/// Estimated glyph width as a fraction of the font size — the same
+/// estimate the canvas sizes its label backgrounds with.
+const CHAR_WIDTH: f64 = 0.6;
+const FONT: &str = "-apple-system, BlinkMacSystemFont, sans-serif";
+
+/// Render `graph_json` (the graph-json wire format) as a standalone SVG
+/// document, laid out by `layout` — a [`LayoutAlgorithm`] identifier, or
+/// `auto` for [`layout::recommend_default_layout`]'s pick. `aspect_w` /
+/// `aspect_h` bias the layout's bounding box as they do in the browser.
+pub fn render_svg(
+    graph_json: &str,
+    layout: &str,
+    aspect_w: u32,
+    aspect_h: u32,
+) -> Result<String, String> {
+    let graph: GraphData = serde_json::from_str(graph_json)
+        .map_err(|e| format!("Failed to parse graph JSON: {}", e))?;
+    let algorithm = if layout == "auto" {
+        layout::recommend_default_layout(&graph)
+    } else {
+        LayoutAlgorithm::from_str(layout).map_err(|e| e.to_string())?
+    };
+    if !algorithm.is_implemented() {
+        return Err(format!(
+            "layout algorithm `{}` is not yet implemented",
+            algorithm.as_str()
+        ));
+    }
+    let (aspect_w, aspect_h) = (aspect_w.max(1), aspect_h.max(1));
+    let mut simulation = if aspect_w != aspect_h {
+        CpuSimulation::from_graph_data(&graph).with_aspect_ratio(aspect_w, aspect_h)
+    } else {
+        CpuSimulation::from_graph_data(&graph)
+    };
+    match layout::static_positions(algorithm, &graph, aspect_w as f32, aspect_h as f32) {
+        Some(positions) => simulation.freeze_at(&positions),
+        None => simulation.run_to_convergence(MAX_TICKS),
+    }
+    Ok(draw(&simulation, &graph.schema_name))
+}
+
+/// The whole document: graph on the left, legend on the right.
+fn draw(sim: &CpuSimulation, title: &str) -> String {
+    let (min, max) = extent(&sim.nodes);
+    let origin = (MARGIN - min.0, MARGIN - min.1);
+    let at = |node: &SimNode| {
+        (
+            node.x as f64 * SCALE + origin.0,
+            node.y as f64 * SCALE + origin.1,
+        )
+    };
+
+    let mut body = String::new();
+    draw_edges(&mut body, sim, &at);
+    draw_nodes(&mut body, sim, &at);
+    draw_edge_labels(&mut body, sim, &at);
+    draw_node_labels(&mut body, sim, &at);
+
+    let graph_w = max.0 - min.0 + 2.0 * MARGIN;
+    let graph_h = max.1 - min.1 + 2.0 * MARGIN;
+    let (legend_svg, legend_h) = draw_legend(&legend_spec_for(sim));
+    let (width, height) = if legend_svg.is_empty() {
+        (graph_w, graph_h)
+    } else {
+        (
+            graph_w + legend_metrics::WIDTH + MARGIN,
+            graph_h.max(legend_h + 2.0 * MARGIN),
+        )
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="{FONT}">"#,
+        w = num(width),
+        h = num(height)
+    );
+    let _ = writeln!(out, "<title>{}</title>", xml_escape(title));
+    let _ = writeln!(
+        out,
+        r#"<rect width="100%" height="100%" fill="{CANVAS_BG}"/>"#
+    );
+    out.push_str(&body);
+    if !legend_svg.is_empty() {
+        let _ = writeln!(
+            out,
+            r#"<g transform="translate({} {})">"#,
+            num(graph_w),
+            num(MARGIN)
+        );
+        out.push_str(&legend_svg);
+        out.push_str("</g>\n");
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Bounding box of every node and its label, in image pixels before the
+/// margin offset — so nothing the drawing puts down is clipped.
+fn extent(nodes: &[SimNode]) -> ((f64, f64), (f64, f64)) {
+    if nodes.is_empty() {
+        return ((0.0, 0.0), (0.0, 0.0));
+    }
+    let mut min = (f64::INFINITY, f64::INFINITY);
+    let mut max = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for node in nodes {
+        let (x, y) = (node.x as f64 * SCALE, node.y as f64 * SCALE);
+        let r = radius(node) + RING_OFF_RULE + RING_W_RULE;
+        let font = node_font(node);
+        let label_w = node.label.chars().count() as f64 * font * CHAR_WIDTH;
+        min.0 = min.0.min(x - r);
+        min.1 = min.1.min(y - r.max(font / 2.0));
+        max.0 = max.0.max(x + radius(node) + LABEL_GAP + label_w);
+        max.1 = max.1.max(y + r.max(font / 2.0));
+    }
+    (min, max)
+}
+
+fn radius(node: &SimNode) -> f64 {
+    node.radius as f64 * SCALE
+}
+
+fn node_font(node: &SimNode) -> f64 {
+    radius(node) * NODE_LABEL_RADIUS_MULT
+}
+
+/// Edges as the canvas draws them: parallel edges bow apart as quadratic
+/// curves, each end inset to its node's rim, and the target end carries a
+/// crow's-foot (a slot's `range`) or an arrowhead.
+fn draw_edges(out: &mut String, sim: &CpuSimulation, at: &impl Fn(&SimNode) -> (f64, f64)) {
+    let mut parallel: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, e) in sim.edges.iter().enumerate() {
+        let key = (e.source.min(e.target), e.source.max(e.target));
+        parallel.entry(key).or_default().push(i);
+    }
+    for (edge_idx, edge) in sim.edges.iter().enumerate() {
+        let source = &sim.nodes[edge.source];
+        let target = &sim.nodes[edge.target];
+        let (src, tgt) = (at(source), at(target));
+        let (source_radius, target_radius) = (radius(source), radius(target));
+        let (r, g, b) = edge_rgb(edge.edge_type);
+        let stroke = format!("rgba({r}, {g}, {b}, 0.85)");
+
+        let (dx, dy) = (tgt.0 - src.0, tgt.1 - src.1);
+        let straight_len = (dx * dx + dy * dy).sqrt();
+        if straight_len < f64::EPSILON {
+            continue;
+        }
+        let group = &parallel[&(edge.source.min(edge.target), edge.source.max(edge.target))];
+        let bow = if group.len() > 1 {
+            let pos = group.iter().position(|&i| i == edge_idx).unwrap_or(0) as f64;
+            let centered = pos - (group.len() as f64 - 1.0) / 2.0;
+            centered * (target_radius * 2.5).max(20.0)
+        } else {
+            0.0
+        };
+        let mid = ((src.0 + tgt.0) / 2.0, (src.1 + tgt.1) / 2.0);
+        let (perp_x, perp_y) = (-dy / straight_len, dx / straight_len);
+        let control = (mid.0 + perp_x * bow, mid.1 + perp_y * bow);
+
+        if straight_len > source_radius + target_radius {
+            let src_tan = unit(control.0 - src.0, control.1 - src.1);
+            let tgt_tan = unit(tgt.0 - control.0, tgt.1 - control.1);
+            let _ = writeln!(
+                out,
+                r#"<path d="M{} {} Q{} {} {} {}" fill="none" stroke="{stroke}" stroke-width="1.5"{}/>"#,
+                num(src.0 + src_tan.0 * source_radius),
+                num(src.1 + src_tan.1 * source_radius),
+                num(control.0),
+                num(control.1),
+                num(tgt.0 - tgt_tan.0 * target_radius),
+                num(tgt.1 - tgt_tan.1 * target_radius),
+                dash(edge_dashed(edge.edge_type)),
+            );
+        }
+
+        let range_cardinality = (edge.edge_type == EdgeType::Range)
+            .then(|| match source.kind_metadata.as_ref() {
+                Some(KindMetadata::Slot {
+                    required,
+                    multivalued,
+                    min,
+                    max,
+                    ..
+                }) => Some(cardinality_glyph(*required, *multivalued, *min, *max)),
+                _ => None,
+            })
+            .flatten();
+        if let Some(glyph) = range_cardinality {
+            cardinality(out, control, tgt, target_radius, &glyph, &stroke);
+        } else {
+            let hollow = edge_hollow_head(edge.edge_type);
+            head(out, control, tgt, target_radius, &stroke, hollow);
+            if edge_both_ends(edge.edge_type) {
+                head(out, control, src, source_radius, &stroke, hollow);
+            }
+        }
+    }
+}
+
+/// Nodes: the kind's shape and fill, an amber ring when a rule touches
+/// it, and a dashed outline for abstract classes and outside nodes.
+fn draw_nodes(out: &mut String, sim: &CpuSimulation, at: &impl Fn(&SimNode) -> (f64, f64)) {
+    for node in &sim.nodes {
+        let (cx, cy) = at(node);
+        let r = radius(node);
+        if node.in_rule {
+            ring(out, (cx, cy), r + RING_OFF_RULE, AMBER, RING_W_RULE);
+        }
+        let shape = if node.node_type == NodeType::External {
+            NodeShape::Circle
+        } else {
+            node_shape(node.kind_metadata.as_ref())
+        };
+        let (border, width, dashed) = if node.node_type == NodeType::External {
+            ("rgba(200, 205, 220, 0.6)", 1.5, true)
+        } else if node.is_abstract {
+            ("rgba(255, 255, 255, 0.55)", 1.5, true)
+        } else {
+            ("rgba(255, 255, 255, 0.3)", 1.0, false)
+        };
+        shape_svg(
+            out,
+            (cx, cy),
+            r,
+            shape,
+            &format!(
+                r#"fill="{}" stroke="{border}" stroke-width="{width}"{}"#,
+                rgba(node.color),
+                dash(dashed)
+            ),
+        );
+    }
+}
+
+fn draw_edge_labels(out: &mut String, sim: &CpuSimulation, at: &impl Fn(&SimNode) -> (f64, f64)) {
+    for edge in &sim.edges {
+        let source = &sim.nodes[edge.source];
+        let (x1, y1) = at(source);
+        let (x2, y2) = at(&sim.nodes[edge.target]);
+        let (mx, my) = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+        let font = radius(source) * EDGE_LABEL_RADIUS_MULT;
+        let padding = 2.0;
+        let w = edge.label.chars().count() as f64 * font * CHAR_WIDTH + padding * 2.0;
+        let h = font + padding * 2.0;
+        let _ = writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="rgba(26, 26, 46, 0.85)"/>"#,
+            num(mx - w / 2.0),
+            num(my - h / 2.0),
+            num(w),
+            num(h)
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" font-size="{}" fill="rgba(180, 180, 200, 0.9)" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+            num(mx),
+            num(my),
+            num(font),
+            xml_escape(&edge.label)
+        );
+    }
+}
+
+fn draw_node_labels(out: &mut String, sim: &CpuSimulation, at: &impl Fn(&SimNode) -> (f64, f64)) {
+    for node in &sim.nodes {
+        let (cx, cy) = at(node);
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" font-size="{}" fill="rgba(255, 255, 255, 0.9)" dominant-baseline="middle">{}</text>"#,
+            num(cx + radius(node) + LABEL_GAP),
+            num(cy),
+            num(node_font(node)),
+            xml_escape(&node.label)
+        );
+    }
+}
+
+/// The adaptive key for a static image: hover and selection are
+/// interactive states it can never show, so only the persistent rule ring
+/// keeps a ring row.
+fn legend_spec_for(sim: &CpuSimulation) -> legend::LegendSpec {
+    let mut spec = legend::legend_spec(sim);
+    spec.selection_ring = false;
+    spec
+}
+
+/// The legend panel and its height — the canvas key's rows, metrics and
+/// glyphs, drawn at 1:1. Empty when the spec has no rows.
+fn draw_legend(spec: &legend::LegendSpec) -> (String, f64) {
+    let label_x = 64.0;
+    let glyph_x = 26.0;
+    let glyph_r = 9.0;
+    let row = legend_metrics::ROW;
+    let mut y = legend_metrics::TOP_Y;
+    let mut out = String::new();
+    let mut first_section = true;
+    let mut header = |out: &mut String, y: &mut f64, text: &str| {
+        if !first_section {
+            *y += legend_metrics::SECTION_GAP;
+        }
+        first_section = false;
+        let _ = writeln!(
+            out,
+            r#"<text x="12" y="{}" font-size="11" font-weight="bold" fill="{LEGEND_HEADER}" dominant-baseline="middle">{text}</text>"#,
+            num(*y)
+        );
+        *y += row;
+    };
+    let label = |out: &mut String, y: f64, text: &str| {
+        let _ = writeln!(
+            out,
+            r#"<text x="{label_x}" y="{}" font-size="12" fill="{LEGEND_TEXT}" dominant-baseline="middle">{}</text>"#,
+            num(y),
+            xml_escape(text)
+        );
+    };
+
+    let node_rows = legend::node_rows_for(spec);
+    if !node_rows.is_empty() {
+        header(&mut out, &mut y, "Nodes");
+    }
+    for (_, shape, fill, text, dashed) in &node_rows {
+        shape_svg(
+            &mut out,
+            (glyph_x, y),
+            glyph_r,
+            *shape,
+            &format!(
+                r#"fill="{}" stroke="{LEGEND_BORDER}" stroke-width="{}"{}"#,
+                rgba(*fill),
+                if *dashed { 1.5 } else { 1.0 },
+                dash(*dashed)
+            ),
+        );
+        label(&mut out, y, text);
+        y += row;
+    }
+
+    let edges = legend::edge_rows_for(spec);
+    if !edges.is_empty() {
+        header(&mut out, &mut y, "Edges");
+    }
+    let (x1, x2) = (12.0, 46.0);
+    for (kind, text) in &edges {
+        let (r, g, b) = edge_rgb(*kind);
+        let color = format!("rgba({r}, {g}, {b}, 0.95)");
+        let _ = writeln!(
+            out,
+            r#"<path d="M{x1} {y} L{x2} {y}" stroke="{color}" stroke-width="1.5"{}/>"#,
+            dash(edge_dashed(*kind)),
+            y = num(y)
+        );
+        let hollow = edge_hollow_head(*kind);
+        head(&mut out, (x1, y), (x2 + 6.0, y), 6.0, &color, hollow);
+        if edge_both_ends(*kind) {
+            head(&mut out, (x2, y), (x1 - 6.0, y), 6.0, &color, hollow);
+        }
+        label(&mut out, y, text);
+        y += row;
+    }
+
+    if spec.cardinality {
+        header(&mut out, &mut y, "Cardinality (range edges)");
+        let (r, g, b) = edge_rgb(EdgeType::Range);
+        let color = format!("rgba({r}, {g}, {b}, 0.95)");
+        let (cx1, tip_x, tr) = (12.0, 40.0, 8.0);
+        for (glyph, text) in [
+            (CardinalityGlyph::MandatoryOne, "1..1  exactly one"),
+            (CardinalityGlyph::OptionalOne, "0..1  at most one"),
+            (CardinalityGlyph::MandatoryMany, "1..*  one or more"),
+            (CardinalityGlyph::OptionalMany, "0..*  any number"),
+        ] {
+            let _ = writeln!(
+                out,
+                r#"<path d="M{cx1} {y} L{tip_x} {y}" stroke="{color}" stroke-width="1.5"/>"#,
+                y = num(y)
+            );
+            cardinality(&mut out, (cx1, y), (tip_x + tr, y), tr, &glyph, &color);
+            label(&mut out, y, text);
+            y += row;
+        }
+    }
+
+    let rings: Vec<_> = legend::ring_rows_for(spec)
+        .into_iter()
+        .filter(|(color, width, ..)| *color == AMBER && *width == RING_W_RULE)
+        .collect();
+    if !rings.is_empty() {
+        header(&mut out, &mut y, "Rings");
+    }
+    for (color, width, shape, fill, text) in rings {
+        let node_r = glyph_r * 0.55;
+        shape_svg(
+            &mut out,
+            (glyph_x, y),
+            node_r,
+            shape,
+            &format!(r#"fill="{}""#, rgba(fill)),
+        );
+        ring(&mut out, (glyph_x, y), node_r + 3.0, color, width);
+        label(&mut out, y, text);
+        y += row;
+    }
+
+    if out.is_empty() {
+        return (out, 0.0);
+    }
+    // The same walk `legend_extent` sizes the canvas panel with.
+    let height = y + legend_metrics::BOTTOM_PAD;
+    let panel = format!(
+        "<rect width=\"{}\" height=\"{}\" rx=\"6\" fill=\"{CANVAS_BG}\" stroke=\"{LEGEND_BORDER}\" stroke-opacity=\"0.3\"/>\n",
+        num(legend_metrics::WIDTH),
+        num(height)
+    );
+    (panel + &out, height)
+}
+
+/// A node outline of `shape` sized to sit within `r` of `c`, mirroring
+/// the canvas's `node_path`.
+fn shape_svg(out: &mut String, c: (f64, f64), r: f64, shape: NodeShape, attrs: &str) {
+    let (cx, cy) = c;
+    let _ = match shape {
+        NodeShape::Circle => writeln!(
+            out,
+            r#"<circle cx="{}" cy="{}" r="{}" {attrs}/>"#,
+            num(cx),
+            num(cy),
+            num(r)
+        ),
+        NodeShape::Rectangle => {
+            let (hw, hh) = (r * 0.8, r * 0.6);
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {attrs}/>"#,
+                num(cx - hw),
+                num(cy - hh),
+                num(hw * 2.0),
+                num(hh * 2.0)
+            )
+        }
+        NodeShape::Diamond => writeln!(
+            out,
+            r#"<polygon points="{},{} {},{} {},{} {},{}" {attrs}/>"#,
+            num(cx),
+            num(cy - r),
+            num(cx + r),
+            num(cy),
+            num(cx),
+            num(cy + r),
+            num(cx - r),
+            num(cy)
+        ),
+        NodeShape::Pill => {
+            let (hw, rr) = (r * 0.9, r * 0.5);
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" {attrs}/>"#,
+                num(cx - hw),
+                num(cy - rr),
+                num(hw * 2.0),
+                num(rr * 2.0),
+                num(rr)
+            )
+        }
+    };
+}
+
+fn ring(out: &mut String, c: (f64, f64), r: f64, color: &str, width: f64) {
+    let _ = writeln!(
+        out,
+        r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{color}" stroke-width="{width}"/>"#,
+        num(c.0),
+        num(c.1),
+        num(r)
+    );
+}
+
+/// An arrowhead from `from` toward `to`, its tip on the rim `r` out from
+/// `to`: hollow for inheritance, filled otherwise. Always solid.
+fn head(out: &mut String, from: (f64, f64), to: (f64, f64), r: f64, color: &str, hollow: bool) {
+    let Some([tip, b1, b2]) = arrowhead_points(from.0, from.1, to.0, to.1, r) else {
+        return;
+    };
+    let paint = if hollow {
+        format!(r#"fill="{CANVAS_BG}" stroke="{color}" stroke-width="1.5""#)
+    } else {
+        format!(r#"fill="{color}""#)
+    };
+    let _ = writeln!(
+        out,
+        r#"<polygon points="{},{} {},{} {},{}" {paint}/>"#,
+        num(tip.0),
+        num(tip.1),
+        num(b1.0),
+        num(b1.1),
+        num(b2.0),
+        num(b2.1)
+    );
+}
+
+/// The crow's-foot terminator for a `range` edge, laid out back from the
+/// target rim exactly as the canvas's `draw_cardinality` does.
+fn cardinality(
+    out: &mut String,
+    from: (f64, f64),
+    to: (f64, f64),
+    target_radius: f64,
+    glyph: &CardinalityGlyph,
+    color: &str,
+) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let len = (dx * dx + dy * dy).sqrt();
+    if len < f64::EPSILON {
+        return;
+    }
+    let (ux, uy) = (dx / len, dy / len);
+    let perp = (-uy, ux);
+    let tip = (to.0 - ux * target_radius, to.1 - uy * target_radius);
+    let size = (target_radius * 0.8).max(5.0);
+    let hw = size * 0.5;
+    let at = |d: f64| (tip.0 - ux * d, tip.1 - uy * d);
+    let line = |out: &mut String, a: (f64, f64), b: (f64, f64)| {
+        let _ = writeln!(
+            out,
+            r#"<path d="M{} {} L{} {}" stroke="{color}" stroke-width="1.5"/>"#,
+            num(a.0),
+            num(a.1),
+            num(b.0),
+            num(b.1)
+        );
+    };
+    let bar = |out: &mut String, c: (f64, f64)| {
+        line(
+            out,
+            (c.0 + perp.0 * hw, c.1 + perp.1 * hw),
+            (c.0 - perp.0 * hw, c.1 - perp.1 * hw),
+        );
+    };
+    let circle = |out: &mut String, c: (f64, f64)| {
+        let _ = writeln!(
+            out,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{CANVAS_BG}" stroke="{color}" stroke-width="1.5"/>"#,
+            num(c.0),
+            num(c.1),
+            num(size * 0.32)
+        );
+    };
+    let foot = |out: &mut String| {
+        let apex = at(size);
+        for end in [
+            tip,
+            (tip.0 + perp.0 * hw, tip.1 + perp.1 * hw),
+            (tip.0 - perp.0 * hw, tip.1 - perp.1 * hw),
+        ] {
+            line(out, apex, end);
+        }
+    };
+    match glyph {
+        CardinalityGlyph::MandatoryOne => {
+            bar(out, at(size * 0.7));
+            bar(out, at(size * 1.4));
+        }
+        CardinalityGlyph::OptionalOne => {
+            bar(out, at(size * 0.7));
+            circle(out, at(size * 1.6));
+        }
+        CardinalityGlyph::MandatoryMany => {
+            foot(out);
+            bar(out, at(size * 1.5));
+        }
+        CardinalityGlyph::OptionalMany => {
+            foot(out);
+            circle(out, at(size * 1.9));
+        }
+        CardinalityGlyph::Text(text) => {
+            let (tx, ty) = at(size + 2.0);
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" font-size="10" fill="{color}">{}</text>"#,
+                num(tx + perp.0 * 8.0),
+                num(ty + perp.1 * 8.0),
+                xml_escape(text)
+            );
+        }
+    }
+}
+
+/// The canvas's 6-on/4-off line dash, as an attribute.
+fn dash(dashed: bool) -> &'static str {
+    if dashed {
+        r#" stroke-dasharray="6 4""#
+    } else {
+        ""
+    }
+}
+
+/// A coordinate rounded to a tenth of a pixel — plenty for an image, and
+/// short, stable text for a diff.
+fn num(v: f64) -> String {
+    let rounded = (v * 10.0).round() / 10.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        format!("{rounded}")
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `Person` is_a abstract `Agent`; slot `status` (required, in a rule)
+    /// ranges over enum `Status`.
+    const GRAPH: &str = r#"{
+        "schema_name": "people",
+        "format_version": "1.2",
+        "nodes": [
+            {"id": "class:Agent", "label": "Agent", "node_type": "class",
+             "color": [0.29, 0.565, 0.851, 0.7], "is_abstract": true,
+             "kind_metadata": {"kind": "class", "slots": [], "parents": [], "mixins": []}},
+            {"id": "class:Person", "label": "Person", "node_type": "class",
+             "color": [0.29, 0.565, 0.851, 1.0],
+             "kind_metadata": {"kind": "class", "slots": [], "parents": ["Agent"], "mixins": [],
+                "rules": [{"governedSlots": ["status"]}]}},
+            {"id": "slot:status", "label": "status", "node_type": "slot",
+             "color": [0.314, 0.784, 0.471, 1.0],
+             "kind_metadata": {"kind": "slot", "required": true}},
+            {"id": "enum:Status", "label": "Status <A&B>", "node_type": "enum",
+             "color": [0.608, 0.349, 0.714, 1.0],
+             "kind_metadata": {"kind": "enum", "permissibleValues": []}}
+        ],
+        "edges": [
+            {"source": "class:Person", "target": "class:Agent", "edge_type": "subclass_of"},
+            {"source": "slot:status", "target": "class:Person", "edge_type": "domain"},
+            {"source": "slot:status", "target": "enum:Status", "edge_type": "range"}
+        ]
+    }"#;
+
+    #[test]
+    fn renders_each_kind_with_the_canvas_notation() {
+        let svg = render_svg(GRAPH, "kamada-kawai", 16, 8).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        // Shapes: class circle, slot pill, enum diamond.
+        assert!(svg.contains("<circle"), "{svg}");
+        assert!(svg.contains(" rx=\""), "slot pill; got:\n{svg}");
+        assert!(svg.contains("<polygon"), "enum diamond; got:\n{svg}");
+        // The abstract class is outlined dashed, the rule slot ringed.
+        assert!(svg.contains(
+            r#"stroke="rgba(255, 255, 255, 0.55)" stroke-width="1.5" stroke-dasharray="6 4""#
+        ));
+        assert!(svg.contains(&format!(r#"fill="none" stroke="{AMBER}" stroke-width="2""#)));
+        // Labels are escaped text.
+        assert!(svg.contains(">Status &lt;A&amp;B&gt;</text>"), "{svg}");
+        // The legend lists only what the graph draws.
+        for row in [
+            "Class",
+            "Abstract class",
+            "Slot",
+            "Enum",
+            "is_a (subclass of)",
+            "range",
+            "1..1  exactly one",
+            "Slot in a rule",
+        ] {
+            assert!(
+                svg.contains(&format!(">{row}</text>")),
+                "legend row `{row}` missing"
+            );
+        }
+        for absent in [
+            "Type",
+            "Individual",
+            "mixin",
+            "Selected node",
+            "Rule participant (on hover)",
+        ] {
+            assert!(
+                !svg.contains(&format!(">{absent}</text>")),
+                "legend row `{absent}` should be omitted"
+            );
+        }
+    }
+
+    #[test]
+    fn static_layouts_render_identical_bytes_each_run() {
+        for layout in ["kamada-kawai", "stress", "sgd", "hierarchical"] {
+            assert_eq!(
+                render_svg(GRAPH, layout, 16, 8).unwrap(),
+                render_svg(GRAPH, layout, 16, 8).unwrap(),
+                "`{layout}` output should be deterministic"
+            );
+        }
+    }
+
+    #[test]
+    fn auto_and_force_directed_lay_out_and_unknown_layouts_fail() {
+        assert!(render_svg(GRAPH, "auto", 16, 8).is_ok());
+        assert!(render_svg(GRAPH, "force-directed", 1, 1).is_ok());
+        assert!(
+            render_svg(GRAPH, "circular", 16, 8)
+                .unwrap_err()
+                .contains("not yet implemented")
+        );
+        assert!(render_svg(GRAPH, "spiral", 16, 8).is_err());
+        assert!(render_svg("{", "sgd", 16, 8).is_err());
+    }
+
+    #[test]
+    fn an_empty_graph_is_a_legend_free_blank_image() {
+        let svg = render_svg(
+            r#"{"schema_name": "empty", "format_version": "1.2", "nodes": [], "edges": []}"#,
+            "sgd",
+            16,
+            8,
+        )
+        .unwrap();
+        assert!(svg.contains("<title>empty</title>"));
+        assert!(!svg.contains("<text"), "{svg}");
+    }
+}
@@ -1,16 +1,21 @@
+use std::io::{Read, Write};
 use std::path::PathBuf;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use panschema::mdbook::{InstallReport, run};
+use panschema::mdbook_preprocessor::{preprocess, supports_renderer};
 
-/// mdbook plugin for panschema: install a toolbar link from an mdbook
-/// book to its panschema-generated schema docs.
+/// mdbook plugin for panschema: embed schema elements in book chapters
+/// (run as a preprocessor) and install a toolbar link from an mdbook book
+/// to its panschema-generated schema docs.
 #[derive(Parser)]
 #[command(name = "mdbook-panschema", version, about)]
 struct Cli {
+    /// With no subcommand, run as mdbook's preprocessor: read
+    /// `[context, book]` JSON on stdin, write the book back to stdout.
     #[command(subcommand)]
-    command: Command,
+    command: Option<Command>,
 }
 
 #[derive(Subcommand)]
@@ -24,11 +29,27 @@ enum Command {
         #[arg(default_value = ".")]
         dir: PathBuf,
     },
+    /// Exit successfully when the preprocessor supports `renderer`; mdbook
+    /// asks this before each build.
+    Supports { renderer: String },
 }
 
 fn main() -> Result<()> {
     match Cli::parse().command {
-        Command::Install { dir } => {
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            let book = preprocess(&input)?;
+            std::io::stdout().write_all(book.as_bytes())?;
+            Ok(())
+        }
+        Some(Command::Supports { renderer }) => {
+            if !supports_renderer(&renderer) {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Install { dir }) => {
             match run(&dir)? {
                 InstallReport::Installed => println!(
                     "Installed schema-link assets into {} and wired book.toml.",
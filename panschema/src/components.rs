@@ -21,7 +21,7 @@
 
 use askama::Template;
 
-use panschema::html_writer::EntityRef;
+use panschema::html_writer::{CardLinks, EntityRef};
 
 /// Namespace prefix/IRI mapping.
 #[derive(Debug, Clone)]
@@ -165,6 +165,11 @@ pub struct HeaderComponent<'a> {
     /// Matches the field on the main `IndexTemplate`. The styleguide
     /// page sits at the output root, so `"./"` is the right value.
     pub site_root_href: &'a str,
+    /// Matches the field on the main `IndexTemplate`.
+    pub page_lang: &'a str,
+    /// Matches the field on the main `IndexTemplate`. The styleguide
+    /// carries no translations, so this is always empty here.
+    pub languages: &'a [String],
 }
 
 /// Footer component template.
@@ -187,6 +192,7 @@ pub struct MetadataCardComponent<'a> {
     pub iri: &'a str,
     pub version: Option<&'a str>,
     pub comment: Option<&'a str>,
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
 }
 
 /// Sidebar navigation component template.
@@ -200,6 +206,8 @@ pub struct SidebarComponent<'a> {
     pub types: &'a [EntityRef],
     pub individuals: &'a [EntityRef],
     pub namespaces: &'a [Namespace],
+    /// Matches IndexTemplate: the schema's declared subsets.
+    pub subsets: &'a [panschema::html_writer::SubsetData],
     /// Graph data JSON for visualization (None = no graph link in sidebar)
     pub graph_json: Option<&'a str>,
     /// Number of nodes in the graph (for sidebar badge)
@@ -254,10 +262,18 @@ pub struct ClassCardComponent<'a> {
     pub is_abstract: bool,
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
     pub rules: &'a [panschema::html_writer::RuleInClass],
     pub unique_keys: &'a [panschema::html_writer::UniqueKeyInClass],
+    pub expressions: &'a [panschema::html_writer::ClassExpressionRow],
+    pub used_by: &'a [panschema::html_writer::UsageRow],
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Property card component template.
@@ -275,17 +291,26 @@ pub struct SlotCardComponent<'a> {
     /// Members of an `any_of` union range; empty for single-range slots.
     pub any_of: &'a [RangeSpec],
     pub pattern: Option<&'a str>,
+    pub unit: Option<&'a panschema::html_writer::UnitData>,
+    pub array: Option<&'a str>,
     pub characteristics: &'a [String],
     pub mappings: &'a [panschema::html_writer::Mapping],
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
     /// The slot's `ifabsent` default, rendered readably; `None` hides the
     /// Default row.
     pub default: Option<&'a str>,
     /// Class rules that govern the slot; empty in the standalone preview.
     pub governing_rules: &'a [panschema::html_writer::GoverningRule],
+    pub used_by: &'a [panschema::html_writer::UsageRow],
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Individual card component template.
@@ -304,6 +329,9 @@ pub struct IndividualCardComponent<'a> {
     /// Whether type/property names link into the schema reference; a
     /// standalone preview always shows them linked.
     pub show_schema_sections: bool,
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Enum card component template.
@@ -316,8 +344,15 @@ pub struct EnumCardComponent<'a> {
     pub permissible_values: &'a [panschema::html_writer::PermissibleValueData],
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
+    pub used_by: &'a [panschema::html_writer::UsageRow],
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Type card component template.
@@ -332,8 +367,14 @@ pub struct TypeCardComponent<'a> {
     pub pattern: Option<&'a str>,
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Sample class data for styleguide previews.
@@ -352,10 +393,15 @@ pub struct SampleClass<'a> {
     pub is_abstract: bool,
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
     pub rules: &'a [panschema::html_writer::RuleInClass],
     pub unique_keys: &'a [panschema::html_writer::UniqueKeyInClass],
+    pub expressions: &'a [panschema::html_writer::ClassExpressionRow],
+    pub used_by: &'a [panschema::html_writer::UsageRow],
 }
 
 /// Sample property data for styleguide previews.
@@ -371,17 +417,23 @@ pub struct SampleSlot<'a> {
     /// Members of an `any_of` union range; empty for single-range slots.
     pub any_of: &'a [RangeSpec],
     pub pattern: Option<&'a str>,
+    pub unit: Option<&'a panschema::html_writer::UnitData>,
+    pub array: Option<&'a str>,
     pub characteristics: &'a [String],
     pub mappings: &'a [panschema::html_writer::Mapping],
     pub deprecated: Option<&'a str>,
     pub aliases: &'a [String],
+    pub in_subset: &'a [String],
     pub see_also: &'a [panschema::html_writer::ExternalLink],
     pub examples: &'a [panschema::linkml::Example],
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
+    pub translations: &'a [panschema::html_writer::Translation],
     /// The slot's `ifabsent` default, rendered readably; `None` hides the
     /// Default row.
     pub default: Option<&'a str>,
     /// Class rules that govern the slot; empty in previews.
     pub governing_rules: &'a [panschema::html_writer::GoverningRule],
+    pub used_by: &'a [panschema::html_writer::UsageRow],
 }
 
 /// Sample individual data for styleguide previews.
@@ -402,12 +454,16 @@ pub struct StyleGuideTemplate<'a> {
     pub iri: &'a str,
     pub version: Option<&'a str>,
     pub comment: Option<&'a str>,
+    /// Matches IndexTemplate. The styleguide's metadata card shows none.
+    pub editorial: Option<&'a panschema::html_writer::Editorial>,
     pub classes: &'a [EntityRef],
     pub slots: &'a [EntityRef],
     pub enums: &'a [EntityRef],
     pub types: &'a [EntityRef],
     pub individuals: &'a [EntityRef],
     pub namespaces: &'a [Namespace],
+    /// Matches IndexTemplate. The styleguide declares no subsets.
+    pub subsets: &'a [panschema::html_writer::SubsetData],
     /// Graph data JSON (None = no graph link in sidebar)
     pub graph_json: Option<&'a str>,
     /// Number of nodes in the graph (for sidebar badge)
@@ -425,6 +481,10 @@ pub struct StyleGuideTemplate<'a> {
     pub page_links: &'a [panschema::html_writer::PageLink],
     /// Matches IndexTemplate. Styleguide page sits at the output root.
     pub site_root_href: &'a str,
+    /// Matches IndexTemplate. The styleguide is in English.
+    pub page_lang: &'a str,
+    /// Matches IndexTemplate. Always empty for the styleguide page.
+    pub languages: &'a [String],
     pub instance_graph_json: Option<&'a str>,
     pub instance_node_count: usize,
     pub instance_edge_count: usize,
@@ -435,6 +495,9 @@ pub struct StyleGuideTemplate<'a> {
     pub instance_dataset_count: usize,
     pub instances_first: bool,
     pub show_schema_sections: bool,
+    /// Matches IndexTemplate. A standalone preview links every card by
+    /// its anchor.
+    pub links: &'a CardLinks,
 }
 
 /// Renders individual components for testing and preview.
@@ -448,6 +511,8 @@ impl ComponentRenderer {
             version_context: None,
             page_links: &[],
             site_root_href: "./",
+            page_lang: "en",
+            languages: &[],
         };
         Ok(template.render()?)
     }
@@ -474,6 +539,7 @@ impl ComponentRenderer {
             iri,
             version,
             comment,
+            editorial: None,
         };
         Ok(template.render()?)
     }
@@ -495,6 +561,7 @@ impl ComponentRenderer {
             types: &[],
             individuals,
             namespaces,
+            subsets: &[],
             graph_json: None, // No graph in component preview
             graph_node_count: 0,
             graph_edge_count: 0,
@@ -558,10 +625,16 @@ impl ComponentRenderer {
             is_abstract: false,
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &[],
+            editorial: None,
+            translations: &[],
             rules: &[],
             unique_keys: &[],
+            expressions: &[],
+            used_by: &[],
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -590,14 +663,21 @@ impl ComponentRenderer {
             range,
             any_of: &[],
             pattern: None,
+            unit: None,
+            array: None,
             characteristics,
             mappings: &[],
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &[],
+            editorial: None,
+            translations: &[],
             default,
             governing_rules: &[],
+            used_by: &[],
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -621,6 +701,7 @@ impl ComponentRenderer {
             types,
             property_values,
             show_schema_sections: true,
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -639,8 +720,13 @@ impl ComponentRenderer {
             permissible_values,
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &[],
+            editorial: None,
+            translations: &[],
+            used_by: &[],
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -663,8 +749,12 @@ impl ComponentRenderer {
             pattern,
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &[],
+            editorial: None,
+            translations: &[],
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -690,6 +780,7 @@ impl ComponentRenderer {
                     datatype: "string".to_string(),
                 }),
                 required: true,
+                recommended: false,
                 multivalued: false,
                 any_of: vec![],
                 suppressed: false,
@@ -697,6 +788,7 @@ impl ComponentRenderer {
                 refined_here: false,
                 origin: Some("mixin Named".to_string()),
                 description_tooltip: Some("Full legal name.".to_string()),
+                group_heading: None,
             },
             panschema::html_writer::SlotInClass {
                 name: "age".to_string(),
@@ -705,6 +797,7 @@ impl ComponentRenderer {
                     datatype: "integer".to_string(),
                 }),
                 required: false,
+                recommended: true,
                 multivalued: false,
                 any_of: vec![],
                 suppressed: false,
@@ -712,6 +805,7 @@ impl ComponentRenderer {
                 refined_here: true,
                 origin: None,
                 description_tooltip: None,
+                group_heading: Some("demographics".to_string()),
             },
         ];
         let class_mappings = vec![
@@ -760,6 +854,20 @@ impl ComponentRenderer {
             slots: vec!["given_name".to_string(), "family_name".to_string()],
             description: Some("a person is unique by full name.".to_string()),
         }];
+        let class_expressions = vec![panschema::html_writer::ClassExpressionRow {
+            label: "Disjoint with",
+            members: vec![
+                r##"<a href="#class-organization" class="entity-ref class-ref">Organization</a>"##
+                    .to_string(),
+            ],
+        }];
+        let class_editorial = panschema::html_writer::Editorial {
+            status: Some("bibo:draft".to_string()),
+            keywords: vec!["people".to_string(), "agents".to_string()],
+            comments: vec!["Covers living and historical persons alike.".to_string()],
+            created: Some("by orcid:0000-0002-1825-0097 on 2024-03-01".to_string()),
+            ..Default::default()
+        };
         let sample_class = SampleClass {
             id: "person",
             label: "Person",
@@ -775,10 +883,15 @@ impl ComponentRenderer {
             is_abstract: false,
             deprecated: None,
             aliases: &class_aliases,
+            in_subset: &[],
             see_also: &class_see_also,
             examples: &class_examples,
+            editorial: Some(&class_editorial),
+            translations: &[],
             rules: &class_rules,
             unique_keys: &class_unique_keys,
+            expressions: &class_expressions,
+            used_by: &[],
         };
 
         let domain = EntityRef::new("person", "Person");
@@ -797,14 +910,20 @@ impl ComponentRenderer {
             range: Some(&range),
             any_of: &[],
             pattern: None,
+            unit: None,
+            array: None,
             characteristics: &characteristics,
             mappings: &slot_mappings,
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &[],
+            editorial: None,
+            translations: &[],
             default: None,
             governing_rules: &[],
+            used_by: &[],
         };
 
         let domain2 = EntityRef::new("person", "Person");
@@ -826,14 +945,20 @@ impl ComponentRenderer {
             range: Some(&range2),
             any_of: &[],
             pattern: Some("^[A-Z][a-z]+$"),
+            unit: None,
+            array: None,
             characteristics: &empty_characteristics,
             mappings: &slot_mappings,
             deprecated: None,
             aliases: &[],
+            in_subset: &[],
             see_also: &[],
             examples: &data_slot_examples,
+            editorial: None,
+            translations: &[],
             default: Some("\"Anonymous\""),
             governing_rules: &[],
+            used_by: &[],
         };
 
         let ind_types = vec![EntityRef::new("person", "Person")];
@@ -860,12 +985,14 @@ impl ComponentRenderer {
             iri: &data.iri,
             version: data.version.as_deref(),
             comment: data.comment.as_deref(),
+            editorial: None,
             classes: &data.classes,
             slots: &data.slots,
             enums: &[],
             types: &[],
             individuals: &data.individuals,
             namespaces: &data.namespaces,
+            subsets: &[],
             graph_json: None, // No graph in styleguide
             graph_node_count: 0,
             graph_edge_count: 0,
@@ -886,6 +1013,9 @@ impl ComponentRenderer {
             version_context: None,
             page_links: &[],
             site_root_href: "./",
+            page_lang: "en",
+            languages: &[],
+            links: &CardLinks::default(),
         };
         Ok(template.render()?)
     }
@@ -1121,10 +1251,16 @@ mod tests {
                 is_abstract: true,
                 deprecated: None,
                 aliases: &[],
+                in_subset: &[],
                 see_also: &[],
                 examples: &[],
+                editorial: None,
+                translations: &[],
                 rules: &[],
                 unique_keys: &[],
+                expressions: &[],
+                used_by: &[],
+                links: &CardLinks::default(),
             };
             let html = template.render().unwrap();
             assert!(
@@ -1154,10 +1290,16 @@ mod tests {
                 is_abstract: false,
                 deprecated: Some("use Person instead"),
                 aliases: &[],
+                in_subset: &[],
                 see_also: &[],
                 examples: &[],
+                editorial: None,
+                translations: &[],
                 rules: &[],
                 unique_keys: &[],
+                expressions: &[],
+                used_by: &[],
+                links: &CardLinks::default(),
             };
             let html = deprecated.render().unwrap();
             assert!(
@@ -1214,10 +1356,16 @@ mod tests {
                 is_abstract: false,
                 deprecated: None,
                 aliases: &aliases,
+                in_subset: &[],
                 see_also: &see_also,
                 examples: &[],
+                editorial: None,
+                translations: &[],
                 rules: &[],
                 unique_keys: &[],
+                expressions: &[],
+                used_by: &[],
+                links: &CardLinks::default(),
             };
             let html = editorial.render().unwrap();
             assert!(
@@ -1239,6 +1387,7 @@ mod tests {
 
             let plain = ClassCardComponent {
                 aliases: &[],
+                in_subset: &[],
                 see_also: &[],
                 ..editorial
             };
@@ -1284,10 +1433,16 @@ mod tests {
                 is_abstract: false,
                 deprecated: None,
                 aliases: &[],
+                in_subset: &[],
                 see_also: &[],
                 examples: &examples,
+                editorial: None,
+                translations: &[],
                 rules: &[],
                 unique_keys: &[],
+                expressions: &[],
+                used_by: &[],
+                links: &CardLinks::default(),
             };
             let html = with_examples.render().unwrap();
             assert!(
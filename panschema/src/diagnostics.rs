@@ -21,9 +21,18 @@
 //! parameterized by the target format so the message names what was
 //! actually requested.
 //!
+//! **Where.** Every schema diagnostic carries the [`SourceLocation`] of
+//! the key it is about, looked up in the schema's
+//! [`crate::source_map::SourceMap`], and [`schema_load_diagnostics`]
+//! renders it as `file:line:col` plus the source line — in a schema split
+//! across imported files, the element's name alone doesn't say which file
+//! to open. A schema read without positions (OWL/Turtle) reports the same
+//! findings with no location.
+//!
 //! [`ClassDefinition`]: crate::linkml::ClassDefinition
 
-use crate::linkml::SchemaDefinition;
+use crate::linkml::{ClassDefinition, ClassExpression, SchemaDefinition};
+use crate::source_map::{SourceLocation, annotate};
 
 /// Class-level LinkML keys panschema parses but deliberately does NOT
 /// warn about — a **denylist that starts empty**.
@@ -44,6 +53,8 @@ pub struct UnmodeledConstruct {
     pub class: String,
     /// The LinkML key that is parsed but not modeled (and not ignored).
     pub construct: String,
+    /// Where the key was written.
+    pub location: Option<SourceLocation>,
 }
 
 impl UnmodeledConstruct {
@@ -89,6 +100,9 @@ pub struct CollidingSlot {
     /// Where each colliding definition lives, sorted: `` class `X` `` or
     /// `` top-level `slots:` ``.
     pub sites: Vec<String>,
+    /// The source position of each definition, one per entry of `sites`
+    /// (`None` where the reader recorded none).
+    pub locations: Vec<Option<SourceLocation>>,
 }
 
 impl CollidingSlot {
@@ -116,9 +130,13 @@ impl CollidingSlot {
 /// the name, mirroring how the RDF writers derive property IRIs.
 pub fn colliding_slot_definitions(schema: &SchemaDefinition) -> Vec<CollidingSlot> {
     use std::collections::BTreeMap;
-    // identity key -> (display name, sorted definition sites)
-    let mut sites: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
-    let mut record = |slot: &crate::linkml::SlotDefinition, name: &str, site: String| {
+    // identity key -> (display name, definition sites with their positions)
+    type Sites = Vec<(String, Option<SourceLocation>)>;
+    let mut sites: BTreeMap<String, (String, Sites)> = BTreeMap::new();
+    let mut record = |slot: &crate::linkml::SlotDefinition,
+                      name: &str,
+                      site: String,
+                      location: Option<SourceLocation>| {
         let key = match &slot.slot_uri {
             Some(uri) => format!("uri:{uri}"),
             None => format!("name:{name}"),
@@ -126,22 +144,39 @@ pub fn colliding_slot_definitions(schema: &SchemaDefinition) -> Vec<CollidingSlo
         let entry = sites
             .entry(key)
             .or_insert_with(|| (name.to_string(), Vec::new()));
-        entry.1.push(site);
+        entry.1.push((site, location));
     };
     for (name, slot) in &schema.slots {
-        record(slot, name, "top-level `slots:`".to_string());
+        record(
+            slot,
+            name,
+            "top-level `slots:`".to_string(),
+            schema.source_map.locate(&["slots", name]),
+        );
     }
     for (class_name, class) in &schema.classes {
         for (name, slot) in &class.attributes {
-            record(slot, name, format!("class `{class_name}`"));
+            record(
+                slot,
+                name,
+                format!("class `{class_name}`"),
+                schema
+                    .source_map
+                    .locate(&["classes", class_name, "attributes", name]),
+            );
         }
     }
     sites
         .into_values()
         .filter(|(_, s)| s.len() > 1)
-        .map(|(name, mut sites)| {
-            sites.sort();
-            CollidingSlot { name, sites }
+        .map(|(name, mut found)| {
+            found.sort_by(|a, b| a.0.cmp(&b.0));
+            let (sites, locations) = found.into_iter().unzip();
+            CollidingSlot {
+                name,
+                sites,
+                locations,
+            }
         })
         .collect()
 }
@@ -154,6 +189,8 @@ pub struct UntypedSlot {
     pub name: String,
     /// Where the slot is defined: `` class `X` `` or `` top-level `slots:` ``.
     pub site: String,
+    /// Where that definition was written.
+    pub location: Option<SourceLocation>,
 }
 
 impl UntypedSlot {
@@ -190,6 +227,7 @@ pub fn untyped_slots(schema: &SchemaDefinition) -> Vec<UntypedSlot> {
         for (name, slot) in crate::linkml_resolve::resolve_effective_slots(class, schema) {
             if untyped(&slot) {
                 out.push(UntypedSlot {
+                    location: slot_location(schema, Some(class_name.as_str()), &name, None),
                     name: name.clone(),
                     site: format!("class `{class_name}`"),
                 });
@@ -202,8 +240,246 @@ pub fn untyped_slots(schema: &SchemaDefinition) -> Vec<UntypedSlot> {
             out.push(UntypedSlot {
                 name: name.clone(),
                 site: "top-level `slots:`".to_string(),
+                location: slot_location(schema, None, name, None),
+            });
+        }
+    }
+    out
+}
+
+/// A slot whose `unit.ucum_code` is not valid UCUM syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUnitCode {
+    pub name: String,
+    /// Where the slot is defined: `` class `X` `` or `` top-level `slots:` ``.
+    pub site: String,
+    pub code: String,
+    /// What the UCUM parser rejected.
+    pub reason: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl InvalidUnitCode {
+    pub fn message(&self) -> String {
+        format!(
+            "slot `{}` ({}) declares `unit.ucum_code: {}`, which is not valid UCUM: {}; \
+             consumers that convert units will reject it",
+            self.name, self.site, self.code, self.reason
+        )
+    }
+}
+
+/// Slot `unit.ucum_code`s that fail [`crate::ucum::check`], read from the
+/// definitions as written — top-level `slots:`, class `attributes` and
+/// `slot_usage` — so each bad code is reported once, where it was written.
+pub fn invalid_unit_codes(schema: &SchemaDefinition) -> Vec<InvalidUnitCode> {
+    let mut out = Vec::new();
+    let mut check = |name: &str, slot: &crate::linkml::SlotDefinition, class: Option<&str>| {
+        let Some(code) = slot.unit.as_ref().and_then(|u| u.ucum_code.as_deref()) else {
+            return;
+        };
+        if let Err(reason) = crate::ucum::check(code) {
+            out.push(InvalidUnitCode {
+                name: name.to_string(),
+                site: class.map_or_else(
+                    || "top-level `slots:`".to_string(),
+                    |class| format!("class `{class}`"),
+                ),
+                code: code.to_string(),
+                reason,
+                location: slot_location(schema, class, name, Some("unit")),
             });
         }
+    };
+    for (name, slot) in &schema.slots {
+        check(name, slot, None);
+    }
+    for (class_name, class) in &schema.classes {
+        for (name, slot) in class.attributes.iter().chain(&class.slot_usage) {
+            check(name, slot, Some(class_name));
+        }
+    }
+    out
+}
+
+/// A slot whose `all_of` / `none_of` leave none of its range
+/// alternatives ([`crate::linkml_resolve::InducedRange::contradictory`]):
+/// no value can conform, and each writer would degrade the empty range
+/// its own way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContradictoryRange {
+    pub name: String,
+    /// The class whose definition or `slot_usage` contradicts itself.
+    pub class: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl ContradictoryRange {
+    pub fn message(&self) -> String {
+        format!(
+            "slot `{}` (class `{}`) admits no range: its `all_of` / `none_of` rule out \
+             every alternative its `range` or `any_of` names, so no value can conform",
+            self.name, self.class
+        )
+    }
+}
+
+/// Contradictory slot ranges, read from each class's resolved view and
+/// reported at the class that states the slot or refines it, not again at
+/// every class inheriting the contradiction.
+pub fn contradictory_ranges(schema: &SchemaDefinition) -> Vec<ContradictoryRange> {
+    let mut out = Vec::new();
+    for (class_name, class) in &schema.classes {
+        let resolved =
+            crate::linkml_resolve::resolve_effective_slots_with_provenance(class, schema);
+        for (name, rs) in resolved {
+            if rs.induced.contradictory
+                && !matches!(
+                    rs.provenance,
+                    crate::linkml_resolve::Provenance::Inherited { .. }
+                )
+            {
+                out.push(ContradictoryRange {
+                    location: slot_location(schema, Some(class_name.as_str()), &name, None),
+                    name,
+                    class: class_name.clone(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// A `structured_pattern` that names a setting the schema never defines.
+/// The loader leaves such a pattern unmaterialized — a regex with a literal
+/// `{name}` in it would constrain the wrong thing — so the constraint is
+/// missing from every output until the setting exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedPatternSetting {
+    /// The slot or type carrying the pattern.
+    pub name: String,
+    /// `` class `X` ``, `` top-level `slots:` `` or `` `types:` ``.
+    pub site: String,
+    /// The undefined setting names, in the order the syntax uses them.
+    pub missing: Vec<String>,
+    pub location: Option<SourceLocation>,
+}
+
+impl UnresolvedPatternSetting {
+    pub fn message(&self) -> String {
+        let missing = self
+            .missing
+            .iter()
+            .map(|name| format!("`{{{name}}}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "`{}` ({}) has a `structured_pattern` referencing {missing}, which no `settings:` \
+             entry defines; the pattern is dropped from every output until it is",
+            self.name, self.site
+        )
+    }
+}
+
+/// `structured_pattern`s — on top-level slots, class `attributes` and
+/// `slot_usage`, and types — whose interpolation names an undefined
+/// setting (see [`crate::linkml_resolve::interpolate_pattern`]).
+pub fn unresolved_pattern_settings(schema: &SchemaDefinition) -> Vec<UnresolvedPatternSetting> {
+    let missing = |expr: Option<&crate::linkml::PatternExpression>| {
+        expr.and_then(|expr| {
+            crate::linkml_resolve::interpolate_pattern(expr, &schema.settings).err()
+        })
+    };
+    let mut out = Vec::new();
+    let mut slot = |name: &str, def: &crate::linkml::SlotDefinition, class: Option<&str>| {
+        if let Some(names) = missing(def.structured_pattern.as_ref()) {
+            out.push(UnresolvedPatternSetting {
+                name: name.to_string(),
+                site: class.map_or_else(
+                    || "top-level `slots:`".to_string(),
+                    |class| format!("class `{class}`"),
+                ),
+                missing: names,
+                location: slot_location(schema, class, name, Some("structured_pattern")),
+            });
+        }
+    };
+    for (name, def) in &schema.slots {
+        slot(name, def, None);
+    }
+    for (class_name, class) in &schema.classes {
+        for (name, def) in class.attributes.iter().chain(&class.slot_usage) {
+            slot(name, def, Some(class_name));
+        }
+    }
+    for (name, ty) in &schema.types {
+        if let Some(names) = missing(ty.structured_pattern.as_ref()) {
+            out.push(UnresolvedPatternSetting {
+                name: name.clone(),
+                site: "`types:`".to_string(),
+                missing: names,
+                location: schema
+                    .source_map
+                    .locate(&["types", name, "structured_pattern"]),
+            });
+        }
+    }
+    out
+}
+
+/// A slot whose `equals_expression` does not parse. `validate` cannot check
+/// the computed value and `generate --infer` cannot fill it, so both skip
+/// the slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEqualsExpression {
+    pub name: String,
+    /// Where the slot is defined: `` class `X` `` or `` top-level `slots:` ``.
+    pub site: String,
+    pub expression: String,
+    /// What the expression parser rejected.
+    pub reason: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl InvalidEqualsExpression {
+    pub fn message(&self) -> String {
+        format!(
+            "slot `{}` ({}) has `equals_expression: {}`, which does not parse: {}; \
+             its values are neither checked nor inferred",
+            self.name, self.site, self.expression, self.reason
+        )
+    }
+}
+
+/// `equals_expression`s that [`crate::expression::Expression::parse`]
+/// rejects, read from the definitions as written — top-level `slots:`,
+/// class `attributes` and `slot_usage`.
+pub fn invalid_equals_expressions(schema: &SchemaDefinition) -> Vec<InvalidEqualsExpression> {
+    let mut out = Vec::new();
+    let mut check = |name: &str, slot: &crate::linkml::SlotDefinition, class: Option<&str>| {
+        let Some(expression) = slot.equals_expression.as_deref() else {
+            return;
+        };
+        if let Err(err) = crate::expression::Expression::parse(expression) {
+            out.push(InvalidEqualsExpression {
+                name: name.to_string(),
+                site: class.map_or_else(
+                    || "top-level `slots:`".to_string(),
+                    |class| format!("class `{class}`"),
+                ),
+                expression: expression.to_string(),
+                reason: err.to_string(),
+                location: slot_location(schema, class, name, Some("equals_expression")),
+            });
+        }
+    };
+    for (name, slot) in &schema.slots {
+        check(name, slot, None);
+    }
+    for (class_name, class) in &schema.classes {
+        for (name, slot) in class.attributes.iter().chain(&class.slot_usage) {
+            check(name, slot, Some(class_name));
+        }
     }
     out
 }
@@ -219,25 +495,52 @@ pub fn schema_load_diagnostics(schema: &SchemaDefinition) -> Vec<String> {
     out.extend(
         unmodeled_class_constructs(schema)
             .iter()
-            .map(|u| u.message()),
+            .map(|u| annotate(u.message(), u.location.as_slice())),
     );
     out.extend(
         unresolved_unique_key_slots(schema)
             .iter()
-            .map(|u| u.message()),
+            .map(|u| annotate(u.message(), u.location.as_slice())),
+    );
+    out.extend(
+        dangling_references(schema)
+            .iter()
+            .map(|d| annotate(d.message(), d.location.as_slice())),
     );
-    out.extend(dangling_references(schema).iter().map(|d| d.message()));
     out.extend(
         unchecked_specializations(schema)
             .iter()
-            .map(|u| u.message()),
+            .map(|u| annotate(u.message(), u.location.as_slice())),
+    );
+    out.extend(colliding_slot_definitions(schema).iter().map(|c| {
+        let located: Vec<SourceLocation> = c.locations.iter().flatten().cloned().collect();
+        annotate(c.message(), &located)
+    }));
+    out.extend(
+        untyped_slots(schema)
+            .iter()
+            .map(|u| annotate(u.message(), u.location.as_slice())),
+    );
+    out.extend(
+        invalid_unit_codes(schema)
+            .iter()
+            .map(|u| annotate(u.message(), u.location.as_slice())),
+    );
+    out.extend(
+        contradictory_ranges(schema)
+            .iter()
+            .map(|c| annotate(c.message(), c.location.as_slice())),
+    );
+    out.extend(
+        unresolved_pattern_settings(schema)
+            .iter()
+            .map(|u| annotate(u.message(), u.location.as_slice())),
     );
     out.extend(
-        colliding_slot_definitions(schema)
+        invalid_equals_expressions(schema)
             .iter()
-            .map(|c| c.message()),
+            .map(|u| annotate(u.message(), u.location.as_slice())),
     );
-    out.extend(untyped_slots(schema).iter().map(|u| u.message()));
     // The metamodel recommends at most one `tree_root` per schema. Several
     // are supported here — each dataset is read against the root it conforms
     // to — but the deviation from that "should" is stated, because upstream
@@ -249,21 +552,58 @@ pub fn schema_load_diagnostics(schema: &SchemaDefinition) -> Vec<String> {
         .map(|(name, _)| name)
         .collect();
     if roots.len() > 1 {
-        out.push(format!(
-            "schema declares {} `tree_root` classes ({}); the LinkML metamodel \
-             recommends at most one — panschema reads each dataset against the \
-             root it conforms to, but other LinkML tooling may not",
-            roots.len(),
-            roots
-                .iter()
-                .map(|r| format!("`{r}`"))
-                .collect::<Vec<_>>()
-                .join(", ")
+        let locations: Vec<SourceLocation> = roots
+            .iter()
+            .filter_map(|r| schema.source_map.locate(&["classes", r, "tree_root"]))
+            .collect();
+        out.push(annotate(
+            format!(
+                "schema declares {} `tree_root` classes ({}); the LinkML metamodel \
+                 recommends at most one — panschema reads each dataset against the \
+                 root it conforms to, but other LinkML tooling may not",
+                roots.len(),
+                roots
+                    .iter()
+                    .map(|r| format!("`{r}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            &locations,
         ));
     }
     out
 }
 
+/// Where the definition of `slot`, as `class` sees it, was written: the
+/// class's own `attributes:` or `slot_usage:` entry, else the top-level
+/// `slots:` entry, else the class itself — narrowed to `key` (`range`,
+/// `is_a`, …) when that key was written there. An inherited slot is
+/// located at its top-level definition, or at the class that uses it.
+fn slot_location(
+    schema: &SchemaDefinition,
+    class: Option<&str>,
+    slot: &str,
+    key: Option<&str>,
+) -> Option<SourceLocation> {
+    let map = &schema.source_map;
+    let narrowed = |base: &[&str]| {
+        let mut path = base.to_vec();
+        path.extend(key);
+        map.locate(&path)
+    };
+    if let Some(class) = class {
+        for section in ["attributes", "slot_usage"] {
+            if map.get(&["classes", class, section, slot]).is_some() {
+                return narrowed(&["classes", class, section, slot]);
+            }
+        }
+    }
+    if map.get(&["slots", slot]).is_some() {
+        return narrowed(&["slots", slot]);
+    }
+    class.and_then(|class| map.locate(&["classes", class]))
+}
+
 /// LinkML's standard built-in scalar types. A slot `range` naming one of
 /// these resolves without a class/enum/`types:` definition, so it is not a
 /// dangling reference. The full standard set is listed so a valid primitive
@@ -306,6 +646,8 @@ pub struct DanglingRef {
     pub kind: &'static str,
     /// The unresolved name.
     pub name: String,
+    /// Where the reference was written.
+    pub location: Option<SourceLocation>,
 }
 
 impl DanglingRef {
@@ -353,6 +695,7 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
             referrer: "schema".to_string(),
             kind: "default_range",
             name: default.to_string(),
+            location: schema.source_map.locate(&["default_range"]),
         });
     }
 
@@ -373,25 +716,43 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
                 referrer: format!("class `{class_name}`"),
                 kind: "is_a",
                 name: parent.clone(),
+                location: schema.source_map.locate(&["classes", class_name, "is_a"]),
             });
         }
-        for mixin in &class.mixins {
+        for (index, mixin) in class.mixins.iter().enumerate() {
             if !schema.classes.contains_key(mixin) {
                 out.push(DanglingRef {
                     referrer: format!("class `{class_name}`"),
                     kind: "mixin",
                     name: mixin.clone(),
+                    location: schema.source_map.locate(&[
+                        "classes",
+                        class_name,
+                        "mixins",
+                        &index.to_string(),
+                    ]),
                 });
             }
         }
     }
 
     // Slot-level references (top-level slots, then inline attributes).
-    let mut slots: Vec<(&str, &_)> = schema.slots.iter().map(|(n, s)| (n.as_str(), s)).collect();
-    for class in schema.classes.values() {
-        slots.extend(class.attributes.iter().map(|(n, s)| (n.as_str(), s)));
+    // Each carries its declaring class (`None` for a top-level slot), so
+    // the reference is located in the right definition.
+    let mut slots: Vec<(Option<&str>, &str, &_)> = schema
+        .slots
+        .iter()
+        .map(|(n, s)| (None, n.as_str(), s))
+        .collect();
+    for (class_name, class) in &schema.classes {
+        slots.extend(
+            class
+                .attributes
+                .iter()
+                .map(|(n, s)| (Some(class_name.as_str()), n.as_str(), s)),
+        );
     }
-    for (slot_name, slot) in slots {
+    for (class_name, slot_name, slot) in slots {
         if let Some(range) = &slot.range
             && !resolves_as_type(range)
         {
@@ -399,6 +760,7 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
                 referrer: format!("slot `{slot_name}`"),
                 kind: "range",
                 name: range.clone(),
+                location: slot_location(schema, class_name, slot_name, Some("range")),
             });
         }
         if let Some(parent) = &slot.is_a
@@ -408,6 +770,7 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
                 referrer: format!("slot `{slot_name}`"),
                 kind: "specializes",
                 name: parent.clone(),
+                location: slot_location(schema, class_name, slot_name, Some("is_a")),
             });
         }
         if let Some(inverse) = &slot.inverse
@@ -417,6 +780,7 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
                 referrer: format!("slot `{slot_name}`"),
                 kind: "inverse",
                 name: inverse.clone(),
+                location: slot_location(schema, class_name, slot_name, Some("inverse")),
             });
         }
     }
@@ -433,6 +797,13 @@ pub fn dangling_references(schema: &SchemaDefinition) -> Vec<DanglingRef> {
                     referrer: format!("slot `{slot_name}` (class `{class_name}`)"),
                     kind: "specializes",
                     name: parent.clone(),
+                    location: schema.source_map.locate(&[
+                        "classes",
+                        class_name,
+                        "slot_usage",
+                        slot_name,
+                        "is_a",
+                    ]),
                 });
             }
         }
@@ -795,6 +1166,7 @@ pub fn unverified_absences(
                 InstanceValue::Reference { target, .. } => Ok(target.clone()),
                 InstanceValue::Scalar(s) => Ok(crate::instances::scalar_to_display(s)),
                 InstanceValue::Unexpected(kind) => Err(*kind),
+                InstanceValue::Array(_) => Err("an array"),
             })
             .collect();
         let anchors = match anchors {
@@ -856,6 +1228,13 @@ pub fn unverified_absences(
                 });
                 continue;
             }
+            Some(InstanceValue::Array(_)) => {
+                out.uncheckable.push(UncheckableAbsence {
+                    referrer: inst.id.clone(),
+                    reason: "its `via` value is an array, not a class reference".to_string(),
+                });
+                continue;
+            }
             Some(InstanceValue::Scalar(s)) => Some(crate::instances::scalar_to_display(s)),
             Some(InstanceValue::Reference { target, .. }) => Some(target.clone()),
             None => None,
@@ -1112,6 +1491,7 @@ fn scan(schema: &SchemaDefinition, ignored: &[&str]) -> Vec<UnmodeledConstruct>
             found.push(UnmodeledConstruct {
                 class: class_name.clone(),
                 construct: key.clone(),
+                location: schema.source_map.locate(&["classes", class_name, key]),
             });
         }
     }
@@ -1125,71 +1505,115 @@ fn scan(schema: &SchemaDefinition, ignored: &[&str]) -> Vec<UnmodeledConstruct>
 pub struct UnprojectedConstruct {
     /// The class carrying the construct.
     pub class: String,
-    /// The construct name (`"rules"` or `"unique_keys"` today).
+    /// The construct name: `"rules"`, `"unique_keys"`, one of the boolean
+    /// combinators (`"any_of"` … `"none_of"`), `"union_of"` or
+    /// `"disjoint_with"`.
     pub construct: &'static str,
 }
 
 impl UnprojectedConstruct {
     /// A user-facing warning line naming the format that was actually
     /// requested — not a hardcoded one, so `--format rust` doesn't claim
-    /// an RDF-specific gap it has nothing to do with. For `rules` the
-    /// line also names the projection that does carry the constraint:
-    /// OWL has no native construct for conditional rules, so the `shacl`
-    /// output is the constraint-bearing RDF projection by design — and an
-    /// RDF consumer wanting one graph can union it with the ontology.
+    /// an RDF-specific gap it has nothing to do with. For `rules` and the
+    /// boolean combinators the line also names the projection that does
+    /// carry the constraint: OWL has no native construct for conditional
+    /// rules or slot-conditioned class expressions, so the `shacl` output
+    /// is the constraint-bearing RDF projection by design — and an RDF
+    /// consumer wanting one graph can union it with the ontology.
     pub fn message(&self, format: &str) -> String {
         let mut message = format!(
             "class `{}` declares `{}`, which panschema does not emit to the `{}` format",
             self.class, self.construct, format
         );
-        if self.construct == "rules" {
+        let shacl_carries = matches!(
+            self.construct,
+            "rules" | "any_of" | "all_of" | "exactly_one_of" | "none_of"
+        );
+        if shacl_carries && !format.eq_ignore_ascii_case("shacl") {
             message.push_str(" — the `shacl` format carries them as shapes");
         }
         message
     }
 }
 
+/// The OWL-family format ids — every serialization of
+/// [`crate::rdf_serializers::build_rdf_graph`].
+const OWL_FORMATS: [&str; 4] = ["ttl", "jsonld", "rdfxml", "ntriples"];
+
 /// Report every class-level construct that's IR-modeled but that `format`
 /// doesn't project — a second, narrower class of silent drop than
-/// [`unmodeled_class_constructs`]: `rules` and `unique_keys` are IR-modeled,
-/// so they never reach the `unmodeled` catch-all, but not every writer
-/// projects them (HTML and Postgres project both; SHACL projects `rules`
-/// only; the rest project neither). Empty for the formats that project the
-/// construct; call for every target format.
+/// [`unmodeled_class_constructs`]: `rules`, `unique_keys` and the class
+/// expressions are IR-modeled, so they never reach the `unmodeled`
+/// catch-all, but not every writer projects them. HTML renders them all;
+/// Postgres projects `rules` and `unique_keys`; SHACL projects everything
+/// but `unique_keys`; the OWL family states `union_of`, `disjoint_with` and
+/// combinators whose members are bare classes; JSON Schema and OpenAPI
+/// state combinators whose members never name a class. Empty for the
+/// formats that project the construct; call for every target format.
 pub fn classes_with_unprojected_constructs(
     schema: &SchemaDefinition,
     format: &str,
 ) -> Vec<UnprojectedConstruct> {
-    // HTML renders both constructs; Postgres projects both (`unique_keys`
-    // as UNIQUE, `rules` as conditional CHECK) — so neither format has an
-    // unprojected-construct gap here. Partial cases (an unresolvable
-    // unique-key slot, a rule that can't become a CHECK) are surfaced by
-    // their own per-construct diagnostics, not this blanket one.
-    if format.eq_ignore_ascii_case("html") || format.eq_ignore_ascii_case("postgres") {
-        return Vec::new();
-    }
-    // SHACL projects `rules` (as conditional shapes) but not `unique_keys`
-    // yet (SHACL Core has no cross-instance uniqueness) — so for shacl only
-    // `unique_keys` is still an unprojected gap.
-    let rules_projected = format.eq_ignore_ascii_case("shacl");
+    let format = format.to_ascii_lowercase();
     let mut found = Vec::new();
     for (class_name, class) in &schema.classes {
-        if !class.rules.is_empty() && !rules_projected {
-            found.push(UnprojectedConstruct {
-                class: class_name.clone(),
-                construct: "rules",
-            });
-        }
-        if !class.unique_keys.is_empty() {
-            found.push(UnprojectedConstruct {
-                class: class_name.clone(),
-                construct: "unique_keys",
-            });
+        let mut constructs: Vec<(&'static str, bool)> = vec![
+            ("rules", !class.rules.is_empty()),
+            ("unique_keys", !class.unique_keys.is_empty()),
+        ];
+        constructs.extend(
+            class
+                .combinators()
+                .iter()
+                .map(|(name, members)| (*name, !members.is_empty())),
+        );
+        constructs.push(("union_of", !class.union_of.is_empty()));
+        constructs.push(("disjoint_with", !class.disjoint_with.is_empty()));
+        for (construct, declared) in constructs {
+            if declared && !format_projects(&format, construct, class) {
+                found.push(UnprojectedConstruct {
+                    class: class_name.clone(),
+                    construct,
+                });
+            }
         }
     }
     found
 }
 
+/// Whether `format`'s writer projects `construct` as `class` declares it.
+/// Partial cases within a projected construct (an unresolvable unique-key
+/// slot, a rule that can't become a CHECK, an expression naming a slot the
+/// class lacks) are surfaced by their own per-writer diagnostics, not here.
+fn format_projects(format: &str, construct: &str, class: &ClassDefinition) -> bool {
+    let members = class
+        .combinators()
+        .into_iter()
+        .find(|(name, _)| *name == construct)
+        .map(|(_, members)| members)
+        .unwrap_or_default();
+    match (format, construct) {
+        ("html", _) => true,
+        // `unique_keys` as UNIQUE, `rules` as conditional CHECK.
+        ("postgres", "rules" | "unique_keys") => true,
+        // SHACL Core has no cross-instance uniqueness.
+        ("shacl", "unique_keys") => false,
+        ("shacl", _) => true,
+        (f, "union_of" | "disjoint_with") if OWL_FORMATS.contains(&f) => true,
+        // OWL states a union, intersection or complement of named classes;
+        // a slot condition would need a property restriction, and
+        // `exactly_one_of` has no single-axiom form.
+        (f, "any_of" | "all_of" | "none_of") if OWL_FORMATS.contains(&f) => {
+            members.iter().all(|m| m.named_class().is_some())
+        }
+        // A JSON document carries no class identity for `is_a` to test.
+        ("json-schema" | "openapi", "any_of" | "all_of" | "exactly_one_of" | "none_of") => {
+            !members.iter().any(ClassExpression::mentions_class)
+        }
+        _ => false,
+    }
+}
+
 /// A class using a specializing slot without its parent: the subset the
 /// schema states (`child is_a parent`) cannot be checked on that class's
 /// records, because there is no parent slot there to contain the values.
@@ -1201,6 +1625,8 @@ pub struct UncheckedSpecialization {
     pub child: String,
     /// The parent slot the class does not use.
     pub parent: String,
+    /// Where the specializing slot's definition was written.
+    pub location: Option<SourceLocation>,
 }
 
 impl UncheckedSpecialization {
@@ -1239,6 +1665,12 @@ pub fn unchecked_specializations(schema: &SchemaDefinition) -> Vec<UncheckedSpec
                     class: class_name.clone(),
                     child: child_name.clone(),
                     parent: parent.clone(),
+                    location: slot_location(
+                        schema,
+                        Some(class_name.as_str()),
+                        child_name,
+                        Some("is_a"),
+                    ),
                 });
             }
         }
@@ -1293,6 +1725,8 @@ pub struct UnresolvedKeySlot {
     pub key: String,
     /// The referenced slot name that isn't in the class's effective set.
     pub slot: String,
+    /// Where the key's slot list was written.
+    pub location: Option<SourceLocation>,
 }
 
 impl UnresolvedKeySlot {
@@ -1326,6 +1760,13 @@ pub fn unresolved_unique_key_slots(schema: &SchemaDefinition) -> Vec<UnresolvedK
                         class: class_name.clone(),
                         key: key_name.clone(),
                         slot: slot.clone(),
+                        location: schema.source_map.locate(&[
+                            "classes",
+                            class_name,
+                            "unique_keys",
+                            key_name,
+                            "unique_key_slots",
+                        ]),
                     });
                 }
             }
@@ -1399,6 +1840,140 @@ mod tests {
 
     use super::*;
 
+    /// Read through the YAML reader — unlike [`parse`], the schema carries
+    /// source positions.
+    fn read(yaml: &str) -> SchemaDefinition {
+        use crate::io::Reader;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("schema.yaml");
+        std::fs::write(&path, yaml).expect("write");
+        crate::yaml_reader::YamlReader::new()
+            .read(&path)
+            .expect("read schema")
+    }
+
+    /// Each load diagnostic names the file, line, and column of the key it
+    /// is about, and quotes the line — the element's name alone doesn't
+    /// say which of a dozen imported files to open.
+    #[test]
+    fn load_diagnostics_point_at_the_offending_key() {
+        let schema = read(
+            "name: s\nclasses:\n  Order:\n    attributes:\n      ships_to:\n        range: Adress\n",
+        );
+        let dangling = dangling_references(&schema);
+        let location = dangling[0].location.as_ref().expect("located");
+        assert_eq!((location.line, location.column), (6, 9));
+        assert!(location.file.ends_with("schema.yaml"));
+
+        let rendered = schema_load_diagnostics(&schema);
+        let line = rendered
+            .iter()
+            .find(|m| m.contains("Adress"))
+            .expect("dangling range reported");
+        assert!(
+            line.contains("schema.yaml:6:9") && line.contains("6 |         range: Adress"),
+            "the message carries file:line:col and the source line; got:\n{line}"
+        );
+    }
+
+    /// A malformed UCUM code is reported once, where it was written, with
+    /// the parser's reason; a well-formed one and a symbol-only unit pass.
+    #[test]
+    fn invalid_unit_codes_are_located_and_explained() {
+        let schema = read(
+            "name: s\nslots:\n  mass:\n    unit:\n      ucum_code: kgs\n  speed:\n    unit: {ucum_code: m/s}\nclasses:\n  Reading:\n    attributes:\n      temp:\n        unit: {symbol: degrees}\n",
+        );
+        let invalid = invalid_unit_codes(&schema);
+        assert_eq!(invalid.len(), 1, "{invalid:?}");
+        assert_eq!(invalid[0].name, "mass");
+        assert_eq!(invalid[0].reason, "`kgs` is not a UCUM unit");
+        let location = invalid[0].location.as_ref().expect("located");
+        assert_eq!(location.line, 4);
+
+        let rendered = schema_load_diagnostics(&schema);
+        assert!(
+            rendered
+                .iter()
+                .any(|m| m.contains("`unit.ucum_code: kgs`") && m.contains("schema.yaml:4")),
+            "{rendered:?}"
+        );
+    }
+
+    /// A slot whose `none_of` strikes its only range is reported once, at
+    /// the class that states it, and not at the subclass inheriting it.
+    #[test]
+    fn a_contradictory_range_is_reported_where_it_is_stated() {
+        let schema = read(
+            "name: s\nclasses:\n  Pet:\n    attributes:\n      owner:\n        range: Person\n        none_of:\n          - range: Person\n  Dog:\n    is_a: Pet\n  Person: {}\n",
+        );
+        let found = contradictory_ranges(&schema);
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert_eq!(
+            (found[0].name.as_str(), found[0].class.as_str()),
+            ("owner", "Pet")
+        );
+        assert_eq!(found[0].location.as_ref().map(|l| l.line), Some(5));
+        assert!(
+            schema_load_diagnostics(&schema)
+                .iter()
+                .any(|m| m.contains("slot `owner` (class `Pet`) admits no range")),
+        );
+    }
+
+    /// A structured pattern naming a setting nobody defines is reported
+    /// with the missing names; a resolvable one is not.
+    #[test]
+    fn unresolved_pattern_settings_name_the_missing_setting() {
+        let schema = read(
+            "name: s\nsettings:\n  digits: '\\d+'\nslots:\n  id:\n    structured_pattern:\n      syntax: '{prefix}:{digits}'\n      interpolated: true\n  code:\n    structured_pattern: {syntax: '{digits}', interpolated: true}\n",
+        );
+        let unresolved = unresolved_pattern_settings(&schema);
+        assert_eq!(unresolved.len(), 1, "{unresolved:?}");
+        assert_eq!(unresolved[0].name, "id");
+        assert_eq!(unresolved[0].missing, vec!["prefix".to_string()]);
+        assert_eq!(unresolved[0].location.as_ref().map(|l| l.line), Some(6));
+        assert!(unresolved[0].message().contains("`{prefix}`"));
+    }
+
+    /// A malformed `equals_expression` is located at its key and carries
+    /// the parser's reason; a well-formed one passes.
+    #[test]
+    fn invalid_equals_expressions_are_located_and_explained() {
+        let schema = read(
+            "name: s\nclasses:\n  Rectangle:\n    attributes:\n      width: {range: float}\n      area:\n        range: float\n        equals_expression: '{width} *'\n      double:\n        equals_expression: '{width} * 2'\n",
+        );
+        let invalid = invalid_equals_expressions(&schema);
+        assert_eq!(invalid.len(), 1, "{invalid:?}");
+        assert_eq!(invalid[0].name, "area");
+        assert_eq!(invalid[0].reason, "expression ends early at position 10");
+        assert_eq!(invalid[0].location.as_ref().map(|l| l.line), Some(8));
+        assert!(
+            schema_load_diagnostics(&schema)
+                .iter()
+                .any(|m| m.contains("`equals_expression: {width} *`")),
+        );
+    }
+
+    /// A colliding slot points at every definition site, in site order.
+    #[test]
+    fn colliding_slots_locate_each_definition() {
+        let schema = read(
+            "name: s\nslots:\n  id:\n    range: string\nclasses:\n  Image:\n    attributes:\n      id:\n        range: string\n",
+        );
+        let collisions = colliding_slot_definitions(&schema);
+        let lines: Vec<Option<usize>> = collisions[0]
+            .locations
+            .iter()
+            .map(|l| l.as_ref().map(|l| l.line))
+            .collect();
+        assert_eq!(collisions[0].locations.len(), collisions[0].sites.len());
+        assert_eq!(
+            lines,
+            vec![Some(8), Some(3)],
+            "class `Image` sorts before top-level `slots:`"
+        );
+    }
+
     fn parse(yaml: &str) -> SchemaDefinition {
         serde_norway::from_str(yaml).expect("parse schema")
     }
@@ -1418,6 +1993,7 @@ mod tests {
             vec![UnmodeledConstruct {
                 class: "C".to_string(),
                 construct: UNKNOWN_KEY.to_string(),
+                location: None,
             }]
         );
     }
@@ -1636,6 +2212,7 @@ mod tests {
         let msg = UnmodeledConstruct {
             class: "Deployment".to_string(),
             construct: "rules".to_string(),
+            location: None,
         }
         .message();
         assert!(
@@ -1649,11 +2226,13 @@ mod tests {
         let unmodeled = vec![UnmodeledConstruct {
             class: "C".to_string(),
             construct: "rules".to_string(),
+            location: None,
         }];
         let dangling = vec![DanglingRef {
             referrer: "slot `x`".to_string(),
             kind: "range",
             name: "Missing".to_string(),
+            location: None,
         }];
         let no_unmodeled: Vec<UnmodeledConstruct> = Vec::new();
         let no_dangling: Vec<DanglingRef> = Vec::new();
@@ -1680,6 +2259,7 @@ mod tests {
         let untyped = vec![UntypedSlot {
             name: "x".to_string(),
             site: "class `C`".to_string(),
+            location: None,
         }];
         assert!(
             should_fail_strict(&no_unmodeled, &no_dangling, &[], &untyped, true),
@@ -1702,10 +2282,12 @@ mod tests {
                 UntypedSlot {
                     name: "label".to_string(),
                     site: "class `Event`".to_string(),
+                    location: None,
                 },
                 UntypedSlot {
                     name: "note".to_string(),
                     site: "top-level `slots:`".to_string(),
+                    location: None,
                 },
             ],
             "both definition sites are named"
@@ -1778,6 +2360,7 @@ mod tests {
             vec![UntypedSlot {
                 name: "label".to_string(),
                 site: "class `Event`".to_string(),
+                location: None,
             }],
         );
     }
@@ -1795,6 +2378,7 @@ mod tests {
             vec![UntypedSlot {
                 name: "u".to_string(),
                 site: "top-level `slots:`".to_string(),
+                location: None,
             }],
         );
 
@@ -1881,6 +2465,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn class_expressions_are_flagged_only_where_the_format_cannot_state_them() {
+        // `Pet`'s `any_of` names bare classes (an OWL union) and its
+        // `exactly_one_of` tests slots (no class identity needed), so each
+        // format flags exactly the half it has no form for.
+        let schema = parse(
+            "name: s\nclasses:\n  Pet:\n    any_of:\n      - is_a: Cat\n      - is_a: Dog\n    \
+             exactly_one_of:\n      - slot_conditions:\n          chip: {required: true}\n      \
+             - slot_conditions:\n          tattoo: {required: true}\n    disjoint_with: [Plant]\n",
+        );
+        let flagged = |format: &str| -> Vec<&'static str> {
+            classes_with_unprojected_constructs(&schema, format)
+                .into_iter()
+                .map(|u| u.construct)
+                .collect()
+        };
+        assert_eq!(flagged("ttl"), vec!["exactly_one_of"]);
+        assert_eq!(flagged("json-schema"), vec!["any_of", "disjoint_with"]);
+        assert!(flagged("shacl").is_empty());
+        assert!(flagged("html").is_empty());
+        assert_eq!(
+            flagged("rust"),
+            vec!["any_of", "exactly_one_of", "disjoint_with"]
+        );
+    }
+
     #[test]
     fn classes_with_unprojected_constructs_empty_for_html() {
         // HTML is the one writer that fully projects both constructs
@@ -1950,6 +2560,7 @@ mod tests {
                 class: "Offering".to_string(),
                 key: "k".to_string(),
                 slot: "ghost".to_string(),
+                location: None,
             }]
         );
     }
@@ -1984,6 +2595,7 @@ mod tests {
             class: "Offering".to_string(),
             key: "k".to_string(),
             slot: "ghost".to_string(),
+            location: None,
         }
         .message();
         assert!(
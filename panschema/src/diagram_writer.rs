@@ -0,0 +1,632 @@
+//! Class-diagram writers: Mermaid, PlantUML and Graphviz DOT
+//!
+//! Static diagrams for design docs and READMEs, where the interactive
+//! viz can't run. All three read [`GraphWriter`]'s node/edge model, so a
+//! diagram shows what the schema graph does: each class with the scalar
+//! slots it declares itself, each enum with its values, `is_a` as
+//! generalization, mixins as (dashed) realization, and every slot ranging
+//! over a class or enum as an association labelled with the slot and the
+//! class's effective multiplicity for it.
+//!
+//! [`DiagramWriter::with_root`] narrows the diagram to one class's
+//! neighborhood ([`GraphData::neighborhood`]), every hop by default or
+//! [`DiagramWriter::with_depth`] hops. Elements and relations sort by
+//! name, so a regenerated diagram diffs cleanly.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::graph_writer::{EdgeType, GraphData, GraphWriter, KindMetadata, NodeType};
+use crate::io::{IoError, IoResult, Writer};
+use crate::linkml::SchemaDefinition;
+use crate::linkml_resolve::Cardinality;
+
+/// The diagram languages [`DiagramWriter`] emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagramFormat {
+    /// A Mermaid `classDiagram`, rendered inline by GitHub and most docs
+    /// sites.
+    Mermaid,
+    /// A PlantUML class diagram (`@startuml` … `@enduml`).
+    PlantUml,
+    /// A Graphviz `digraph` of record-shaped nodes.
+    Dot,
+}
+
+impl DiagramFormat {
+    /// The `--format` id: `mermaid`, `plantuml` or `dot`.
+    pub fn id(self) -> &'static str {
+        match self {
+            DiagramFormat::Mermaid => "mermaid",
+            DiagramFormat::PlantUml => "plantuml",
+            DiagramFormat::Dot => "dot",
+        }
+    }
+
+    /// The format a `--format` id names, case-insensitively.
+    pub fn from_id(id: &str) -> Option<Self> {
+        [
+            DiagramFormat::Mermaid,
+            DiagramFormat::PlantUml,
+            DiagramFormat::Dot,
+        ]
+        .into_iter()
+        .find(|f| f.id().eq_ignore_ascii_case(id))
+    }
+}
+
+/// Writer for class diagrams in one [`DiagramFormat`].
+pub struct DiagramWriter {
+    format: DiagramFormat,
+    root: Option<String>,
+    depth: Option<usize>,
+}
+
+impl DiagramWriter {
+    pub fn new(format: DiagramFormat) -> Self {
+        Self {
+            format,
+            root: None,
+            depth: None,
+        }
+    }
+
+    /// Draw only `class` and what it reaches; see [`Self::with_depth`].
+    pub fn with_root(mut self, class: impl Into<String>) -> Self {
+        self.root = Some(class.into());
+        self
+    }
+
+    /// How many hops from the root class to draw. Without a root the
+    /// whole schema is drawn and the depth is unused.
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The diagram's source text. Errors when the root names no class.
+    pub fn render(&self, schema: &SchemaDefinition) -> IoResult<String> {
+        let graph = GraphWriter::new().schema_to_graph(schema);
+        let graph = match &self.root {
+            Some(root) => {
+                if !schema.classes.contains_key(root) {
+                    return Err(IoError::Write(format!(
+                        "diagram root `{root}` is not a class in the schema"
+                    )));
+                }
+                graph.neighborhood(&format!("class:{root}"), self.depth.unwrap_or(usize::MAX))
+            }
+            None => graph,
+        };
+        let diagram = Diagram::from_graph(&graph);
+        Ok(match self.format {
+            DiagramFormat::Mermaid => diagram.mermaid(),
+            DiagramFormat::PlantUml => diagram.plantuml(),
+            DiagramFormat::Dot => diagram.dot(&schema.name),
+        })
+    }
+}
+
+impl Writer for DiagramWriter {
+    fn write(&self, schema: &SchemaDefinition, output: &Path) -> IoResult<()> {
+        let text = self.render(schema)?;
+        crate::io::ensure_output_parent(output)?;
+        std::fs::write(output, text).map_err(IoError::Io)?;
+        Ok(())
+    }
+
+    fn format_id(&self) -> &str {
+        self.format.id()
+    }
+}
+
+/// A class box: the class and the scalar slots it declares itself
+/// (inherited ones show on the ancestor's box).
+struct ClassBox {
+    name: String,
+    is_abstract: bool,
+    attributes: Vec<(String, Option<String>, String)>,
+}
+
+struct EnumBox {
+    name: String,
+    values: Vec<String>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Relation {
+    /// `child` is_a `parent`.
+    Generalization { child: String, parent: String },
+    /// `class` mixes in `mixin`.
+    Realization { class: String, mixin: String },
+    /// `class`'s slot `slot` ranges over `target`.
+    Association {
+        class: String,
+        target: String,
+        slot: String,
+        multiplicity: Option<String>,
+    },
+}
+
+/// The diagram-language-neutral picture every format renders.
+struct Diagram {
+    classes: Vec<ClassBox>,
+    enums: Vec<EnumBox>,
+    relations: Vec<Relation>,
+}
+
+impl Diagram {
+    fn from_graph(graph: &GraphData) -> Self {
+        let drawn: BTreeSet<&str> = graph
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::Class | NodeType::Enum))
+            .map(|n| n.id.as_str())
+            .collect();
+        let name_of = |id: &str| id.split_once(':').map_or(id, |(_, n)| n).to_string();
+        let multiplicity = |class_id: &str, slot: &str| {
+            graph
+                .nodes
+                .iter()
+                .find(|n| n.id == class_id)
+                .and_then(|n| match &n.kind_metadata {
+                    Some(KindMetadata::Class { slots, .. }) => {
+                        slots.iter().find(|s| s.name == slot)
+                    }
+                    _ => None,
+                })
+                .map(|s| {
+                    Cardinality {
+                        required: s.required,
+                        multivalued: s.multivalued,
+                        min: s.min,
+                        max: s.max,
+                    }
+                    .multiplicity()
+                })
+        };
+
+        let mut classes = Vec::new();
+        let mut enums = Vec::new();
+        for node in &graph.nodes {
+            match (&node.node_type, &node.kind_metadata) {
+                (NodeType::Class, Some(KindMetadata::Class { slots, .. })) => {
+                    let attributes = slots
+                        .iter()
+                        .filter(|s| s.origin.is_none())
+                        .filter(|s| {
+                            !s.range.as_deref().is_some_and(|r| {
+                                drawn.contains(format!("class:{r}").as_str())
+                                    || drawn.contains(format!("enum:{r}").as_str())
+                            })
+                        })
+                        .map(|s| {
+                            let card = Cardinality {
+                                required: s.required,
+                                multivalued: s.multivalued,
+                                min: s.min,
+                                max: s.max,
+                            };
+                            (s.name.clone(), s.range.clone(), card.multiplicity())
+                        })
+                        .collect();
+                    classes.push(ClassBox {
+                        name: name_of(&node.id),
+                        is_abstract: node.is_abstract,
+                        attributes,
+                    });
+                }
+                (NodeType::Enum, metadata) => enums.push(EnumBox {
+                    name: name_of(&node.id),
+                    values: match metadata {
+                        Some(KindMetadata::Enum { permissible_values }) => {
+                            permissible_values.iter().map(|v| v.text.clone()).collect()
+                        }
+                        _ => Vec::new(),
+                    },
+                }),
+                _ => {}
+            }
+        }
+        classes.sort_by(|a, b| a.name.cmp(&b.name));
+        enums.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut relations: Vec<Relation> = graph
+            .class_edges()
+            .into_iter()
+            .filter(|e| drawn.contains(e.source.as_str()) && drawn.contains(e.target.as_str()))
+            .filter_map(|e| match e.edge_type {
+                EdgeType::SubclassOf => Some(Relation::Generalization {
+                    child: name_of(&e.source),
+                    parent: name_of(&e.target),
+                }),
+                EdgeType::Mixin => Some(Relation::Realization {
+                    class: name_of(&e.source),
+                    mixin: name_of(&e.target),
+                }),
+                EdgeType::Range => {
+                    let slot = e.label?;
+                    Some(Relation::Association {
+                        multiplicity: multiplicity(&e.source, &slot),
+                        class: name_of(&e.source),
+                        target: name_of(&e.target),
+                        slot,
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+        relations.sort();
+        relations.dedup();
+        Diagram {
+            classes,
+            enums,
+            relations,
+        }
+    }
+
+    fn mermaid(&self) -> String {
+        let id = |name: &str| {
+            if is_plain_identifier(name) {
+                name.to_string()
+            } else {
+                format!("`{name}`")
+            }
+        };
+        let mut out = String::from("classDiagram\n");
+        for class in &self.classes {
+            // Mermaid wants no braces around an empty member list.
+            if !class.is_abstract && class.attributes.is_empty() {
+                let _ = writeln!(out, "    class {}", id(&class.name));
+                continue;
+            }
+            let _ = writeln!(out, "    class {} {{", id(&class.name));
+            if class.is_abstract {
+                out.push_str("        <<abstract>>\n");
+            }
+            for (name, range, card) in &class.attributes {
+                let _ = writeln!(out, "        {}", attribute(name, range.as_deref(), card));
+            }
+            out.push_str("    }\n");
+        }
+        for en in &self.enums {
+            let _ = writeln!(out, "    class {} {{", id(&en.name));
+            out.push_str("        <<enumeration>>\n");
+            for value in &en.values {
+                let _ = writeln!(out, "        {value}");
+            }
+            out.push_str("    }\n");
+        }
+        for relation in &self.relations {
+            let _ = match relation {
+                Relation::Generalization { child, parent } => {
+                    writeln!(out, "    {} <|-- {}", id(parent), id(child))
+                }
+                Relation::Realization { class, mixin } => {
+                    writeln!(out, "    {} <|.. {}", id(mixin), id(class))
+                }
+                Relation::Association {
+                    class,
+                    target,
+                    slot,
+                    multiplicity,
+                } => match multiplicity {
+                    Some(m) => {
+                        writeln!(out, "    {} --> \"{m}\" {} : {slot}", id(class), id(target))
+                    }
+                    None => writeln!(out, "    {} --> {} : {slot}", id(class), id(target)),
+                },
+            };
+        }
+        out
+    }
+
+    fn plantuml(&self) -> String {
+        let id = |name: &str| {
+            if is_plain_identifier(name) {
+                name.to_string()
+            } else {
+                format!("\"{name}\"")
+            }
+        };
+        let mut out = String::from("@startuml\nhide empty members\n");
+        for class in &self.classes {
+            let keyword = if class.is_abstract {
+                "abstract class"
+            } else {
+                "class"
+            };
+            let _ = writeln!(out, "{keyword} {} {{", id(&class.name));
+            for (name, range, card) in &class.attributes {
+                let _ = writeln!(out, "  {}", attribute(name, range.as_deref(), card));
+            }
+            out.push_str("}\n");
+        }
+        for en in &self.enums {
+            let _ = writeln!(out, "enum {} {{", id(&en.name));
+            for value in &en.values {
+                let _ = writeln!(out, "  {value}");
+            }
+            out.push_str("}\n");
+        }
+        for relation in &self.relations {
+            let _ = match relation {
+                Relation::Generalization { child, parent } => {
+                    writeln!(out, "{} <|-- {}", id(parent), id(child))
+                }
+                Relation::Realization { class, mixin } => {
+                    writeln!(out, "{} <|.. {}", id(mixin), id(class))
+                }
+                Relation::Association {
+                    class,
+                    target,
+                    slot,
+                    multiplicity,
+                } => match multiplicity {
+                    Some(m) => writeln!(out, "{} --> \"{m}\" {} : {slot}", id(class), id(target)),
+                    None => writeln!(out, "{} --> {} : {slot}", id(class), id(target)),
+                },
+            };
+        }
+        out.push_str("@enduml\n");
+        out
+    }
+
+    fn dot(&self, schema_name: &str) -> String {
+        let mut out = format!("digraph {} {{\n", dot_string(schema_name));
+        out.push_str("    rankdir=BT;\n");
+        out.push_str("    node [shape=record, fontname=\"Helvetica\", fontsize=10];\n");
+        out.push_str("    edge [fontname=\"Helvetica\", fontsize=9];\n");
+        for class in &self.classes {
+            let heading = if class.is_abstract {
+                format!("«abstract»\\n{}", record_escape(&class.name))
+            } else {
+                record_escape(&class.name)
+            };
+            let body: String = class
+                .attributes
+                .iter()
+                .map(|(name, range, card)| {
+                    format!(
+                        "{}\\l",
+                        record_escape(&attribute(name, range.as_deref(), card))
+                    )
+                })
+                .collect();
+            let _ = writeln!(
+                out,
+                "    {} [label=\"{{{heading}|{body}}}\"];",
+                dot_string(&class.name)
+            );
+        }
+        for en in &self.enums {
+            let body: String = en
+                .values
+                .iter()
+                .map(|v| format!("{}\\l", record_escape(v)))
+                .collect();
+            let _ = writeln!(
+                out,
+                "    {} [label=\"{{«enumeration»\\n{}|{body}}}\"];",
+                dot_string(&en.name),
+                record_escape(&en.name)
+            );
+        }
+        for relation in &self.relations {
+            let _ = match relation {
+                Relation::Generalization { child, parent } => writeln!(
+                    out,
+                    "    {} -> {} [arrowhead=empty];",
+                    dot_string(child),
+                    dot_string(parent)
+                ),
+                Relation::Realization { class, mixin } => writeln!(
+                    out,
+                    "    {} -> {} [arrowhead=empty, style=dashed];",
+                    dot_string(class),
+                    dot_string(mixin)
+                ),
+                Relation::Association {
+                    class,
+                    target,
+                    slot,
+                    multiplicity,
+                } => {
+                    let label = match multiplicity {
+                        Some(m) => format!("\"{}\\n{m}\"", dot_escape(slot)),
+                        None => dot_string(slot),
+                    };
+                    writeln!(
+                        out,
+                        "    {} -> {} [arrowhead=vee, label={label}];",
+                        dot_string(class),
+                        dot_string(target)
+                    )
+                }
+            };
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// A scalar slot's line in a class box: `name : range [multiplicity]`.
+fn attribute(name: &str, range: Option<&str>, multiplicity: &str) -> String {
+    match range {
+        Some(range) => format!("{name} : {range} [{multiplicity}]"),
+        None => format!("{name} [{multiplicity}]"),
+    }
+}
+
+/// Whether `name` can stand unquoted as a Mermaid or PlantUML class name.
+fn is_plain_identifier(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `text` as a double-quoted DOT string.
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", dot_escape(text))
+}
+
+/// `text` made literal inside a double-quoted DOT string.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// `text` made literal inside a DOT record label, where braces, bars
+/// and angle brackets are structure.
+fn record_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{ClassDefinition, EnumDefinition, PermissibleValue, SlotDefinition};
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        let mut status = EnumDefinition::new("Status");
+        status
+            .permissible_values
+            .insert("ACTIVE".to_string(), PermissibleValue::new("ACTIVE"));
+        schema.enums.insert("Status".to_string(), status);
+
+        let mut agent = ClassDefinition::new("Agent");
+        agent.r#abstract = true;
+        let mut name = SlotDefinition::new("name");
+        name.range = Some("string".to_string());
+        name.required = true;
+        agent.attributes.insert("name".to_string(), name);
+        schema.classes.insert("Agent".to_string(), agent);
+        schema
+            .classes
+            .insert("Named".to_string(), ClassDefinition::new("Named"));
+
+        let mut person = ClassDefinition::new("Person");
+        person.is_a = Some("Agent".to_string());
+        person.mixins = vec!["Named".to_string()];
+        let mut state = SlotDefinition::new("state");
+        state.range = Some("Status".to_string());
+        person.attributes.insert("state".to_string(), state);
+        let mut friends = SlotDefinition::new("friends");
+        friends.range = Some("Person".to_string());
+        friends.multivalued = true;
+        person.attributes.insert("friends".to_string(), friends);
+        schema.classes.insert("Person".to_string(), person);
+
+        let mut org = ClassDefinition::new("Organization");
+        org.is_a = Some("Agent".to_string());
+        schema.classes.insert("Organization".to_string(), org);
+        schema
+    }
+
+    fn render(writer: DiagramWriter) -> String {
+        writer.render(&schema()).unwrap()
+    }
+
+    #[test]
+    fn mermaid_draws_inheritance_mixins_and_ranged_slots() {
+        let out = render(DiagramWriter::new(DiagramFormat::Mermaid));
+        assert!(out.starts_with("classDiagram\n"), "{out}");
+        assert!(
+            out.contains(
+                "    class Agent {\n        <<abstract>>\n        name : string [1]\n    }"
+            ),
+            "{out}"
+        );
+        assert!(out.contains("    class Status {\n        <<enumeration>>\n        ACTIVE\n"));
+        assert!(out.contains("    class Named\n"), "{out}");
+        assert!(out.contains("    Agent <|-- Person\n"), "{out}");
+        assert!(out.contains("    Named <|.. Person\n"), "{out}");
+        assert!(
+            out.contains("    Person --> \"0..1\" Status : state\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("    Person --> \"0..*\" Person : friends\n"),
+            "{out}"
+        );
+        assert!(
+            !out.contains("state : "),
+            "a ranged slot is an association, not an attribute"
+        );
+    }
+
+    #[test]
+    fn plantuml_and_dot_carry_the_same_picture() {
+        let uml = render(DiagramWriter::new(DiagramFormat::PlantUml));
+        assert!(uml.starts_with("@startuml\n") && uml.ends_with("@enduml\n"));
+        assert!(
+            uml.contains("abstract class Agent {\n  name : string [1]\n}"),
+            "{uml}"
+        );
+        assert!(uml.contains("enum Status {\n  ACTIVE\n}"), "{uml}");
+        assert!(uml.contains("Person --> \"0..1\" Status : state"), "{uml}");
+
+        let dot = render(DiagramWriter::new(DiagramFormat::Dot));
+        assert!(dot.starts_with("digraph \"people\" {"), "{dot}");
+        assert!(
+            dot.contains("\"Agent\" [label=\"{«abstract»\\nAgent|name : string [1]\\l}\"];"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\"Person\" -> \"Agent\" [arrowhead=empty];"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\"Person\" -> \"Named\" [arrowhead=empty, style=dashed];"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\"Person\" -> \"Status\" [arrowhead=vee, label=\"state\\n0..1\"];"),
+            "{dot}"
+        );
+    }
+
+    #[test]
+    fn a_root_and_depth_limit_the_diagram() {
+        let out = render(
+            DiagramWriter::new(DiagramFormat::Mermaid)
+                .with_root("Organization")
+                .with_depth(1),
+        );
+        assert!(out.contains("class Agent"), "{out}");
+        assert!(!out.contains("class Person"), "two hops away; got:\n{out}");
+        let out = render(DiagramWriter::new(DiagramFormat::Mermaid).with_root("Organization"));
+        assert!(
+            out.contains("class Person") && out.contains("class Status"),
+            "{out}"
+        );
+
+        let err = DiagramWriter::new(DiagramFormat::Dot)
+            .with_root("Nobody")
+            .render(&schema())
+            .unwrap_err();
+        assert!(err.to_string().contains("`Nobody`"), "{err}");
+    }
+
+    #[test]
+    fn format_ids_round_trip() {
+        for format in [
+            DiagramFormat::Mermaid,
+            DiagramFormat::PlantUml,
+            DiagramFormat::Dot,
+        ] {
+            assert_eq!(DiagramFormat::from_id(format.id()), Some(format));
+        }
+        assert_eq!(
+            DiagramFormat::from_id("PlantUML"),
+            Some(DiagramFormat::PlantUml)
+        );
+        assert_eq!(DiagramFormat::from_id("svg"), None);
+    }
+}
@@ -0,0 +1,791 @@
+//! Dynamic enum expansion: materialize `reachable_from`, `concepts`,
+//! `include` and `minus` into `permissible_values` at load.
+//!
+//! A LinkML enum may define its values intensionally — "every subclass
+//! of X in ontology Y" — instead of listing them. Every writer and the
+//! validator read `permissible_values` only, so the load path expands the
+//! expressions here, once, against local copies of the named ontologies:
+//! Rust enums, Postgres enum types, JSON Schema `enum`s and instance
+//! validation all see the same materialized value set.
+//!
+//! Expansion never touches the network. `panschema fetch` downloads each
+//! `source_ontology` URL into the ontology cache (`<cache root>/ontologies`,
+//! the same sha256-of-URL keying as the label cache) and pins each
+//! expansion's value set in `panschema.lock`; loading reads the cache, and
+//! `generate` warns when what it read no longer matches the pin. A
+//! `source_ontology` that is not a URL is read as a file path relative to
+//! the file declaring the enum — an import's own directory, for an enum an
+//! import declares. A document that is missing or unparseable contributes
+//! no values and is reported — the enum keeps whatever else it declares.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::labels::{LabelFetchError, LabelSource, source_key};
+use crate::linkml::{EnumDefinition, EnumExpression, PermissibleValue, SchemaDefinition};
+
+/// Edge followed by a `reachable_from` query that names no
+/// `relationship_types`.
+const RDFS_SUB_CLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+
+#[derive(Debug, Error)]
+pub enum OntologyCacheError {
+    #[error("failed to create ontology cache dir {dir}: {source}")]
+    CreateDir {
+        dir: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to write ontology cache file {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error(transparent)]
+    Fetch(#[from] LabelFetchError),
+}
+
+/// On-disk cache of fetched ontology documents: one file per source URL,
+/// named by the SHA-256 of the URL, stored as served (Turtle or RDF/XML).
+pub struct OntologyCache {
+    dir: PathBuf,
+}
+
+impl OntologyCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The production cache, `<cache root>/ontologies`. `None` when no
+    /// cache root can be determined.
+    pub fn open_default() -> Option<Self> {
+        crate::cache::cache_root()
+            .ok()
+            .map(|root| Self::new(root.join("ontologies")))
+    }
+
+    fn path_for(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{}.rdf", source_key(url)))
+    }
+
+    /// The cached document for `url`, if present.
+    pub fn read(&self, url: &str) -> Option<String> {
+        fs::read_to_string(self.path_for(url)).ok()
+    }
+
+    /// The document at `location`: a cached copy for a URL, the file
+    /// itself for a path.
+    pub fn read_location(&self, location: &OntologyLocation) -> Option<String> {
+        match location {
+            OntologyLocation::Url(url) => self.read(url),
+            OntologyLocation::File(path) => fs::read_to_string(path).ok(),
+        }
+    }
+
+    /// Download `url` through `source` and store it, replacing any cached
+    /// copy.
+    pub fn fetch(&self, url: &str, source: &dyn LabelSource) -> Result<(), OntologyCacheError> {
+        let body = source.fetch(url)?;
+        fs::create_dir_all(&self.dir).map_err(|source| OntologyCacheError::CreateDir {
+            dir: self.dir.clone(),
+            source,
+        })?;
+        let path = self.path_for(url);
+        fs::write(&path, body).map_err(|source| OntologyCacheError::Write { path, source })
+    }
+}
+
+/// Where a `source_ontology` resolves to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OntologyLocation {
+    /// Fetched by `panschema fetch`, read from the [`OntologyCache`].
+    Url(String),
+    /// A local document, read directly.
+    File(PathBuf),
+}
+
+impl std::fmt::Display for OntologyLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(url) => f.write_str(url),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Resolve a `source_ontology` value: a URL as is, a CURIE through the
+/// schema's prefixes, anything else as a path relative to `base_dir`.
+pub fn resolve_location(
+    schema: &SchemaDefinition,
+    source_ontology: &str,
+    base_dir: &Path,
+) -> OntologyLocation {
+    let is_url = |v: &str| v.starts_with("http://") || v.starts_with("https://");
+    if is_url(source_ontology) {
+        return OntologyLocation::Url(source_ontology.to_string());
+    }
+    if let Some(path) = source_ontology.strip_prefix("file://") {
+        return OntologyLocation::File(base_dir.join(path));
+    }
+    if let Some((prefix, _)) = source_ontology.split_once(':')
+        && schema.prefixes.contains_key(prefix)
+        && let Some(expanded) = crate::linkml_resolve::expand_curie(schema, source_ontology)
+        && is_url(&expanded)
+    {
+        return OntologyLocation::Url(expanded);
+    }
+    OntologyLocation::File(base_dir.join(source_ontology))
+}
+
+/// The directory a dynamic enum's relative `source_ontology` is read
+/// against: that of the file declaring the enum, as the source map
+/// recorded it, or `root_dir` (the root schema's) for a schema read
+/// without positions.
+fn declaring_dir(schema: &SchemaDefinition, enum_name: &str, root_dir: &Path) -> PathBuf {
+    schema
+        .source_map
+        .get(&["enums", enum_name])
+        .and_then(|location| location.file.parent())
+        .map_or_else(|| root_dir.to_path_buf(), Path::to_path_buf)
+}
+
+/// Every ontology location a dynamic enum of `schema` queries, so
+/// `panschema fetch` knows what to download. `base_dir` is the root
+/// schema's directory; see [`declaring_dir`].
+pub fn ontology_locations(
+    schema: &SchemaDefinition,
+    base_dir: &Path,
+) -> BTreeSet<OntologyLocation> {
+    fn walk(
+        schema: &SchemaDefinition,
+        expr: &EnumExpression,
+        base_dir: &Path,
+        out: &mut BTreeSet<OntologyLocation>,
+    ) {
+        if let Some(ontology) = expr
+            .reachable_from
+            .as_ref()
+            .and_then(|q| q.source_ontology.as_deref())
+        {
+            out.insert(resolve_location(schema, ontology, base_dir));
+        }
+        for nested in expr.include.iter().chain(&expr.minus) {
+            walk(schema, nested, base_dir, out);
+        }
+    }
+    let mut out = BTreeSet::new();
+    for (name, enum_def) in schema.enums.iter().filter(|(_, e)| e.is_dynamic()) {
+        let dir = declaring_dir(schema, name, base_dir);
+        walk(schema, &as_expression(enum_def), &dir, &mut out);
+    }
+    out
+}
+
+/// One dynamic enum's expansion, as pinned in the lockfile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumExpansion {
+    pub name: String,
+    /// The ontology documents the expansion read: URLs, and local paths
+    /// relative to the schema.
+    pub sources: Vec<String>,
+    /// `sha256:<hex>` over the expanded value texts, sorted.
+    pub checksum: String,
+}
+
+/// Outcome of [`expand_dynamic_enums`].
+#[derive(Debug, Default)]
+pub struct ExpansionReport {
+    pub enums: Vec<EnumExpansion>,
+    /// Missing documents, parse failures, unresolvable CURIEs and source
+    /// nodes the ontology does not mention — surfaced as load warnings.
+    pub problems: Vec<String>,
+}
+
+/// Expand every dynamic enum of `schema` in place, reading ontology
+/// documents through `read`. Values from the expressions are unioned
+/// with the enum's static `permissible_values` (a static entry wins over
+/// an expanded one of the same text); `minus` is applied last.
+/// `base_dir` is the root schema's directory: a relative
+/// `source_ontology` resolves against its declaring file's directory
+/// ([`declaring_dir`]), and local sources are reported relative to
+/// `base_dir`.
+pub fn expand_dynamic_enums(
+    schema: &mut SchemaDefinition,
+    base_dir: &Path,
+    read: &dyn Fn(&OntologyLocation) -> Option<String>,
+) -> ExpansionReport {
+    let mut report = ExpansionReport::default();
+    let mut expander = Expander {
+        schema: &*schema,
+        base_dir: base_dir.to_path_buf(),
+        read,
+        graphs: BTreeMap::new(),
+        problems: Vec::new(),
+    };
+    let mut expanded = Vec::new();
+    for (name, enum_def) in schema.enums.iter().filter(|(_, e)| e.is_dynamic()) {
+        let mut sources = BTreeSet::new();
+        expander.base_dir = declaring_dir(expander.schema, name, base_dir);
+        let values = expander.evaluate(name, &as_expression(enum_def), &mut sources);
+        expanded.push((name.clone(), values, sources));
+    }
+    report.problems = expander.problems;
+
+    for (name, values, sources) in expanded {
+        let enum_def = schema.enums.get_mut(&name).expect("expanded enum exists");
+        enum_def.permissible_values = values;
+        report.enums.push(EnumExpansion {
+            checksum: value_set_checksum(&enum_def.permissible_values),
+            // Local documents relative to the root schema, so the
+            // lockfile stays portable across checkouts.
+            sources: sources
+                .iter()
+                .map(|location| match location {
+                    OntologyLocation::Url(url) => url.clone(),
+                    OntologyLocation::File(path) => path
+                        .strip_prefix(base_dir)
+                        .unwrap_or(path)
+                        .display()
+                        .to_string(),
+                })
+                .collect(),
+            name,
+        });
+    }
+    report
+}
+
+/// Expand `schema`'s dynamic enums from the default ontology cache (and
+/// local files next to `schema_path`), returning the report. A schema
+/// without dynamic enums is left untouched.
+pub fn expand_from_default_cache(
+    schema: &mut SchemaDefinition,
+    schema_path: &Path,
+) -> ExpansionReport {
+    if !schema.enums.values().any(EnumDefinition::is_dynamic) {
+        return ExpansionReport::default();
+    }
+    let Some(cache) = OntologyCache::open_default() else {
+        return ExpansionReport {
+            problems: vec![
+                "no cache directory for ontology documents; dynamic enums keep their static \
+                 values"
+                    .to_string(),
+            ],
+            ..ExpansionReport::default()
+        };
+    };
+    let base_dir = schema_path.parent().unwrap_or(Path::new("."));
+    expand_dynamic_enums(schema, base_dir, &|location| cache.read_location(location))
+}
+
+/// `sha256:<hex>` over the value texts, newline-terminated, in key order.
+pub fn value_set_checksum(values: &BTreeMap<String, PermissibleValue>) -> String {
+    let mut hasher = Sha256::new();
+    for text in values.keys() {
+        hasher.update(text.as_bytes());
+        hasher.update(b"\n");
+    }
+    format!("sha256:{}", hex::encode(hasher.finalize()))
+}
+
+fn as_expression(enum_def: &EnumDefinition) -> EnumExpression {
+    EnumExpression {
+        permissible_values: enum_def.permissible_values.clone(),
+        reachable_from: enum_def.reachable_from.clone(),
+        concepts: enum_def.concepts.clone(),
+        include: enum_def.include.clone(),
+        minus: enum_def.minus.clone(),
+    }
+}
+
+/// The parts of an ontology document expansion needs: IRI-to-IRI edges
+/// and `rdfs:label`s.
+struct OntologyGraph {
+    edges: Vec<(String, String, String)>,
+    labels: BTreeMap<String, String>,
+}
+
+impl OntologyGraph {
+    fn parse(rdf: &str) -> Result<Self, String> {
+        use sophia::api::graph::Graph;
+        use sophia::api::term::Term;
+        use sophia::api::triple::Triple;
+
+        let graph = crate::labels::parse_rdf(rdf).map_err(|e| e.to_string())?;
+        let mut edges = Vec::new();
+        let mut labels = BTreeMap::new();
+        for triple in graph.triples().flatten() {
+            let (Some(s), Some(p)) = (triple.s().iri(), triple.p().iri()) else {
+                continue;
+            };
+            if let Some(o) = triple.o().iri() {
+                edges.push((
+                    s.as_str().to_string(),
+                    p.as_str().to_string(),
+                    o.as_str().to_string(),
+                ));
+            } else if p.as_str() == RDFS_LABEL
+                && triple
+                    .o()
+                    .language_tag()
+                    .is_none_or(|tag| tag.as_str().eq_ignore_ascii_case("en"))
+                && let Some(label) = triple.o().lexical_form()
+            {
+                labels
+                    .entry(s.as_str().to_string())
+                    .or_insert_with(|| label.to_string());
+            }
+        }
+        Ok(Self { edges, labels })
+    }
+
+    fn mentions(&self, iri: &str) -> bool {
+        self.labels.contains_key(iri) || self.edges.iter().any(|(s, _, o)| s == iri || o == iri)
+    }
+}
+
+struct Expander<'a> {
+    schema: &'a SchemaDefinition,
+    /// The directory of the file declaring the enum being evaluated.
+    base_dir: PathBuf,
+    read: &'a dyn Fn(&OntologyLocation) -> Option<String>,
+    /// Parsed documents by location; `None` records a failed read so it
+    /// is reported once.
+    graphs: BTreeMap<OntologyLocation, Option<OntologyGraph>>,
+    problems: Vec<String>,
+}
+
+impl Expander<'_> {
+    fn evaluate(
+        &mut self,
+        enum_name: &str,
+        expr: &EnumExpression,
+        sources: &mut BTreeSet<OntologyLocation>,
+    ) -> BTreeMap<String, PermissibleValue> {
+        let mut values = BTreeMap::new();
+        if let Some(query) = &expr.reachable_from {
+            self.reachable(enum_name, query, sources, &mut values);
+        }
+        for nested in &expr.include {
+            values.extend(self.evaluate(enum_name, nested, sources));
+        }
+        // Concepts after the includes, so their labels can come from any
+        // document the expression loaded.
+        for concept in &expr.concepts {
+            if let Some(iri) = self.expand(enum_name, concept) {
+                let label = self.label(&iri);
+                self.insert_term(&iri, label, &mut values);
+            }
+        }
+        // Static values last, so an explicit entry keeps its own
+        // description over one derived from the ontology.
+        values.extend(expr.permissible_values.clone());
+        for nested in &expr.minus {
+            let removed = self.evaluate(enum_name, nested, sources);
+            let meanings: BTreeSet<&String> = removed
+                .values()
+                .filter_map(|pv| pv.meaning.as_ref())
+                .collect();
+            values.retain(|text, pv| {
+                !removed.contains_key(text)
+                    && pv.meaning.as_ref().is_none_or(|m| !meanings.contains(m))
+            });
+        }
+        values
+    }
+
+    fn reachable(
+        &mut self,
+        enum_name: &str,
+        query: &crate::linkml::ReachabilityQuery,
+        sources: &mut BTreeSet<OntologyLocation>,
+        values: &mut BTreeMap<String, PermissibleValue>,
+    ) {
+        let Some(ontology) = &query.source_ontology else {
+            self.problems.push(format!(
+                "enum `{enum_name}`: `reachable_from` names no `source_ontology`; it contributes \
+                 no values"
+            ));
+            return;
+        };
+        let location = resolve_location(self.schema, ontology, &self.base_dir);
+        sources.insert(location.clone());
+        if !self.load(enum_name, &location) {
+            return;
+        }
+        let predicates: BTreeSet<String> = if query.relationship_types.is_empty() {
+            BTreeSet::from([RDFS_SUB_CLASS_OF.to_string()])
+        } else {
+            query
+                .relationship_types
+                .iter()
+                .filter_map(|r| self.expand(enum_name, r))
+                .collect()
+        };
+        let start: Vec<String> = query
+            .source_nodes
+            .iter()
+            .filter_map(|n| self.expand(enum_name, n))
+            .collect();
+
+        let graph = self.graphs[&location].as_ref().expect("loaded above");
+        // Edges run child → parent (`child rdfs:subClassOf parent`), so
+        // descendants follow them backwards, ancestors forwards.
+        let mut next: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (s, p, o) in &graph.edges {
+            if predicates.contains(p) {
+                let (from, to) = if query.traverse_up { (s, o) } else { (o, s) };
+                next.entry(from.as_str()).or_default().push(to.as_str());
+            }
+        }
+        let mut missing = Vec::new();
+        let mut reached: BTreeSet<&str> = BTreeSet::new();
+        let mut frontier: Vec<&str> = Vec::new();
+        for node in &start {
+            if !graph.mentions(node) {
+                missing.push(node.clone());
+            }
+            if query.include_self {
+                reached.insert(node.as_str());
+            }
+            frontier.push(node.as_str());
+        }
+        let mut visited: BTreeSet<&str> = frontier.iter().copied().collect();
+        while !frontier.is_empty() {
+            let mut following = Vec::new();
+            for node in frontier {
+                for &to in next.get(node).into_iter().flatten() {
+                    reached.insert(to);
+                    if visited.insert(to) {
+                        following.push(to);
+                    }
+                }
+            }
+            frontier = if query.is_direct {
+                Vec::new()
+            } else {
+                following
+            };
+        }
+        let terms: Vec<(String, Option<String>)> = reached
+            .into_iter()
+            .map(|iri| (iri.to_string(), graph.labels.get(iri).cloned()))
+            .collect();
+
+        for node in missing {
+            self.problems.push(format!(
+                "enum `{enum_name}`: source node `{node}` does not occur in `{location}`"
+            ));
+        }
+        for (iri, label) in terms {
+            self.insert_term(&iri, label, values);
+        }
+    }
+
+    /// Parse `location` once; `false` when it cannot be read or parsed.
+    fn load(&mut self, enum_name: &str, location: &OntologyLocation) -> bool {
+        if !self.graphs.contains_key(location) {
+            let graph = match (self.read)(location) {
+                None => {
+                    self.problems.push(match location {
+                        OntologyLocation::Url(url) => format!(
+                            "enum `{enum_name}`: ontology `{url}` is not in the local cache; run \
+                             `panschema fetch` to download it"
+                        ),
+                        OntologyLocation::File(path) => format!(
+                            "enum `{enum_name}`: cannot read ontology `{}`",
+                            path.display()
+                        ),
+                    });
+                    None
+                }
+                Some(rdf) => match OntologyGraph::parse(&rdf) {
+                    Ok(graph) => Some(graph),
+                    Err(err) => {
+                        self.problems
+                            .push(format!("enum `{enum_name}`: ontology `{location}`: {err}"));
+                        None
+                    }
+                },
+            };
+            self.graphs.insert(location.clone(), graph);
+        }
+        self.graphs[location].is_some()
+    }
+
+    fn expand(&mut self, enum_name: &str, curie: &str) -> Option<String> {
+        let iri = crate::linkml_resolve::expand_curie(self.schema, curie);
+        if iri.is_none() {
+            self.problems.push(format!(
+                "enum `{enum_name}`: cannot expand `{curie}`; declare its prefix under `prefixes:`"
+            ));
+        }
+        iri
+    }
+
+    /// A term's label from any document loaded so far.
+    fn label(&self, iri: &str) -> Option<String> {
+        self.graphs
+            .values()
+            .flatten()
+            .find_map(|graph| graph.labels.get(iri).cloned())
+    }
+
+    /// Add a term keyed by its CURIE, with the label as description.
+    fn insert_term(
+        &self,
+        iri: &str,
+        label: Option<String>,
+        values: &mut BTreeMap<String, PermissibleValue>,
+    ) {
+        let text = contract_iri(self.schema, iri);
+        values
+            .entry(text.clone())
+            .or_insert_with(|| PermissibleValue {
+                text: text.clone(),
+                description: label,
+                meaning: Some(text),
+                metadata: Default::default(),
+            });
+    }
+}
+
+/// Contract `iri` to a CURIE by the longest matching namespace in the
+/// schema's `prefixes:`; the IRI itself when none matches.
+fn contract_iri(schema: &SchemaDefinition, iri: &str) -> String {
+    schema
+        .prefixes
+        .iter()
+        .filter(|(_, ns)| !ns.is_empty() && iri.starts_with(ns.as_str()))
+        .max_by_key(|(_, ns)| ns.len())
+        .map(|(prefix, ns)| format!("{prefix}:{}", &iri[ns.len()..]))
+        .unwrap_or_else(|| iri.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::ReachabilityQuery;
+
+    const ANIMALS: &str = r#"
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/onto/> .
+ex:Mammal rdfs:subClassOf ex:Animal ; rdfs:label "mammal" .
+ex:Dog rdfs:subClassOf ex:Mammal ; rdfs:label "dog" .
+ex:Cat rdfs:subClassOf ex:Mammal ; rdfs:label "cat" .
+ex:Bird rdfs:subClassOf ex:Animal ; rdfs:label "bird" .
+"#;
+
+    fn schema_with(enum_def: EnumDefinition) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .prefixes
+            .insert("ex".to_string(), "http://example.org/onto/".to_string());
+        schema.enums.insert(enum_def.name.clone(), enum_def);
+        schema
+    }
+
+    fn query(nodes: &[&str]) -> ReachabilityQuery {
+        ReachabilityQuery {
+            source_ontology: Some("https://example.org/animals.ttl".to_string()),
+            source_nodes: nodes.iter().map(|n| n.to_string()).collect(),
+            ..ReachabilityQuery::default()
+        }
+    }
+
+    fn expand(schema: &mut SchemaDefinition) -> ExpansionReport {
+        expand_dynamic_enums(schema, Path::new("."), &|location| match location {
+            OntologyLocation::Url(url) if url == "https://example.org/animals.ttl" => {
+                Some(ANIMALS.to_string())
+            }
+            _ => None,
+        })
+    }
+
+    fn values(schema: &SchemaDefinition, name: &str) -> Vec<String> {
+        schema.enums[name]
+            .permissible_values
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn reachable_from_materializes_descendants_with_labels() {
+        let mut animal = EnumDefinition::new("AnimalKind");
+        animal.reachable_from = Some(query(&["ex:Animal"]));
+        let mut schema = schema_with(animal);
+        let report = expand(&mut schema);
+
+        assert!(report.problems.is_empty(), "{:?}", report.problems);
+        assert_eq!(
+            values(&schema, "AnimalKind"),
+            vec!["ex:Bird", "ex:Cat", "ex:Dog", "ex:Mammal"],
+            "every transitive descendant, keyed by CURIE; the source node itself is excluded"
+        );
+        let dog = &schema.enums["AnimalKind"].permissible_values["ex:Dog"];
+        assert_eq!(dog.description.as_deref(), Some("dog"));
+        assert_eq!(dog.meaning.as_deref(), Some("ex:Dog"));
+        assert_eq!(
+            report.enums[0].sources,
+            vec!["https://example.org/animals.ttl"]
+        );
+        assert_eq!(
+            report.enums[0].checksum,
+            value_set_checksum(&schema.enums["AnimalKind"].permissible_values)
+        );
+    }
+
+    #[test]
+    fn query_flags_shape_the_traversal() {
+        let mut direct = EnumDefinition::new("Direct");
+        let mut q = query(&["ex:Animal"]);
+        q.is_direct = true;
+        q.include_self = true;
+        direct.reachable_from = Some(q);
+        let mut schema = schema_with(direct);
+        let mut up = EnumDefinition::new("Up");
+        let mut q = query(&["ex:Dog"]);
+        q.traverse_up = true;
+        up.reachable_from = Some(q);
+        schema.enums.insert("Up".to_string(), up);
+        expand(&mut schema);
+
+        assert_eq!(
+            values(&schema, "Direct"),
+            vec!["ex:Animal", "ex:Bird", "ex:Mammal"]
+        );
+        assert_eq!(values(&schema, "Up"), vec!["ex:Animal", "ex:Mammal"]);
+    }
+
+    #[test]
+    fn include_minus_and_concepts_compose_with_static_values() {
+        let mut pets = EnumDefinition::new("Pet");
+        pets.permissible_values.insert(
+            "goldfish".to_string(),
+            PermissibleValue {
+                text: "goldfish".to_string(),
+                description: None,
+                meaning: None,
+                metadata: Default::default(),
+            },
+        );
+        pets.concepts = vec!["ex:Bird".to_string()];
+        pets.include = vec![EnumExpression {
+            reachable_from: Some(query(&["ex:Mammal"])),
+            ..EnumExpression::default()
+        }];
+        pets.minus = vec![EnumExpression {
+            concepts: vec!["ex:Cat".to_string()],
+            ..EnumExpression::default()
+        }];
+        let mut schema = schema_with(pets);
+        let report = expand(&mut schema);
+
+        assert!(report.problems.is_empty(), "{:?}", report.problems);
+        assert_eq!(
+            values(&schema, "Pet"),
+            vec!["ex:Bird", "ex:Dog", "goldfish"]
+        );
+        assert_eq!(
+            schema.enums["Pet"].permissible_values["ex:Bird"]
+                .description
+                .as_deref(),
+            Some("bird"),
+            "a concept takes its label from an ontology the expansion loaded"
+        );
+    }
+
+    #[test]
+    fn missing_documents_and_unknown_nodes_are_reported_not_fatal() {
+        let mut uncached = EnumDefinition::new("Uncached");
+        let mut q = query(&["ex:Animal"]);
+        q.source_ontology = Some("https://example.org/missing.ttl".to_string());
+        uncached.reachable_from = Some(q);
+        let mut schema = schema_with(uncached);
+        let mut typo = EnumDefinition::new("Typo");
+        typo.reachable_from = Some(query(&["ex:Animl"]));
+        schema.enums.insert("Typo".to_string(), typo);
+        let report = expand(&mut schema);
+
+        assert!(schema.enums["Uncached"].permissible_values.is_empty());
+        assert_eq!(
+            report.problems,
+            vec![
+                "enum `Typo`: source node `http://example.org/onto/Animl` does not occur in \
+                 `https://example.org/animals.ttl`",
+                "enum `Uncached`: ontology `https://example.org/missing.ttl` is not in the local \
+                 cache; run `panschema fetch` to download it",
+            ]
+        );
+    }
+
+    /// A relative `source_ontology` in an imported file is read beside
+    /// that file, not beside the root schema.
+    #[test]
+    fn an_imported_enum_reads_its_ontology_beside_its_own_file() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let root = tmp.path();
+        fs::create_dir(root.join("zoo")).unwrap();
+        fs::write(
+            root.join("app.yaml"),
+            "id: https://example.org/app\nname: app\nimports: [zoo/animals]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("zoo/animals.yaml"),
+            "id: https://example.org/animals\nname: animals\n\
+             prefixes:\n  ex: http://example.org/onto/\n\
+             enums:\n  AnimalKind:\n    reachable_from:\n      \
+             source_ontology: animals.ttl\n      source_nodes: [ex:Animal]\n",
+        )
+        .unwrap();
+        fs::write(root.join("zoo/animals.ttl"), ANIMALS).unwrap();
+
+        let registry = crate::io::FormatRegistry::with_defaults();
+        let mut schema = crate::import_resolve::load_schema_merged(
+            &root.join("app.yaml"),
+            &registry,
+            &BTreeMap::new(),
+        )
+        .expect("load");
+        assert_eq!(
+            ontology_locations(&schema, root),
+            BTreeSet::from([OntologyLocation::File(root.join("zoo/animals.ttl"))])
+        );
+        let report = expand_dynamic_enums(&mut schema, root, &|location| match location {
+            OntologyLocation::File(path) => fs::read_to_string(path).ok(),
+            OntologyLocation::Url(_) => None,
+        });
+        assert!(report.problems.is_empty(), "{:?}", report.problems);
+        assert!(values(&schema, "AnimalKind").contains(&"ex:Dog".to_string()));
+        assert_eq!(report.enums[0].sources, vec!["zoo/animals.ttl"]);
+    }
+
+    #[test]
+    fn source_ontology_resolves_urls_curies_and_relative_paths() {
+        let mut schema = SchemaDefinition::new("s");
+        schema.prefixes.insert(
+            "obo".to_string(),
+            "http://purl.obolibrary.org/obo/".to_string(),
+        );
+        let base = Path::new("schemas");
+        assert_eq!(
+            resolve_location(&schema, "obo:go.owl", base),
+            OntologyLocation::Url("http://purl.obolibrary.org/obo/go.owl".to_string())
+        );
+        assert_eq!(
+            resolve_location(&schema, "https://example.org/a.ttl", base),
+            OntologyLocation::Url("https://example.org/a.ttl".to_string())
+        );
+        assert_eq!(
+            resolve_location(&schema, "onto/animals.ttl", base),
+            OntologyLocation::File(PathBuf::from("schemas/onto/animals.ttl"))
+        );
+    }
+}
@@ -0,0 +1,787 @@
+//! The LinkML expression language, for computed slots (`equals_expression`).
+//!
+//! A small, Python-flavoured subset: number and string literals, `True` /
+//! `False` / `None`, slot references written `{slot}` (or bare), the
+//! arithmetic operators `+ - * / % **`, `+` as string concatenation,
+//! comparisons (`== != < <= > >=`), and `and` / `or` / `not`, with
+//! parentheses for grouping. As in LinkML's own evaluator, an absent slot
+//! is `None` and `None` propagates: any operation on it yields `None`, so a
+//! record missing an input has nothing to check rather than a false
+//! mismatch.
+//!
+//! `validate` evaluates each computed slot against the record's other
+//! values and reports a stored value that disagrees; `generate --infer`
+//! fills the slots a record leaves out.
+//!
+//! Reference: <https://linkml.io/linkml/schemas/advanced.html#equals-expression>
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::instances::{Instance, InstanceValue, ScalarValue};
+
+/// Errors raised while parsing or evaluating an expression.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ExpressionError {
+    /// The source text is not a well-formed expression.
+    #[error("{message} at position {position}")]
+    Parse { position: usize, message: String },
+    /// An operator was applied to values it has no meaning for.
+    #[error("cannot apply `{op}` to {left} and {right}")]
+    Type {
+        op: &'static str,
+        left: &'static str,
+        right: &'static str,
+    },
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+/// A value an expression reads or produces.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "None",
+            Value::Bool(_) => "a boolean",
+            Value::Number(_) => "a number",
+            Value::String(_) => "a string",
+        }
+    }
+
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => *n != 0.0,
+            Value::String(s) => !s.is_empty(),
+        }
+    }
+
+    /// Whether a stored value agrees with a computed one: numbers within a
+    /// relative tolerance (so `0.1 + 0.2` equals a stored `0.3`, and `12`
+    /// equals `12.0`), everything else exactly.
+    pub fn agrees_with(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => {
+                (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+            }
+            _ => self == other,
+        }
+    }
+
+    /// The value as a stored scalar for a slot of `range`: an integral
+    /// number becomes an integer at an `integer` range, and a float
+    /// otherwise. `None` for `Null`.
+    pub fn to_scalar(&self, range: Option<&str>) -> Option<ScalarValue> {
+        Some(match self {
+            Value::Null => return None,
+            Value::Bool(b) => ScalarValue::Boolean(*b),
+            Value::Number(n) if range == Some("integer") && n.fract() == 0.0 => {
+                ScalarValue::Integer(*n as i64)
+            }
+            Value::Number(n) => ScalarValue::Float(*n),
+            Value::String(s) => ScalarValue::String(s.clone()),
+        })
+    }
+}
+
+impl From<&ScalarValue> for Value {
+    fn from(scalar: &ScalarValue) -> Self {
+        match scalar {
+            ScalarValue::String(s) => Value::String(s.clone()),
+            ScalarValue::Integer(i) => Value::Number(*i as f64),
+            ScalarValue::Float(f) => Value::Number(*f),
+            ScalarValue::Boolean(b) => Value::Bool(*b),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("None"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Value::Number(n) => write!(f, "{n}"),
+            Value::String(s) => f.write_str(s),
+        }
+    }
+}
+
+/// A record's value for `slot`, as an expression reads it: its single
+/// scalar, or a referenced record's id. An absent slot, several values, or
+/// a mis-kinded one read as `None`.
+pub fn record_value(record: &Instance, slot: &str) -> Value {
+    let Some(values) = record
+        .slot_values
+        .iter()
+        .find(|sv| sv.slot == slot)
+        .map(|sv| sv.values.as_slice())
+    else {
+        return Value::Null;
+    };
+    match values {
+        [InstanceValue::Scalar(scalar)] => Value::from(scalar),
+        [InstanceValue::Reference { target, .. }] => Value::String(target.clone()),
+        _ => Value::Null,
+    }
+}
+
+/// A parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    root: Node,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal(Value),
+    Slot(String),
+    Not(Box<Node>),
+    Neg(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+impl BinaryOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+            BinaryOp::Pow => "**",
+            BinaryOp::Eq => "==",
+            BinaryOp::Ne => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+            BinaryOp::And => "and",
+            BinaryOp::Or => "or",
+        }
+    }
+}
+
+impl Expression {
+    /// Parse `source`.
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: source.len(),
+            depth: 0,
+        };
+        let root = parser.or_expr()?;
+        if let Some((position, token)) = parser.tokens.get(parser.pos) {
+            return Err(parse_error(*position, format!("unexpected `{token}`")));
+        }
+        Ok(Self { root })
+    }
+
+    /// Every slot the expression reads.
+    pub fn references(&self) -> BTreeSet<String> {
+        fn walk(node: &Node, out: &mut BTreeSet<String>) {
+            match node {
+                Node::Literal(_) => {}
+                Node::Slot(name) => {
+                    out.insert(name.clone());
+                }
+                Node::Not(inner) | Node::Neg(inner) => walk(inner, out),
+                Node::Binary(_, left, right) => {
+                    walk(left, out);
+                    walk(right, out);
+                }
+            }
+        }
+        let mut out = BTreeSet::new();
+        walk(&self.root, &mut out);
+        out
+    }
+
+    /// Evaluate with `lookup` supplying each referenced slot's value.
+    pub fn evaluate(&self, lookup: impl Fn(&str) -> Value) -> Result<Value, ExpressionError> {
+        eval(&self.root, &lookup)
+    }
+}
+
+fn eval(node: &Node, lookup: &dyn Fn(&str) -> Value) -> Result<Value, ExpressionError> {
+    Ok(match node {
+        Node::Literal(value) => value.clone(),
+        Node::Slot(name) => lookup(name),
+        Node::Not(inner) => match eval(inner, lookup)? {
+            Value::Null => Value::Null,
+            value => Value::Bool(!value.truthy()),
+        },
+        Node::Neg(inner) => match eval(inner, lookup)? {
+            Value::Null => Value::Null,
+            Value::Number(n) => Value::Number(-n),
+            other => {
+                return Err(ExpressionError::Type {
+                    op: "-",
+                    left: "nothing",
+                    right: other.kind(),
+                });
+            }
+        },
+        Node::Binary(op, left, right) => {
+            let (left, right) = (eval(left, lookup)?, eval(right, lookup)?);
+            binary(*op, left, right)?
+        }
+    })
+}
+
+fn binary(op: BinaryOp, left: Value, right: Value) -> Result<Value, ExpressionError> {
+    use BinaryOp::*;
+    if matches!(left, Value::Null) || matches!(right, Value::Null) {
+        return Ok(Value::Null);
+    }
+    let mismatch = || ExpressionError::Type {
+        op: op.symbol(),
+        left: left.kind(),
+        right: right.kind(),
+    };
+    Ok(match (op, &left, &right) {
+        (And, _, _) => Value::Bool(left.truthy() && right.truthy()),
+        (Or, _, _) => Value::Bool(left.truthy() || right.truthy()),
+        (Eq, _, _) => Value::Bool(left.agrees_with(&right)),
+        (Ne, _, _) => Value::Bool(!left.agrees_with(&right)),
+        (Add, Value::String(a), Value::String(b)) => Value::String(format!("{a}{b}")),
+        (Lt | Le | Gt | Ge, Value::String(a), Value::String(b)) => {
+            Value::Bool(compare(op, a.cmp(b)))
+        }
+        (_, Value::Number(a), Value::Number(b)) => {
+            let (a, b) = (*a, *b);
+            match op {
+                Add => Value::Number(a + b),
+                Sub => Value::Number(a - b),
+                Mul => Value::Number(a * b),
+                Div | Rem if b == 0.0 => return Err(ExpressionError::DivisionByZero),
+                Div => Value::Number(a / b),
+                Rem => Value::Number(floor_rem(a, b)),
+                Pow => Value::Number(a.powf(b)),
+                Lt | Le | Gt | Ge => match a.partial_cmp(&b) {
+                    Some(ordering) => Value::Bool(compare(op, ordering)),
+                    None => Value::Bool(false),
+                },
+                Eq | Ne | And | Or => unreachable!("handled above"),
+            }
+        }
+        _ => return Err(mismatch()),
+    })
+}
+
+/// Python's `%`: the remainder of floor division, which takes the
+/// divisor's sign (`7 % -3 == -2`, `-7 % 3 == 2`).
+fn floor_rem(a: f64, b: f64) -> f64 {
+    let rem = a % b;
+    if rem != 0.0 && (rem < 0.0) != (b < 0.0) {
+        rem + b
+    } else {
+        rem
+    }
+}
+
+fn compare(op: BinaryOp, ordering: std::cmp::Ordering) -> bool {
+    match op {
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::Le => ordering.is_le(),
+        BinaryOp::Gt => ordering.is_gt(),
+        _ => ordering.is_ge(),
+    }
+}
+
+fn parse_error(position: usize, message: impl Into<String>) -> ExpressionError {
+    ExpressionError::Parse {
+        position: position + 1,
+        message: message.into(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    String(String),
+    Ident(String),
+    /// A `{slot}` reference.
+    Slot(String),
+    Op(&'static str),
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{n}"),
+            Token::String(s) => write!(f, "'{s}'"),
+            Token::Ident(name) => f.write_str(name),
+            Token::Slot(name) => write!(f, "{{{name}}}"),
+            Token::Op(op) => f.write_str(op),
+            Token::Open => f.write_str("("),
+            Token::Close => f.write_str(")"),
+        }
+    }
+}
+
+/// Operators, longest first so `**` wins over `*`.
+const OPERATORS: &[&str] = &[
+    "**", "==", "!=", "<=", ">=", "+", "-", "*", "/", "%", "<", ">",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let rest = &source[start..];
+        let token = if c.is_ascii_digit()
+            || (c == '.' && rest[1..].starts_with(|d: char| d.is_ascii_digit()))
+        {
+            let len = rest
+                .find(|d: char| !(d.is_ascii_digit() || d == '.'))
+                .unwrap_or(rest.len());
+            let number = rest[..len]
+                .parse()
+                .map_err(|_| parse_error(start, format!("malformed number `{}`", &rest[..len])))?;
+            advance(&mut chars, start + len);
+            Token::Number(number)
+        } else if c == '\'' || c == '"' {
+            let close = rest[1..]
+                .find(c)
+                .ok_or_else(|| parse_error(start, "unterminated string"))?;
+            advance(&mut chars, start + close + 2);
+            Token::String(rest[1..close + 1].to_string())
+        } else if c == '{' {
+            let close = rest
+                .find('}')
+                .ok_or_else(|| parse_error(start, "unterminated `{` slot reference"))?;
+            let name = rest[1..close].trim();
+            if !is_identifier(name) {
+                return Err(parse_error(
+                    start,
+                    format!("`{{{name}}}` is not a slot name"),
+                ));
+            }
+            advance(&mut chars, start + close + 1);
+            Token::Slot(name.to_string())
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|d: char| !(d.is_alphanumeric() || d == '_'))
+                .unwrap_or(rest.len());
+            advance(&mut chars, start + len);
+            Token::Ident(rest[..len].to_string())
+        } else if c == '(' || c == ')' {
+            chars.next();
+            if c == '(' { Token::Open } else { Token::Close }
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            advance(&mut chars, start + op.len());
+            Token::Op(*op)
+        } else {
+            return Err(parse_error(start, format!("unexpected `{c}`")));
+        };
+        tokens.push((start, token));
+    }
+    Ok(tokens)
+}
+
+fn advance(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, to: usize) {
+    while chars.next_if(|&(i, _)| i < to).is_some() {}
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// How deeply an expression may nest — parentheses, prefix operators,
+/// and the operator tree itself. Parsing and evaluating both recurse, so
+/// a pathological `((((…` or `1+1+…+1` is refused as a parse error
+/// rather than overflowing the stack.
+const MAX_DEPTH: usize = 256;
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    /// Source length, the position reported for "ran out of input".
+    end: usize,
+    /// How many nested constructs the parser is inside.
+    depth: usize,
+}
+
+/// The height of the operator tree under `node`.
+fn height(node: &Node) -> usize {
+    match node {
+        Node::Literal(_) | Node::Slot(_) => 1,
+        Node::Not(inner) | Node::Neg(inner) => 1 + height(inner),
+        Node::Binary(_, left, right) => 1 + height(left).max(height(right)),
+    }
+}
+
+impl Parser {
+    /// Where the token just consumed starts.
+    fn last_position(&self) -> usize {
+        self.pos.checked_sub(1).map_or(0, |at| self.tokens[at].0)
+    }
+
+    fn too_deep(&self, position: usize) -> ExpressionError {
+        parse_error(
+            position,
+            format!("expression nests more than {MAX_DEPTH} levels deep"),
+        )
+    }
+
+    /// Run `parse` one nesting level down, refusing past [`MAX_DEPTH`].
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Node, ExpressionError>,
+    ) -> Result<Node, ExpressionError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.too_deep(self.last_position()));
+        }
+        self.depth += 1;
+        let node = parse(self);
+        self.depth -= 1;
+        node
+    }
+
+    /// `node`, unless its operator tree is taller than [`MAX_DEPTH`] —
+    /// the operator at `position` is the one that crossed it.
+    fn capped(&self, position: usize, node: Node) -> Result<Node, ExpressionError> {
+        if height(&node) > MAX_DEPTH {
+            return Err(self.too_deep(position));
+        }
+        Ok(node)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    fn eat_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let matched = matches!(self.peek(), Some(Token::Ident(name)) if name == keyword);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    /// or_expr := and_expr ('or' and_expr)*
+    fn or_expr(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.and_expr()?;
+        while self.eat_keyword("or") {
+            let at = self.last_position();
+            let right = self.and_expr()?;
+            node = self.capped(
+                at,
+                Node::Binary(BinaryOp::Or, Box::new(node), Box::new(right)),
+            )?;
+        }
+        Ok(node)
+    }
+
+    /// and_expr := not_expr ('and' not_expr)*
+    fn and_expr(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.not_expr()?;
+        while self.eat_keyword("and") {
+            let at = self.last_position();
+            let right = self.not_expr()?;
+            node = self.capped(
+                at,
+                Node::Binary(BinaryOp::And, Box::new(node), Box::new(right)),
+            )?;
+        }
+        Ok(node)
+    }
+
+    /// not_expr := 'not' not_expr | comparison
+    fn not_expr(&mut self) -> Result<Node, ExpressionError> {
+        if self.eat_keyword("not") {
+            let at = self.last_position();
+            let inner = self.nested(Self::not_expr)?;
+            return self.capped(at, Node::Not(Box::new(inner)));
+        }
+        self.comparison()
+    }
+
+    /// comparison := additive (cmp-op additive)?
+    fn comparison(&mut self) -> Result<Node, ExpressionError> {
+        let left = self.additive()?;
+        let op = match self.eat_op(&["==", "!=", "<=", ">=", "<", ">"]) {
+            Some("==") => BinaryOp::Eq,
+            Some("!=") => BinaryOp::Ne,
+            Some("<=") => BinaryOp::Le,
+            Some(">=") => BinaryOp::Ge,
+            Some("<") => BinaryOp::Lt,
+            Some(_) => BinaryOp::Gt,
+            None => return Ok(left),
+        };
+        let at = self.last_position();
+        let right = self.additive()?;
+        self.capped(at, Node::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    /// additive := term (('+' | '-') term)*
+    fn additive(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.term()?;
+        while let Some(op) = self.eat_op(&["+", "-"]) {
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            let at = self.last_position();
+            let right = self.term()?;
+            node = self.capped(at, Node::Binary(op, Box::new(node), Box::new(right)))?;
+        }
+        Ok(node)
+    }
+
+    /// term := unary (('*' | '/' | '%') unary)*
+    fn term(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.unary()?;
+        while let Some(op) = self.eat_op(&["*", "/", "%"]) {
+            let op = match op {
+                "*" => BinaryOp::Mul,
+                "/" => BinaryOp::Div,
+                _ => BinaryOp::Rem,
+            };
+            let at = self.last_position();
+            let right = self.unary()?;
+            node = self.capped(at, Node::Binary(op, Box::new(node), Box::new(right)))?;
+        }
+        Ok(node)
+    }
+
+    /// unary := '-' unary | power
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        if self.eat_op(&["-"]).is_some() {
+            let at = self.last_position();
+            let inner = self.nested(Self::unary)?;
+            return self.capped(at, Node::Neg(Box::new(inner)));
+        }
+        self.power()
+    }
+
+    /// power := primary ('**' unary)?  — right-associative, binding tighter
+    /// than a unary minus on its left, as in Python.
+    fn power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.primary()?;
+        if self.eat_op(&["**"]).is_some() {
+            let at = self.last_position();
+            let exponent = self.nested(Self::unary)?;
+            return self.capped(
+                at,
+                Node::Binary(BinaryOp::Pow, Box::new(base), Box::new(exponent)),
+            );
+        }
+        Ok(base)
+    }
+
+    /// primary := number | string | True | False | None | slot | '(' or_expr ')'
+    fn primary(&mut self) -> Result<Node, ExpressionError> {
+        let Some((position, token)) = self.tokens.get(self.pos).cloned() else {
+            return Err(parse_error(self.end, "expression ends early"));
+        };
+        self.pos += 1;
+        Ok(match token {
+            Token::Number(n) => Node::Literal(Value::Number(n)),
+            Token::String(s) => Node::Literal(Value::String(s)),
+            Token::Slot(name) => Node::Slot(name),
+            Token::Ident(name) => match name.as_str() {
+                "True" => Node::Literal(Value::Bool(true)),
+                "False" => Node::Literal(Value::Bool(false)),
+                "None" => Node::Literal(Value::Null),
+                "and" | "or" | "not" => {
+                    return Err(parse_error(position, format!("unexpected `{name}`")));
+                }
+                _ if matches!(self.peek(), Some(Token::Open)) => {
+                    return Err(parse_error(position, format!("unknown function `{name}`")));
+                }
+                _ => Node::Slot(name),
+            },
+            Token::Open => {
+                let inner = self.nested(Self::or_expr)?;
+                match self.peek() {
+                    Some(Token::Close) => self.pos += 1,
+                    _ => return Err(parse_error(position, "unbalanced `(`")),
+                }
+                inner
+            }
+            other => return Err(parse_error(position, format!("unexpected `{other}`"))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_with(source: &str, vars: &[(&str, Value)]) -> Result<Value, ExpressionError> {
+        Expression::parse(source)?.evaluate(|name| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map_or(Value::Null, |(_, v)| v.clone())
+        })
+    }
+
+    #[test]
+    fn evaluates_arithmetic_concatenation_references_and_comparison() {
+        let vars = [
+            ("length", Value::Number(3.0)),
+            ("width", Value::Number(5.0)),
+            ("first", Value::String("Ada".into())),
+            ("last", Value::String("Lovelace".into())),
+        ];
+        for (source, expected) in [
+            ("{length} * {width}", Value::Number(15.0)),
+            ("length * width + 1", Value::Number(16.0)),
+            ("2 + 3 * 4 - 6 / 2", Value::Number(11.0)),
+            ("(2 + 3) * 4", Value::Number(20.0)),
+            ("-2 ** 2", Value::Number(-4.0)),
+            ("7 % 3", Value::Number(1.0)),
+            ("7 % -3", Value::Number(-2.0)),
+            ("-7 % 3", Value::Number(2.0)),
+            ("-7 % -3", Value::Number(-1.0)),
+            ("6 % -3", Value::Number(0.0)),
+            (
+                "{first} + ' ' + {last}",
+                Value::String("Ada Lovelace".into()),
+            ),
+            ("{length} < {width}", Value::Bool(true)),
+            ("{length} == 3 and not {width} != 5", Value::Bool(true)),
+            ("{first} == 'Ada' or False", Value::Bool(true)),
+        ] {
+            assert_eq!(eval_with(source, &vars), Ok(expected), "`{source}`");
+        }
+    }
+
+    #[test]
+    fn an_absent_slot_propagates_none() {
+        assert_eq!(eval_with("{length} * 2", &[]), Ok(Value::Null));
+        assert_eq!(eval_with("{a} == 1", &[]), Ok(Value::Null));
+    }
+
+    #[test]
+    fn reports_parse_and_evaluation_errors() {
+        for (source, message) in [
+            ("{length} *", "expression ends early at position 11"),
+            ("(1 + 2", "unbalanced `(` at position 1"),
+            ("1 + 'a", "unterminated string at position 5"),
+            (
+                "{first name}",
+                "`{first name}` is not a slot name at position 1",
+            ),
+            ("len({x})", "unknown function `len` at position 1"),
+            ("1 2", "unexpected `2` at position 3"),
+        ] {
+            assert_eq!(
+                Expression::parse(source)
+                    .map(|_| ())
+                    .unwrap_err()
+                    .to_string(),
+                message,
+                "`{source}`"
+            );
+        }
+        assert_eq!(
+            eval_with("'a' * 2", &[]).unwrap_err().to_string(),
+            "cannot apply `*` to a string and a number"
+        );
+        assert_eq!(
+            eval_with("1 / 0", &[]),
+            Err(ExpressionError::DivisionByZero)
+        );
+    }
+
+    /// Nesting past the cap is a parse error, never a stack overflow —
+    /// whether it comes from parentheses, prefix operators, or one long
+    /// operator chain.
+    #[test]
+    fn nesting_past_the_depth_cap_is_a_parse_error() {
+        let deep = MAX_DEPTH + 1;
+        for source in [
+            format!("{}1{}", "(".repeat(deep), ")".repeat(deep)),
+            format!("{}1", "not ".repeat(deep)),
+            format!("{}1", "-".repeat(deep)),
+            vec!["1"; deep + 1].join(" + "),
+            vec!["2"; deep + 1].join(" ** "),
+        ] {
+            let err = Expression::parse(&source).unwrap_err();
+            assert!(
+                matches!(&err, ExpressionError::Parse { message, .. }
+                    if message == "expression nests more than 256 levels deep"),
+                "{err}"
+            );
+        }
+        let shallow = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+        assert_eq!(eval_with(&shallow, &[]), Ok(Value::Number(1.0)));
+    }
+
+    #[test]
+    fn references_lists_every_slot_read() {
+        let expr = Expression::parse("{a} + b * ({c} - a)").unwrap();
+        assert_eq!(
+            expr.references().into_iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn numbers_agree_within_tolerance_and_convert_by_range() {
+        assert!(Value::Number(0.3).agrees_with(&Value::Number(0.1 + 0.2)));
+        assert!(!Value::Number(12.0).agrees_with(&Value::Number(12.5)));
+        assert_eq!(
+            Value::Number(15.0).to_scalar(Some("integer")),
+            Some(ScalarValue::Integer(15))
+        );
+        assert_eq!(
+            Value::Number(15.0).to_scalar(Some("float")),
+            Some(ScalarValue::Float(15.0))
+        );
+        assert_eq!(Value::Null.to_scalar(None), None);
+    }
+}
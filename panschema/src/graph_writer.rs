@@ -9,7 +9,7 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use crate::io::{IoError, IoResult, Writer};
-use crate::linkml::SchemaDefinition;
+use crate::linkml::{SchemaDefinition, SlotDefinition};
 
 /// Color constants for node types (RGBA, normalized 0.0-1.0)
 pub mod colors {
@@ -139,6 +139,13 @@ pub struct GraphNode {
     /// be empty (e.g. types).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kind_metadata: Option<KindMetadata>,
+
+    /// Inbound references to a class, enum or slot node (see
+    /// [`crate::linkml_resolve::usage_index`]), for the hover card's
+    /// "Used by" row. Writer-only for now: the viz-side mirror gains
+    /// this field when it consumes it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub used_by: Vec<crate::linkml_resolve::Usage>,
 }
 
 /// Per-kind structured payload carried by [`GraphNode::kind_metadata`].
@@ -172,7 +179,8 @@ pub enum KindMetadata {
     /// flags with the explicit `min` / `max` bounds. `pattern`,
     /// `identifier`, and `any_of` (the element ranges of a
     /// polymorphic range) surface the constraint fields authors
-    /// edit most.
+    /// edit most; `all_of` / `exactly_one_of` / `none_of` carry the
+    /// other combinators' branch ranges the same way.
     Slot {
         /// Every class this slot is a domain of (explicit `domain:`, or
         /// the classes that list it in `slots:`). A slot can belong to
@@ -191,10 +199,19 @@ pub enum KindMetadata {
         max: Option<u32>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pattern: Option<String>,
+        /// The slot's unit of measure, rendered readably (`kg (kilogram)`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<String>,
         #[serde(default, skip_serializing_if = "std::ops::Not::not")]
         identifier: bool,
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         any_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        all_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        exactly_one_of: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        none_of: Vec<String>,
     },
     /// Permissible values for a LinkML enum, in declaration order —
     /// each with its optional description and curie-expanded
@@ -344,6 +361,12 @@ pub(crate) fn resolve_node_uri(
     }
 }
 
+/// The ranges a slot combinator's branches name, in order; a branch that
+/// only adds facets names none.
+fn branch_ranges(branches: &[SlotDefinition]) -> Vec<String> {
+    branches.iter().filter_map(|s| s.range.clone()).collect()
+}
+
 fn resolve_class_slots(schema: &SchemaDefinition, class_name: &str) -> Vec<SlotSummary> {
     let Some(class_def) = schema.classes.get(class_name) else {
         return Vec::new();
@@ -420,6 +443,86 @@ impl GraphData {
             graph_kind: GraphKind::default(),
         }
     }
+
+    /// The graph around node `center`, out to `depth` hops: everything
+    /// within that many edges, where a slot node is a waypoint rather
+    /// than a hop — reaching a slot also reaches what it ranges over, so
+    /// a class's neighborhood shows where its slots lead, not just the
+    /// slots.
+    pub fn neighborhood(&self, center: &str, depth: usize) -> GraphData {
+        let mut keep: std::collections::HashSet<&str> = std::collections::HashSet::from([center]);
+        let mut frontier = vec![center];
+        for _ in 0..depth {
+            if frontier.is_empty() {
+                break;
+            }
+            let mut reached = Vec::new();
+            for edge in &self.edges {
+                for (from, to) in [(&edge.source, &edge.target), (&edge.target, &edge.source)] {
+                    if frontier.contains(&from.as_str()) && keep.insert(to) {
+                        reached.push(to.as_str());
+                    }
+                }
+            }
+            for edge in &self.edges {
+                if edge.edge_type == EdgeType::Range
+                    && edge.source.starts_with("slot:")
+                    && reached.contains(&edge.source.as_str())
+                    && keep.insert(&edge.target)
+                {
+                    reached.push(&edge.target);
+                }
+            }
+            frontier = reached;
+        }
+        let mut local = GraphData::new(self.schema_name.clone(), self.schema_title.clone());
+        local.nodes = self
+            .nodes
+            .iter()
+            .filter(|n| keep.contains(n.id.as_str()))
+            .cloned()
+            .collect();
+        local.edges = self
+            .edges
+            .iter()
+            .filter(|e| keep.contains(e.source.as_str()) && keep.contains(e.target.as_str()))
+            .cloned()
+            .collect();
+        local
+    }
+
+    /// The edges as a class diagram draws them. A slot that some class in
+    /// this graph declares folds into an edge from each such class to each
+    /// of the slot's ranges, labelled with the slot's name, and its domain
+    /// edges go; a slot no class here declares keeps its own edges.
+    pub fn class_edges(&self) -> Vec<GraphEdge> {
+        let mut domains: std::collections::HashMap<&str, Vec<&str>> =
+            std::collections::HashMap::new();
+        for edge in &self.edges {
+            if edge.edge_type == EdgeType::Domain && self.nodes.iter().any(|n| n.id == edge.target)
+            {
+                domains.entry(&edge.source).or_default().push(&edge.target);
+            }
+        }
+        let mut edges = Vec::new();
+        for edge in &self.edges {
+            match (edge.edge_type, domains.get(edge.source.as_str())) {
+                (EdgeType::Domain, Some(_)) => {}
+                (EdgeType::Range, Some(classes)) => {
+                    for class in classes {
+                        edges.push(GraphEdge {
+                            source: class.to_string(),
+                            target: edge.target.clone(),
+                            edge_type: EdgeType::Range,
+                            label: edge.source.strip_prefix("slot:").map(str::to_string),
+                        });
+                    }
+                }
+                _ => edges.push(edge.clone()),
+            }
+        }
+        edges
+    }
 }
 
 /// Options for controlling graph generation
@@ -542,6 +645,15 @@ impl GraphWriter {
             self.add_class_side_slot_edges(schema, &mut graph);
         }
 
+        // Inbound references for the hover card, computed once over the
+        // whole schema so hidden slot nodes still count as referrers.
+        let mut usages = crate::linkml_resolve::usage_index(schema);
+        for node in &mut graph.nodes {
+            if let Some(used_by) = usages.remove(&node.id) {
+                node.used_by = used_by;
+            }
+        }
+
         graph
     }
 
@@ -699,6 +811,7 @@ impl GraphWriter {
                         })
                         .collect(),
                 }),
+                used_by: Vec::new(),
             });
         }
 
@@ -717,6 +830,7 @@ impl GraphWriter {
                     enum_name,
                     usage_count,
                 }),
+                used_by: Vec::new(),
             });
         }
 
@@ -733,6 +847,7 @@ impl GraphWriter {
                 uri_unresolved,
                 is_abstract: false,
                 kind_metadata: None,
+                used_by: Vec::new(),
             });
         }
 
@@ -837,7 +952,11 @@ impl GraphWriter {
                 // slot-side edges. (A pure `maximum_cardinality: 0`
                 // refinement names no range and is suppressed downstream
                 // regardless, so it correctly draws nothing.)
-                let narrows_range = override_def.range.is_some() || !override_def.any_of.is_empty();
+                let narrows_range = override_def.range.is_some()
+                    || override_def
+                        .combinators()
+                        .iter()
+                        .any(|(_, branches)| !branches.is_empty());
                 if !narrows_range {
                     continue;
                 }
@@ -925,6 +1044,7 @@ impl GraphWriter {
                 uri_unresolved,
                 is_abstract: class_def.r#abstract,
                 kind_metadata,
+                used_by: Vec::new(),
             });
 
             // Add subclass edge (is_a)
@@ -1009,6 +1129,7 @@ impl GraphWriter {
                 uri_unresolved,
                 is_abstract: false,
                 kind_metadata: None,
+                used_by: Vec::new(),
             });
         }
 
@@ -1042,12 +1163,12 @@ impl GraphWriter {
                 min: cardinality.min,
                 max: cardinality.max,
                 pattern: slot_def.pattern.clone(),
+                unit: slot_def.unit.as_ref().and_then(|u| u.display()),
                 identifier: slot_def.identifier,
-                any_of: slot_def
-                    .any_of
-                    .iter()
-                    .filter_map(|s| s.range.clone())
-                    .collect(),
+                any_of: branch_ranges(&slot_def.any_of),
+                all_of: branch_ranges(&slot_def.all_of),
+                exactly_one_of: branch_ranges(&slot_def.exactly_one_of),
+                none_of: branch_ranges(&slot_def.none_of),
             });
 
             let (uri, uri_unresolved) = resolve_node_uri(schema, slot_def.slot_uri.as_deref());
@@ -1061,6 +1182,7 @@ impl GraphWriter {
                 uri_unresolved,
                 is_abstract: false,
                 kind_metadata,
+                used_by: Vec::new(),
             });
 
             // Add domain edge (slot -> class)
@@ -1080,13 +1202,23 @@ impl GraphWriter {
             // can be a single `range:` or a polymorphic `any_of` union of
             // member ranges; draw one edge per distinct target so the
             // union members aren't left disconnected (a missing edge here
-            // makes panschema's docs misrepresent the schema).
+            // makes panschema's docs misrepresent the schema). The
+            // `exactly_one_of` and `all_of` branches name ranges a value
+            // takes too; a `none_of` branch names one it must not, so it
+            // draws no edge — and strikes a same-named range.
             if self.options.include_range_edges {
                 let mut seen = std::collections::HashSet::new();
+                let excluded = branch_ranges(&slot_def.none_of);
                 let ranges = slot_def
                     .range
                     .iter()
-                    .chain(slot_def.any_of.iter().filter_map(|s| s.range.as_ref()));
+                    .chain(
+                        [&slot_def.any_of, &slot_def.exactly_one_of, &slot_def.all_of]
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|s| s.range.as_ref()),
+                    )
+                    .filter(|range| !excluded.contains(*range));
                 for range in ranges {
                     if let Some(target) = self.resolve_range_target(schema, range)
                         && seen.insert(target.clone())
@@ -1157,6 +1289,7 @@ impl GraphWriter {
                 uri_unresolved: false,
                 is_abstract: false,
                 kind_metadata,
+                used_by: Vec::new(),
             });
         }
     }
@@ -1175,6 +1308,7 @@ impl GraphWriter {
                 uri_unresolved,
                 is_abstract: false,
                 kind_metadata: None,
+                used_by: Vec::new(),
             });
 
             // Add typeof edge (type -> parent type)
@@ -1304,6 +1438,44 @@ mod tests {
         );
     }
 
+    /// Depth counts class-to-class hops; a slot on the way reaches its
+    /// range in the same hop.
+    #[test]
+    fn neighborhood_grows_one_hop_per_depth_through_slots() {
+        let mut schema = SchemaDefinition::new("s");
+        let mut org = ClassDefinition::new("Organization");
+        org.is_a = Some("Agent".to_string());
+        schema
+            .classes
+            .insert("Agent".to_string(), ClassDefinition::new("Agent"));
+        schema.classes.insert("Organization".to_string(), org);
+        let mut employer = SlotDefinition::new("employer");
+        employer.range = Some("Organization".to_string());
+        employer.domain = Some("Person".to_string());
+        schema.slots.insert("employer".to_string(), employer);
+        schema
+            .classes
+            .insert("Person".to_string(), ClassDefinition::new("Person"));
+        let graph = GraphWriter::new().schema_to_graph(&schema);
+
+        let ids = |depth| {
+            let mut ids: Vec<String> = graph
+                .neighborhood("class:Person", depth)
+                .nodes
+                .into_iter()
+                .map(|n| n.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(0), ["class:Person"]);
+        assert_eq!(
+            ids(1),
+            ["class:Organization", "class:Person", "slot:employer"]
+        );
+        assert!(ids(2).contains(&"class:Agent".to_string()));
+    }
+
     // ========== Instance (A-box) graph ==========
 
     #[test]
@@ -2470,6 +2642,7 @@ mod tests {
             uri_unresolved: false,
             is_abstract: false,
             kind_metadata: None,
+            used_by: Vec::new(),
         });
         graph.edges.push(GraphEdge {
             source: "class:Dog".to_string(),
@@ -2929,6 +3102,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn exactly_one_of_draws_range_edges_and_none_of_strikes_them() {
+        let mut schema = SchemaDefinition::new("combinator_edges");
+        for name in ["Dataset", "Question", "Result"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+        let branch = |range: &str| {
+            let mut b = SlotDefinition::new("");
+            b.range = Some(range.to_string());
+            b
+        };
+        let mut has_input = SlotDefinition::new("hasInput");
+        has_input.exactly_one_of = vec![branch("Dataset"), branch("Question"), branch("Result")];
+        has_input.none_of = vec![branch("Result")];
+        schema.slots.insert("hasInput".to_string(), has_input);
+
+        let graph = GraphWriter::new().schema_to_graph(&schema);
+        let mut range_targets: Vec<&str> = graph
+            .edges
+            .iter()
+            .filter(|e| e.source == "slot:hasInput" && e.edge_type == EdgeType::Range)
+            .map(|e| e.target.as_str())
+            .collect();
+        range_targets.sort_unstable();
+        assert_eq!(range_targets, vec!["class:Dataset", "class:Question"]);
+
+        let node = graph
+            .nodes
+            .iter()
+            .find(|n| n.id == "slot:hasInput")
+            .unwrap();
+        match node.kind_metadata.as_ref().unwrap() {
+            KindMetadata::Slot {
+                exactly_one_of,
+                none_of,
+                ..
+            } => {
+                assert_eq!(exactly_one_of.len(), 3);
+                assert_eq!(none_of, &vec!["Result".to_string()]);
+            }
+            other => panic!("expected Slot metadata, got {other:?}"),
+        }
+    }
+
     #[test]
     fn induced_range_draws_per_class_edges_and_skips_suppressed() {
         // A class that narrows an inherited `any_of` via `slot_usage`
@@ -3211,4 +3430,50 @@ mod tests {
             .unwrap();
         assert!(node.kind_metadata.is_none());
     }
+
+    #[test]
+    fn nodes_carry_their_inbound_references_for_the_hover_payload() {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        let mut dog = ClassDefinition::new("Dog");
+        dog.is_a = Some("Animal".to_string());
+        schema.classes.insert("Dog".to_string(), dog);
+        let mut keeps = SlotDefinition::new("keeps");
+        keeps.range = Some("Animal".to_string());
+        schema.slots.insert("keeps".to_string(), keeps);
+
+        let graph = GraphWriter::new().schema_to_graph(&schema);
+        let node = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap();
+        let sources: Vec<(&str, &str)> = node("class:Animal")
+            .used_by
+            .iter()
+            .map(|u| (u.kind.label(), u.source.as_str()))
+            .collect();
+        assert_eq!(sources, [("range", "slot:keeps"), ("is_a", "class:Dog")]);
+        assert!(node("class:Dog").used_by.is_empty());
+
+        let json = serde_json::to_value(&graph).unwrap();
+        let animal = json["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|n| n["id"] == "class:Animal")
+            .unwrap();
+        assert_eq!(
+            animal["used_by"][0],
+            serde_json::json!({"kind": "range", "source": "slot:keeps"})
+        );
+        let dog = json["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|n| n["id"] == "class:Dog")
+            .unwrap();
+        assert!(
+            dog.get("used_by").is_none(),
+            "an unreferenced node omits it"
+        );
+    }
 }
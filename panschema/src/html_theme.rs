@@ -0,0 +1,474 @@
+//! HTML themes: override components, CSS and assets for the generated page
+//!
+//! The built-in templates are compiled into the binary, so branding a
+//! page — a logo, colours, header links, footer legal text — goes
+//! through a theme directory instead, named by the manifest's
+//! `html_theme` or `generate --theme`:
+//!
+//! ```text
+//! theme/
+//!   header.html   replaces the site header
+//!   footer.html   replaces the site footer
+//!   hero.html     a banner above the overview's metadata card
+//!   theme.css     linked after the built-in styles
+//!   assets/       copied beside the page, for logos, fonts, images
+//! ```
+//!
+//! Every file is optional. A component override is plain HTML with
+//! `{{ name }}` placeholders drawn from that component's variable
+//! contract ([`Component::variables`]). Values are HTML-escaped (the
+//! hero's `description`, already-rendered Markdown, is the exception),
+//! and `{{ default }}` places the built-in component at that spot, so a
+//! theme can extend a component — a logo strip before the stock header
+//! — rather than replace it. Placeholders are checked when the theme
+//! loads: a name outside the contract fails generation before any page
+//! is written.
+//!
+//! Theme files land in the output's `theme/` directory; `{{ assets }}`
+//! is the page-relative URL of `theme/assets/`, and `theme.css` can
+//! reach the same files as `url(assets/…)`.
+//! A self-contained page (`generate --self-contained`) carries them
+//! inline instead; see [`Theme::inline_files`].
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::io::{IoError, IoResult};
+use crate::self_contained::{Inline, inline_css_urls, inline_tags};
+
+/// Where theme files land inside the HTML output directory.
+const OUTPUT_DIR: &str = "theme";
+const STYLESHEET: &str = "theme.css";
+const ASSETS_DIR: &str = "assets";
+
+/// Errors from loading a theme directory.
+#[derive(Debug, Error)]
+pub enum ThemeError {
+    #[error("theme directory `{0}` does not exist")]
+    NotFound(PathBuf),
+
+    #[error("reading theme file `{path}`: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("theme `{file}`: unknown variable `{name}`; {file} provides: {known}")]
+    UnknownVariable {
+        file: &'static str,
+        name: String,
+        known: String,
+    },
+
+    #[error("theme `{file}`: `{{{{` at byte {offset} has no closing `}}}}`")]
+    Unclosed { file: &'static str, offset: usize },
+
+    #[error("theme `{file}`: `{{{{ default }}}}` may appear at most once")]
+    RepeatedDefault { file: &'static str },
+}
+
+/// A built-in page component a theme can override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Component {
+    Header,
+    Footer,
+    Hero,
+}
+
+impl Component {
+    /// The override's file name in the theme directory.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Component::Header => "header.html",
+            Component::Footer => "footer.html",
+            Component::Hero => "hero.html",
+        }
+    }
+
+    /// The placeholders an override of this component may use:
+    ///
+    /// - `title` — the schema's title (its name when untitled)
+    /// - `version` — the schema's version, empty when undeclared
+    /// - `page_lang` — the page's language tag
+    /// - `site_root_href` — the URL the built-in brand link targets
+    /// - `assets` — the page-relative URL of the theme's `assets/`,
+    ///   ending in `/`
+    /// - `default` — the built-in component itself
+    /// - `description` (hero only) — the schema's description as
+    ///   rendered HTML, empty when undeclared
+    pub fn variables(self) -> &'static [&'static str] {
+        const COMMON: &[&str] = &[
+            "title",
+            "version",
+            "page_lang",
+            "site_root_href",
+            "assets",
+            "default",
+        ];
+        const HERO: &[&str] = &[
+            "title",
+            "version",
+            "page_lang",
+            "site_root_href",
+            "assets",
+            "default",
+            "description",
+        ];
+        match self {
+            Component::Header | Component::Footer => COMMON,
+            Component::Hero => HERO,
+        }
+    }
+}
+
+/// One piece of a parsed override.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Var(String),
+}
+
+/// A loaded theme directory, its overrides parsed and checked.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    dir: PathBuf,
+    header: Option<Vec<Segment>>,
+    footer: Option<Vec<Segment>>,
+    hero: Option<Vec<Segment>>,
+    has_stylesheet: bool,
+}
+
+/// The values a page fills a theme's placeholders with.
+pub(crate) struct ThemeVars<'a> {
+    pub title: &'a str,
+    pub version: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub page_lang: &'a str,
+    pub site_root_href: &'a str,
+    /// Prefix from the page to the output root: `""` for the overview,
+    /// `"../"` for an element page.
+    pub root: &'a str,
+}
+
+/// A component override rendered for one page: the markup around the
+/// built-in component, and whether that component is drawn at all.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ThemeSlot {
+    pub before: String,
+    pub after: String,
+    pub keep_default: bool,
+}
+
+/// A theme rendered for one page; empty when no theme is set.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ThemePage {
+    pub header: Option<ThemeSlot>,
+    pub footer: Option<ThemeSlot>,
+    pub hero: Option<ThemeSlot>,
+    /// Page-relative URL of `theme.css`.
+    pub stylesheet: Option<String>,
+}
+
+impl Theme {
+    /// Load and check the theme in `dir`.
+    pub fn load(dir: &Path) -> Result<Self, ThemeError> {
+        if !dir.is_dir() {
+            return Err(ThemeError::NotFound(dir.to_path_buf()));
+        }
+        let read = |component: Component| -> Result<Option<Vec<Segment>>, ThemeError> {
+            let path = dir.join(component.file_name());
+            if !path.is_file() {
+                return Ok(None);
+            }
+            let text = fs::read_to_string(&path).map_err(|source| ThemeError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            parse(component, &text).map(Some)
+        };
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            header: read(Component::Header)?,
+            footer: read(Component::Footer)?,
+            hero: read(Component::Hero)?,
+            has_stylesheet: dir.join(STYLESHEET).is_file(),
+        })
+    }
+
+    /// Render the overrides with one page's values.
+    pub(crate) fn page(&self, vars: &ThemeVars<'_>) -> ThemePage {
+        let slot = |component: Component, segments: &Option<Vec<Segment>>| {
+            segments
+                .as_ref()
+                .map(|segments| render(component, segments, vars))
+        };
+        ThemePage {
+            header: slot(Component::Header, &self.header),
+            footer: slot(Component::Footer, &self.footer),
+            hero: slot(Component::Hero, &self.hero),
+            stylesheet: self
+                .has_stylesheet
+                .then(|| format!("{}{OUTPUT_DIR}/{STYLESHEET}", vars.root)),
+        }
+    }
+
+    /// Copy `theme.css` and `assets/` into `output`'s `theme/` directory,
+    /// replacing what an earlier generation left there.
+    pub(crate) fn copy_files(&self, output: &Path) -> IoResult<()> {
+        let target = output.join(OUTPUT_DIR);
+        if target.exists() {
+            fs::remove_dir_all(&target).map_err(IoError::Io)?;
+        }
+        let assets = self.dir.join(ASSETS_DIR);
+        if !self.has_stylesheet && !assets.is_dir() {
+            return Ok(());
+        }
+        fs::create_dir_all(&target).map_err(IoError::Io)?;
+        if self.has_stylesheet {
+            fs::copy(self.dir.join(STYLESHEET), target.join(STYLESHEET)).map_err(IoError::Io)?;
+        }
+        if assets.is_dir() {
+            copy_dir(&assets, &target.join(ASSETS_DIR))?;
+        }
+        Ok(())
+    }
+
+    /// Fold `theme.css` and `assets/` into `html`, a page written at the
+    /// output root, instead of copying them beside it (see
+    /// [`crate::self_contained`]): every tag loading a `theme/assets/…`
+    /// file gets a `data:` URI, and the stylesheet link a `<style>` block
+    /// whose `url(assets/…)` references are inlined the same way.
+    pub(crate) fn inline_files(&self, html: &str) -> IoResult<String> {
+        let mut assets = HashMap::new();
+        let dir = self.dir.join(ASSETS_DIR);
+        if dir.is_dir() {
+            collect_assets(&dir, "", &mut assets)?;
+        }
+        let asset = |url: &str, prefix: &str| assets.get(url.strip_prefix(prefix)?).cloned();
+
+        let style = if self.has_stylesheet {
+            let css = fs::read_to_string(self.dir.join(STYLESHEET)).map_err(IoError::Io)?;
+            let css_assets = format!("{ASSETS_DIR}/");
+            let css = inline_css_urls(&css, |url| asset(url, &css_assets));
+            Some(format!(
+                "<style>\n{}\n</style>",
+                css.replace("</style", "<\\/style")
+            ))
+        } else {
+            None
+        };
+        // The link `base.html` writes for the copied stylesheet.
+        let stylesheet = format!("{OUTPUT_DIR}/{STYLESHEET}");
+        let assets_url = format!("{OUTPUT_DIR}/{ASSETS_DIR}/");
+        Ok(inline_tags(html, |tag, url| {
+            if tag == "link" && url == stylesheet {
+                return style.clone().map(Inline::Tag);
+            }
+            asset(url, &assets_url).map(Inline::Value)
+        }))
+    }
+}
+
+/// Every file under `dir`, keyed by its path relative to the assets root
+/// with `/` separators, as a `data:` URI.
+fn collect_assets(dir: &Path, prefix: &str, out: &mut HashMap<String, String>) -> IoResult<()> {
+    for entry in fs::read_dir(dir).map_err(IoError::Io)? {
+        let entry = entry.map_err(IoError::Io)?;
+        let path = entry.path();
+        let rel = format!("{prefix}{}", entry.file_name().to_string_lossy());
+        if path.is_dir() {
+            collect_assets(&path, &format!("{rel}/"), out)?;
+        } else {
+            let bytes = fs::read(&path).map_err(IoError::Io)?;
+            out.insert(rel, crate::self_contained::data_uri(&path, &bytes));
+        }
+    }
+    Ok(())
+}
+
+/// Split an override into text and `{{ name }}` placeholders, checking
+/// each name against the component's contract.
+fn parse(component: Component, text: &str) -> Result<Vec<Segment>, ThemeError> {
+    let file = component.file_name();
+    let mut segments = Vec::new();
+    let mut rest = text;
+    let mut offset = 0;
+    let mut defaults = 0;
+    while let Some(open) = rest.find("{{") {
+        let Some(close) = rest[open + 2..].find("}}") else {
+            return Err(ThemeError::Unclosed {
+                file,
+                offset: offset + open,
+            });
+        };
+        let name = rest[open + 2..open + 2 + close].trim();
+        if !component.variables().contains(&name) {
+            return Err(ThemeError::UnknownVariable {
+                file,
+                name: name.to_string(),
+                known: component.variables().join(", "),
+            });
+        }
+        if name == "default" {
+            defaults += 1;
+            if defaults > 1 {
+                return Err(ThemeError::RepeatedDefault { file });
+            }
+        }
+        if open > 0 {
+            segments.push(Segment::Text(rest[..open].to_string()));
+        }
+        segments.push(Segment::Var(name.to_string()));
+        let end = open + 2 + close + 2;
+        offset += end;
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest.to_string()));
+    }
+    Ok(segments)
+}
+
+fn render(component: Component, segments: &[Segment], vars: &ThemeVars<'_>) -> ThemeSlot {
+    let mut slot = ThemeSlot::default();
+    for segment in segments {
+        let out = if slot.keep_default {
+            &mut slot.after
+        } else {
+            &mut slot.before
+        };
+        match segment {
+            Segment::Text(text) => out.push_str(text),
+            Segment::Var(name) => match name.as_str() {
+                "default" => slot.keep_default = true,
+                "title" => out.push_str(&html_escape(vars.title)),
+                "version" => out.push_str(&html_escape(vars.version.unwrap_or(""))),
+                "page_lang" => out.push_str(&html_escape(vars.page_lang)),
+                "site_root_href" => out.push_str(&html_escape(vars.site_root_href)),
+                "assets" => out.push_str(&html_escape(&format!(
+                    "{}{OUTPUT_DIR}/{ASSETS_DIR}/",
+                    vars.root
+                ))),
+                "description" if component == Component::Hero => {
+                    out.push_str(vars.description.unwrap_or(""))
+                }
+                // `parse` admitted only the component's own names.
+                _ => unreachable!("unchecked theme variable `{name}`"),
+            },
+        }
+    }
+    slot
+}
+
+pub(crate) fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn copy_dir(from: &Path, to: &Path) -> IoResult<()> {
+    fs::create_dir_all(to).map_err(IoError::Io)?;
+    for entry in fs::read_dir(from).map_err(IoError::Io)? {
+        let entry = entry.map_err(IoError::Io)?;
+        let path = entry.path();
+        if path.is_dir() {
+            copy_dir(&path, &to.join(entry.file_name()))?;
+        } else {
+            fs::copy(&path, to.join(entry.file_name())).map_err(IoError::Io)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(root: &str) -> ThemeVars<'_> {
+        ThemeVars {
+            title: "Zoo & Co",
+            version: Some("1.2.0"),
+            description: Some("<p>Animals</p>"),
+            page_lang: "en",
+            site_root_href: "./",
+            root,
+        }
+    }
+
+    #[test]
+    fn placeholders_fill_escaped_around_the_default_component() {
+        let segments = parse(
+            Component::Header,
+            r#"<img src="{{ assets }}logo.svg" alt="{{title}}">{{ default }}<p>v{{ version }}</p>"#,
+        )
+        .unwrap();
+        let slot = render(Component::Header, &segments, &vars("../"));
+        assert_eq!(
+            slot.before,
+            r#"<img src="../theme/assets/logo.svg" alt="Zoo &amp; Co">"#
+        );
+        assert!(slot.keep_default);
+        assert_eq!(slot.after, "<p>v1.2.0</p>");
+
+        let segments = parse(Component::Hero, "<div>{{ description }}</div>").unwrap();
+        let slot = render(Component::Hero, &segments, &vars(""));
+        assert!(!slot.keep_default, "no `default` replaces the component");
+        assert_eq!(slot.before, "<div><p>Animals</p></div>");
+    }
+
+    #[test]
+    fn placeholders_outside_the_contract_are_rejected() {
+        let err = parse(Component::Footer, "{{ description }}").unwrap_err();
+        assert!(
+            matches!(err, ThemeError::UnknownVariable { ref name, .. } if name == "description"),
+            "{err}"
+        );
+        assert!(
+            err.to_string().contains("provides: title, version"),
+            "{err}"
+        );
+        assert!(matches!(
+            parse(Component::Header, "<b>{{ title </b>").unwrap_err(),
+            ThemeError::Unclosed { offset: 3, .. }
+        ));
+        assert!(matches!(
+            parse(Component::Header, "{{ default }}{{ default }}").unwrap_err(),
+            ThemeError::RepeatedDefault { .. }
+        ));
+    }
+
+    #[test]
+    fn load_reads_optional_files_and_copies_assets() {
+        let theme_dir = tempfile::tempdir().unwrap();
+        fs::write(theme_dir.path().join("footer.html"), "<p>© {{ title }}</p>").unwrap();
+        fs::write(
+            theme_dir.path().join("theme.css"),
+            ":root { --color-primary: red; }",
+        )
+        .unwrap();
+        fs::create_dir_all(theme_dir.path().join("assets/img")).unwrap();
+        fs::write(theme_dir.path().join("assets/img/logo.svg"), "<svg/>").unwrap();
+
+        let theme = Theme::load(theme_dir.path()).unwrap();
+        let page = theme.page(&vars(""));
+        assert!(page.header.is_none() && page.hero.is_none());
+        assert_eq!(page.footer.unwrap().before, "<p>© Zoo &amp; Co</p>");
+        assert_eq!(page.stylesheet.as_deref(), Some("theme/theme.css"));
+
+        let output = tempfile::tempdir().unwrap();
+        theme.copy_files(output.path()).unwrap();
+        assert!(output.path().join("theme/theme.css").is_file());
+        assert!(output.path().join("theme/assets/img/logo.svg").is_file());
+
+        let missing = theme_dir.path().join("nope");
+        assert!(matches!(
+            Theme::load(&missing).unwrap_err(),
+            ThemeError::NotFound(_)
+        ));
+    }
+}
@@ -2,15 +2,17 @@
 //!
 //! Writes LinkML SchemaDefinition to HTML documentation.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
 use askama::Template;
 
 use crate::graph_writer::GraphWriter;
+use crate::html_theme::{Theme, ThemePage, ThemeVars, html_escape};
 use crate::io::{IoError, IoResult, Writer};
-use crate::linkml::{Example, SchemaDefinition};
+use crate::linkml::{CommonMetadata, Example, SchemaDefinition, rank_order};
+use crate::schema_diff::{ChangeKind, ElementChange, ElementKind, FieldChange};
 
 /// Entity reference for sidebar navigation and cross-references.
 #[derive(Debug, Clone)]
@@ -66,6 +68,9 @@ pub struct ClassData {
     /// Alternative names from `aliases:`. Rendered as a comma-joined
     /// "Aliases" row; empty renders nothing.
     pub aliases: Vec<String>,
+    /// Subsets the class belongs to (`in_subset:`). Rendered as one badge
+    /// per subset in the heading, linking to its Subsets entry.
+    pub in_subset: Vec<String>,
     /// Related-resource references from `see_also:`, CURIE-expanded into
     /// links. Rendered as a "See also" row; empty renders nothing.
     pub see_also: Vec<ExternalLink>,
@@ -73,12 +78,71 @@ pub struct ClassData {
     /// section listing each value with its optional description; empty
     /// renders nothing.
     pub examples: Vec<Example>,
+    /// Editorial and provenance rows (`comments:`, `status:`, `created_by:`,
+    /// …); `None` when the class sets none of them.
+    pub editorial: Option<Editorial>,
+    /// The label and description in other languages, from `local_names:`
+    /// / `alt_descriptions:`; hidden until the page's language switcher
+    /// picks one. Empty renders nothing.
+    pub translations: Vec<Translation>,
     /// Conditional constraints from `rules:`. Rendered as a "Rules"
     /// section; empty renders nothing.
     pub rules: Vec<RuleInClass>,
     /// Uniqueness constraints from `unique_keys:`. Rendered as a "Unique
     /// keys" row; empty renders nothing.
     pub unique_keys: Vec<UniqueKeyInClass>,
+    /// Boolean class expressions and set axioms (`union_of`,
+    /// `disjoint_with`, `any_of`, `all_of`, `exactly_one_of`, `none_of`),
+    /// one labeled row each; empty renders nothing.
+    pub expressions: Vec<ClassExpressionRow>,
+    /// Inbound references: slots ranging over the class, subclasses and
+    /// mixers, class expressions naming it, and the default dataset's
+    /// records typed by it. Rendered as a "Used by" row; empty renders
+    /// nothing.
+    pub used_by: Vec<UsageRow>,
+}
+
+/// One inbound reference on a card's "Used by" row (see
+/// [`crate::linkml_resolve::usage_index`]): how the referrer uses the
+/// element, and a link to the referrer's card.
+#[derive(Debug, Clone)]
+pub struct UsageRow {
+    /// The relation, e.g. "range", "is_a", "rule" or "record".
+    pub relation: &'static str,
+    pub label: String,
+    /// Where inside the referrer the reference sits: the attribute or
+    /// `slot_usage` slot, the rule title or the unique key name.
+    pub detail: Option<String>,
+    /// The referrer's card anchor, e.g. `slot-owner` or `ind-rex`.
+    pub anchor: String,
+    /// The referrer's kind, for its link class: `class`, `slot` or
+    /// `individual`.
+    pub kind: &'static str,
+}
+
+/// One class-expression row on a class card, e.g. "Any of" over its
+/// members. A member naming a bare class renders as a class link, the way
+/// a slot's `any_of` range does; any other renders as its markdown
+/// summary (see [`crate::rules::class_expression_summary`]).
+#[derive(Debug, Clone)]
+pub struct ClassExpressionRow {
+    /// The row label: "Union of", "Disjoint with", "Any of", "All of",
+    /// "Exactly one of" or "None of".
+    pub label: &'static str,
+    /// Rendered HTML per member.
+    pub members: Vec<String>,
+}
+
+/// One named subset as rendered in the Subsets section: its description
+/// and the elements that declare membership through `in_subset`.
+#[derive(Debug, Clone)]
+pub struct SubsetData {
+    pub id: String,
+    /// Markdown-rendered, like [`ClassData::description`].
+    pub description: Option<String>,
+    /// Rendered HTML link per member: classes, then slots, enumerations
+    /// and types, each in name order.
+    pub members: Vec<String>,
 }
 
 /// A `rules` entry as rendered on a class card.
@@ -116,10 +180,11 @@ pub struct UniqueKeyInClass {
 /// descendant of (each closed with a `</ul></li>` pair).
 #[derive(Debug, Clone)]
 pub struct ClassTreeEntry {
-    /// Index into the alphabetical `class_data` list — the card to
-    /// render at this position. Doubling as the class's alphabetical
-    /// rank, it is also the CSS `order` value the flat view sorts
-    /// cards by after dissolving the tree with `display: contents`.
+    /// Index into the card-ordered `class_data` list (by `rank`, then
+    /// alphabetical) — the card to render at this position. Doubling as
+    /// the class's position in that order, it is also the CSS `order`
+    /// value the flat view sorts cards by after dissolving the tree with
+    /// `display: contents`.
     pub index: usize,
     pub depth: usize,
     pub has_children: bool,
@@ -135,9 +200,9 @@ impl ClassTreeEntry {
     }
 }
 
-/// Arrange the alphabetical class list into a pre-order `is_a`
+/// Arrange the card-ordered class list into a pre-order `is_a`
 /// forest: roots are classes with no resolvable parent, children
-/// nest under their parent in alphabetical order. Fail-open on
+/// nest under their parent in card order. Fail-open on
 /// pathological shapes — an `is_a` cycle leaves its members
 /// unreachable from any root, so a sweep pass renders them as
 /// roots rather than dropping them.
@@ -217,6 +282,8 @@ pub struct SlotInClass {
     pub name: String,
     pub range: Option<RangeRef>,
     pub required: bool,
+    /// LinkML `recommended`: expected but not required.
+    pub recommended: bool,
     pub multivalued: bool,
     /// Members of an `any_of` union; empty for single-range slots.
     pub any_of: Vec<RangeRef>,
@@ -236,6 +303,11 @@ pub struct SlotInClass {
     /// `description` and `description_tooltip` are mutually
     /// exclusive.
     pub description_tooltip: Option<String>,
+    /// The `slot_group` this slot is listed under, set only on the first
+    /// slot of each group so the card opens a section heading there.
+    /// Slots arrive grouped (see
+    /// [`crate::linkml_resolve::grouped_slot_order`]).
+    pub group_heading: Option<String>,
 }
 
 /// Range reference for property cards - either a class link or a datatype name.
@@ -256,6 +328,255 @@ pub struct PermissibleValueData {
     pub meaning: Option<ExternalLink>,
 }
 
+/// The editorial and provenance rows of a card, built from an element's
+/// [`CommonMetadata`]. `rank` is absent: it orders the cards rather than
+/// rendering on one.
+#[derive(Debug, Clone, Default)]
+pub struct Editorial {
+    pub status: Option<String>,
+    pub keywords: Vec<String>,
+    pub comments: Vec<String>,
+    pub notes: Vec<String>,
+    pub todos: Vec<String>,
+    /// `source:` as a link, CURIE-expanded like `see_also`.
+    pub source: Option<ExternalLink>,
+    /// "by <agent> on <date>" for the Created row, from `created_by` /
+    /// `created_on`; either half may be missing.
+    pub created: Option<String>,
+    /// The same for the Last updated row, from `modified_by` /
+    /// `last_updated_on`.
+    pub updated: Option<String>,
+}
+
+impl Editorial {
+    /// The rows for `metadata`, or `None` when it sets nothing a card shows.
+    pub fn from_metadata(
+        metadata: &CommonMetadata,
+        schema: &SchemaDefinition,
+        labels: Option<&crate::labels::LabelStore>,
+    ) -> Option<Self> {
+        // Rank orders the cards and the language fields feed the
+        // translations; neither is a row.
+        let rows_only = CommonMetadata {
+            rank: None,
+            in_language: None,
+            local_names: Default::default(),
+            alt_descriptions: Default::default(),
+            structured_aliases: Vec::new(),
+            ..metadata.clone()
+        };
+        if rows_only.is_empty() {
+            return None;
+        }
+        Some(Self {
+            status: metadata.status.clone(),
+            keywords: metadata.keywords.clone(),
+            comments: metadata.comments.clone(),
+            notes: metadata.notes.clone(),
+            todos: metadata.todos.clone(),
+            source: metadata
+                .source
+                .as_ref()
+                .and_then(|raw| build_see_also(std::slice::from_ref(raw), schema, labels).pop()),
+            created: provenance(
+                metadata.created_by.as_deref(),
+                metadata.created_on.as_deref(),
+            ),
+            updated: provenance(
+                metadata.modified_by.as_deref(),
+                metadata.last_updated_on.as_deref(),
+            ),
+        })
+    }
+}
+
+/// "by <agent> on <date>", dropping whichever half is absent.
+fn provenance(agent: Option<&str>, date: Option<&str>) -> Option<String> {
+    match (agent, date) {
+        (Some(agent), Some(date)) => Some(format!("by {agent} on {date}")),
+        (Some(agent), None) => Some(format!("by {agent}")),
+        (None, Some(date)) => Some(format!("on {date}")),
+        (None, None) => None,
+    }
+}
+
+/// One language's label and description for a card, from the element's
+/// `local_names` / `alt_descriptions`. Either half may be missing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translation {
+    /// BCP 47 language tag, e.g. `fr`.
+    pub lang: String,
+    pub label: Option<String>,
+    /// Rendered like the card's own description.
+    pub description: Option<String>,
+    /// The `structured_aliases` in this language.
+    pub aliases: Vec<String>,
+}
+
+impl Translation {
+    /// Every language `metadata` translates the element into, in tag order.
+    fn all_from(
+        metadata: &CommonMetadata,
+        schema: &SchemaDefinition,
+        links: &CardLinks,
+    ) -> Vec<Self> {
+        let tags: std::collections::BTreeSet<&str> = metadata
+            .local_names
+            .keys()
+            .chain(metadata.alt_descriptions.keys())
+            .map(String::as_str)
+            .chain(
+                metadata
+                    .structured_aliases
+                    .iter()
+                    .filter_map(|a| a.in_language.as_deref()),
+            )
+            .collect();
+        tags.into_iter()
+            .map(|tag| Self {
+                lang: tag.to_string(),
+                label: metadata.local_names.get(tag).cloned(),
+                description: metadata
+                    .alt_descriptions
+                    .get(tag)
+                    .map(|d| render_description(d, schema, links)),
+                aliases: metadata
+                    .structured_aliases
+                    .iter()
+                    .filter(|a| a.in_language.as_deref() == Some(tag))
+                    .map(|a| a.literal_form.clone())
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// One card in the page's search index, embedded as JSON for the sidebar
+/// search box, which ranks entries client-side. `kind` and `name` locate
+/// the card (`#class-Person`) and its graph node (`class:Person`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SearchEntry {
+    /// `class`, `slot`, `enum` or `type`.
+    pub kind: &'static str,
+    pub name: String,
+    pub label: String,
+    /// Aliases, plus every translated label and alias on the card.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// The element's CURIE or IRI, when it has one beyond its name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curie: Option<String>,
+    /// Plain text of the card's description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An enum's permissible values.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+    /// Mapping CURIEs and their cached upstream labels.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mappings: Vec<String>,
+    /// The element's own page in multi-page mode; without one the
+    /// result jumps to the card on the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl SearchEntry {
+    fn new(
+        kind: &'static str,
+        name: &str,
+        label: &str,
+        aliases: &[String],
+        translations: &[Translation],
+        description: Option<&str>,
+    ) -> Self {
+        Self {
+            kind,
+            name: name.to_string(),
+            label: label.to_string(),
+            aliases: aliases
+                .iter()
+                .cloned()
+                .chain(
+                    translations
+                        .iter()
+                        .flat_map(|t| t.label.iter().cloned().chain(t.aliases.iter().cloned())),
+                )
+                .collect(),
+            curie: None,
+            description: description.map(plain_text),
+            values: Vec::new(),
+            mappings: Vec::new(),
+            page: None,
+        }
+    }
+}
+
+/// The text of rendered card HTML: tags dropped, the escapes
+/// [`render_description`] produces decoded.
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/// A card's Aliases row: `aliases:` plus the `structured_aliases` that
+/// name no language (those that do render with their translation).
+fn card_aliases(aliases: &[String], metadata: &CommonMetadata) -> Vec<String> {
+    aliases
+        .iter()
+        .cloned()
+        .chain(
+            metadata
+                .structured_aliases
+                .iter()
+                .filter(|a| a.in_language.is_none())
+                .map(|a| a.literal_form.clone()),
+        )
+        .collect()
+}
+
+/// Make `lang`'s translation a card's primary text: its label and
+/// description replace `label` / `description`, its aliases join
+/// `aliases`, and the text they replace becomes a translation tagged
+/// `source_lang`. A card without `lang` keeps its source text.
+fn make_primary(
+    lang: &str,
+    source_lang: &str,
+    label: &mut String,
+    description: &mut Option<String>,
+    aliases: &mut Vec<String>,
+    translations: &mut Vec<Translation>,
+) {
+    let Some(pos) = translations.iter().position(|t| t.lang == lang) else {
+        return;
+    };
+    let chosen = translations.remove(pos);
+    aliases.extend(chosen.aliases);
+    let source = Translation {
+        lang: source_lang.to_string(),
+        label: chosen.label.map(|l| std::mem::replace(label, l)),
+        description: chosen.description.and_then(|d| description.replace(d)),
+        aliases: Vec::new(),
+    };
+    translations.push(source);
+    translations.sort_by(|a, b| a.lang.cmp(&b.lang));
+}
+
 /// Enumeration data for rendering an enum card.
 #[derive(Debug, Clone)]
 pub struct EnumData {
@@ -267,10 +588,19 @@ pub struct EnumData {
     pub deprecated: Option<String>,
     /// Alternative names; see [`ClassData::aliases`].
     pub aliases: Vec<String>,
+    /// Subset membership; see [`ClassData::in_subset`].
+    pub in_subset: Vec<String>,
     /// Related-resource links; see [`ClassData::see_also`].
     pub see_also: Vec<ExternalLink>,
     /// Worked examples; see [`ClassData::examples`].
     pub examples: Vec<Example>,
+    /// Editorial rows; see [`ClassData::editorial`].
+    pub editorial: Option<Editorial>,
+    /// Other-language label and description; see
+    /// [`ClassData::translations`].
+    pub translations: Vec<Translation>,
+    /// Inbound references; see [`ClassData::used_by`].
+    pub used_by: Vec<UsageRow>,
 }
 
 /// Type data for rendering a type card.
@@ -290,10 +620,17 @@ pub struct TypeData {
     pub deprecated: Option<String>,
     /// Alternative names; see [`ClassData::aliases`].
     pub aliases: Vec<String>,
+    /// Subset membership; see [`ClassData::in_subset`].
+    pub in_subset: Vec<String>,
     /// Related-resource links; see [`ClassData::see_also`].
     pub see_also: Vec<ExternalLink>,
     /// Worked examples; see [`ClassData::examples`].
     pub examples: Vec<Example>,
+    /// Editorial rows; see [`ClassData::editorial`].
+    pub editorial: Option<Editorial>,
+    /// Other-language label and description; see
+    /// [`ClassData::translations`].
+    pub translations: Vec<Translation>,
 }
 
 /// A cross-ontology mapping rendered on class / property cards.
@@ -378,6 +715,11 @@ pub struct SlotData {
     /// Validation `pattern` (regex), if any — rendered truncated with the
     /// full value on a tooltip.
     pub pattern: Option<String>,
+    /// The slot's `unit` of measure, for the Unit row. `None` renders no row.
+    pub unit: Option<UnitData>,
+    /// The shape of an `array` slot, e.g. `2-D array of float; time (any) ×
+    /// channel (3)`, for the Array row. `None` renders no row.
+    pub array: Option<String>,
     pub characteristics: Vec<String>,
     pub mappings: Vec<Mapping>,
     /// Deprecation note when the slot is marked `deprecated:`. The
@@ -386,10 +728,17 @@ pub struct SlotData {
     pub deprecated: Option<String>,
     /// Alternative names; see [`ClassData::aliases`].
     pub aliases: Vec<String>,
+    /// Subset membership; see [`ClassData::in_subset`].
+    pub in_subset: Vec<String>,
     /// Related-resource links; see [`ClassData::see_also`].
     pub see_also: Vec<ExternalLink>,
     /// Worked examples; see [`ClassData::examples`].
     pub examples: Vec<Example>,
+    /// Editorial rows; see [`ClassData::editorial`].
+    pub editorial: Option<Editorial>,
+    /// Other-language label and description; see
+    /// [`ClassData::translations`].
+    pub translations: Vec<Translation>,
     /// The slot's `ifabsent` default, rendered readably for the Default
     /// row (`planned`, `8080`, `"svc"`, `true`). `None` renders no row.
     pub default: Option<String>,
@@ -397,6 +746,9 @@ pub struct SlotData {
     /// the class and carrying the rendered rule summary — the slot card's
     /// "Governed by" section. Empty when no rule names the slot.
     pub governing_rules: Vec<GoverningRule>,
+    /// Inbound references — subslots, and rules and unique keys naming
+    /// the slot; see [`ClassData::used_by`].
+    pub used_by: Vec<UsageRow>,
 }
 
 /// A class rule that references a slot, for the slot card's "Governed by"
@@ -413,6 +765,27 @@ pub struct GoverningRule {
     pub participants: String,
 }
 
+/// A slot's unit of measure for the slot card's Unit row: the readable
+/// rendering (`kg (kilogram)`), linked to the first `exact_mappings` IRI
+/// (typically a QUDT unit) when it expands.
+#[derive(Debug, Clone)]
+pub struct UnitData {
+    pub display: String,
+    pub href: Option<String>,
+}
+
+impl UnitData {
+    fn from_unit(unit: &crate::linkml::UnitOfMeasure, schema: &SchemaDefinition) -> Option<Self> {
+        Some(Self {
+            display: unit.display()?,
+            href: unit
+                .exact_mappings
+                .iter()
+                .find_map(|m| crate::linkml_resolve::expand_curie(schema, m)),
+        })
+    }
+}
+
 /// A resolved property value for rendering individual cards.
 #[derive(Debug, Clone)]
 pub struct PropertyValueData {
@@ -467,6 +840,8 @@ struct IndexTemplate<'a> {
     iri: &'a str,
     version: Option<&'a str>,
     comment: Option<&'a str>,
+    /// The schema's own editorial rows, on the metadata card.
+    editorial: Option<&'a Editorial>,
     active_section: &'a str,
     classes: &'a [EntityRef],
     class_data: &'a [ClassData],
@@ -478,6 +853,9 @@ struct IndexTemplate<'a> {
     types: &'a [EntityRef],
     type_data: &'a [TypeData],
     namespaces: &'a [Namespace],
+    /// The schema's declared subsets, for the Subsets section and its
+    /// sidebar entry; empty renders neither.
+    subsets: &'a [SubsetData],
     /// Empty slice for class cards that don't have slots yet
     /// Graph data JSON for visualization (None = no graph)
     graph_json: Option<&'a str>,
@@ -518,8 +896,88 @@ struct IndexTemplate<'a> {
     site_root_href: &'a str,
     /// Page composition: lead with the instance section.
     instances_first: bool,
+    /// The page's language, for `<html lang>` and the switcher's first
+    /// choice.
+    page_lang: &'a str,
+    /// The other languages the cards are translated into; empty hides
+    /// the language switcher.
+    languages: &'a [String],
     /// Page composition: render the schema reference sections.
     show_schema_sections: bool,
+    /// The cards' [`SearchEntry`] index as JSON (`<` escaped); `None`
+    /// hides the search box.
+    search_json: Option<&'a str>,
+    /// Multi-page mode: the cards live on their own pages, so the
+    /// sections keep only their entity lists and the sidebar links the
+    /// sitemap.
+    site_pages: bool,
+    /// Where the cards' links point: their anchors here, or in
+    /// multi-page mode the elements' pages.
+    links: &'a CardLinks,
+    /// The previous published version, for the sidebar's link to the
+    /// Changes page; `None` hides it.
+    changes_since: Option<&'a str>,
+    /// The theme's overrides for this page; empty without a theme.
+    theme: &'a ThemePage,
+}
+
+/// A multi-page site's page for one class, slot, enum or type: its card
+/// (with its "Used by" list), a breadcrumb back to the overview, and —
+/// for a class — the graph around it. Exactly one of the card fields is
+/// set.
+#[derive(Template)]
+#[template(path = "element.html")]
+struct ElementTemplate<'a> {
+    /// The schema's title, for the header and the breadcrumb root.
+    title: &'a str,
+    page_lang: &'a str,
+    languages: &'a [String],
+    version_context: Option<&'a VersionContext>,
+    page_links: &'a [PageLink],
+    site_root_href: &'a str,
+    /// `classes`, `slots`, `enums` or `types`: the page's directory and
+    /// its section's anchor on the overview.
+    kind_dir: &'a str,
+    /// The section heading, e.g. `Classes`.
+    kind_title: &'a str,
+    element_label: &'a str,
+    /// Every page of this kind, the current one `active`, for the
+    /// sidebar.
+    siblings: &'a [PageLink],
+    class: Option<&'a ClassData>,
+    slot: Option<&'a SlotData>,
+    en: Option<&'a EnumData>,
+    ty: Option<&'a TypeData>,
+    /// Where the card's links point, as seen from this page.
+    links: &'a CardLinks,
+    /// A class page's neighborhood graph (see
+    /// [`crate::graph_writer::GraphData::neighborhood`]); `None` for the
+    /// other kinds or with the graph off.
+    graph_json: Option<&'a str>,
+    graph_aspect_w: u32,
+    graph_aspect_h: u32,
+    graph_default_layout: &'a str,
+    theme: &'a ThemePage,
+}
+
+/// One kind's entries on the sitemap.
+struct SitemapSection<'a> {
+    id: &'a str,
+    title: &'a str,
+    pages: Vec<PageLink>,
+}
+
+#[derive(Template)]
+#[template(path = "sitemap.html")]
+struct SitemapTemplate<'a> {
+    title: &'a str,
+    page_lang: &'a str,
+    languages: &'a [String],
+    version_context: Option<&'a VersionContext>,
+    page_links: &'a [PageLink],
+    site_root_href: &'a str,
+    sections: &'a [SitemapSection<'a>],
+    theme: &'a ThemePage,
 }
 
 /// One entry in the header's page nav: a page of the published site,
@@ -590,6 +1048,78 @@ impl VersionContext {
     }
 }
 
+/// What a published version changed since the one before it, from
+/// [`crate::schema_diff::diff_schemas`]. Set by `panschema publish` on
+/// every version but the oldest: the page gains a Changes page
+/// (`changes.html`) linked from the sidebar, and its cards gain "changed
+/// in" badges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionChanges {
+    /// The version this one is compared against, e.g. `v0.1.0`.
+    pub previous: String,
+    /// The previous version's docs root, relative to this version's
+    /// (`../v0.1.0/`). The Changes page links removed and modified
+    /// elements there.
+    pub previous_href: String,
+    /// What differs from `previous`, in page order.
+    pub changes: Vec<ElementChange>,
+    /// Each badged card's badge, keyed by card anchor (`class-Person`):
+    /// the latest version up to this one that added or modified the
+    /// element.
+    pub badges: BTreeMap<String, ChangeBadge>,
+}
+
+/// A card's "changed in" badge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeBadge {
+    /// The version that last added or modified the element.
+    pub version: String,
+    /// [`ChangeKind::Added`] reads "new in"; anything else "changed in".
+    pub change: ChangeKind,
+    /// The element's entry on that version's Changes page, relative to
+    /// the rendering version's root.
+    pub href: String,
+}
+
+/// One element on the Changes page.
+struct ChangeEntry<'a> {
+    anchor: String,
+    name: &'a str,
+    /// `added`, `removed` or `modified`.
+    change: &'static str,
+    /// The element's card in this version; `None` once removed.
+    href: Option<String>,
+    /// The element's card in the previous version; `None` when added.
+    previous_href: Option<String>,
+    fields: &'a [FieldChange],
+    /// A changed field constrains instance data.
+    constraint: bool,
+}
+
+/// One kind's entries on the Changes page.
+struct ChangeSection<'a> {
+    id: &'static str,
+    title: &'static str,
+    entries: Vec<ChangeEntry<'a>>,
+}
+
+#[derive(Template)]
+#[template(path = "changes.html")]
+struct ChangesTemplate<'a> {
+    title: &'a str,
+    page_lang: &'a str,
+    languages: &'a [String],
+    version_context: Option<&'a VersionContext>,
+    page_links: &'a [PageLink],
+    site_root_href: &'a str,
+    /// The version the page describes.
+    viewing: &'a str,
+    previous: &'a str,
+    previous_href: &'a str,
+    sections: &'a [ChangeSection<'a>],
+    theme: &'a ThemePage,
+}
+
 /// Writer for HTML documentation output
 /// Which half of a composed page leads. One shared type for the
 /// manifest's `html_page_layout`, the publish spec's `layout`, and the
@@ -603,6 +1133,21 @@ pub enum PageLayout {
     InstancesFirst,
 }
 
+/// How the schema reference is laid out on disk: every card on one
+/// `index.html`, or a page per element at a stable URL. The manifest's
+/// `html_site_mode` parses straight into it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SiteMode {
+    #[default]
+    SinglePage,
+    /// `index.html` keeps the overview, the schema graph and the entity
+    /// lists; each class, slot, enum and type gets its own page under
+    /// `classes/`, `slots/`, `enums/` and `types/`, listed by
+    /// `sitemap.html`.
+    MultiPage,
+}
+
 pub struct HtmlWriter {
     /// Whether to include graph visualization (default: true)
     pub include_graph: bool,
@@ -650,6 +1195,24 @@ pub struct HtmlWriter {
     /// individuals embedded in the schema. More than one renders a
     /// selector; the first is shown by default.
     pub instance_datasets: Vec<InstanceDataset>,
+    /// Page language (`--lang`). Cards translated into it show that
+    /// label and description, keeping the source text as a translation;
+    /// `None` renders the schema's own language.
+    pub lang: Option<String>,
+    /// One page, or a page per element; see [`SiteMode`]. Only the
+    /// schema sections split, so a page without them is always single.
+    pub site_mode: SiteMode,
+    /// Override components, CSS and assets (`html_theme`); `None`
+    /// renders the built-in look.
+    pub theme: Option<Theme>,
+    /// Changes since the previous published version; see
+    /// [`VersionChanges`]. `None` for `panschema generate` and for a
+    /// site's oldest version.
+    pub changes: Option<VersionChanges>,
+    /// Write one `index.html` carrying the viz bundle and theme files
+    /// inline instead of beside it (`--self-contained`); see
+    /// [`crate::self_contained`].
+    pub self_contained: bool,
 }
 
 /// One curated A-box rendered in the Instance Graph section.
@@ -747,6 +1310,11 @@ impl HtmlWriter {
             site_root_href: None,
             label_store: None,
             instance_datasets: Vec::new(),
+            lang: None,
+            site_mode: SiteMode::SinglePage,
+            theme: None,
+            changes: None,
+            self_contained: false,
         }
     }
 
@@ -763,6 +1331,11 @@ impl HtmlWriter {
             site_root_href: None,
             label_store: None,
             instance_datasets: Vec::new(),
+            lang: None,
+            site_mode: SiteMode::SinglePage,
+            theme: None,
+            changes: None,
+            self_contained: false,
         }
     }
 
@@ -782,6 +1355,45 @@ impl HtmlWriter {
         self
     }
 
+    /// Split the schema reference into a page per element; see
+    /// [`SiteMode`].
+    #[must_use]
+    pub fn with_site_mode(mut self, site_mode: SiteMode) -> Self {
+        self.site_mode = site_mode;
+        self
+    }
+
+    /// Render the page in `lang` where the schema translates it; see
+    /// [`Self::lang`].
+    #[must_use]
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Brand the page with a loaded theme; see [`crate::html_theme`].
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Write a single self-contained file; see [`crate::self_contained`].
+    /// Fails at write time with a multi-page [`SiteMode`].
+    #[must_use]
+    pub fn with_self_contained(mut self, self_contained: bool) -> Self {
+        self.self_contained = self_contained;
+        self
+    }
+
+    /// Render the Changes page and card badges for a published version;
+    /// see [`VersionChanges`].
+    #[must_use]
+    pub fn with_changes(mut self, changes: VersionChanges) -> Self {
+        self.changes = Some(changes);
+        self
+    }
+
     /// Attach a populated upstream-label cache so external CURIEs
     /// render as human-readable labels.
     #[must_use]
@@ -848,7 +1460,7 @@ impl HtmlWriter {
     /// (external references render as CURIEs).
     #[cfg(test)]
     fn build_template_data(schema: &SchemaDefinition) -> TemplateData {
-        Self::build_template_data_with_labels(schema, None, true)
+        Self::build_template_data_with_labels(schema, None, true, CardLinks::default())
     }
 
     /// Individual card data from the instance model: one entry per
@@ -945,11 +1557,13 @@ impl HtmlWriter {
 
     /// Build template data, rendering upstream labels for external
     /// references when a populated [`crate::labels::LabelStore`] is
-    /// supplied.
+    /// supplied. Card links, the description cross-references among
+    /// them, point where `links` says.
     fn build_template_data_with_labels(
         schema: &SchemaDefinition,
         labels: Option<&crate::labels::LabelStore>,
         schema_sections: bool,
+        links: CardLinks,
     ) -> TemplateData {
         let iri = schema.id.clone().unwrap_or_else(|| schema.name.clone());
         let title = schema.title.clone().unwrap_or_else(|| schema.name.clone());
@@ -966,6 +1580,7 @@ impl HtmlWriter {
                 iri,
                 version: schema.version.clone(),
                 comment: schema.description.clone(),
+                editorial: Editorial::from_metadata(&schema.metadata, schema, labels),
                 namespaces,
                 class_refs: Vec::new(),
                 class_data: Vec::new(),
@@ -976,19 +1591,24 @@ impl HtmlWriter {
                 enum_data: Vec::new(),
                 type_refs: Vec::new(),
                 type_data: Vec::new(),
+                subset_data: Vec::new(),
+                links,
             };
         }
 
+        // Inbound references for every card's "Used by" row, computed once.
+        let usages = crate::linkml_resolve::usage_index(schema);
+
         // Build class data
         let mut class_refs = Vec::new();
         let mut class_data_list = Vec::new();
 
-        // Sort classes by name for consistent ordering
+        // Order classes by rank, then label, for consistent ordering
         let mut sorted_classes: Vec<_> = schema.classes.iter().collect();
         sorted_classes.sort_by(|a, b| {
             let label_a = a.1.annotations.get("panschema:label").unwrap_or(a.0);
             let label_b = b.1.annotations.get("panschema:label").unwrap_or(b.0);
-            label_a.cmp(label_b)
+            rank_order((&a.1.metadata, label_a), (&b.1.metadata, label_b))
         });
 
         for (class_id, class_def) in &sorted_classes {
@@ -1058,10 +1678,17 @@ impl HtmlWriter {
 
             let resolved =
                 crate::linkml_resolve::resolve_effective_slots_with_provenance(class_def, schema);
-            let slots: Vec<SlotInClass> = resolved
-                .iter()
-                .map(|(slot_name, rs)| {
+            let order = crate::linkml_resolve::grouped_slot_order(&resolved, schema);
+            let mut previous_group = None;
+            let slots: Vec<SlotInClass> = order
+                .into_iter()
+                .map(|(slot_name, group)| {
+                    let rs = &resolved[slot_name];
                     let slot_def = &rs.definition;
+                    let group_heading = group
+                        .filter(|_| group != previous_group)
+                        .map(str::to_string);
+                    previous_group = group;
                     let cardinality = crate::linkml_resolve::effective_cardinality(slot_def);
                     let origin = rs.provenance.origin_label(class_id);
                     // Inline description only where the slot is
@@ -1074,7 +1701,7 @@ impl HtmlWriter {
                             slot_def
                                 .description
                                 .as_deref()
-                                .map(|d| render_description(d, schema)),
+                                .map(|d| render_description(d, schema, &links)),
                             None,
                         )
                     };
@@ -1096,9 +1723,10 @@ impl HtmlWriter {
                         )
                     };
                     SlotInClass {
-                        name: slot_name.clone(),
+                        name: slot_name.to_string(),
                         range,
                         required: cardinality.required,
+                        recommended: slot_def.recommended && !cardinality.required,
                         multivalued: cardinality.multivalued,
                         any_of,
                         suppressed: induced.suppressed,
@@ -1106,6 +1734,7 @@ impl HtmlWriter {
                         refined_here: class_def.slot_usage.contains_key(slot_name),
                         origin,
                         description_tooltip,
+                        group_heading,
                     }
                 })
                 .collect();
@@ -1156,7 +1785,7 @@ impl HtmlWriter {
                 description: class_def
                     .description
                     .as_deref()
-                    .map(|d| render_description(d, schema)),
+                    .map(|d| render_description(d, schema, &links)),
                 superclass,
                 subclasses,
                 mixins,
@@ -1165,11 +1794,16 @@ impl HtmlWriter {
                 external_superclasses,
                 is_abstract: class_def.r#abstract,
                 deprecated: class_def.deprecated.clone(),
-                aliases: class_def.aliases.clone(),
+                aliases: card_aliases(&class_def.aliases, &class_def.metadata),
+                in_subset: class_def.in_subset.clone(),
                 see_also: build_see_also(&class_def.see_also, schema, labels),
                 examples: class_def.examples.clone(),
-                rules: build_rules(class_id, &class_def.rules, schema),
-                unique_keys: build_unique_keys(&class_def.unique_keys, schema),
+                editorial: Editorial::from_metadata(&class_def.metadata, schema, labels),
+                translations: Translation::all_from(&class_def.metadata, schema, &links),
+                rules: build_rules(class_id, &class_def.rules, schema, &links),
+                unique_keys: build_unique_keys(&class_def.unique_keys, schema, &links),
+                expressions: build_class_expressions(class_def, schema, &links),
+                used_by: build_used_by(&usages, &format!("class:{class_id}")),
             });
         }
 
@@ -1177,12 +1811,12 @@ impl HtmlWriter {
         let mut slot_refs = Vec::new();
         let mut slot_data_list = Vec::new();
 
-        // Sort slots by label for consistent ordering
+        // Order slots by rank, then label, for consistent ordering
         let mut sorted_slots: Vec<_> = schema.slots.iter().collect();
         sorted_slots.sort_by(|a, b| {
             let label_a = a.1.annotations.get("panschema:label").unwrap_or(a.0);
             let label_b = b.1.annotations.get("panschema:label").unwrap_or(b.0);
-            label_a.cmp(label_b)
+            rank_order((&a.1.metadata, label_a), (&b.1.metadata, label_b))
         });
 
         for (slot_id, slot_def) in &sorted_slots {
@@ -1346,35 +1980,48 @@ impl HtmlWriter {
                 description: slot_def
                     .description
                     .as_deref()
-                    .map(|d| render_description(d, schema)),
+                    .map(|d| render_description(d, schema, &links)),
                 domains,
                 range,
                 any_of,
                 pattern: slot_def.pattern.clone(),
+                unit: slot_def
+                    .unit
+                    .as_ref()
+                    .and_then(|u| UnitData::from_unit(u, schema)),
+                array: slot_def
+                    .array
+                    .as_ref()
+                    .map(|a| a.describe(slot_def.range.as_deref().unwrap_or("string"))),
                 characteristics,
                 mappings,
                 deprecated: slot_def.deprecated.clone(),
-                aliases: slot_def.aliases.clone(),
+                aliases: card_aliases(&slot_def.aliases, &slot_def.metadata),
+                in_subset: slot_def.in_subset.clone(),
                 see_also: build_see_also(&slot_def.see_also, schema, labels),
                 examples: slot_def.examples.clone(),
+                editorial: Editorial::from_metadata(&slot_def.metadata, schema, labels),
+                translations: Translation::all_from(&slot_def.metadata, schema, &links),
                 default: slot_def.ifabsent.as_deref().map(format_ifabsent_default),
-                governing_rules: governing_rules_for_slot(slot_id, schema),
+                governing_rules: governing_rules_for_slot(slot_id, schema, &links),
+                used_by: build_used_by(&usages, &format!("slot:{slot_id}")),
             });
         }
 
-        // Build enumeration data, sorted by name for stable output.
+        // Build enumeration data, ordered by rank then name for stable output.
         let mut enum_refs = Vec::new();
         let mut enum_data_list = Vec::new();
         let mut sorted_enums: Vec<_> = schema.enums.iter().collect();
-        sorted_enums.sort_by(|a, b| a.0.cmp(b.0));
+        sorted_enums.sort_by(|a, b| rank_order((&a.1.metadata, a.0), (&b.1.metadata, b.0)));
         for (enum_id, enum_def) in sorted_enums {
             enum_refs.push(EntityRef {
                 id: enum_id.clone(),
                 label: enum_id.clone(),
             });
-            let permissible_values = enum_def
-                .permissible_values
-                .iter()
+            let mut ranked_values: Vec<_> = enum_def.permissible_values.iter().collect();
+            ranked_values.sort_by(|a, b| rank_order((&a.1.metadata, a.0), (&b.1.metadata, b.0)));
+            let permissible_values = ranked_values
+                .into_iter()
                 .map(|(text, pv)| PermissibleValueData {
                     text: text.clone(),
                     description: pv.description.clone(),
@@ -1396,20 +2043,24 @@ impl HtmlWriter {
                 description: enum_def
                     .description
                     .as_deref()
-                    .map(|d| render_description(d, schema)),
+                    .map(|d| render_description(d, schema, &links)),
                 permissible_values,
                 deprecated: enum_def.deprecated.clone(),
-                aliases: enum_def.aliases.clone(),
+                aliases: card_aliases(&enum_def.aliases, &enum_def.metadata),
+                in_subset: enum_def.in_subset.clone(),
                 see_also: build_see_also(&enum_def.see_also, schema, labels),
                 examples: enum_def.examples.clone(),
+                editorial: Editorial::from_metadata(&enum_def.metadata, schema, labels),
+                translations: Translation::all_from(&enum_def.metadata, schema, &links),
+                used_by: build_used_by(&usages, &format!("enum:{enum_id}")),
             });
         }
 
-        // Build type data, sorted by name for stable output.
+        // Build type data, ordered by rank then name for stable output.
         let mut type_refs = Vec::new();
         let mut type_data_list = Vec::new();
         let mut sorted_types: Vec<_> = schema.types.iter().collect();
-        sorted_types.sort_by(|a, b| a.0.cmp(b.0));
+        sorted_types.sort_by(|a, b| rank_order((&a.1.metadata, a.0), (&b.1.metadata, b.0)));
         for (type_id, type_def) in sorted_types {
             type_refs.push(EntityRef {
                 id: type_id.clone(),
@@ -1437,13 +2088,16 @@ impl HtmlWriter {
                 description: type_def
                     .description
                     .as_deref()
-                    .map(|d| render_description(d, schema)),
+                    .map(|d| render_description(d, schema, &links)),
                 base_type,
                 pattern: type_def.pattern.clone(),
                 deprecated: type_def.deprecated.clone(),
-                aliases: type_def.aliases.clone(),
+                aliases: card_aliases(&type_def.aliases, &type_def.metadata),
+                in_subset: type_def.in_subset.clone(),
                 see_also: build_see_also(&type_def.see_also, schema, labels),
                 examples: type_def.examples.clone(),
+                editorial: Editorial::from_metadata(&type_def.metadata, schema, labels),
+                translations: Translation::all_from(&type_def.metadata, schema, &links),
             });
         }
 
@@ -1454,7 +2108,8 @@ impl HtmlWriter {
             comment: schema
                 .description
                 .as_deref()
-                .map(|d| render_description(d, schema)),
+                .map(|d| render_description(d, schema, &links)),
+            editorial: Editorial::from_metadata(&schema.metadata, schema, labels),
             namespaces,
             class_refs,
             class_tree: build_class_tree(&class_data_list),
@@ -1465,6 +2120,8 @@ impl HtmlWriter {
             enum_data: enum_data_list,
             type_refs,
             type_data: type_data_list,
+            subset_data: build_subsets(schema, &links),
+            links,
         }
     }
 }
@@ -1481,6 +2138,7 @@ struct TemplateData {
     iri: String,
     version: Option<String>,
     comment: Option<String>,
+    editorial: Option<Editorial>,
     namespaces: Vec<Namespace>,
     class_refs: Vec<EntityRef>,
     class_data: Vec<ClassData>,
@@ -1491,60 +2149,711 @@ struct TemplateData {
     enum_data: Vec<EnumData>,
     type_refs: Vec<EntityRef>,
     type_data: Vec<TypeData>,
+    subset_data: Vec<SubsetData>,
+    /// Where the cards' links point on the page this data renders.
+    links: CardLinks,
 }
 
-impl HtmlWriter {
-    /// The datasets to render. With none attached, the schema's own embedded
-    /// OWL individuals are the subject.
-    fn effective_datasets(&self, schema: &SchemaDefinition) -> Vec<InstanceDataset> {
-        if self.instance_datasets.is_empty() {
-            return vec![InstanceDataset {
-                name: String::new(),
-                provenance: None,
-                set: crate::instances::InstanceSet::from_owl_annotations(schema),
-                default_selected: true,
-            }];
+impl TemplateData {
+    /// Show every card translated into `lang` in it, and the schema's
+    /// title and description too; see [`make_primary`]. The sidebar
+    /// follows the cards' labels.
+    fn localize(&mut self, lang: &str, source_lang: &str, schema: &SchemaDefinition) {
+        let mut comment = self.comment.take();
+        make_primary(
+            lang,
+            source_lang,
+            &mut self.title,
+            &mut comment,
+            &mut Vec::new(),
+            &mut Translation::all_from(&schema.metadata, schema, &self.links),
+        );
+        self.comment = comment;
+
+        fn relabel(refs: &mut [EntityRef], labels: HashMap<&str, &str>) {
+            for r in refs {
+                if let Some(label) = labels.get(r.id.as_str()) {
+                    r.label = (*label).to_string();
+                }
+            }
         }
-        self.instance_datasets.clone()
+        macro_rules! localize_cards {
+            ($cards:expr, $refs:expr) => {{
+                for card in $cards.iter_mut() {
+                    make_primary(
+                        lang,
+                        source_lang,
+                        &mut card.label,
+                        &mut card.description,
+                        &mut card.aliases,
+                        &mut card.translations,
+                    );
+                }
+                let labels = $cards
+                    .iter()
+                    .map(|c| (c.id.as_str(), c.label.as_str()))
+                    .collect();
+                relabel(&mut $refs, labels);
+            }};
+        }
+        localize_cards!(self.class_data, self.class_refs);
+        localize_cards!(self.slot_data, self.slot_refs);
+        localize_cards!(self.enum_data, self.enum_refs);
+        localize_cards!(self.type_data, self.type_refs);
     }
-}
 
-impl Writer for HtmlWriter {
-    fn write(&self, schema: &SchemaDefinition, output: &Path) -> IoResult<()> {
-        // Create output directory if it doesn't exist
-        fs::create_dir_all(output).map_err(IoError::Io)?;
+    /// The languages the cards carry translations into, other than
+    /// `page_lang` — the language switcher's choices.
+    fn languages(&self, page_lang: &str) -> Vec<String> {
+        let langs: std::collections::BTreeSet<&str> = self
+            .class_data
+            .iter()
+            .flat_map(|c| &c.translations)
+            .chain(self.slot_data.iter().flat_map(|c| &c.translations))
+            .chain(self.enum_data.iter().flat_map(|c| &c.translations))
+            .chain(self.type_data.iter().flat_map(|c| &c.translations))
+            .map(|t| t.lang.as_str())
+            .filter(|lang| *lang != page_lang)
+            .collect();
+        langs.into_iter().map(str::to_string).collect()
+    }
+
+    /// The search index over every card on the page, in section order.
+    fn search_index(&self) -> Vec<SearchEntry> {
+        let mut entries = Vec::new();
+        for c in &self.class_data {
+            let mut entry = SearchEntry::new(
+                "class",
+                &c.id,
+                &c.label,
+                &c.aliases,
+                &c.translations,
+                c.description.as_deref(),
+            );
+            entry.curie = (c.iri != c.id).then(|| c.iri.clone());
+            entry.mappings = mapping_terms(&c.mappings);
+            entries.push(entry);
+        }
+        for s in &self.slot_data {
+            let mut entry = SearchEntry::new(
+                "slot",
+                &s.id,
+                &s.label,
+                &s.aliases,
+                &s.translations,
+                s.description.as_deref(),
+            );
+            entry.curie = (s.iri != s.id).then(|| s.iri.clone());
+            entry.mappings = mapping_terms(&s.mappings);
+            entries.push(entry);
+        }
+        for e in &self.enum_data {
+            let mut entry = SearchEntry::new(
+                "enum",
+                &e.id,
+                &e.label,
+                &e.aliases,
+                &e.translations,
+                e.description.as_deref(),
+            );
+            entry.values = e
+                .permissible_values
+                .iter()
+                .map(|pv| pv.text.clone())
+                .collect();
+            entries.push(entry);
+        }
+        for t in &self.type_data {
+            let mut entry = SearchEntry::new(
+                "type",
+                &t.id,
+                &t.label,
+                &t.aliases,
+                &t.translations,
+                t.description.as_deref(),
+            );
+            entry.curie = t.uri.as_ref().map(|u| u.display.clone());
+            entries.push(entry);
+        }
+        entries
+    }
 
-        let datasets = self.effective_datasets(schema);
-        let data = Self::build_template_data_with_labels(
-            schema,
-            self.label_store.as_ref(),
-            self.schema_sections,
-        );
+    /// One kind's sidebar entries; `kind` is one of [`ELEMENT_KINDS`].
+    fn refs_of(&self, kind: &str) -> &[EntityRef] {
+        match kind {
+            "class" => &self.class_refs,
+            "slot" => &self.slot_refs,
+            "enum" => &self.enum_refs,
+            _ => &self.type_refs,
+        }
+    }
+}
 
-        // Generate graph JSON for visualization — only when a section
-        // will carry it: the schema graph lives in the schema reference,
-        // so a page without those sections never embeds it.
-        let (graph_json_string, graph_node_count, graph_edge_count) =
-            if self.include_graph && self.schema_sections {
-                let graph_data = GraphWriter::new()
-                    .schema_to_graph_with_labels(schema, self.label_store.as_ref());
-                let node_count = graph_data.nodes.len();
-                let edge_count = graph_data.edges.len();
-                // The JSON is embedded in an inline <script>; serde_json does
-                // not escape `<`, so a `</script>` inside any schema string
-                // would close the element mid-JSON and execute what follows.
-                // Escaping `<` as its `<` form keeps the JSON byte-for-byte
-                // equivalent (JSON.parse decodes it back), so panschema-viz reads
-                // the identical wire shape — only the on-page bytes change.
-                let json = serde_json::to_string(&graph_data)
-                    .map_err(|e| IoError::Write(e.to_string()))?
-                    .replace('<', "\\u003c");
-                (Some(json), node_count, edge_count)
+/// The element kinds multi-page mode splits out, with each one's page
+/// directory and section heading, in page order.
+const ELEMENT_KINDS: [(&str, &str, &str); 4] = [
+    ("class", "classes", "Classes"),
+    ("slot", "slots", "Slots"),
+    ("enum", "enums", "Enumerations"),
+    ("type", "types", "Types"),
+];
+
+/// An element name as a file name: anything but letters, digits, `-`,
+/// `_` and `.` becomes `_`, so no name can reach outside its directory.
+fn page_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
             } else {
-                (None, 0, 0)
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Every class, slot, enum and type's page path without its extension,
+/// keyed by the element's card anchor (`class-Person` →
+/// `classes/Person`). Names whose [`page_stem`]s meet in one directory —
+/// `a b` and `a_b`, or `Person` and `person` on a case-insensitive file
+/// system — would write over each other's page, so each later one in
+/// name order takes a numbered stem (`classes/a_b-2`). Multi-page HTML
+/// and the Markdown writer both name their pages from this one map.
+pub(crate) fn element_page_paths(schema: &SchemaDefinition) -> HashMap<String, String> {
+    let mut pages = HashMap::new();
+    for (kind, dir, _) in ELEMENT_KINDS {
+        let names: Vec<&String> = match kind {
+            "class" => schema.classes.keys().collect(),
+            "slot" => schema.slots.keys().collect(),
+            "enum" => schema.enums.keys().collect(),
+            _ => schema.types.keys().collect(),
+        };
+        let mut taken = std::collections::HashSet::new();
+        for name in names {
+            let stem = page_stem(name);
+            let mut file = stem.clone();
+            let mut n = 1;
+            while !taken.insert(file.to_lowercase()) {
+                n += 1;
+                file = format!("{stem}-{n}");
+            }
+            pages.insert(format!("{kind}-{name}"), format!("{dir}/{file}"));
+        }
+    }
+    pages
+}
+
+/// Multi-page mode's page for every card, keyed by the card's anchor
+/// (`class-Person` → `classes/Person.html`), relative to the site root;
+/// see [`element_page_paths`].
+fn element_pages(schema: &SchemaDefinition) -> HashMap<String, String> {
+    element_page_paths(schema)
+        .into_iter()
+        .map(|(anchor, path)| (anchor, format!("{path}.html")))
+        .collect()
+}
+
+/// Where a page's links to cards point. On a single page every card is
+/// a fragment of it. In multi-page mode a class, slot, enum or type
+/// link goes to that element's page instead, and from an element page
+/// every other card link leads back to its anchor on the overview.
+#[derive(Debug, Clone, Default)]
+pub struct CardLinks {
+    /// Element pages by card anchor; see [`element_pages`].
+    pages: HashMap<String, String>,
+    /// The way back to the site root: empty on `index.html`, `../` on
+    /// an element page.
+    root: &'static str,
+}
+
+impl CardLinks {
+    /// The link to the card with HTML id `anchor` (`class-Person`).
+    pub fn href(&self, anchor: &str) -> String {
+        match self.pages.get(anchor) {
+            Some(page) => format!("{}{page}", self.root),
+            None if self.root.is_empty() => format!("#{anchor}"),
+            None => format!("{}index.html#{anchor}", self.root),
+        }
+    }
+
+    /// The link to `kind`'s card `name`: `to("class", "Person")`.
+    pub fn to(&self, kind: &str, name: &str) -> String {
+        self.href(&format!("{kind}-{name}"))
+    }
+
+    /// The element page `anchor` has, relative to the site root.
+    fn page(&self, anchor: &str) -> Option<&str> {
+        self.pages.get(anchor).map(String::as_str)
+    }
+
+    /// The same links as seen from an element page, one directory down.
+    fn for_element_page(&self) -> Self {
+        Self {
+            pages: self.pages.clone(),
+            root: "../",
+        }
+    }
+}
+
+/// A card's "Used by" rows from the schema's usage index; `node` is the
+/// card's graph node id (`class:Person`).
+fn build_used_by(
+    usages: &BTreeMap<String, Vec<crate::linkml_resolve::Usage>>,
+    node: &str,
+) -> Vec<UsageRow> {
+    let Some(usages) = usages.get(node) else {
+        return Vec::new();
+    };
+    usages
+        .iter()
+        .filter_map(|usage| {
+            let (kind, name) = usage.source.split_once(':')?;
+            let kind = match kind {
+                "class" => "class",
+                "slot" => "slot",
+                _ => return None,
             };
+            Some(UsageRow {
+                relation: usage.kind.label(),
+                label: name.to_string(),
+                detail: usage.detail.clone(),
+                anchor: format!("{kind}-{name}"),
+                kind,
+            })
+        })
+        .collect()
+}
 
-        // Each curated A-box gets its own cards and its own viz payload,
+/// Append a "record" row to each class card for every record in `view`
+/// typed by that class.
+fn add_record_usages(classes: &mut [ClassData], view: &InstanceDatasetView<'_>) {
+    for class in classes {
+        for record in view.individual_data {
+            if record.types.iter().any(|t| t.id == class.id) {
+                class.used_by.push(UsageRow {
+                    relation: "record",
+                    label: record.label.clone(),
+                    detail: None,
+                    anchor: format!("{}ind-{}", view.anchor_prefix, record.id),
+                    kind: "individual",
+                });
+            }
+        }
+    }
+}
+
+/// Where an element's card lives, relative to a version's root: its
+/// own page in multi-page mode, else its anchor on the overview.
+fn card_href(links: &CardLinks, kind: ElementKind, name: &str) -> String {
+    let anchor = format!("{}-{name}", kind.anchor_prefix());
+    match links.page(&anchor) {
+        Some(page) => page.to_string(),
+        None => format!("index.html#{anchor}"),
+    }
+}
+
+/// Badge each changed card's title with the version that last changed
+/// it (see [`VersionChanges::badges`]). `root` is the way back to the
+/// version root, as for [`CardLinks`].
+fn add_change_badges(html: &str, badges: &BTreeMap<String, ChangeBadge>, root: &str) -> String {
+    let mut html = html.to_string();
+    for (anchor, badge) in badges {
+        let Some(card) = html.find(&format!("id=\"{}\"", html_escape(anchor))) else {
+            continue;
+        };
+        let Some(close) = html[card..].find("</h3>") else {
+            continue;
+        };
+        let href = if root.is_empty() {
+            badge.href.clone()
+        } else {
+            up_one_level(&badge.href)
+        };
+        let verb = if badge.change == ChangeKind::Added {
+            "new"
+        } else {
+            "changed"
+        };
+        let tag = format!(
+            " <a class=\"changed-badge\" href=\"{}\">{verb} in {}</a>",
+            html_escape(&href),
+            html_escape(&badge.version),
+        );
+        html.insert_str(card + close, &tag);
+    }
+    html
+}
+
+/// A site-relative link as seen from one directory down: relative hrefs
+/// gain a `../`, absolute ones pass through.
+fn up_one_level(href: &str) -> String {
+    if href.starts_with('/') || href.contains("://") {
+        href.to_string()
+    } else {
+        format!("../{}", href.trim_start_matches("./"))
+    }
+}
+
+/// A card's mappings as searchable terms: each CURIE, then its cached
+/// upstream label.
+fn mapping_terms(mappings: &[Mapping]) -> Vec<String> {
+    mappings
+        .iter()
+        .flat_map(|m| std::iter::once(m.display.clone()).chain(m.label.clone()))
+        .collect()
+}
+
+impl HtmlWriter {
+    /// The theme's overrides filled for one page; `root` climbs from the
+    /// page to the output directory.
+    fn theme_page(
+        &self,
+        data: &TemplateData,
+        page_lang: &str,
+        site_root_href: &str,
+        root: &str,
+    ) -> ThemePage {
+        self.theme
+            .as_ref()
+            .map_or_else(ThemePage::default, |theme| {
+                theme.page(&ThemeVars {
+                    title: &data.title,
+                    version: data.version.as_deref(),
+                    description: data.comment.as_deref(),
+                    page_lang,
+                    site_root_href,
+                    root,
+                })
+            })
+    }
+
+    /// The page's template data: its card links pointing where `links`
+    /// says, its cards shown in `page_lang`.
+    fn page_data(
+        &self,
+        schema: &SchemaDefinition,
+        links: CardLinks,
+        page_lang: &str,
+        source_lang: &str,
+    ) -> TemplateData {
+        let mut data = Self::build_template_data_with_labels(
+            schema,
+            self.label_store.as_ref(),
+            self.schema_sections,
+            links,
+        );
+        if page_lang != source_lang {
+            data.localize(page_lang, source_lang, schema);
+        }
+        data
+    }
+
+    /// The datasets to render. With none attached, the schema's own embedded
+    /// OWL individuals are the subject.
+    fn effective_datasets(&self, schema: &SchemaDefinition) -> Vec<InstanceDataset> {
+        if self.instance_datasets.is_empty() {
+            return vec![InstanceDataset {
+                name: String::new(),
+                provenance: None,
+                set: crate::instances::InstanceSet::from_owl_annotations(schema),
+                default_selected: true,
+            }];
+        }
+        self.instance_datasets.clone()
+    }
+
+    /// Multi-page mode: a page per class, slot, enum and type under its
+    /// kind's directory, and `sitemap.html` listing them. Returns whether
+    /// any page embeds a graph, so the caller ships the viz assets.
+    fn write_element_pages(
+        &self,
+        schema: &SchemaDefinition,
+        data: &TemplateData,
+        page_lang: &str,
+        languages: &[String],
+        output: &Path,
+    ) -> IoResult<bool> {
+        // Class pages draw their neighborhood; without the viz there is
+        // nothing to draw.
+        let graph = self.include_graph.then(|| {
+            GraphWriter::new().schema_to_graph_with_labels(schema, self.label_store.as_ref())
+        });
+
+        // The element pages sit one directory down, so every link the
+        // header carries climbs a level.
+        let site_root_href = up_one_level(self.site_root_href.as_deref().unwrap_or("./"));
+        let page_links: Vec<PageLink> = self
+            .page_links
+            .iter()
+            .map(|link| PageLink {
+                href: up_one_level(&link.href),
+                ..link.clone()
+            })
+            .collect();
+        let version_context = self.version_context.as_ref().map(|vc| VersionContext {
+            url_pattern: up_one_level(&vc.url_pattern),
+            ..vc.clone()
+        });
+
+        let theme = self.theme_page(data, page_lang, &site_root_href, "../");
+
+        let mut any_graph = false;
+        let mut sections = Vec::new();
+        for (kind, dir, kind_title) in ELEMENT_KINDS {
+            let refs = data.refs_of(kind);
+            if refs.is_empty() {
+                continue;
+            }
+            fs::create_dir_all(output.join(dir)).map_err(IoError::Io)?;
+            let pages: Vec<(&EntityRef, &str)> = refs
+                .iter()
+                .filter_map(|r| Some((r, data.links.page(&format!("{kind}-{}", r.id))?)))
+                .collect();
+            // Pages of one kind share a directory, so a sibling is linked
+            // by its file name.
+            let mut siblings: Vec<PageLink> = pages
+                .iter()
+                .map(|(r, page)| PageLink {
+                    label: r.label.clone(),
+                    href: page.rsplit('/').next().unwrap_or(page).to_string(),
+                    active: false,
+                })
+                .collect();
+            for (i, (r, page)) in pages.iter().enumerate() {
+                siblings[i].active = true;
+                let graph_json = if let Some(graph) = &graph
+                    && kind == "class"
+                {
+                    // Escaped like the overview's graph JSON; see `write`.
+                    let node = format!("class:{}", r.id);
+                    let json = serde_json::to_string(&graph.neighborhood(&node, 1))
+                        .map_err(|e| IoError::Write(e.to_string()))?
+                        .replace('<', "\\u003c");
+                    any_graph = true;
+                    Some(json)
+                } else {
+                    None
+                };
+                let mut template = ElementTemplate {
+                    title: &data.title,
+                    page_lang,
+                    languages,
+                    version_context: version_context.as_ref(),
+                    page_links: &page_links,
+                    site_root_href: &site_root_href,
+                    kind_dir: dir,
+                    kind_title,
+                    element_label: &r.label,
+                    siblings: &siblings,
+                    class: None,
+                    slot: None,
+                    en: None,
+                    ty: None,
+                    links: &data.links,
+                    graph_json: graph_json.as_deref(),
+                    graph_aspect_w: self.graph_aspect.0,
+                    graph_aspect_h: self.graph_aspect.1,
+                    graph_default_layout: &self.graph_default_layout,
+                    theme: &theme,
+                };
+                match kind {
+                    "class" => template.class = data.class_data.iter().find(|c| c.id == r.id),
+                    "slot" => template.slot = data.slot_data.iter().find(|c| c.id == r.id),
+                    "enum" => template.en = data.enum_data.iter().find(|c| c.id == r.id),
+                    _ => template.ty = data.type_data.iter().find(|c| c.id == r.id),
+                }
+                let html = template
+                    .render()
+                    .map_err(|e| IoError::Write(e.to_string()))?;
+                let html = match &self.changes {
+                    Some(changes) => add_change_badges(&html, &changes.badges, "../"),
+                    None => html,
+                };
+                fs::write(output.join(page), html).map_err(IoError::Io)?;
+                siblings[i].active = false;
+            }
+            sections.push(SitemapSection {
+                id: dir,
+                title: kind_title,
+                pages: siblings
+                    .into_iter()
+                    .zip(&pages)
+                    .map(|(link, (_, page))| PageLink {
+                        href: page.to_string(),
+                        ..link
+                    })
+                    .collect(),
+            });
+        }
+
+        let site_root_href = self.site_root_href.as_deref().unwrap_or("./");
+        let sitemap = SitemapTemplate {
+            title: &data.title,
+            page_lang,
+            languages,
+            version_context: self.version_context.as_ref(),
+            page_links: &self.page_links,
+            site_root_href,
+            sections: &sections,
+            theme: &self.theme_page(data, page_lang, site_root_href, ""),
+        };
+        let html = sitemap
+            .render()
+            .map_err(|e| IoError::Write(e.to_string()))?;
+        fs::write(output.join("sitemap.html"), html).map_err(IoError::Io)?;
+        Ok(any_graph)
+    }
+
+    /// `changes.html`: each added, removed and modified element, linked
+    /// to its card in this version and in the previous one.
+    fn write_changes_page(
+        &self,
+        data: &TemplateData,
+        changes: &VersionChanges,
+        page_lang: &str,
+        languages: &[String],
+        output: &Path,
+    ) -> IoResult<()> {
+        let mut sections: Vec<ChangeSection<'_>> = Vec::new();
+        for change in &changes.changes {
+            let (id, title) = (change.kind.anchor_prefix(), change.kind.title());
+            if sections.last().is_none_or(|s| s.id != id) {
+                sections.push(ChangeSection {
+                    id,
+                    title,
+                    entries: Vec::new(),
+                });
+            }
+            let here = card_href(&data.links, change.kind, &change.name);
+            let entry = ChangeEntry {
+                anchor: change.anchor(),
+                name: &change.name,
+                change: change.change.as_str(),
+                href: (change.change != ChangeKind::Removed).then(|| here.clone()),
+                previous_href: (change.change != ChangeKind::Added)
+                    .then(|| format!("{}{here}", changes.previous_href)),
+                fields: &change.fields,
+                constraint: change.touches_constraints(),
+            };
+            if let Some(section) = sections.last_mut() {
+                section.entries.push(entry);
+            }
+        }
+
+        let viewing = self
+            .version_context
+            .as_ref()
+            .map(|vc| vc.viewing.as_str())
+            .or(data.version.as_deref())
+            .unwrap_or("this version");
+        let site_root_href = self.site_root_href.as_deref().unwrap_or("./");
+        let template = ChangesTemplate {
+            title: &data.title,
+            page_lang,
+            languages,
+            version_context: self.version_context.as_ref(),
+            page_links: &self.page_links,
+            site_root_href,
+            viewing,
+            previous: &changes.previous,
+            previous_href: &changes.previous_href,
+            sections: &sections,
+            theme: &self.theme_page(data, page_lang, site_root_href, ""),
+        };
+        let mut html = template
+            .render()
+            .map_err(|e| IoError::Write(e.to_string()))?;
+        // Sits beside `index.html`, so its theme files inline the same way.
+        if self.self_contained
+            && let Some(theme) = &self.theme
+        {
+            html = theme.inline_files(&html)?;
+        }
+        fs::write(output.join("changes.html"), html).map_err(IoError::Io)
+    }
+}
+
+impl Writer for HtmlWriter {
+    fn write(&self, schema: &SchemaDefinition, output: &Path) -> IoResult<()> {
+        // Create output directory if it doesn't exist
+        fs::create_dir_all(output).map_err(IoError::Io)?;
+
+        let datasets = self.effective_datasets(schema);
+
+        // Multi-page mode moves the cards out of the schema sections, so
+        // a page without those sections stays single.
+        let site_pages = self.site_mode == SiteMode::MultiPage && self.schema_sections;
+        if site_pages && self.self_contained {
+            return Err(IoError::Write(
+                "a self-contained page is a single file, but html_site_mode \"multi-page\" \
+                 writes a page per element"
+                    .to_string(),
+            ));
+        }
+        let links = CardLinks {
+            pages: if site_pages {
+                element_pages(schema)
+            } else {
+                HashMap::new()
+            },
+            root: "",
+        };
+
+        // A schema that declares no `in_language` is taken to be English,
+        // as the page's `lang` attribute always has.
+        let source_lang = schema.metadata.in_language.as_deref().unwrap_or("en");
+        let page_lang = self.lang.as_deref().unwrap_or(source_lang);
+        let mut data = self.page_data(schema, links, page_lang, source_lang);
+        let languages = data.languages(page_lang);
+
+        // The search index points at cards, so it ships only with the
+        // schema sections that render them. Embedded inline and escaped
+        // like the graph JSON below, so search works from `file://` too.
+        let search_json = if self.schema_sections {
+            let mut entries = data.search_index();
+            for entry in &mut entries {
+                entry.page = data
+                    .links
+                    .page(&format!("{}-{}", entry.kind, entry.name))
+                    .map(str::to_string);
+            }
+            if entries.is_empty() {
+                None
+            } else {
+                Some(
+                    serde_json::to_string(&entries)
+                        .map_err(|e| IoError::Write(e.to_string()))?
+                        .replace('<', "\\u003c"),
+                )
+            }
+        } else {
+            None
+        };
+
+        // Generate graph JSON for visualization — only when a section
+        // will carry it: the schema graph lives in the schema reference,
+        // so a page without those sections never embeds it.
+        let (graph_json_string, graph_node_count, graph_edge_count) =
+            if self.include_graph && self.schema_sections {
+                let graph_data = GraphWriter::new()
+                    .schema_to_graph_with_labels(schema, self.label_store.as_ref());
+                let node_count = graph_data.nodes.len();
+                let edge_count = graph_data.edges.len();
+                // The JSON is embedded in an inline <script>; serde_json does
+                // not escape `<`, so a `</script>` inside any schema string
+                // would close the element mid-JSON and execute what follows.
+                // Escaping `<` as its `<` form keeps the JSON byte-for-byte
+                // equivalent (JSON.parse decodes it back), so panschema-viz reads
+                // the identical wire shape — only the on-page bytes change.
+                let json = serde_json::to_string(&graph_data)
+                    .map_err(|e| IoError::Write(e.to_string()))?
+                    .replace('<', "\\u003c");
+                (Some(json), node_count, edge_count)
+            } else {
+                (None, 0, 0)
+            };
+
+        // Each curated A-box gets its own cards and its own viz payload,
         // rendered as a graph distinct from the schema (T-box) one. A
         // payload is emitted only for a non-empty A-box, so an
         // individual-free schema gets no instance graph. Escaped the same
@@ -1633,17 +2942,25 @@ impl Writer for HtmlWriter {
         }
         let dataset_views = dataset_views;
 
+        // The records of the dataset the page opens on join their classes'
+        // "Used by" rows.
+        let default_view = dataset_views.iter().find(|v| v.is_default);
+        if let Some(view) = default_view {
+            add_record_usages(&mut data.class_data, view);
+        }
+
         // The sidebar badge describes the dataset the reader sees first.
-        let (instance_node_count, instance_edge_count) = dataset_views
-            .iter()
-            .find(|v| v.is_default)
-            .map_or((0, 0), |v| (v.node_count, v.edge_count));
+        let (instance_node_count, instance_edge_count) =
+            default_view.map_or((0, 0), |v| (v.node_count, v.edge_count));
 
+        let site_root_href = self.site_root_href.as_deref().unwrap_or("./");
+        let theme = self.theme_page(&data, page_lang, site_root_href, "");
         let template = IndexTemplate {
             title: &data.title,
             iri: &data.iri,
             version: data.version.as_deref(),
             comment: data.comment.as_deref(),
+            editorial: data.editorial.as_ref(),
             active_section: "metadata",
             classes: &data.class_refs,
             class_data: &data.class_data,
@@ -1655,6 +2972,7 @@ impl Writer for HtmlWriter {
             types: &data.type_refs,
             type_data: &data.type_data,
             namespaces: &data.namespaces,
+            subsets: &data.subset_data,
             graph_json: graph_json_string.as_deref(),
             instance_datasets: &dataset_views,
             instance_node_count,
@@ -1671,27 +2989,81 @@ impl Writer for HtmlWriter {
             // `panschema generate` writes the page at the output root, so
             // `./` always resolves to the deploy root. `panschema publish`
             // sets this explicitly from the manifest's `site_root_url`.
-            site_root_href: self.site_root_href.as_deref().unwrap_or("./"),
+            site_root_href,
             instances_first: self.instances_first,
             show_schema_sections: self.schema_sections,
+            page_lang,
+            languages: &languages,
+            search_json: search_json.as_deref(),
+            site_pages,
+            links: &data.links,
+            changes_since: self.changes.as_ref().map(|c| c.previous.as_str()),
+            theme: &theme,
         };
 
-        let html = template
+        let mut html = template
             .render()
             .map_err(|e| IoError::Write(e.to_string()))?;
+        if let Some(changes) = &self.changes {
+            html = add_change_badges(&html, &changes.badges, "");
+        }
+        // Some canvas on the page imports the viz bundle.
+        let page_viz = graph_json_string.is_some()
+            || dataset_parts
+                .iter()
+                .any(|(_, _, json, _, _, _)| json.is_some());
+        if self.self_contained {
+            if let Some(theme) = &self.theme {
+                html = theme.inline_files(&html)?;
+            }
+            if page_viz {
+                html = crate::self_contained::inline_viz_bundle(
+                    &html,
+                    wasm_files::VIZ_JS,
+                    wasm_files::VIZ_WASM,
+                );
+            }
+        }
 
         let output_path = output.join("index.html");
         fs::write(&output_path, html).map_err(IoError::Io)?;
 
+        let element_graphs = if site_pages {
+            // The element pages sit a directory down, so their cards are
+            // rebuilt with links from there.
+            let mut element_data = self.page_data(
+                schema,
+                data.links.for_element_page(),
+                page_lang,
+                source_lang,
+            );
+            if let Some(view) = default_view {
+                add_record_usages(&mut element_data.class_data, view);
+            }
+            self.write_element_pages(schema, &element_data, page_lang, &languages, output)?
+        } else {
+            false
+        };
+
+        if let Some(changes) = &self.changes {
+            self.write_changes_page(&data, changes, page_lang, &languages, output)?;
+        }
+
+        // A self-contained page already carries everything below.
+        if self.self_contained {
+            return Ok(());
+        }
+
+        if let Some(theme) = &self.theme {
+            theme.copy_files(output)?;
+        }
+
         // Copy the viz assets only when some canvas on the page imports
         // them — a composed page with neither a schema graph nor any
         // instance graph would otherwise ship megabytes of dead wasm.
-        let any_viz = graph_json_string.is_some()
-            || dataset_parts
-                .iter()
-                .any(|(_, _, json, _, _, _)| json.is_some());
         // `any_viz` is only ever set when `include_graph` allowed a
         // build, so it alone decides.
+        let any_viz = page_viz || element_graphs;
         if any_viz {
             fs::write(output.join("panschema_viz.js"), wasm_files::VIZ_JS).map_err(IoError::Io)?;
             fs::write(output.join("panschema_viz_bg.wasm"), wasm_files::VIZ_WASM)
@@ -1734,7 +3106,7 @@ fn slot_display_label(schema: &SchemaDefinition, name: &str) -> String {
 /// markdown construct starts with `[[`), so post-processing the
 /// rendered HTML to substitute them is safe — they only appear in
 /// text nodes, never inside tag attributes.
-fn render_description(text: &str, schema: &SchemaDefinition) -> String {
+fn render_description(text: &str, schema: &SchemaDefinition, links: &CardLinks) -> String {
     use pulldown_cmark::{Event, Parser, html};
 
     // Route raw HTML through text escaping so author-embedded
@@ -1747,14 +3119,14 @@ fn render_description(text: &str, schema: &SchemaDefinition) -> String {
     });
     let mut rendered = String::with_capacity(text.len());
     html::push_html(&mut rendered, events);
-    substitute_xref_markers(&rendered, schema)
+    substitute_xref_markers(&rendered, schema, links)
 }
 
 /// Walk the markdown-rendered HTML, replacing `[[Name]]` markers
 /// (which markdown passes through as text — see [`render_description`])
 /// with anchor links. Plain text outside markers is left as-is; it has
 /// already been HTML-escaped by the markdown renderer.
-fn substitute_xref_markers(html: &str, schema: &SchemaDefinition) -> String {
+fn substitute_xref_markers(html: &str, schema: &SchemaDefinition, links: &CardLinks) -> String {
     let mut out = String::with_capacity(html.len());
     let mut remainder = html;
     while let Some((before, after_open)) = remainder.split_once("[[") {
@@ -1762,7 +3134,7 @@ fn substitute_xref_markers(html: &str, schema: &SchemaDefinition) -> String {
         if let Some((name, after_close)) = after_open.split_once("]]")
             && is_xref_ident(name)
         {
-            out.push_str(&render_xref(name, schema));
+            out.push_str(&render_xref(name, schema, links));
             remainder = after_close;
             continue;
         }
@@ -1910,7 +3282,11 @@ fn build_see_also(
 /// trigger or governed side), for the slot card's "Governed by" section — so
 /// a reader viewing a slot sees the conditional logic that constrains it.
 /// Classes iterate in sorted (`BTreeMap`) order for deterministic output.
-fn governing_rules_for_slot(slot_name: &str, schema: &SchemaDefinition) -> Vec<GoverningRule> {
+fn governing_rules_for_slot(
+    slot_name: &str,
+    schema: &SchemaDefinition,
+    links: &CardLinks,
+) -> Vec<GoverningRule> {
     let mut governing = Vec::new();
     for (class_id, class_def) in &schema.classes {
         let class_label = class_def
@@ -1933,7 +3309,7 @@ fn governing_rules_for_slot(slot_name: &str, schema: &SchemaDefinition) -> Vec<G
                     },
                     title: rule.title.clone(),
                     summary: crate::rules::rule_summary(rule)
-                        .map(|s| render_description(&s, schema)),
+                        .map(|s| render_description(&s, schema, links)),
                     participants: rule_participant_ids(class_id, rule),
                 });
             }
@@ -1963,6 +3339,7 @@ fn build_rules(
     class_id: &str,
     rules: &[crate::linkml::ClassRule],
     schema: &SchemaDefinition,
+    links: &CardLinks,
 ) -> Vec<RuleInClass> {
     rules
         .iter()
@@ -1971,19 +3348,90 @@ fn build_rules(
             description: rule
                 .description
                 .as_deref()
-                .map(|d| render_description(d, schema)),
-            summary: crate::rules::rule_summary(rule).map(|s| render_description(&s, schema)),
+                .map(|d| render_description(d, schema, links)),
+            summary: crate::rules::rule_summary(rule)
+                .map(|s| render_description(&s, schema, links)),
             participants: rule_participant_ids(class_id, rule),
         })
         .collect()
 }
 
+/// Build the class card's expression rows, set axioms first, in metamodel
+/// order. Combinators with no members produce no row.
+fn build_class_expressions(
+    class_def: &crate::linkml::ClassDefinition,
+    schema: &SchemaDefinition,
+    links: &CardLinks,
+) -> Vec<ClassExpressionRow> {
+    let xrefs = |names: &[String]| -> Vec<String> {
+        names
+            .iter()
+            .map(|n| render_xref(n, schema, links))
+            .collect()
+    };
+    let mut rows = vec![
+        ClassExpressionRow {
+            label: "Union of",
+            members: xrefs(&class_def.union_of),
+        },
+        ClassExpressionRow {
+            label: "Disjoint with",
+            members: xrefs(&class_def.disjoint_with),
+        },
+    ];
+    for (combinator, members) in class_def.combinators() {
+        let label = match combinator {
+            "any_of" => "Any of",
+            "all_of" => "All of",
+            "exactly_one_of" => "Exactly one of",
+            _ => "None of",
+        };
+        let members = members
+            .iter()
+            .filter_map(|member| match member.named_class() {
+                Some(class) => Some(render_xref(class, schema, links)),
+                None => crate::rules::class_expression_summary(member)
+                    .map(|s| render_description(&s, schema, links)),
+            })
+            .collect();
+        rows.push(ClassExpressionRow { label, members });
+    }
+    rows.retain(|row| !row.members.is_empty());
+    rows
+}
+
+/// Build the Subsets section, one entry per declared subset in name order.
+/// Members link to their cards; a subset no element joins still renders,
+/// with an empty member list.
+fn build_subsets(schema: &SchemaDefinition, links: &CardLinks) -> Vec<SubsetData> {
+    schema
+        .subsets
+        .iter()
+        .map(|(name, subset)| {
+            let (classes, slots, enums, types) = crate::subset::members(schema, name);
+            SubsetData {
+                id: name.clone(),
+                description: subset
+                    .description
+                    .as_deref()
+                    .map(|d| render_description(d, schema, links)),
+                members: [classes, slots, enums, types]
+                    .concat()
+                    .iter()
+                    .map(|member| render_xref(member, schema, links))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
 /// Build the rendered `unique_keys` list, in stable name-sorted order
 /// (the source is a `BTreeMap`). Descriptions pass through the same
 /// markdown pipeline as [`ClassData::description`].
 fn build_unique_keys(
     unique_keys: &std::collections::BTreeMap<String, crate::linkml::UniqueKey>,
     schema: &SchemaDefinition,
+    links: &CardLinks,
 ) -> Vec<UniqueKeyInClass> {
     unique_keys
         .iter()
@@ -1993,7 +3441,7 @@ fn build_unique_keys(
             description: key
                 .description
                 .as_deref()
-                .map(|d| render_description(d, schema)),
+                .map(|d| render_description(d, schema, links)),
         })
         .collect()
 }
@@ -2034,21 +3482,25 @@ fn lookup_term(
     }
 }
 
-fn render_xref(name: &str, schema: &SchemaDefinition) -> String {
-    if schema.classes.contains_key(name) {
-        format!(r##"<a href="#class-{name}" class="entity-ref class-ref">{name}</a>"##)
+fn render_xref(name: &str, schema: &SchemaDefinition, links: &CardLinks) -> String {
+    let kind = if schema.classes.contains_key(name) {
+        "class"
     } else if schema.enums.contains_key(name) {
-        format!(r##"<a href="#enum-{name}" class="entity-ref enum-ref">{name}</a>"##)
+        "enum"
     } else if schema.slots.contains_key(name) {
-        format!(r##"<a href="#slot-{name}" class="entity-ref slot-ref">{name}</a>"##)
+        "slot"
     } else if schema.types.contains_key(name) {
-        format!(r##"<a href="#type-{name}" class="entity-ref type-ref">{name}</a>"##)
+        "type"
     } else {
-        format!(
+        return format!(
             "[[{name}]]<!-- WARNING: [[{name}]] does not resolve to a class, \
              enum, slot, or type in this schema -->"
-        )
-    }
+        );
+    };
+    format!(
+        r#"<a href="{}" class="entity-ref {kind}-ref">{name}</a>"#,
+        html_escape(&links.to(kind, name))
+    )
 }
 
 #[cfg(test)]
@@ -2588,6 +4040,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn slot_card_shows_the_unit_linked_to_its_qudt_iri() {
+        use crate::linkml::{SchemaDefinition, SlotDefinition, UnitOfMeasure};
+        let mut schema = SchemaDefinition::new("s");
+        schema
+            .prefixes
+            .insert("unit".into(), "http://qudt.org/vocab/unit/".into());
+        let mut mass = SlotDefinition::new("mass");
+        mass.range = Some("float".into());
+        mass.unit = Some(UnitOfMeasure {
+            ucum_code: Some("kg".into()),
+            descriptive_name: Some("kilogram".into()),
+            exact_mappings: vec!["unit:KiloGM".into()],
+            ..UnitOfMeasure::default()
+        });
+        schema.slots.insert("mass".into(), mass);
+
+        let writer = HtmlWriter::new();
+        let temp_dir = std::env::temp_dir().join("panschema_slot_unit_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        writer.write(&schema, &temp_dir).expect("write failed");
+        let html =
+            fs::read_to_string(temp_dir.join("index.html")).expect("failed to read index.html");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(html.contains("<dt>Unit</dt>"), "unit row rendered");
+        assert!(
+            html.contains(r#"href="http://qudt.org/vocab/unit/KiloGM""#)
+                && html.contains(">kg (kilogram)</a>"),
+            "the unit links to its expanded QUDT IRI"
+        );
+    }
+
+    #[test]
+    fn slot_card_shows_an_array_slot_shape() {
+        use crate::linkml::{
+            ArrayExpression, DimensionExpression, SchemaDefinition, SlotDefinition,
+        };
+        let mut schema = SchemaDefinition::new("s");
+        let mut samples = SlotDefinition::new("samples");
+        samples.range = Some("float".into());
+        samples.array = Some(ArrayExpression {
+            dimensions: vec![
+                DimensionExpression {
+                    alias: Some("time".into()),
+                    ..DimensionExpression::default()
+                },
+                DimensionExpression {
+                    alias: Some("channel".into()),
+                    exact_cardinality: Some(3),
+                    ..DimensionExpression::default()
+                },
+            ],
+            ..ArrayExpression::default()
+        });
+        schema.slots.insert("samples".into(), samples);
+
+        let temp_dir = std::env::temp_dir().join("panschema_slot_array_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        HtmlWriter::new()
+            .write(&schema, &temp_dir)
+            .expect("write failed");
+        let html =
+            fs::read_to_string(temp_dir.join("index.html")).expect("failed to read index.html");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(
+            html.contains("<dt>Array</dt>")
+                && html.contains("<dd>2-D array of float; time (any) × channel (3)</dd>"),
+            "the array row states the shape"
+        );
+    }
+
     #[test]
     fn html_writer_renders_enum_and_type_sections() {
         // Enums and types each get their own HTML section, card, and
@@ -2696,7 +4221,11 @@ mod tests {
         schema
             .classes
             .insert("Question".to_string(), ClassDefinition::new("Question"));
-        let html = render_description("see [[Question]] for context", &schema);
+        let html = render_description(
+            "see [[Question]] for context",
+            &schema,
+            &CardLinks::default(),
+        );
         assert!(
             html.contains(
                 r##"<a href="#class-Question" class="entity-ref class-ref">Question</a>"##
@@ -2712,7 +4241,11 @@ mod tests {
         schema
             .enums
             .insert("ActStatus".to_string(), EnumDefinition::new("ActStatus"));
-        let html = render_description("captured by the [[ActStatus]] enum", &schema);
+        let html = render_description(
+            "captured by the [[ActStatus]] enum",
+            &schema,
+            &CardLinks::default(),
+        );
         assert!(
             html.contains(
                 r##"<a href="#enum-ActStatus" class="entity-ref enum-ref">ActStatus</a>"##
@@ -2728,7 +4261,7 @@ mod tests {
         schema
             .slots
             .insert("status".to_string(), SlotDefinition::new("status"));
-        let html = render_description("the [[status]] slot", &schema);
+        let html = render_description("the [[status]] slot", &schema, &CardLinks::default());
         assert!(
             html.contains(r##"<a href="#slot-status" class="entity-ref slot-ref">status</a>"##),
             "expected slot anchor; got: {html}"
@@ -2739,7 +4272,7 @@ mod tests {
     fn render_description_emits_warning_comment_for_unresolved_reference() {
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description("nothing here: [[Phantom]]", &schema);
+        let html = render_description("nothing here: [[Phantom]]", &schema, &CardLinks::default());
         assert!(
             html.contains("[[Phantom]]"),
             "expected literal text; got: {html}"
@@ -2754,7 +4287,7 @@ mod tests {
     fn render_description_html_escapes_surrounding_plain_text() {
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description("if a < b & c > d", &schema);
+        let html = render_description("if a < b & c > d", &schema, &CardLinks::default());
         // `< > &` in body content must be escaped — the rendered HTML
         // is mounted via `|safe` in entity descriptions, so the writer
         // can't lean on Askama for escaping. `"` and `'` are body-safe
@@ -2773,7 +4306,7 @@ mod tests {
         // rendered source instead of `&quot;`/`&#39;`-encoding them.
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description(r#"says "hi" and 'bye'"#, &schema);
+        let html = render_description(r#"says "hi" and 'bye'"#, &schema, &CardLinks::default());
         assert!(html.contains(r#"says "hi" and 'bye'"#), "got: {html}");
     }
 
@@ -2784,10 +4317,15 @@ mod tests {
         // is treated as literal `[[...]]` text, not a cross-reference.
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        assert!(render_description("[[]]", &schema).contains("[[]]"));
-        assert!(render_description("[[123abc]]", &schema).contains("[[123abc]]"));
-        assert!(render_description("[[has space]]", &schema).contains("[[has space]]"));
-        assert!(render_description("[[a-b]]", &schema).contains("[[a-b]]"));
+        assert!(render_description("[[]]", &schema, &CardLinks::default()).contains("[[]]"));
+        assert!(
+            render_description("[[123abc]]", &schema, &CardLinks::default()).contains("[[123abc]]")
+        );
+        assert!(
+            render_description("[[has space]]", &schema, &CardLinks::default())
+                .contains("[[has space]]")
+        );
+        assert!(render_description("[[a-b]]", &schema, &CardLinks::default()).contains("[[a-b]]"));
     }
 
     #[test]
@@ -2797,7 +4335,7 @@ mod tests {
         schema
             .classes
             .insert("_Internal".to_string(), ClassDefinition::new("_Internal"));
-        let html = render_description("[[_Internal]]", &schema);
+        let html = render_description("[[_Internal]]", &schema, &CardLinks::default());
         assert!(
             html.contains(r##"<a href="#class-_Internal""##),
             "expected underscore-leading ident to resolve; got: {html}"
@@ -2808,7 +4346,7 @@ mod tests {
     fn render_description_passes_lone_brackets_through() {
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description("[note] and [[unclosed", &schema);
+        let html = render_description("[note] and [[unclosed", &schema, &CardLinks::default());
         assert!(html.contains("[note] and [[unclosed"), "got: {html}");
     }
 
@@ -2821,7 +4359,11 @@ mod tests {
         // remains how a description references another schema entity.
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description("see the [book](../../) for context", &schema);
+        let html = render_description(
+            "see the [book](../../) for context",
+            &schema,
+            &CardLinks::default(),
+        );
         assert!(
             html.contains(r#"<a href="../../">book</a>"#),
             "expected rendered markdown link; got: {html}"
@@ -2832,7 +4374,11 @@ mod tests {
     fn render_description_renders_markdown_emphasis_and_code() {
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description("**bold** and *italic* and `code`", &schema);
+        let html = render_description(
+            "**bold** and *italic* and `code`",
+            &schema,
+            &CardLinks::default(),
+        );
         assert!(
             html.contains("<strong>bold</strong>"),
             "expected bold; got: {html}"
@@ -2855,7 +4401,11 @@ mod tests {
         // a link uses markdown `[text](url)` syntax instead.
         use crate::linkml::SchemaDefinition;
         let schema = SchemaDefinition::new("s");
-        let html = render_description(r#"plain <a href="evil.html">click</a> tail"#, &schema);
+        let html = render_description(
+            r#"plain <a href="evil.html">click</a> tail"#,
+            &schema,
+            &CardLinks::default(),
+        );
         assert!(
             !html.contains(r#"<a href="evil.html">"#),
             "raw HTML must not survive verbatim; got: {html}"
@@ -2879,7 +4429,7 @@ mod tests {
         schema
             .classes
             .insert("Question".to_string(), ClassDefinition::new("Question"));
-        let html = render_description("[via [[Question]]](../../)", &schema);
+        let html = render_description("[via [[Question]]](../../)", &schema, &CardLinks::default());
         // Outer markdown link survives.
         assert!(html.contains(r#"<a href="../../">"#), "got: {html}");
         // Inner xref also resolves.
@@ -3429,12 +4979,119 @@ mod tests {
     }
 
     #[test]
-    fn slot_card_shows_examples() {
-        use crate::linkml::{ClassDefinition, Example, SchemaDefinition, SlotDefinition};
-        // A class or slot with `examples:` carries each `value` and its
-        // optional `description` through to the card-data `examples`
-        // list, ready for the "Examples" section. An element with no
-        // examples carries an empty list, so no section renders.
+    fn class_card_shows_boolean_expressions() {
+        use crate::linkml::{ClassDefinition, ClassExpression, SchemaDefinition, SlotCondition};
+        // Each combinator and set axiom renders as its own labeled row. A
+        // bare-class member links to the class card, like a slot's `any_of`
+        // range; a slot-conditioned member reads as its summary.
+        let mut schema = SchemaDefinition::new("pets");
+        let mut pet = ClassDefinition::new("Pet");
+        pet.any_of = vec![
+            ClassExpression {
+                is_a: Some("Cat".to_string()),
+                ..Default::default()
+            },
+            ClassExpression {
+                is_a: Some("Dog".to_string()),
+                ..Default::default()
+            },
+        ];
+        pet.exactly_one_of = vec![ClassExpression {
+            slot_conditions: [(
+                "chip".to_string(),
+                SlotCondition {
+                    required: true,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        }];
+        pet.disjoint_with = vec!["Plant".to_string()];
+        schema.classes.insert("Pet".to_string(), pet);
+        for name in ["Cat", "Dog", "Plant"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+
+        let data = HtmlWriter::build_template_data(&schema);
+        let card = data.class_data.iter().find(|c| c.id == "Pet").unwrap();
+        let labels: Vec<&str> = card.expressions.iter().map(|r| r.label).collect();
+        assert_eq!(labels, vec!["Disjoint with", "Any of", "Exactly one of"]);
+        assert_eq!(
+            card.expressions[1].members,
+            vec![
+                r##"<a href="#class-Cat" class="entity-ref class-ref">Cat</a>"##,
+                r##"<a href="#class-Dog" class="entity-ref class-ref">Dog</a>"##,
+            ]
+        );
+        assert!(
+            card.expressions[2].members[0].contains("<code>chip</code> is required"),
+            "got: {:?}",
+            card.expressions[2].members
+        );
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let html = std::fs::read_to_string(out.path().join("index.html")).unwrap();
+        assert!(
+            html.contains("<dt>Exactly one of</dt>"),
+            "expected the labeled row in the page; got: {html}"
+        );
+    }
+
+    #[test]
+    fn subsets_render_a_section_and_per_element_badges() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition, SubsetDefinition};
+        // Each declared subset gets an entry listing its members; each
+        // member's card carries a badge linking back to that entry.
+        let mut schema = SchemaDefinition::new("shop");
+        let mut public = SubsetDefinition::new("public");
+        public.description = Some("Partner-facing elements.".to_string());
+        schema.subsets.insert("public".to_string(), public);
+        schema
+            .subsets
+            .insert("internal".to_string(), SubsetDefinition::new("internal"));
+        let mut order = ClassDefinition::new("Order");
+        order.in_subset = vec!["public".to_string()];
+        schema.classes.insert("Order".to_string(), order);
+        schema
+            .classes
+            .insert("AuditLog".to_string(), ClassDefinition::new("AuditLog"));
+
+        let data = HtmlWriter::build_template_data(&schema);
+        let ids: Vec<&str> = data.subset_data.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["internal", "public"]);
+        assert!(data.subset_data[0].members.is_empty());
+        assert_eq!(
+            data.subset_data[1].members,
+            vec![r##"<a href="#class-Order" class="entity-ref class-ref">Order</a>"##]
+        );
+        let order = data.class_data.iter().find(|c| c.id == "Order").unwrap();
+        assert_eq!(order.in_subset, vec!["public"]);
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let html = std::fs::read_to_string(out.path().join("index.html")).unwrap();
+        assert!(html.contains(r#"<section id="subsets">"#), "got: {html}");
+        assert!(html.contains(r#"href="#subsets""#), "sidebar entry; got: {html}");
+        assert!(
+            html.contains(r##"<a href="#subset-public" class="subset-badge""##),
+            "the member's card carries the badge; got: {html}"
+        );
+        assert!(html.contains("No elements declare this subset."));
+    }
+
+    #[test]
+    fn slot_card_shows_examples() {
+        use crate::linkml::{ClassDefinition, Example, SchemaDefinition, SlotDefinition};
+        // A class or slot with `examples:` carries each `value` and its
+        // optional `description` through to the card-data `examples`
+        // list, ready for the "Examples" section. An element with no
+        // examples carries an empty list, so no section renders.
         let mut schema = SchemaDefinition::new("editorial");
 
         let mut region = ClassDefinition::new("Region");
@@ -3500,6 +5157,694 @@ mod tests {
     /// A slot specializing another (slot-level `is_a`) shows the relation
     /// on its card, labeled by the parent's display label — the same
     /// surfacing `inverse` gets.
+    #[test]
+    fn common_metadata_renders_editorial_rows_and_rank_orders_the_cards() {
+        use crate::linkml::{ClassDefinition, EnumDefinition, PermissibleValue, SchemaDefinition};
+        // Curators' editorial fields render as rows on the card, the
+        // schema's own on the metadata card; `rank` renders nowhere but
+        // orders the cards (lower first, unranked after, by name within).
+        let mut schema = SchemaDefinition::new("curated");
+        schema
+            .prefixes
+            .insert("orcid".to_string(), "https://orcid.org/".to_string());
+        schema.metadata.status = Some("bibo:draft".to_string());
+
+        let mut zebra = ClassDefinition::new("Zebra");
+        zebra.metadata.rank = Some(1);
+        zebra.metadata.comments = vec!["Striped, unlike the rest.".to_string()];
+        zebra.metadata.todos = vec!["split by species".to_string()];
+        zebra.metadata.keywords = vec!["equine".to_string(), "savanna".to_string()];
+        zebra.metadata.source = Some("orcid:0000-0002-1825-0097".to_string());
+        zebra.metadata.created_by = Some("orcid:0000-0002-1825-0097".to_string());
+        zebra.metadata.created_on = Some("2024-03-01".to_string());
+        zebra.metadata.last_updated_on = Some("2024-06-30".to_string());
+        schema.classes.insert("Zebra".to_string(), zebra);
+        let mut yak = ClassDefinition::new("Yak");
+        yak.metadata.rank = Some(2);
+        schema.classes.insert("Yak".to_string(), yak);
+        schema
+            .classes
+            .insert("Aardvark".to_string(), ClassDefinition::new("Aardvark"));
+
+        let mut size = EnumDefinition::new("Size");
+        for (text, rank) in [("large", Some(1)), ("small", None), ("medium", Some(2))] {
+            let mut pv = PermissibleValue::new(text);
+            pv.metadata.rank = rank;
+            size.permissible_values.insert(text.to_string(), pv);
+        }
+        schema.enums.insert("Size".to_string(), size);
+
+        let data = HtmlWriter::build_template_data(&schema);
+        let order: Vec<&str> = data.class_data.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(order, ["Zebra", "Yak", "Aardvark"]);
+        let values: Vec<&str> = data.enum_data[0]
+            .permissible_values
+            .iter()
+            .map(|pv| pv.text.as_str())
+            .collect();
+        assert_eq!(values, ["large", "medium", "small"]);
+
+        let zebra = &data.class_data[0];
+        let editorial = zebra.editorial.as_ref().expect("zebra has editorial rows");
+        assert_eq!(
+            editorial.created.as_deref(),
+            Some("by orcid:0000-0002-1825-0097 on 2024-03-01")
+        );
+        assert_eq!(editorial.updated.as_deref(), Some("on 2024-06-30"));
+        assert_eq!(
+            editorial.source.as_ref().and_then(|l| l.href.as_deref()),
+            Some("https://orcid.org/0000-0002-1825-0097")
+        );
+        assert!(
+            data.class_data[1].editorial.is_none(),
+            "a rank alone renders no editorial rows"
+        );
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let html = std::fs::read_to_string(out.path().join("index.html")).unwrap();
+        for expected in [
+            "<dt>Comments</dt>",
+            "Striped, unlike the rest.",
+            "<dt>To do</dt>",
+            "equine, savanna",
+            "<dt>Last updated</dt>",
+            "<code class=\"mono\">bibo:draft</code>",
+        ] {
+            assert!(
+                html.contains(expected),
+                "expected {expected:?}; got: {html}"
+            );
+        }
+    }
+
+    #[test]
+    fn search_index_covers_every_card_and_ships_with_the_schema_sections() {
+        use crate::linkml::{ClassDefinition, EnumDefinition, PermissibleValue, SchemaDefinition};
+        let mut schema = SchemaDefinition::new("searchable");
+        let mut person = ClassDefinition::new("Person");
+        person.class_uri = Some("schema:Person".to_string());
+        person.aliases = vec!["Human".to_string()];
+        person.description = Some("A person, see [[Status]] & more.".to_string());
+        person.exact_mappings = vec!["foaf:Person".to_string()];
+        schema.classes.insert("Person".to_string(), person);
+        let mut status = EnumDefinition::new("Status");
+        status
+            .permissible_values
+            .insert("ACTIVE".to_string(), PermissibleValue::new("ACTIVE"));
+        schema.enums.insert("Status".to_string(), status);
+
+        let index = HtmlWriter::build_template_data(&schema).search_index();
+        let person = index.iter().find(|e| e.kind == "class").expect("class");
+        assert_eq!(person.name, "Person");
+        assert_eq!(person.aliases, ["Human"]);
+        assert_eq!(person.curie.as_deref(), Some("schema:Person"));
+        assert_eq!(person.mappings, ["foaf:Person"]);
+        assert_eq!(
+            person.description.as_deref(),
+            Some("A person, see Status & more."),
+            "the description is indexed as plain text"
+        );
+        let status = index.iter().find(|e| e.kind == "enum").expect("enum");
+        assert_eq!(status.values, ["ACTIVE"]);
+
+        let render = |writer: HtmlWriter| {
+            let out = tempfile::tempdir().unwrap();
+            crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+            std::fs::read_to_string(out.path().join("index.html")).unwrap()
+        };
+        let html = render(HtmlWriter::with_options(false));
+        assert!(html.contains("id=\"panschema-search\""));
+        assert!(html.contains("\"label\":\"Person\",\"aliases\":[\"Human\"]"));
+        let html = render(HtmlWriter::with_options(false).with_schema_sections(false));
+        assert!(
+            !html.contains("panschema-search-index"),
+            "no cards, no index"
+        );
+    }
+
+    #[test]
+    fn multi_page_mode_gives_each_element_a_page_with_its_usages_and_a_sitemap() {
+        use crate::linkml::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+        let mut schema = SchemaDefinition::new("paged");
+        let mut status = SlotDefinition::new("status");
+        status.range = Some("Status".to_string());
+        schema.slots.insert("status".to_string(), status);
+        schema
+            .enums
+            .insert("Status".to_string(), EnumDefinition::new("Status"));
+        let mut person = ClassDefinition::new("Person");
+        person.slots = vec!["status".to_string()];
+        schema.classes.insert("Person".to_string(), person);
+        let mut employee = ClassDefinition::new("Employee");
+        employee.is_a = Some("Person".to_string());
+        schema.classes.insert("Employee".to_string(), employee);
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::new().with_site_mode(SiteMode::MultiPage);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let read = |page: &str| std::fs::read_to_string(out.path().join(page)).unwrap();
+
+        let index = read("index.html");
+        assert!(
+            !index.contains("class=\"entity-card"),
+            "the cards moved to their own pages"
+        );
+        assert!(index.contains("href=\"classes/Person.html\""));
+        assert!(index.contains("href=\"sitemap.html\""));
+        assert!(
+            index.contains("\"page\":\"enums/Status.html\""),
+            "search opens the page"
+        );
+
+        let person = read("classes/Person.html");
+        assert!(
+            person.contains("id=\"class-Person\""),
+            "the overview's card"
+        );
+        assert!(person.contains("<a href=\"../index.html\">paged</a>"));
+        assert!(
+            person.contains("href=\"../slots/status.html\""),
+            "card links go to pages"
+        );
+        assert!(person.contains("href=\"../classes/Employee.html\""));
+        assert!(person.contains("window.__PANSCHEMA_ASSET_BASE__ = '../';"));
+
+        let status = read("enums/Status.html");
+        assert!(
+            status.contains(
+                "<span class=\"used-by-relation\">range</span> \
+                 <a href=\"../slots/status.html\" class=\"entity-ref slot-ref\">status</a>"
+            ),
+            "the card's usages link to pages"
+        );
+        assert!(
+            !status.contains("Used as range by"),
+            "usages are listed once"
+        );
+        assert!(
+            !status.contains("__PANSCHEMA_GRAPH_DATA__"),
+            "only a class page carries a graph"
+        );
+
+        let sitemap = read("sitemap.html");
+        for page in [
+            "classes/Employee.html",
+            "classes/Person.html",
+            "slots/status.html",
+            "enums/Status.html",
+        ] {
+            assert!(
+                sitemap.contains(&format!("href=\"{page}\"")),
+                "{page} missing from the sitemap"
+            );
+        }
+        assert!(out.path().join("panschema_viz.js").is_file());
+    }
+
+    #[test]
+    fn a_theme_overrides_components_and_ships_its_files_on_every_page() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition};
+        let mut schema = SchemaDefinition::new("branded");
+        schema
+            .classes
+            .insert("Person".to_string(), ClassDefinition::new("Person"));
+
+        let theme_dir = tempfile::tempdir().unwrap();
+        let file = |name: &str, text: &str| {
+            std::fs::write(theme_dir.path().join(name), text).unwrap();
+        };
+        file(
+            "header.html",
+            r#"<img class="brand" src="{{ assets }}logo.svg">{{ default }}"#,
+        );
+        file("footer.html", "<footer>© Acme — {{ title }}</footer>");
+        file("hero.html", r#"<div class="banner">{{ default }}</div>"#);
+        file("theme.css", ":root { --color-primary: #c00; }");
+        std::fs::create_dir(theme_dir.path().join("assets")).unwrap();
+        file("assets/logo.svg", "<svg/>");
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::new()
+            .with_site_mode(SiteMode::MultiPage)
+            .with_theme(Theme::load(theme_dir.path()).unwrap());
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let read = |page: &str| std::fs::read_to_string(out.path().join(page)).unwrap();
+
+        let index = read("index.html");
+        assert!(index.contains(r#"<img class="brand" src="theme/assets/logo.svg">"#));
+        assert!(
+            index.contains("site-header"),
+            "`default` keeps the stock header"
+        );
+        assert!(index.contains("<footer>© Acme — branded</footer>"));
+        assert!(
+            !index.contains("site-footer"),
+            "no `default` replaces the stock footer"
+        );
+        assert!(index.contains(r#"<div class="banner"><section class="hero">"#));
+        assert!(index.contains(r#"<link rel="stylesheet" href="theme/theme.css">"#));
+
+        let person = read("classes/Person.html");
+        assert!(person.contains(r#"src="../theme/assets/logo.svg""#));
+        assert!(person.contains(r#"href="../theme/theme.css""#));
+        assert!(
+            !person.contains("class=\"hero\""),
+            "the hero is the overview's"
+        );
+
+        assert!(out.path().join("theme/theme.css").is_file());
+        assert!(out.path().join("theme/assets/logo.svg").is_file());
+    }
+
+    #[test]
+    fn a_self_contained_page_is_one_file_and_never_multi_page() {
+        use crate::linkml::ClassDefinition;
+        let mut schema = SchemaDefinition::new("solo");
+        schema
+            .classes
+            .insert("Person".to_string(), ClassDefinition::new("Person"));
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false).with_self_contained(true);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let files: Vec<_> = fs::read_dir(out.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["index.html"]);
+        let index = fs::read_to_string(out.path().join("index.html")).unwrap();
+        assert!(
+            !index.contains("__PANSCHEMA_VIZ_BUNDLE__ ="),
+            "a page with no canvas carries no bundle"
+        );
+
+        let split = HtmlWriter::new()
+            .with_site_mode(SiteMode::MultiPage)
+            .with_self_contained(true);
+        let err = crate::io::Writer::write(&split, &schema, out.path()).unwrap_err();
+        assert!(err.to_string().contains("multi-page"), "{err}");
+    }
+
+    #[test]
+    fn a_self_contained_page_inlines_loaded_assets_but_not_mentions_of_them() {
+        use crate::linkml::ClassDefinition;
+        let theme_dir = tempfile::tempdir().unwrap();
+        let file = |name: &str, text: &str| {
+            fs::write(theme_dir.path().join(name), text).unwrap();
+        };
+        file(
+            "header.html",
+            r#"<img class="brand" src="{{ assets }}logo.svg">{{ default }}"#,
+        );
+        file("theme.css", ".brand { background: url(assets/logo.svg); }");
+        fs::create_dir(theme_dir.path().join("assets")).unwrap();
+        file("assets/logo.svg", "<svg/>");
+
+        let mut schema = SchemaDefinition::new("branded");
+        let mut person = ClassDefinition::new("Person");
+        person.description = Some("Brand it with `theme/assets/logo.svg`.".to_string());
+        schema.classes.insert("Person".to_string(), person);
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false)
+            .with_self_contained(true)
+            .with_theme(Theme::load(theme_dir.path()).unwrap())
+            .with_changes(VersionChanges {
+                previous: "v0.1.0".to_string(),
+                previous_href: "../v0.1.0/".to_string(),
+                changes: Vec::new(),
+                badges: BTreeMap::new(),
+            });
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let uri = "data:image/svg+xml;base64,PHN2Zy8+";
+        for page in ["index.html", "changes.html"] {
+            let html = fs::read_to_string(out.path().join(page)).unwrap();
+            assert!(
+                html.contains(&format!(r#"<img class="brand" src="{uri}">"#)),
+                "{page}: the header's logo is inlined"
+            );
+            assert!(
+                html.contains(&format!(".brand {{ background: url({uri}); }}")),
+                "{page}: the stylesheet is inlined with its asset"
+            );
+            assert!(!html.contains("theme/theme.css"), "{page}");
+        }
+        let index = fs::read_to_string(out.path().join("index.html")).unwrap();
+        assert!(
+            index.contains("<code>theme/assets/logo.svg</code>"),
+            "the description still names the path"
+        );
+    }
+
+    #[test]
+    fn version_changes_render_a_changes_page_and_badge_the_cards() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition};
+        use crate::schema_diff::diff_schemas;
+
+        let mut old = SchemaDefinition::new("zoo");
+        old.classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        old.classes
+            .insert("Keeper".to_string(), ClassDefinition::new("Keeper"));
+        let mut new = old.clone();
+        new.classes.remove("Keeper");
+        let mut name = SlotDefinition::new("name");
+        name.required = true;
+        new.slots.insert("name".to_string(), name);
+        new.classes.get_mut("Animal").unwrap().slots = vec!["name".to_string()];
+
+        let changes = diff_schemas(&old, &new).unwrap();
+        let badges = BTreeMap::from([
+            (
+                "class-Animal".to_string(),
+                ChangeBadge {
+                    version: "v0.2.0".to_string(),
+                    change: ChangeKind::Modified,
+                    href: "changes.html#class-Animal".to_string(),
+                },
+            ),
+            (
+                "slot-name".to_string(),
+                ChangeBadge {
+                    version: "v0.2.0".to_string(),
+                    change: ChangeKind::Added,
+                    href: "changes.html#slot-name".to_string(),
+                },
+            ),
+        ]);
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::new().with_changes(VersionChanges {
+            previous: "v0.1.0".to_string(),
+            previous_href: "../v0.1.0/".to_string(),
+            changes,
+            badges,
+        });
+        crate::io::Writer::write(&writer, &new, out.path()).unwrap();
+        let read = |page: &str| std::fs::read_to_string(out.path().join(page)).unwrap();
+
+        let index = read("index.html");
+        assert!(
+            index.contains(
+                r#"<a href="changes.html" class="sidebar-link">Changes since v0.1.0</a>"#
+            )
+        );
+        assert!(index.contains(
+            r#"<a class="changed-badge" href="changes.html#class-Animal">changed in v0.2.0</a></h3>"#
+        ));
+        assert!(index.contains(
+            r#"<a class="changed-badge" href="changes.html#slot-name">new in v0.2.0</a></h3>"#
+        ));
+
+        let page = read("changes.html");
+        assert!(page.contains(r#"<li id="class-Keeper" class="change change-removed">"#));
+        assert!(
+            page.contains(r#"<a href="../v0.1.0/index.html#class-Keeper">v0.1.0</a>"#),
+            "a removed element links into the previous version"
+        );
+        assert!(
+            !page.contains(r#"<a href="index.html#class-Keeper">"#),
+            "and not into this one"
+        );
+        assert!(page.contains(r#"<li id="slot-name" class="change change-added">"#));
+        assert!(page.contains("<th><code>slots</code></th>"));
+        assert!(
+            page.contains(r#"<span class="change-constraint""#),
+            "a class's slot list constrains its records"
+        );
+    }
+
+    #[test]
+    fn change_badges_climb_a_level_on_element_pages() {
+        let badges = BTreeMap::from([(
+            "class-Person".to_string(),
+            ChangeBadge {
+                version: "v0.2.0".to_string(),
+                change: ChangeKind::Modified,
+                href: "../v0.2.0/changes.html#class-Person".to_string(),
+            },
+        )]);
+        let html = r#"<article id="class-Person"><h3 class="entity-title">Person</h3></article>"#;
+        assert_eq!(
+            add_change_badges(html, &badges, "../"),
+            r#"<article id="class-Person"><h3 class="entity-title">Person <a class="changed-badge" href="../../v0.2.0/changes.html#class-Person">changed in v0.2.0</a></h3></article>"#
+        );
+        assert_eq!(
+            add_change_badges("<p>no cards</p>", &badges, ""),
+            "<p>no cards</p>"
+        );
+    }
+
+    #[test]
+    fn element_page_links_resolve_from_one_directory_down() {
+        let links = CardLinks {
+            pages: HashMap::from([(
+                "class-Person".to_string(),
+                "classes/Person.html".to_string(),
+            )]),
+            root: "",
+        };
+        assert_eq!(links.to("class", "Person"), "classes/Person.html");
+        assert_eq!(links.to("subset", "core"), "#subset-core");
+        let below = links.for_element_page();
+        assert_eq!(below.to("class", "Person"), "../classes/Person.html");
+        assert_eq!(below.to("subset", "core"), "../index.html#subset-core");
+        assert_eq!(CardLinks::default().to("class", "Person"), "#class-Person");
+        assert_eq!(page_stem("a/b c"), "a_b_c");
+        assert_eq!(up_one_level("./"), "../");
+        assert_eq!(up_one_level("../current/"), "../../current/");
+        assert_eq!(up_one_level("https://example.org/"), "https://example.org/");
+    }
+
+    #[test]
+    fn colliding_page_stems_get_numbered_pages() {
+        use crate::linkml::{ClassDefinition, SlotDefinition};
+        let mut schema = SchemaDefinition::new("crowded");
+        for name in ["a b", "a_b", "Person", "person", "Foo_bar", "foo-bar"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+        schema
+            .slots
+            .insert("Person".to_string(), SlotDefinition::new("Person"));
+
+        let pages = element_pages(&schema);
+        assert_eq!(pages["class-Person"], "classes/Person.html");
+        assert_eq!(
+            pages["class-person"], "classes/person-2.html",
+            "a case-insensitive file system would merge the two"
+        );
+        assert_eq!(pages["class-a b"], "classes/a_b.html");
+        assert_eq!(pages["class-a_b"], "classes/a_b-2.html");
+        assert_eq!(pages["class-Foo_bar"], "classes/Foo_bar.html");
+        assert_eq!(pages["class-foo-bar"], "classes/foo-bar.html");
+        assert_eq!(
+            pages["slot-Person"], "slots/Person.html",
+            "kinds live in their own directories"
+        );
+        let files: std::collections::HashSet<_> =
+            pages.values().map(|p| p.to_lowercase()).collect();
+        assert_eq!(files.len(), pages.len(), "every card gets its own file");
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false).with_site_mode(SiteMode::MultiPage);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let read = |page: &str| std::fs::read_to_string(out.path().join(page)).unwrap();
+        assert!(read("classes/person-2.html").contains("id=\"class-person\""));
+        assert!(read("classes/Person.html").contains("id=\"class-Person\""));
+        assert!(read("sitemap.html").contains("href=\"classes/a_b-2.html\""));
+        assert!(read("index.html").contains("\"page\":\"classes/person-2.html\""));
+    }
+
+    #[test]
+    fn description_cross_references_link_to_pages_from_where_they_render() {
+        use crate::linkml::{ClassDefinition, SubsetDefinition};
+        let mut schema = SchemaDefinition::new("xref");
+        let mut person = ClassDefinition::new("Person");
+        person.description = Some("Employs no one; see [[Employer]].".to_string());
+        person.in_subset = vec!["core".to_string()];
+        schema.classes.insert("Person".to_string(), person);
+        schema
+            .classes
+            .insert("Employer".to_string(), ClassDefinition::new("Employer"));
+        schema
+            .subsets
+            .insert("core".to_string(), SubsetDefinition::new("core"));
+
+        let out = tempfile::tempdir().unwrap();
+        let writer = HtmlWriter::with_options(false).with_site_mode(SiteMode::MultiPage);
+        crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+        let read = |page: &str| std::fs::read_to_string(out.path().join(page)).unwrap();
+
+        assert!(
+            read("index.html").contains(
+                r#"<a href="classes/Person.html" class="entity-ref class-ref">Person</a>"#
+            ),
+            "the subset lists its members"
+        );
+        let person = read("classes/Person.html");
+        assert!(person.contains(
+            r#"<a href="../classes/Employer.html" class="entity-ref class-ref">Employer</a>"#
+        ));
+        assert!(
+            person.contains(r#"href="../index.html#subset-core""#),
+            "the subset badge goes back to the overview"
+        );
+    }
+
+    #[test]
+    fn slot_groups_open_sections_and_recommended_gets_its_badge() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition};
+        let mut schema = SchemaDefinition::new("grouped");
+        let mut contact = SlotDefinition::new("contact");
+        contact.is_grouping_slot = true;
+        schema.slots.insert("contact".to_string(), contact);
+        let mut person = ClassDefinition::new("Person");
+        for (name, group, recommended) in [
+            ("email", Some("contact"), true),
+            ("phone", Some("contact"), false),
+            ("name", None, false),
+        ] {
+            let mut slot = SlotDefinition::new(name);
+            slot.slot_group = group.map(str::to_string);
+            slot.recommended = recommended;
+            person.attributes.insert(name.to_string(), slot);
+        }
+        schema.classes.insert("Person".to_string(), person);
+
+        let data = HtmlWriter::build_template_data(&schema);
+        let slots = &data.class_data[0].slots;
+        let rows: Vec<(&str, Option<&str>, bool)> = slots
+            .iter()
+            .map(|s| (s.name.as_str(), s.group_heading.as_deref(), s.recommended))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("name", None, false),
+                ("email", Some("contact"), true),
+                ("phone", None, false),
+            ],
+            "the group's heading opens on its first member only"
+        );
+
+        let out = tempfile::tempdir().unwrap();
+        crate::io::Writer::write(&HtmlWriter::with_options(false), &schema, out.path()).unwrap();
+        let html = std::fs::read_to_string(out.path().join("index.html")).unwrap();
+        assert!(
+            html.contains(
+                "<li class=\"slot-group-heading\" id=\"class-Person-group-contact\">contact</li>"
+            ),
+            "got: {html}"
+        );
+        assert!(html.contains("<span class=\"slot-flag recommended\""));
+    }
+
+    #[test]
+    fn translations_render_hidden_and_lang_makes_one_primary() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition, StructuredAlias};
+        // Other-language labels and descriptions ride on the card as hidden
+        // blocks the header's switcher reveals; `--lang` swaps one in as
+        // the card's own text, and the source text becomes a translation.
+        let mut schema = SchemaDefinition::new("multilingual");
+        schema.metadata.in_language = Some("en".to_string());
+        let mut person = ClassDefinition::new("Person");
+        person.description = Some("A human being.".to_string());
+        let m = &mut person.metadata;
+        m.local_names
+            .insert("fr".to_string(), "Personne".to_string());
+        m.local_names.insert("de".to_string(), "Mensch".to_string());
+        m.alt_descriptions
+            .insert("fr".to_string(), "Un être humain.".to_string());
+        m.structured_aliases = vec![
+            StructuredAlias {
+                literal_form: "Individu".to_string(),
+                predicate: None,
+                in_language: Some("fr".to_string()),
+            },
+            StructuredAlias {
+                literal_form: "Human".to_string(),
+                predicate: None,
+                in_language: None,
+            },
+        ];
+        schema.classes.insert("Person".to_string(), person);
+        schema
+            .classes
+            .insert("Place".to_string(), ClassDefinition::new("Place"));
+
+        let data = HtmlWriter::build_template_data(&schema);
+        let card = &data.class_data[0];
+        let langs: Vec<&str> = card.translations.iter().map(|t| t.lang.as_str()).collect();
+        assert_eq!(langs, ["de", "fr"]);
+        assert_eq!(card.translations[1].aliases, ["Individu"]);
+        assert_eq!(
+            card.aliases,
+            ["Human"],
+            "an unlanguaged alias joins the row"
+        );
+        assert!(
+            card.editorial.is_none(),
+            "translations are not editorial rows"
+        );
+
+        let render = |writer: HtmlWriter| {
+            let out = tempfile::tempdir().unwrap();
+            crate::io::Writer::write(&writer, &schema, out.path()).unwrap();
+            std::fs::read_to_string(out.path().join("index.html")).unwrap()
+        };
+        let html = render(HtmlWriter::with_options(false));
+        for expected in [
+            "<html lang=\"en\">",
+            "id=\"lang-select\"",
+            "<option value=\"de\">de</option>",
+            "data-lang=\"fr\" lang=\"fr\" hidden>",
+            "<p class=\"translation-label\">Personne</p>",
+            "Un être humain.",
+        ] {
+            assert!(
+                html.contains(expected),
+                "expected {expected:?}; got: {html}"
+            );
+        }
+
+        let html = render(HtmlWriter::with_options(false).with_lang("fr"));
+        assert!(html.contains("<html lang=\"fr\">"), "got: {html}");
+        assert!(
+            html.contains("<option value=\"en\">en</option>"),
+            "the source language stays reachable: {html}"
+        );
+        assert!(
+            html.contains("<h3 class=\"entity-title\">Personne</h3>"),
+            "the French label heads the card: {html}"
+        );
+        assert!(
+            html.contains("data-lang=\"en\" lang=\"en\" hidden>"),
+            "the English text becomes a translation: {html}"
+        );
+        assert!(
+            html.contains("<h3 class=\"entity-title\">Place</h3>"),
+            "an untranslated card keeps its text"
+        );
+
+        let plain = {
+            let mut schema = SchemaDefinition::new("monolingual");
+            schema
+                .classes
+                .insert("Place".to_string(), ClassDefinition::new("Place"));
+            let out = tempfile::tempdir().unwrap();
+            crate::io::Writer::write(&HtmlWriter::with_options(false), &schema, out.path())
+                .unwrap();
+            std::fs::read_to_string(out.path().join("index.html")).unwrap()
+        };
+        assert!(
+            !plain.contains("lang-select"),
+            "no translations, no switcher"
+        );
+    }
+
     #[test]
     fn slot_card_shows_the_slot_it_specializes() {
         use crate::linkml::{SchemaDefinition, SlotDefinition};
@@ -4251,6 +6596,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cards_list_their_inbound_references_and_typed_records() {
+        let schema = bottle_rack_schema();
+        let only = instance_set_from_yaml(&schema, "bottles:\n  - id: b1\n    name: Morgon\n");
+
+        let writer = HtmlWriter::new().with_instance_dataset(InstanceDataset::new("only", only));
+        let temp_dir = std::env::temp_dir().join("panschema_used_by_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        writer.write(&schema, &temp_dir).expect("write");
+        let html = fs::read_to_string(temp_dir.join("index.html")).expect("read");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let card = |anchor: &str| {
+            let start = html.find(&format!(r#"id="{anchor}""#)).expect(anchor);
+            let end = start + html[start..].find("</article>").unwrap();
+            html[start..end].to_string()
+        };
+        let rack = card("class-Rack");
+        assert!(rack.contains("Used by"), "{rack}");
+        assert!(
+            rack.contains(
+                r##"<span class="used-by-relation">range</span> <a href="#class-Bottle" class="entity-ref class-ref">Bottle</a> <span class="used-by-detail">(stored_in)</span>"##
+            ),
+            "the attribute ranging over Rack is listed with its slot: {rack}"
+        );
+        assert!(
+            rack.contains(r##"href="#class-Cellar""##),
+            "the container's racks attribute counts too"
+        );
+        assert!(!rack.contains("individual-ref"), "no rack records loaded");
+
+        let bottle = card("class-Bottle");
+        assert!(
+            bottle.contains(r##"<span class="used-by-relation">record</span> <a href="#ind-b1" class="entity-ref individual-ref">"##),
+            "the default dataset's records join their class's row: {bottle}"
+        );
+        assert!(
+            !card("class-Cellar").contains("Used by"),
+            "nothing references the container"
+        );
+    }
+
     #[test]
     fn entity_list_disambiguates_shared_labels_by_class() {
         // Two individuals of different classes can legitimately share a
@@ -77,6 +77,35 @@ pub fn load_schema_with_deps(
     input: &Path,
     registry: &FormatRegistry,
     deps: &BTreeMap<String, PathBuf>,
+) -> IoResult<SchemaDefinition> {
+    let mut schema = load_schema_merged(input, registry, deps)?;
+
+    // Dynamic enums expand against the local ontology cache only; a
+    // document `panschema fetch` has not downloaded yet is a warning, and
+    // the enum keeps its static values.
+    let expansion = crate::dynamic_enums::expand_from_default_cache(&mut schema, input);
+    for problem in &expansion.problems {
+        eprintln!("warning: {problem}");
+    }
+
+    // Schema-level diagnostics that don't depend on the output format, so every
+    // command surfaces them — previously only `generate` did. `--strict`
+    // enforcement and format-specific warnings stay at the `generate` site.
+    for message in crate::diagnostics::schema_load_diagnostics(&schema) {
+        eprintln!("warning: {message}");
+    }
+
+    Ok(schema)
+}
+
+/// The first half of [`load_schema_with_deps`]: read, resolve imports and
+/// inheritance, but neither expand dynamic enums nor print the load
+/// diagnostics. `panschema fetch` and `verify` start here, so they can
+/// populate or check the ontology cache before expanding.
+pub fn load_schema_merged(
+    input: &Path,
+    registry: &FormatRegistry,
+    deps: &BTreeMap<String, PathBuf>,
 ) -> IoResult<SchemaDefinition> {
     let reader = registry.reader_for_path(input)?;
     let (mut schema, reader_warnings) = reader.read_with_warnings(input)?;
@@ -136,13 +165,9 @@ pub fn load_schema_with_deps(
     // defaults, per LinkML's derivation order.
     crate::linkml_resolve::resolve_slot_inheritance(&mut schema);
     crate::linkml_resolve::materialize_deferred_default_range(&mut schema);
-
-    // Schema-level diagnostics that don't depend on the output format, so every
-    // command surfaces them — previously only `generate` did. `--strict`
-    // enforcement and format-specific warnings stay at the `generate` site.
-    for message in crate::diagnostics::schema_load_diagnostics(&schema) {
-        eprintln!("warning: {message}");
-    }
+    // Structured patterns last: the merge has unioned every file's
+    // `settings`, and inheritance has carried each pattern to its slots.
+    crate::linkml_resolve::materialize_structured_patterns(&mut schema);
 
     Ok(schema)
 }
@@ -491,7 +516,7 @@ fn canonicalize_lossy(path: &Path) -> PathBuf {
 }
 
 /// Merge `imported` into `root`. For each of `classes`, `slots`,
-/// `enums`, `types`, and `prefixes`:
+/// `enums`, `types`, `subsets`, `settings`, and `prefixes`:
 ///
 /// - A name unused in `root` is inserted and its origin recorded.
 /// - A name already present whose *existing* and *incoming* definitions
@@ -538,6 +563,8 @@ fn merge_schema(
     merge_map!(slots, "slot");
     merge_map!(enums, "enum");
     merge_map!(types, "type");
+    merge_map!(subsets, "subset");
+    merge_map!(settings, "setting");
 
     // Prefixes union the same way; an identical mapping unifies, a
     // differing one keeps the root's and records a `prefix` collision.
@@ -559,6 +586,11 @@ fn merge_schema(
                 .insert(format!("prefix {prefix}"), origin.to_path_buf());
         }
     }
+
+    // Positions follow the same precedence: a key the root already located
+    // keeps the root's position, so a diagnostic on a colliding element
+    // points at the definition that survived.
+    root.source_map.absorb(&imported.source_map);
 }
 
 #[cfg(test)]
@@ -621,6 +653,27 @@ mod tests {
         assert!(root.imports.is_empty());
     }
 
+    #[test]
+    fn merged_elements_keep_the_position_of_the_file_that_defined_them() {
+        // A diagnostic about an imported element must point into the
+        // import, not the root: the merge carries each file's positions.
+        let registry = FormatRegistry::with_defaults();
+        let (mut root, path) = read_root("app.yaml");
+        resolve_imports(&mut root, &path, &registry, &no_deps()).expect("resolve imports");
+
+        let street = root
+            .source_map
+            .get(&["classes", "Address", "attributes", "street"])
+            .expect("imported attribute located");
+        assert_eq!(street.file, fixtures_dir().join("common.yaml"));
+        assert_eq!((street.line, street.column), (14, 7));
+        let customer = root
+            .source_map
+            .get(&["classes", "Customer"])
+            .expect("root class located");
+        assert_eq!(customer.file, path);
+    }
+
     #[test]
     fn resolve_imports_rejects_traversal_escaping_the_root_directory() {
         // An import that resolves outside the root schema's own directory
@@ -7,6 +7,7 @@
 //! (`from_owl_annotations`); the LinkML instance-data reader populates the
 //! same model.
 
+use crate::expression::{Expression, record_value};
 use crate::linkml::SchemaDefinition;
 
 /// A typed reference from one instance to another — an object-property
@@ -56,6 +57,9 @@ pub enum InstanceValue {
         target: String,
         held: bool,
     },
+    /// The value of an `array` slot: its authored nested lists, each
+    /// element a scalar, a further list, or a value of the wrong kind.
+    Array(Vec<InstanceValue>),
     Unexpected(&'static str),
 }
 
@@ -653,6 +657,86 @@ impl InstanceSet {
         }
         Some(out)
     }
+
+    /// Fill every computed slot (one with an `equals_expression`) a record
+    /// leaves out, from the record's other values, returning how many were
+    /// filled. A computed slot may read another, so passes repeat until one
+    /// fills nothing. A slot whose expression doesn't parse, fails, or
+    /// reads an absent input (evaluating to `None`) stays empty; a stored
+    /// value is never overwritten — `validate` checks those.
+    pub fn infer_computed_slots(&mut self, schema: &SchemaDefinition) -> usize {
+        // (class, slot, expression, range, display label) per computed slot.
+        let mut computed: Vec<(String, String, Expression, Option<String>, String)> = Vec::new();
+        let classes: std::collections::BTreeSet<&String> = self
+            .instances
+            .iter()
+            .filter_map(|i| i.types.first())
+            .collect();
+        for class_name in classes {
+            let Some(class) = schema.classes.get(class_name) else {
+                continue;
+            };
+            for (slot_name, rs) in
+                crate::linkml_resolve::resolve_effective_slots_with_provenance(class, schema)
+            {
+                let slot = &rs.definition;
+                let Some(expression) = slot
+                    .equals_expression
+                    .as_deref()
+                    .and_then(|source| Expression::parse(source).ok())
+                else {
+                    continue;
+                };
+                let label = slot
+                    .annotations
+                    .get("panschema:label")
+                    .cloned()
+                    .unwrap_or_else(|| slot_name.clone());
+                computed.push((
+                    class_name.clone(),
+                    slot_name,
+                    expression,
+                    slot.range.clone(),
+                    label,
+                ));
+            }
+        }
+
+        let mut filled = 0;
+        loop {
+            let mut pass = 0;
+            for inst in &mut self.instances {
+                let Some(class_name) = inst.types.first().cloned() else {
+                    continue;
+                };
+                for (_, slot_name, expression, range, label) in
+                    computed.iter().filter(|(c, ..)| *c == class_name)
+                {
+                    if inst.slot_values.iter().any(|sv| &sv.slot == slot_name) {
+                        continue;
+                    }
+                    let Some(scalar) = expression
+                        .evaluate(|name| record_value(inst, name))
+                        .ok()
+                        .and_then(|value| value.to_scalar(range.as_deref()))
+                    else {
+                        continue;
+                    };
+                    inst.literals
+                        .push((label.clone(), scalar_to_display(&scalar)));
+                    inst.slot_values.push(SlotValue {
+                        slot: slot_name.clone(),
+                        values: vec![InstanceValue::Scalar(scalar)],
+                    });
+                    pass += 1;
+                }
+            }
+            if pass == 0 {
+                return filled;
+            }
+            filled += pass;
+        }
+    }
 }
 
 /// Which `tree_root` a data file was read against.
@@ -1480,6 +1564,16 @@ impl LinkmlLoader<'_> {
             let display = Some(field) != id_slot.as_deref()
                 && Some(field) != label_slot.as_deref()
                 && field != "description";
+            // An array slot's nested lists are one value, not a list of
+            // values; its shape is judged by validation.
+            if slot.is_some_and(|s| s.array.is_some()) {
+                let value = array_value(field_value);
+                if display {
+                    literals.push((property, array_to_display(&value)));
+                }
+                push_slot_value(&mut slot_values, field, value);
+                continue;
+            }
             self.ingest_field(
                 field,
                 &ranges,
@@ -1786,6 +1880,37 @@ fn push_slot_value(slot_values: &mut Vec<SlotValue>, slot: &str, value: Instance
     }
 }
 
+/// An `array` slot's YAML value as nested [`InstanceValue::Array`]s with
+/// scalar leaves; a mapping or null anywhere is kept as `Unexpected`.
+fn array_value(value: &serde_norway::Value) -> InstanceValue {
+    match value {
+        serde_norway::Value::Sequence(items) => {
+            InstanceValue::Array(items.iter().map(array_value).collect())
+        }
+        serde_norway::Value::Null => InstanceValue::Unexpected("a null"),
+        other => scalar_value(other)
+            .map(InstanceValue::Scalar)
+            .unwrap_or_else(|| InstanceValue::Unexpected(yaml_kind(other))),
+    }
+}
+
+/// Render an `array` slot's value as nested brackets, e.g. `[[1, 2], [3, 4]]`.
+pub(crate) fn array_to_display(value: &InstanceValue) -> String {
+    match value {
+        InstanceValue::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(array_to_display)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        InstanceValue::Scalar(scalar) => scalar_to_display(scalar),
+        InstanceValue::Reference { target, .. } => target.clone(),
+        InstanceValue::Unexpected(kind) => format!("<{kind}>"),
+    }
+}
+
 /// A format-neutral typed scalar from a YAML value; non-scalars yield `None`.
 fn scalar_value(value: &serde_norway::Value) -> Option<ScalarValue> {
     match value {
@@ -5134,4 +5259,53 @@ classes:
         );
         assert_eq!(set.duplicate_ids, Vec::<String>::new());
     }
+
+    /// Computed slots fill from a record's other values — including from
+    /// another computed slot — never overwrite a stored value, and stay
+    /// empty when an input is missing.
+    #[test]
+    fn infer_computed_slots_fills_chained_expressions() {
+        let schema: SchemaDefinition = serde_norway::from_str(
+            "name: s\nclasses:\n  Root:\n    tree_root: true\n    attributes:\n      boxes: {range: Box, multivalued: true}\n  Box:\n    attributes:\n      id: {identifier: true}\n      side: {range: integer}\n      area:\n        range: integer\n        equals_expression: '{side} * {side}'\n      capacity:\n        range: integer\n        equals_expression: '{volume} / 1000'\n      volume:\n        range: integer\n        equals_expression: '{area} * {side}'\n",
+        )
+        .expect("schema");
+        let data: serde_norway::Value = serde_norway::from_str(
+            "boxes:\n  - {id: a, side: 30}\n  - {id: b, side: 2, area: 5}\n  - {id: c}\n",
+        )
+        .expect("data");
+        let mut set = InstanceSet::from_linkml_data(&schema, &data);
+        // `capacity` reads `volume`, which sorts after it: a second pass
+        // fills it.
+        assert_eq!(set.infer_computed_slots(&schema), 5);
+
+        let value = |id: &str, slot: &str| {
+            let inst = set.instances.iter().find(|i| i.id == id).expect(id);
+            inst.slot_values
+                .iter()
+                .find(|sv| sv.slot == slot)
+                .map(|sv| sv.values.clone())
+        };
+        assert_eq!(
+            value("a", "area"),
+            Some(vec![InstanceValue::Scalar(ScalarValue::Integer(900))])
+        );
+        assert_eq!(
+            value("a", "capacity"),
+            Some(vec![InstanceValue::Scalar(ScalarValue::Integer(27))])
+        );
+        assert_eq!(
+            value("b", "volume"),
+            Some(vec![InstanceValue::Scalar(ScalarValue::Integer(10))]),
+            "the stored `area` is used, not recomputed"
+        );
+        assert_eq!(value("c", "area"), None, "no `side`, nothing to compute");
+        assert!(
+            set.instances
+                .iter()
+                .find(|i| i.id == "a")
+                .unwrap()
+                .literals
+                .contains(&("area".to_string(), "900".to_string()))
+        );
+    }
 }
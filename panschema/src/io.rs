@@ -14,10 +14,12 @@ use std::path::Path;
 
 use thiserror::Error;
 
+use crate::diagram_writer::{DiagramFormat, DiagramWriter};
 use crate::graph_writer::GraphWriter;
 use crate::html_writer::HtmlWriter;
 use crate::json_schema_writer::JsonSchemaWriter;
 use crate::linkml::SchemaDefinition;
+use crate::markdown_writer::MarkdownWriter;
 use crate::openapi_writer::OpenApiWriter;
 use crate::owl_reader::OwlReader;
 use crate::owl_writer::OwlWriter;
@@ -161,7 +163,9 @@ impl FormatRegistry {
     /// - Writers: `HtmlWriter` (html), `OwlWriter` (ttl), `JsonLdWriter` (jsonld),
     ///   `RdfXmlWriter` (rdfxml), `NTriplesWriter` (ntriples), `GraphWriter` (graph-json),
     ///   `RustWriter` (rust), `PostgresWriter` (postgres), `ShaclWriter` (shacl),
-    ///   `JsonSchemaWriter` (json-schema)
+    ///   `JsonSchemaWriter` (json-schema), `OpenApiWriter` (openapi),
+    ///   `MarkdownWriter` (markdown), `DiagramWriter` (mermaid, plantuml, dot),
+    ///   and with the `svg` feature `SvgWriter` (svg)
     pub fn with_defaults() -> Self {
         let mut registry = Self::new();
         registry.register_reader(Box::new(OwlReader::new()));
@@ -178,6 +182,12 @@ impl FormatRegistry {
         registry.register_writer(Box::new(ShaclWriter::new()));
         registry.register_writer(Box::new(JsonSchemaWriter::new()));
         registry.register_writer(Box::new(OpenApiWriter::new()));
+        registry.register_writer(Box::new(MarkdownWriter::new()));
+        registry.register_writer(Box::new(DiagramWriter::new(DiagramFormat::Mermaid)));
+        registry.register_writer(Box::new(DiagramWriter::new(DiagramFormat::PlantUml)));
+        registry.register_writer(Box::new(DiagramWriter::new(DiagramFormat::Dot)));
+        #[cfg(feature = "svg")]
+        registry.register_writer(Box::new(crate::svg_writer::SvgWriter::new()));
         registry
     }
 
@@ -428,7 +438,7 @@ mod tests {
         assert!(registry.writer_for_format("HTML").is_some()); // case insensitive
 
         // Should not find writer for unsupported formats
-        assert!(registry.writer_for_format("markdown").is_none());
+        assert!(registry.writer_for_format("pdf").is_none());
     }
 
     #[test]
@@ -528,6 +538,32 @@ mod tests {
         assert!(registry.writer_for_format("OpenAPI").is_some()); // case insensitive
     }
 
+    #[test]
+    fn with_defaults_registers_markdown_writer() {
+        let registry = FormatRegistry::with_defaults();
+
+        assert!(registry.writer_for_format("markdown").is_some());
+        assert!(registry.writer_for_format("Markdown").is_some()); // case insensitive
+    }
+
+    #[test]
+    fn with_defaults_registers_diagram_writers() {
+        let registry = FormatRegistry::with_defaults();
+
+        assert!(registry.writer_for_format("mermaid").is_some());
+        assert!(registry.writer_for_format("PlantUML").is_some()); // case insensitive
+        assert!(registry.writer_for_format("dot").is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "svg")]
+    fn with_defaults_registers_svg_writer() {
+        let registry = FormatRegistry::with_defaults();
+
+        assert!(registry.writer_for_format("svg").is_some());
+        assert!(registry.writer_for_format("SVG").is_some()); // case insensitive
+    }
+
     #[test]
     fn writer_format_ids_lists_every_registered_writer() {
         // The definitive list `generate --help`'s hand-written format
@@ -550,6 +586,10 @@ mod tests {
             "shacl",
             "json-schema",
             "openapi",
+            "markdown",
+            "mermaid",
+            "plantuml",
+            "dot",
         ] {
             assert!(
                 ids.contains(&expected),
@@ -15,7 +15,10 @@
 //! Inherited/mixed-in slots flatten onto each subclass. A range that resolves
 //! to none of these is emitted permissively (`true`) so
 //! `additionalProperties: false` never rejects an otherwise-valid instance.
+//! A class's `any_of` / `all_of` / `exactly_one_of` / `none_of` over slot
+//! conditions project to `anyOf` / `allOf` / `oneOf` / `not`.
 
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
@@ -23,7 +26,11 @@ use std::path::Path;
 use serde_json::{Value, json};
 
 use crate::io::{IoError, IoResult, Writer};
-use crate::linkml::{SchemaDefinition, SlotDefinition};
+use crate::linkml::{
+    ArrayExpression, ClassExpression, SchemaDefinition, SlotCondition, SlotDefinition,
+    ValuePresence,
+};
+use crate::linkml_resolve::ResolvedSlot;
 
 /// The JSON Schema dialect the emitted documents declare.
 const DIALECT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
@@ -79,6 +86,11 @@ impl Writer for JsonSchemaWriter {
 /// schema per class. Deterministic — `$defs` and each class's `properties`
 /// come out in `serde_json::Map` (sorted) order, and `required` follows the
 /// resolver's alphabetical slot order, so the output is byte-stable.
+///
+/// LinkML `recommended` slots are listed under `x-recommended`, and a class
+/// with `slot_group`s carries its grouped property order as
+/// `x-property-order` (each grouped property names its group in
+/// `x-slot-group`) — annotations validators ignore.
 pub fn build_json_schema(schema: &SchemaDefinition) -> Value {
     let defs = build_class_defs(schema);
 
@@ -108,18 +120,28 @@ pub(crate) fn build_class_defs(schema: &SchemaDefinition) -> serde_json::Map<Str
         // Effective slots: the same resolver the HTML/Rust/Postgres writers
         // use, so JSON Schema describes the same shape (inherited, mixed-in,
         // and refined slots included).
-        for (slot_name, resolved) in
-            crate::linkml_resolve::resolve_effective_slots_with_provenance(class_def, schema)
-        {
+        let effective =
+            crate::linkml_resolve::resolve_effective_slots_with_provenance(class_def, schema);
+        let mut recommended: Vec<Value> = Vec::new();
+        for (slot_name, resolved) in &effective {
             let cardinality = crate::linkml_resolve::effective_cardinality(&resolved.definition);
-            properties.insert(
-                slot_name.clone(),
-                slot_property(&resolved.definition, schema),
-            );
+            let mut property = slot_property(&resolved.definition, schema);
+            if let Some(group) = &resolved.definition.slot_group
+                && let Some(obj) = property.as_object_mut()
+            {
+                obj.insert("x-slot-group".to_string(), json!(group));
+            }
+            properties.insert(slot_name.clone(), property);
             if cardinality.required {
-                required.push(Value::String(slot_name));
+                required.push(Value::String(slot_name.clone()));
+            } else if resolved.definition.recommended {
+                recommended.push(Value::String(slot_name.clone()));
             }
         }
+        // `properties` is a sorted map, so grouped presentation order is
+        // carried as an explicit list — only when some slot is grouped, as
+        // the sorted order is otherwise the answer.
+        let order = crate::linkml_resolve::grouped_slot_order(&effective, schema);
 
         let mut obj = serde_json::Map::new();
         obj.insert("type".to_string(), json!("object"));
@@ -130,40 +152,263 @@ pub(crate) fn build_class_defs(schema: &SchemaDefinition) -> serde_json::Map<Str
         if !required.is_empty() {
             obj.insert("required".to_string(), Value::Array(required));
         }
+        if !recommended.is_empty() {
+            obj.insert("x-recommended".to_string(), Value::Array(recommended));
+        }
+        if order.iter().any(|(_, group)| group.is_some()) {
+            let names: Vec<&str> = order.iter().map(|(name, _)| *name).collect();
+            obj.insert("x-property-order".to_string(), json!(names));
+        }
         // Closed object: a stray property is a bug, not silently accepted —
         // what strict LLM structured output and instance validation want.
         obj.insert("additionalProperties".to_string(), json!(false));
 
+        // Class expressions whose members test only slots become JSON
+        // Schema's own combinators. One naming a class (`is_a`) has nothing
+        // in a JSON document to test, so it is left out and reported by
+        // `classes_with_unprojected_constructs`.
+        for (combinator, members) in class_def.combinators() {
+            if members.is_empty() || members.iter().any(ClassExpression::mentions_class) {
+                continue;
+            }
+            let (key, value) = combinator_schema(combinator, members, &effective);
+            obj.insert(key.to_string(), value);
+        }
+
         defs.insert(class_name.clone(), Value::Object(obj));
     }
 
     defs
 }
 
+/// A class-expression combinator as its JSON Schema keyword and value:
+/// `any_of` → `anyOf`, `all_of` → `allOf`, `exactly_one_of` → `oneOf`, and
+/// `none_of` → `not` over an `anyOf` of the members.
+fn combinator_schema(
+    combinator: &str,
+    members: &[ClassExpression],
+    effective: &BTreeMap<String, ResolvedSlot>,
+) -> (&'static str, Value) {
+    let members: Vec<Value> = members
+        .iter()
+        .map(|m| expression_schema(m, effective))
+        .collect();
+    match combinator {
+        "any_of" => ("anyOf", Value::Array(members)),
+        "all_of" => ("allOf", Value::Array(members)),
+        "exactly_one_of" => ("oneOf", Value::Array(members)),
+        _ => ("not", json!({ "anyOf": members })),
+    }
+}
+
+/// One class-expression member as an object schema: its slot conditions
+/// as `properties` (plus `required` for each slot the condition needs
+/// present), an absent slot as `not: {required: [...]}`, and each nested
+/// combinator — all conjoined under `allOf` when there is more than one.
+fn expression_schema(expr: &ClassExpression, effective: &BTreeMap<String, ResolvedSlot>) -> Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    let mut parts = Vec::new();
+    for (slot, cond) in &expr.slot_conditions {
+        let multivalued = effective.get(slot).is_some_and(|r| {
+            crate::linkml_resolve::effective_cardinality(&r.definition).multivalued
+        });
+        // An absent slot never equals a constant, so equality implies
+        // presence just as `required` does.
+        let present = cond.required
+            || cond.value_presence == Some(ValuePresence::Present)
+            || cond.minimum_cardinality.is_some_and(|n| n > 0)
+            || cond.equals_string.is_some()
+            || cond.equals_number.is_some();
+        if present {
+            required.push(json!(slot));
+        }
+        if cond.value_presence == Some(ValuePresence::Absent) {
+            parts.push(json!({ "not": { "required": [slot] } }));
+        }
+        properties.insert(slot.clone(), condition_schema(cond, multivalued));
+    }
+    if !properties.is_empty() {
+        let mut obj = serde_json::Map::new();
+        obj.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            obj.insert("required".to_string(), Value::Array(required));
+        }
+        parts.insert(0, Value::Object(obj));
+    }
+    for (combinator, members) in expr.combinators() {
+        if !members.is_empty() {
+            let (key, value) = combinator_schema(combinator, members, effective);
+            parts.push(json!({ key: value }));
+        }
+    }
+    match parts.len() {
+        0 => json!({}),
+        1 => parts.remove(0),
+        _ => json!({ "allOf": parts }),
+    }
+}
+
+/// A slot condition as the schema of the property it tests. Value tests
+/// (`pattern`, bounds) apply to every value — under `items` on a
+/// multivalued slot — while equality is membership, so a multivalued slot
+/// says it with `contains`. Cardinality bounds become `minItems`/`maxItems`.
+fn condition_schema(cond: &SlotCondition, multivalued: bool) -> Value {
+    let mut per_value = serde_json::Map::new();
+    if let Some(pattern) = &cond.pattern {
+        per_value.insert("pattern".to_string(), json!(pattern));
+    }
+    if let Some(min) = cond.minimum_value {
+        per_value.insert("minimum".to_string(), json!(min));
+    }
+    if let Some(max) = cond.maximum_value {
+        per_value.insert("maximum".to_string(), json!(max));
+    }
+    let equals = cond
+        .equals_string
+        .as_ref()
+        .map(|v| json!(v))
+        .or(cond.equals_number.map(|n| json!(n)));
+
+    let mut out = serde_json::Map::new();
+    if multivalued {
+        if !per_value.is_empty() {
+            out.insert("items".to_string(), Value::Object(per_value));
+        }
+        if let Some(v) = equals {
+            out.insert("contains".to_string(), json!({ "const": v }));
+        }
+        if let Some(min) = cond.minimum_cardinality {
+            out.insert("minItems".to_string(), json!(min));
+        }
+        if let Some(max) = cond.maximum_cardinality {
+            out.insert("maxItems".to_string(), json!(max));
+        }
+    } else {
+        out = per_value;
+        if let Some(v) = equals {
+            out.insert("const".to_string(), v);
+        }
+    }
+    if !cond.any_of.is_empty() {
+        let alts: Vec<Value> = cond
+            .any_of
+            .iter()
+            .map(|alt| condition_schema(alt, multivalued))
+            .collect();
+        out.insert("anyOf".to_string(), Value::Array(alts));
+    }
+    Value::Object(out)
+}
+
 /// The JSON Schema for a single slot: its value schema (see
-/// [`slot_value_schema`]), wrapped in an `array` when the slot is multivalued.
+/// [`slot_value_schema`]), wrapped in an `array` when the slot is multivalued,
+/// or in nested arrays of the declared shape when it is an `array` slot.
+/// A slot's `unit` rides along as the `x-unit` annotation (its UCUM code,
+/// else the unit's other identifying text), which validators ignore.
 fn slot_property(slot: &SlotDefinition, schema: &SchemaDefinition) -> Value {
     let base = slot_value_schema(slot, schema);
-    if crate::linkml_resolve::effective_cardinality(slot).multivalued {
+    let mut property = if let Some(array) = &slot.array {
+        array_schema(array, base)
+    } else if crate::linkml_resolve::effective_cardinality(slot).multivalued {
         json!({ "type": "array", "items": base })
     } else {
         base
+    };
+    if let Some(code) = slot.unit.as_ref().and_then(|u| u.code())
+        && let Some(obj) = property.as_object_mut()
+    {
+        obj.insert("x-unit".to_string(), json!(code));
+    }
+    property
+}
+
+/// Nested `array` schemas for an `array` slot, outermost axis first, each
+/// axis's cardinality as `minItems`/`maxItems` and the slot's value schema
+/// at the leaves. A dimension count that may vary becomes an `anyOf` over
+/// each allowed depth; with no upper bound the deepest listed level also
+/// admits further nesting, whose leaves go unchecked.
+fn array_schema(array: &ArrayExpression, leaf: Value) -> Value {
+    let nested = |depth: u32, leaf: Value| {
+        (0..depth as usize).rev().fold(leaf, |items, axis| {
+            let mut node = serde_json::Map::new();
+            node.insert("type".to_string(), json!("array"));
+            node.insert("items".to_string(), items);
+            let (min, max) = array.axis_bounds(axis);
+            if let Some(min) = min {
+                node.insert("minItems".to_string(), json!(min));
+            }
+            if let Some(max) = max {
+                node.insert("maxItems".to_string(), json!(max));
+            }
+            Value::Object(node)
+        })
+    };
+    match array.dimension_bounds() {
+        (min, Some(max)) if min == max => nested(min, leaf),
+        (min, Some(max)) => json!({
+            "anyOf": (min..=max).map(|d| nested(d, leaf.clone())).collect::<Vec<_>>()
+        }),
+        (min, None) => nested(min, json!({ "anyOf": [leaf, { "type": "array" }] })),
     }
 }
 
 /// The (unwrapped) JSON Schema for a slot's value: an enum's permissible
 /// values as a JSON `enum`, a class range as a `$ref` to its `$def`, or a
 /// scalar type with any value constraints (`pattern`, numeric bounds) applied.
+/// `all_of` and `none_of` layer onto whatever the range or union admits:
+/// every `all_of` branch must hold as well (`allOf`), and no `none_of`
+/// branch may (`not` over their `anyOf`).
 fn slot_value_schema(slot: &SlotDefinition, schema: &SchemaDefinition) -> Value {
-    // A polymorphic `any_of` range: the value must satisfy at least one branch,
-    // which is JSON Schema `anyOf` over each branch's value schema.
+    let mut value = if slot.all_of.is_empty() {
+        slot_alternatives_schema(slot, schema)
+    } else {
+        let mut members = vec![slot_alternatives_schema(slot, schema)];
+        members.extend(branch_schemas(&slot.all_of, slot, schema));
+        json!({ "allOf": members })
+    };
+    if !slot.none_of.is_empty() {
+        let excluded = json!({ "anyOf": branch_schemas(&slot.none_of, slot, schema) });
+        match value.as_object_mut() {
+            Some(obj) => {
+                obj.insert("not".to_string(), excluded);
+            }
+            None => value = json!({ "not": excluded }),
+        }
+    }
+    value
+}
+
+/// Each combinator branch's value schema. A branch without a `range`
+/// inherits the slot's own — LinkML's reading of a branch that only adds
+/// facets — so a bounds-only branch on an `integer` slot stays an integer.
+fn branch_schemas(
+    branches: &[SlotDefinition],
+    slot: &SlotDefinition,
+    schema: &SchemaDefinition,
+) -> Vec<Value> {
+    branches
+        .iter()
+        .map(|branch| match (&branch.range, &slot.range) {
+            (None, Some(outer)) => {
+                let mut inheriting = branch.clone();
+                inheriting.range = Some(outer.clone());
+                slot_value_schema(&inheriting, schema)
+            }
+            _ => slot_value_schema(branch, schema),
+        })
+        .collect()
+}
+
+/// What a slot's value may be before `all_of` / `none_of` apply: an `any_of`
+/// union (`anyOf` — at least one branch), an `exactly_one_of` (`oneOf` —
+/// one branch and no other), or the single range.
+fn slot_alternatives_schema(slot: &SlotDefinition, schema: &SchemaDefinition) -> Value {
     if !slot.any_of.is_empty() {
-        let branches: Vec<Value> = slot
-            .any_of
-            .iter()
-            .map(|branch| slot_value_schema(branch, schema))
-            .collect();
-        return json!({ "anyOf": branches });
+        return json!({ "anyOf": branch_schemas(&slot.any_of, slot, schema) });
+    }
+    if !slot.exactly_one_of.is_empty() {
+        return json!({ "oneOf": branch_schemas(&slot.exactly_one_of, slot, schema) });
     }
 
     // A slot that reaches a writer still rangeless (an OWL/Turtle property
@@ -290,7 +535,7 @@ fn scalar_json_type(range: &str) -> Value {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linkml::{ClassDefinition, SlotDefinition};
+    use crate::linkml::{ClassDefinition, DimensionExpression, SlotDefinition};
 
     /// A slot-level `is_a` has no JSON Schema form, so the writer reports
     /// the drop as a projection gap instead of making it silently.
@@ -363,6 +608,92 @@ mod tests {
         assert_eq!(scalar_json_type("Wine"), Value::Bool(true));
     }
 
+    #[test]
+    fn slot_groups_order_properties_and_recommended_slots_are_listed() {
+        let mut schema = wine_schema();
+        let mut tasting = SlotDefinition::new("tasting");
+        tasting.is_grouping_slot = true;
+        schema.slots.insert("tasting".to_string(), tasting);
+        let wine = schema.classes.get_mut("Wine").unwrap();
+        let vintage = wine.attributes.get_mut("vintage").unwrap();
+        vintage.recommended = true;
+        vintage.slot_group = Some("tasting".to_string());
+        // `required` wins: a required slot is not also recommended.
+        wine.attributes.get_mut("name").unwrap().recommended = true;
+
+        let doc = build_json_schema(&schema);
+        let wine = &doc["$defs"]["Wine"];
+        assert_eq!(wine["x-recommended"], json!(["vintage"]));
+        assert_eq!(wine["x-property-order"], json!(["name", "tags", "vintage"]));
+        assert_eq!(wine["properties"]["vintage"]["x-slot-group"], "tasting");
+        assert!(
+            build_json_schema(&wine_schema())["$defs"]["Wine"]
+                .get("x-property-order")
+                .is_none(),
+            "an ungrouped class keeps the plain sorted order"
+        );
+    }
+
+    #[test]
+    fn slot_unit_is_carried_as_x_unit() {
+        let mut schema = wine_schema();
+        let wine = schema.classes.get_mut("Wine").unwrap();
+        let mut volume = SlotDefinition::new("volume");
+        volume.range = Some("decimal".to_string());
+        volume.unit = Some(crate::linkml::UnitOfMeasure {
+            ucum_code: Some("mL".to_string()),
+            ..Default::default()
+        });
+        wine.attributes.insert("volume".to_string(), volume);
+
+        let doc = build_json_schema(&schema);
+        let properties = &doc["$defs"]["Wine"]["properties"];
+        assert_eq!(
+            properties["volume"],
+            json!({ "type": "number", "x-unit": "mL" })
+        );
+        assert!(properties["vintage"].get("x-unit").is_none());
+    }
+
+    #[test]
+    fn array_slot_becomes_nested_arrays_with_axis_bounds() {
+        let mut schema = wine_schema();
+        let wine = schema.classes.get_mut("Wine").unwrap();
+        let mut spectrum = SlotDefinition::new("spectrum");
+        spectrum.range = Some("float".to_string());
+        spectrum.array = Some(ArrayExpression {
+            dimensions: vec![
+                DimensionExpression {
+                    alias: Some("sample".to_string()),
+                    minimum_cardinality: Some(1),
+                    ..Default::default()
+                },
+                DimensionExpression {
+                    alias: Some("band".to_string()),
+                    exact_cardinality: Some(3),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        wine.attributes.insert("spectrum".to_string(), spectrum);
+
+        let doc = build_json_schema(&schema);
+        assert_eq!(
+            doc["$defs"]["Wine"]["properties"]["spectrum"],
+            json!({
+                "type": "array",
+                "minItems": 1,
+                "items": {
+                    "type": "array",
+                    "minItems": 3,
+                    "maxItems": 3,
+                    "items": { "type": "number" }
+                }
+            })
+        );
+    }
+
     #[test]
     fn document_roots_at_the_tree_root_class() {
         // Without a tree_root, the document is $defs-only (no root ref).
@@ -395,6 +726,55 @@ mod tests {
         assert_eq!(wine["required"], serde_json::json!(["name"]));
     }
 
+    #[test]
+    fn slot_conditioned_class_expressions_project_to_json_combinators() {
+        // Exactly one of `vintage` / `tags: [nv]` identifies a Wine; an
+        // `any_of` naming a class has no JSON form and is left out.
+        let mut schema = wine_schema();
+        let wine = schema.classes.get_mut("Wine").unwrap();
+        wine.tree_root = true;
+        let member = |slot: &str, cond: SlotCondition| ClassExpression {
+            slot_conditions: [(slot.to_string(), cond)].into(),
+            ..Default::default()
+        };
+        wine.exactly_one_of = vec![
+            member(
+                "vintage",
+                SlotCondition {
+                    required: true,
+                    ..Default::default()
+                },
+            ),
+            member(
+                "tags",
+                SlotCondition {
+                    equals_string: Some("nv".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ];
+        wine.any_of = vec![ClassExpression {
+            is_a: Some("Wine".to_string()),
+            ..Default::default()
+        }];
+        let doc = build_json_schema(&schema);
+        let def = &doc["$defs"]["Wine"];
+        assert!(
+            def.get("anyOf").is_none(),
+            "an is_a member has no JSON form"
+        );
+        assert_eq!(
+            def["oneOf"][1],
+            json!({ "properties": { "tags": { "contains": { "const": "nv" } } }, "required": ["tags"] })
+        );
+
+        let validator = jsonschema::validator_for(&doc).expect("compiles");
+        assert!(validator.is_valid(&json!({ "name": "a", "vintage": 2019 })));
+        assert!(validator.is_valid(&json!({ "name": "a", "tags": ["nv"] })));
+        assert!(!validator.is_valid(&json!({ "name": "a", "vintage": 2019, "tags": ["nv"] })));
+        assert!(!validator.is_valid(&json!({ "name": "a" })));
+    }
+
     // Oracle 1: the emitted document is a usable JSON Schema — it compiles in
     // an independent validator (`jsonschema`), which rejects a structurally
     // invalid schema. Proves we emit a real schema, not just a JSON shape
@@ -739,4 +1119,61 @@ classes:
             "a value matching neither branch should fail"
         );
     }
+
+    #[test]
+    fn slot_combinators_project_to_one_of_all_of_and_not() {
+        let schema: SchemaDefinition = serde_norway::from_str(
+            "\
+name: cellar
+classes:
+  Wine:
+    tree_root: true
+    attributes:
+      code:
+        range: string
+        all_of:
+          - pattern: '^[A-Z]'
+        exactly_one_of:
+          - pattern: '^A'
+          - pattern: '1$'
+      score:
+        range: integer
+        none_of:
+          - minimum_value: 90
+            maximum_value: 100
+",
+        )
+        .expect("parse schema");
+        let doc = build_json_schema(&schema);
+        let props = &doc["$defs"]["Wine"]["properties"];
+        assert_eq!(
+            props["code"],
+            json!({ "allOf": [
+                { "oneOf": [
+                    { "type": "string", "pattern": "^A" },
+                    { "type": "string", "pattern": "1$" },
+                ] },
+                { "type": "string", "pattern": "^[A-Z]" },
+            ] })
+        );
+        assert_eq!(
+            props["score"],
+            json!({
+                "type": "integer",
+                "not": { "anyOf": [ { "type": "integer", "minimum": 90.0, "maximum": 100.0 } ] },
+            })
+        );
+
+        let v = jsonschema::validator_for(&doc).expect("document compiles");
+        assert!(v.is_valid(&json!({ "code": "AB", "score": 42 })));
+        assert!(!v.is_valid(&json!({ "code": "A1" })), "two branches match");
+        assert!(
+            !v.is_valid(&json!({ "code": "b1" })),
+            "the all_of branch fails"
+        );
+        assert!(
+            !v.is_valid(&json!({ "score": 95 })),
+            "a none_of branch matches"
+        );
+    }
 }
@@ -149,7 +149,7 @@ impl LabelStore {
     }
 }
 
-fn source_key(source_url: &str) -> String {
+pub(crate) fn source_key(source_url: &str) -> String {
     hex::encode(Sha256::digest(source_url.as_bytes()))
 }
 
@@ -249,17 +249,7 @@ pub const BUILTIN_LABEL_SOURCES: &[(&str, &str)] = &[
 /// puts the more definitional predicates first; `dc:description`
 /// trails because it's the one most often used for examples.
 pub fn extract_terms(rdf: &str) -> Result<BTreeMap<String, TermInfo>, LabelExtractError> {
-    use sophia::api::prelude::TripleSource;
-    use sophia::inmem::graph::FastGraph;
-
-    let graph: FastGraph = match sophia::turtle::parser::turtle::parse_str(rdf).collect_triples() {
-        Ok(graph) => graph,
-        Err(ttl_err) => sophia::xml::parser::parse_str(rdf)
-            .collect_triples()
-            .map_err(|xml_err| {
-                LabelExtractError(format!("not Turtle ({ttl_err}) nor RDF/XML ({xml_err})"))
-            })?,
-    };
+    let graph = parse_rdf(rdf)?;
 
     const LABEL_PREDICATES: &[&str] = &[
         "http://www.w3.org/2000/01/rdf-schema#label",
@@ -279,6 +269,22 @@ pub fn extract_terms(rdf: &str) -> Result<BTreeMap<String, TermInfo>, LabelExtra
     Ok(out)
 }
 
+/// Parse an RDF document into an in-memory graph — Turtle first, falling
+/// back to RDF/XML. Shared with dynamic-enum expansion, which reads the
+/// same upstream documents for their class hierarchy.
+pub(crate) fn parse_rdf(rdf: &str) -> Result<sophia::inmem::graph::FastGraph, LabelExtractError> {
+    use sophia::api::prelude::TripleSource;
+
+    match sophia::turtle::parser::turtle::parse_str(rdf).collect_triples() {
+        Ok(graph) => Ok(graph),
+        Err(ttl_err) => sophia::xml::parser::parse_str(rdf)
+            .collect_triples()
+            .map_err(|xml_err| {
+                LabelExtractError(format!("not Turtle ({ttl_err}) nor RDF/XML ({xml_err})"))
+            }),
+    }
+}
+
 /// For each subject, store the first `@en`-or-untagged literal found
 /// across `predicates` (listed in priority order) into the `TermInfo`
 /// field selected by `field`.
@@ -6,7 +6,11 @@
 pub mod cache;
 pub mod casing;
 pub mod diagnostics;
+pub mod diagram_writer;
+pub mod dynamic_enums;
+pub mod expression;
 pub mod graph_writer;
+pub mod html_theme;
 pub mod html_writer;
 pub mod import_resolve;
 pub mod instances;
@@ -15,11 +19,17 @@ pub mod json_schema_writer;
 pub mod labels;
 pub mod linkml;
 pub mod linkml_resolve;
+pub mod lint;
 pub mod lockfile;
 pub mod manifest;
+pub mod markdown_writer;
 /// Backs the `mdbook-panschema` binary; not part of the conversion API.
 #[doc(hidden)]
 pub mod mdbook;
+/// Backs the `mdbook-panschema` preprocessor; not part of the conversion API.
+#[doc(hidden)]
+pub mod mdbook_preprocessor;
+pub mod metaschema;
 pub mod openapi_writer;
 pub mod owl_model;
 pub mod owl_reader;
@@ -30,8 +40,15 @@ pub mod publish;
 pub mod rdf_serializers;
 pub mod rules;
 pub mod rust_writer;
+pub mod schema_diff;
+pub mod self_contained;
 pub mod shacl_writer;
 pub mod source;
+pub mod source_map;
+pub mod subset;
+#[cfg(feature = "svg")]
+pub mod svg_writer;
+pub mod ucum;
 pub mod validate;
 pub mod yaml_reader;
 
@@ -71,6 +71,140 @@ pub struct Example {
     pub description: Option<String>,
 }
 
+/// The editorial and provenance long tail of LinkML's `common_metadata`
+/// mixin, shared by the schema and every element kind.
+///
+/// Flattened into each definition, so the keys sit beside `description`
+/// in the source exactly as LinkML writes them. HTML renders them as rows
+/// on the element's card (with `rank` ordering the cards); RDF emits and
+/// the OWL reader reads them back through the predicates named on each
+/// field.
+/// Reference: <https://linkml.io/linkml-model/latest/docs/common_metadata/>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommonMetadata {
+    /// Free-text remarks beyond the description (`skos:note`; not
+    /// `rdfs:comment`, which already carries the description).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+    /// Editorial notes for curators (`skos:editorialNote`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    /// Outstanding work on the element (`linkml:todos`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub todos: Vec<String>,
+    /// Lifecycle status, usually a CURIE such as `bibo:draft`
+    /// (`bibo:status`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Position among its siblings; lower ranks come first and unranked
+    /// elements follow (`sh:order`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<i64>,
+    /// Search keywords (`schema:keywords`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    /// The resource the element was derived from (`dcterms:source`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Agent who created the element, typically an ORCID CURIE
+    /// (`pav:createdBy`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// Agent who last modified the element (`oslc:modifiedBy`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_by: Option<String>,
+    /// Creation timestamp, ISO 8601 (`pav:createdOn`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<String>,
+    /// Last-modification timestamp, ISO 8601 (`pav:lastUpdateOn`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated_on: Option<String>,
+    /// Language of the element's own title and description, a BCP 47 tag
+    /// such as `en` (`dcterms:language`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_language: Option<String>,
+    /// Names other sources give the element, keyed by the source — a
+    /// naming authority such as `ncit` or `wikidata`, or a language tag
+    /// when the schema uses one as the source (which is what `--lang`
+    /// picks up). Emitted to RDF as one `linkml:local_names` node per
+    /// source. Reads LinkML's compact `ncit: Person` and expanded
+    /// `{local_name_source, local_name_value}` forms alike.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "keyed_text"
+    )]
+    pub local_names: BTreeMap<String, String>,
+    /// Descriptions of the element from other sources, keyed by the
+    /// source the way `local_names` is. Emitted to RDF as one
+    /// `linkml:alt_descriptions` node per source. Reads the compact and
+    /// the expanded `{source, description}` forms alike.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "keyed_text"
+    )]
+    pub alt_descriptions: BTreeMap<String, String>,
+    /// Aliases that carry a language or a synonym predicate. Emitted to
+    /// RDF as `linkml:structured_aliases` nodes that keep both, plus a
+    /// `skos:altLabel` (language-tagged when `in_language` is set).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub structured_aliases: Vec<StructuredAlias>,
+}
+
+/// One entry of LinkML's `structured_aliases`.
+/// Reference: <https://linkml.io/linkml-model/latest/docs/StructuredAlias/>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructuredAlias {
+    /// The alias text.
+    pub literal_form: String,
+    /// The synonym relation, e.g. `EXACT_SYNONYM`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    /// The alias's language tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_language: Option<String>,
+}
+
+/// Deserialize a `local_names` / `alt_descriptions` map to key → text,
+/// accepting each entry compact (a bare string) or expanded (a map whose
+/// `local_name_value` / `description` holds the text).
+fn keyed_text<'de, D>(deserializer: D) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Compact(String),
+        Expanded {
+            #[serde(alias = "local_name_value", alias = "description")]
+            value: String,
+        },
+    }
+    let entries = BTreeMap::<String, Entry>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|(key, entry)| match entry {
+            Entry::Compact(text) | Entry::Expanded { value: text } => (key, text),
+        })
+        .collect())
+}
+
+impl CommonMetadata {
+    /// Whether no field is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Orders elements by `rank` (lower first, unranked last), then by name —
+/// the card order for every HTML section.
+pub fn rank_order(a: (&CommonMetadata, &str), b: (&CommonMetadata, &str)) -> std::cmp::Ordering {
+    let key = |m: &CommonMetadata| (m.rank.is_none(), m.rank);
+    key(a.0).cmp(&key(b.0)).then_with(|| a.1.cmp(b.1))
+}
+
 /// The range LinkML's derivation rules give a schema that omits
 /// `default_range`. Applied at read time by the LinkML YAML reader, so an
 /// omitted default means the same thing here as through linkml-runtime.
@@ -80,7 +214,7 @@ pub const LINKML_DEFAULT_RANGE: &str = "string";
 ///
 /// Corresponds to LinkML SchemaDefinition.
 /// Reference: <https://linkml.io/linkml-model/latest/docs/SchemaDefinition/>
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaDefinition {
     /// A unique, machine-readable identifier for the schema
     pub name: String,
@@ -113,6 +247,9 @@ pub struct SchemaDefinition {
     /// predicate, so it is not emitted to RDF.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
     /// Schema version
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
@@ -152,9 +289,23 @@ pub struct SchemaDefinition {
     /// Type definitions in this schema
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub types: BTreeMap<String, TypeDefinition>,
+    /// Named subsets (LinkML `subsets`) elements opt into through
+    /// `in_subset`, e.g. a partner-facing profile of the schema.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub subsets: BTreeMap<String, SubsetDefinition>,
+    /// Named text fragments (LinkML `settings`) a `structured_pattern` with
+    /// `interpolated: true` splices in as `{name}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub settings: BTreeMap<String, String>,
     /// Format-specific annotations (e.g., OWL-specific metadata)
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub annotations: BTreeMap<String, String>,
+    /// Where each element and key was written, for diagnostics to point
+    /// at. Filled by the YAML reader, unioned across files by the import
+    /// merge; empty for sources without positions. Never serialized, and
+    /// left out of [`SchemaDefinition`]'s equality.
+    #[serde(skip)]
+    pub source_map: crate::source_map::SourceMap,
 }
 
 impl SchemaDefinition {
@@ -171,6 +322,34 @@ impl SchemaDefinition {
         })
     }
 
+    /// Every language tag the schema translates an element into, through
+    /// `local_names`, `alt_descriptions`, or a `structured_aliases` entry.
+    pub fn translation_languages(&self) -> std::collections::BTreeSet<String> {
+        let metadata = std::iter::once(&self.metadata)
+            .chain(self.classes.values().flat_map(|c| {
+                std::iter::once(&c.metadata).chain(c.attributes.values().map(|a| &a.metadata))
+            }))
+            .chain(self.slots.values().map(|s| &s.metadata))
+            .chain(self.enums.values().flat_map(|e| {
+                std::iter::once(&e.metadata)
+                    .chain(e.permissible_values.values().map(|v| &v.metadata))
+            }))
+            .chain(self.types.values().map(|t| &t.metadata));
+        metadata
+            .flat_map(|m| {
+                m.local_names
+                    .keys()
+                    .chain(m.alt_descriptions.keys())
+                    .chain(
+                        m.structured_aliases
+                            .iter()
+                            .filter_map(|a| a.in_language.as_ref()),
+                    )
+                    .cloned()
+            })
+            .collect()
+    }
+
     /// Create a new schema with the given name
     pub fn new(name: impl Into<String>) -> Self {
         Self {
@@ -182,6 +361,7 @@ impl SchemaDefinition {
             aliases: Vec::new(),
             see_also: Vec::new(),
             examples: Vec::new(),
+            metadata: CommonMetadata::default(),
             version: None,
             license: None,
             contributors: Vec::new(),
@@ -195,7 +375,10 @@ impl SchemaDefinition {
             slots: BTreeMap::new(),
             enums: BTreeMap::new(),
             types: BTreeMap::new(),
+            subsets: BTreeMap::new(),
+            settings: BTreeMap::new(),
             annotations: BTreeMap::new(),
+            source_map: Default::default(),
         }
     }
 
@@ -205,6 +388,95 @@ impl SchemaDefinition {
     }
 }
 
+/// Two schemas are equal when they define the same things: `source_map`
+/// records where the definitions were written, not what they say, so a
+/// schema read back from another format or another file layout still
+/// compares equal. The destructure is exhaustive so a new field can't be
+/// left out of the comparison by accident.
+impl PartialEq for SchemaDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            name,
+            id,
+            title,
+            description,
+            deprecated,
+            aliases,
+            see_also,
+            examples,
+            metadata,
+            version,
+            license,
+            contributors,
+            created,
+            modified,
+            imports,
+            prefixes,
+            default_prefix,
+            default_range,
+            classes,
+            slots,
+            enums,
+            types,
+            subsets,
+            settings,
+            annotations,
+            source_map: _,
+        } = self;
+        *name == other.name
+            && *id == other.id
+            && *title == other.title
+            && *description == other.description
+            && *deprecated == other.deprecated
+            && *aliases == other.aliases
+            && *see_also == other.see_also
+            && *examples == other.examples
+            && *metadata == other.metadata
+            && *version == other.version
+            && *license == other.license
+            && *contributors == other.contributors
+            && *created == other.created
+            && *modified == other.modified
+            && *imports == other.imports
+            && *prefixes == other.prefixes
+            && *default_prefix == other.default_prefix
+            && *default_range == other.default_range
+            && *classes == other.classes
+            && *slots == other.slots
+            && *enums == other.enums
+            && *types == other.types
+            && *subsets == other.subsets
+            && *settings == other.settings
+            && *annotations == other.annotations
+    }
+}
+
+/// A named subset of the schema's elements (LinkML `subsets`).
+///
+/// Membership is declared on the elements themselves, through `in_subset`;
+/// the definition only names and documents the subset.
+/// Reference: <https://linkml.io/linkml-model/latest/docs/SubsetDefinition/>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubsetDefinition {
+    /// The unique name of this subset within the schema.
+    /// Inferred from the dict key by `YamlReader::backfill_names` if absent.
+    #[serde(default)]
+    pub name: String,
+    /// Human-readable description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl SubsetDefinition {
+    /// Create a new subset with the given name
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+        }
+    }
+}
+
 /// A conditional constraint on a class: LinkML's `rules` metaslot.
 ///
 /// Corresponds to LinkML ClassRule.
@@ -300,6 +572,65 @@ pub struct SlotCondition {
     pub any_of: Vec<SlotCondition>,
 }
 
+/// An anonymous class expression: LinkML's `AnonymousClassExpression`, the
+/// member type of a class's `any_of` / `all_of` / `exactly_one_of` /
+/// `none_of`.
+///
+/// A record satisfies the expression when it is an instance of `is_a` (when
+/// given), every `slot_conditions` entry holds on it, and each nested
+/// combinator holds — the same reading [`RuleConditions`] gives a rule side.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClassExpression {
+    /// The class the record must be (itself or through inheritance).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_a: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub slot_conditions: BTreeMap<String, SlotCondition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exactly_one_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub none_of: Vec<ClassExpression>,
+}
+
+impl ClassExpression {
+    /// The class this expression names when it is *only* `is_a: X` — the
+    /// one shape OWL can state without a restriction. `None` for anything
+    /// carrying slot conditions or nested combinators.
+    pub fn named_class(&self) -> Option<&str> {
+        let bare = self.slot_conditions.is_empty()
+            && self.any_of.is_empty()
+            && self.all_of.is_empty()
+            && self.exactly_one_of.is_empty()
+            && self.none_of.is_empty();
+        self.is_a.as_deref().filter(|_| bare)
+    }
+
+    /// Whether `is_a` appears anywhere in the expression, nested members
+    /// included — the part a format without class identity (JSON Schema)
+    /// cannot check.
+    pub fn mentions_class(&self) -> bool {
+        self.is_a.is_some()
+            || self
+                .combinators()
+                .iter()
+                .any(|(_, members)| members.iter().any(ClassExpression::mentions_class))
+    }
+
+    /// The four boolean combinators by metaslot name, in metamodel order.
+    pub fn combinators(&self) -> [(&'static str, &[ClassExpression]); 4] {
+        [
+            ("any_of", &self.any_of),
+            ("all_of", &self.all_of),
+            ("exactly_one_of", &self.exactly_one_of),
+            ("none_of", &self.none_of),
+        ]
+    }
+}
+
 /// A uniqueness constraint on a class: LinkML's `unique_keys` metaslot.
 ///
 /// The tuple of `unique_key_slots` must be unique across instances of the
@@ -341,6 +672,13 @@ pub struct ClassDefinition {
     /// Worked examples; see [`SchemaDefinition::examples`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>,
+    /// Names of the schema [`subsets`](SchemaDefinition::subsets) this
+    /// element belongs to (LinkML `in_subset`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub in_subset: Vec<String>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
     /// Primary parent class (single inheritance)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_a: Option<String>,
@@ -400,6 +738,24 @@ pub struct ClassDefinition {
     /// Rendered as a "Unique keys" row on the class card.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub unique_keys: BTreeMap<String, UniqueKey>,
+    /// Boolean class expressions (LinkML `any_of` / `all_of` /
+    /// `exactly_one_of` / `none_of`): every record of the class must satisfy
+    /// at least one, all, exactly one, or none of the members respectively.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exactly_one_of: Vec<ClassExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub none_of: Vec<ClassExpression>,
+    /// Classes no record of this class may also be (`owl:disjointWith`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disjoint_with: Vec<String>,
+    /// Classes this class is the union of: every record of it must also be
+    /// a record of one of these (`owl:unionOf`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub union_of: Vec<String>,
     /// LinkML keys present on this class in the source but not modeled
     /// by panschema. Captured (rather than silently dropped by serde)
     /// so [`crate::diagnostics`] can warn when a producer writes a
@@ -419,6 +775,8 @@ impl ClassDefinition {
             aliases: Vec::new(),
             see_also: Vec::new(),
             examples: Vec::new(),
+            in_subset: Vec::new(),
+            metadata: CommonMetadata::default(),
             is_a: None,
             mixins: Vec::new(),
             unmodeled: BTreeMap::new(),
@@ -437,6 +795,12 @@ impl ClassDefinition {
             annotations: BTreeMap::new(),
             rules: Vec::new(),
             unique_keys: BTreeMap::new(),
+            any_of: Vec::new(),
+            all_of: Vec::new(),
+            exactly_one_of: Vec::new(),
+            none_of: Vec::new(),
+            disjoint_with: Vec::new(),
+            union_of: Vec::new(),
         }
     }
 
@@ -444,6 +808,17 @@ impl ClassDefinition {
     pub fn display_label(&self) -> &str {
         &self.name
     }
+
+    /// The class's boolean combinators by metaslot name — see
+    /// [`ClassExpression::combinators`].
+    pub fn combinators(&self) -> [(&'static str, &[ClassExpression]); 4] {
+        [
+            ("any_of", &self.any_of),
+            ("all_of", &self.all_of),
+            ("exactly_one_of", &self.exactly_one_of),
+            ("none_of", &self.none_of),
+        ]
+    }
 }
 
 /// A slot (property) definition in a LinkML schema
@@ -471,6 +846,13 @@ pub struct SlotDefinition {
     /// Worked examples; see [`SchemaDefinition::examples`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>,
+    /// Names of the schema [`subsets`](SchemaDefinition::subsets) this
+    /// element belongs to (LinkML `in_subset`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub in_subset: Vec<String>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
     /// The type of values this slot holds (class name, type name, or enum name)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub range: Option<String>,
@@ -486,6 +868,11 @@ pub struct SlotDefinition {
     /// Whether this slot must be present
     #[serde(default, skip_serializing_if = "is_false")]
     pub required: bool,
+    /// Whether this slot should be present though it may be absent
+    /// (LinkML `recommended`): a soft requirement that `validate` reports
+    /// as a warning, never an error.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub recommended: bool,
     /// Whether this slot can hold multiple values
     #[serde(default, skip_serializing_if = "is_false")]
     pub multivalued: bool,
@@ -503,6 +890,17 @@ pub struct SlotDefinition {
     /// Regular expression pattern for string values
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+    /// A pattern assembled from schema `settings` (LinkML
+    /// `structured_pattern`). Materialized into [`Self::pattern`] at load,
+    /// so every consumer sees only the concrete regex.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_pattern: Option<PatternExpression>,
+    /// An expression in the LinkML expression language computing this
+    /// slot's value from the record's other slots (LinkML
+    /// `equals_expression`), e.g. `{length} * {width}`. See
+    /// [`crate::expression`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equals_expression: Option<String>,
     /// Whether this slot uniquely identifies instances
     #[serde(default, skip_serializing_if = "is_false")]
     pub identifier: bool,
@@ -561,6 +959,19 @@ pub struct SlotDefinition {
     pub minimum_value: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub maximum_value: Option<f64>,
+    /// Unit of measure for a quantity-valued slot (LinkML `unit`). Shown on
+    /// the slot card, emitted as `qudt:unit` / `qudt:ucumCode` in RDF,
+    /// `x-unit` in JSON Schema, a doc-comment line in Rust and a column
+    /// comment in Postgres; the UCUM code is syntax-checked at load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<UnitOfMeasure>,
+    /// The slot's value is an N-dimensional array of its `range` (LinkML
+    /// `array`): nested lists whose depth and per-axis lengths the
+    /// expression bounds. Shown on the slot card, projected to nested JSON
+    /// Schema arrays, nested Rust `Vec`s / fixed arrays and a Postgres
+    /// multidimensional array column, and shape-checked by `validate`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub array: Option<ArrayExpression>,
     /// Polymorphic range alternatives. A value of this slot matches any
     /// one of the branches; each branch is itself a partial slot
     /// definition that can override `range`, `required`, `multivalued`,
@@ -568,6 +979,19 @@ pub struct SlotDefinition {
     /// is fine.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub any_of: Vec<SlotDefinition>,
+    /// LinkML `all_of`: every branch applies to each value — a conjunction
+    /// of partial slot definitions (a narrower `range`, an extra `pattern`
+    /// or bound).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<SlotDefinition>,
+    /// LinkML `exactly_one_of`: each value matches one branch and no other
+    /// — [`any_of`](Self::any_of) with the overlap ruled out.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exactly_one_of: Vec<SlotDefinition>,
+    /// LinkML `none_of`: no value may match any branch, e.g. a `range` the
+    /// slot's wider range would otherwise admit.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub none_of: Vec<SlotDefinition>,
     /// Cross-ontology mappings; see [`ClassDefinition::exact_mappings`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub exact_mappings: Vec<String>,
@@ -579,6 +1003,16 @@ pub struct SlotDefinition {
     pub narrow_mappings: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub broad_mappings: Vec<String>,
+    /// The grouping slot this slot is presented under (LinkML
+    /// `slot_group`), naming a slot declared `is_grouping_slot`. Classes
+    /// list a group's members together: a section on the HTML card, a
+    /// contiguous run in the JSON Schema property order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot_group: Option<String>,
+    /// Whether this slot only names a group for others to join rather
+    /// than holding a value (LinkML `is_grouping_slot`).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_grouping_slot: bool,
     /// Format-specific annotations
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub annotations: BTreeMap<String, String>,
@@ -594,15 +1028,20 @@ impl SlotDefinition {
             aliases: Vec::new(),
             see_also: Vec::new(),
             examples: Vec::new(),
+            in_subset: Vec::new(),
+            metadata: CommonMetadata::default(),
             range: None,
             domain: None,
             ifabsent: None,
             required: false,
+            recommended: false,
             multivalued: false,
             designates_type: false,
             minimum_cardinality: None,
             maximum_cardinality: None,
             pattern: None,
+            structured_pattern: None,
+            equals_expression: None,
             identifier: false,
             key: false,
             inlined: None,
@@ -617,12 +1056,19 @@ impl SlotDefinition {
             transitive: false,
             minimum_value: None,
             maximum_value: None,
+            unit: None,
+            array: None,
             any_of: Vec::new(),
+            all_of: Vec::new(),
+            exactly_one_of: Vec::new(),
+            none_of: Vec::new(),
             exact_mappings: Vec::new(),
             close_mappings: Vec::new(),
             related_mappings: Vec::new(),
             narrow_mappings: Vec::new(),
             broad_mappings: Vec::new(),
+            slot_group: None,
+            is_grouping_slot: false,
             annotations: BTreeMap::new(),
         }
     }
@@ -631,6 +1077,16 @@ impl SlotDefinition {
     pub fn display_label(&self) -> &str {
         &self.name
     }
+
+    /// The slot's range combinators by metaslot name, in metamodel order.
+    pub fn combinators(&self) -> [(&'static str, &[SlotDefinition]); 4] {
+        [
+            ("any_of", &self.any_of),
+            ("all_of", &self.all_of),
+            ("exactly_one_of", &self.exactly_one_of),
+            ("none_of", &self.none_of),
+        ]
+    }
 }
 
 /// An enumeration definition in a LinkML schema
@@ -658,15 +1114,46 @@ pub struct EnumDefinition {
     /// Worked examples; see [`SchemaDefinition::examples`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>,
+    /// Names of the schema [`subsets`](SchemaDefinition::subsets) this
+    /// element belongs to (LinkML `in_subset`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub in_subset: Vec<String>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
     /// The allowed values for this enum
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub permissible_values: BTreeMap<String, PermissibleValue>,
+    /// Dynamic value set: the ontology terms reachable from a set of
+    /// source nodes (LinkML `reachable_from`). Materialized into
+    /// `permissible_values` at load by [`crate::dynamic_enums`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachable_from: Option<ReachabilityQuery>,
+    /// Concept CURIEs that are each a value of the enum (LinkML
+    /// `concepts`); materialized like `reachable_from`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub concepts: Vec<String>,
+    /// Value sets unioned into this one (LinkML `include`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<EnumExpression>,
+    /// Value sets removed from this one (LinkML `minus`), applied last.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub minus: Vec<EnumExpression>,
     /// Format-specific annotations
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub annotations: BTreeMap<String, String>,
 }
 
 impl EnumDefinition {
+    /// Whether any value of the enum comes from an expression expanded at
+    /// load rather than from `permissible_values` alone.
+    pub fn is_dynamic(&self) -> bool {
+        self.reachable_from.is_some()
+            || !self.concepts.is_empty()
+            || !self.include.is_empty()
+            || !self.minus.is_empty()
+    }
+
     /// Create a new enum with the given name
     pub fn new(name: impl Into<String>) -> Self {
         Self {
@@ -676,12 +1163,261 @@ impl EnumDefinition {
             aliases: Vec::new(),
             see_also: Vec::new(),
             examples: Vec::new(),
+            in_subset: Vec::new(),
+            metadata: CommonMetadata::default(),
             permissible_values: BTreeMap::new(),
+            reachable_from: None,
+            concepts: Vec::new(),
+            include: Vec::new(),
+            minus: Vec::new(),
             annotations: BTreeMap::new(),
         }
     }
 }
 
+/// A regex built from parts: LinkML's `PatternExpression`. With
+/// `interpolated: true`, each `{name}` in `syntax` naming a schema
+/// [`setting`](SchemaDefinition::settings) is replaced by its value; a
+/// brace group that is no identifier (`\d{3}`) stays a regex quantifier.
+/// Unless `partial_match` is set the result must match the whole value.
+/// Reference: <https://linkml.io/linkml-model/latest/docs/PatternExpression/>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatternExpression {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub syntax: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub interpolated: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub partial_match: bool,
+}
+
+/// A unit of measure: LinkML's `UnitOfMeasure`. Any one field identifies
+/// the unit; `ucum_code` is the machine-readable one, and `exact_mappings`
+/// carries equivalent unit IRIs (typically QUDT, e.g. `unit:KiloGM`).
+/// Reference: <https://linkml.io/linkml-model/latest/docs/UnitOfMeasure/>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnitOfMeasure {
+    /// Code in the Unified Code for Units of Measure, e.g. `kg`, `m/s2`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ucum_code: Option<String>,
+    /// Display symbol, e.g. `kg`, `°C`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abbreviation: Option<String>,
+    /// Full name, e.g. `kilogram`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descriptive_name: Option<String>,
+    /// Equivalent unit IRIs or CURIEs in other vocabularies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exact_mappings: Vec<String>,
+    /// The quantity kind measured, e.g. `quantitykind:Mass`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_quantity_kind: Option<String>,
+}
+
+impl UnitOfMeasure {
+    /// The unit's shortest identifying text: the UCUM code, else the
+    /// symbol, abbreviation, descriptive name, or first mapping. `None`
+    /// when the unit is empty.
+    pub fn code(&self) -> Option<&str> {
+        self.ucum_code
+            .as_deref()
+            .or(self.symbol.as_deref())
+            .or(self.abbreviation.as_deref())
+            .or(self.descriptive_name.as_deref())
+            .or(self.exact_mappings.first().map(String::as_str))
+    }
+
+    /// Human-readable rendering, e.g. `kg (kilogram)`: the code, followed
+    /// by the descriptive name when it says something the code doesn't.
+    pub fn display(&self) -> Option<String> {
+        let code = self.code()?;
+        Some(match self.descriptive_name.as_deref() {
+            Some(name) if name != code => format!("{code} ({name})"),
+            _ => code.to_string(),
+        })
+    }
+}
+
+/// The shape of an array-valued slot: LinkML's `ArrayExpression`. With no
+/// dimension count stated, the listed `dimensions` fix it; a minimum
+/// leaves the axes past the listed ones unconstrained, and an empty
+/// expression admits an array of any shape.
+/// Reference: <https://linkml.io/linkml/schemas/arrays.html>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArrayExpression {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exact_number_dimensions: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_number_dimensions: Option<u32>,
+    /// `false` in the source (LinkML's "unbounded") reads as `None`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "count_or_false"
+    )]
+    pub maximum_number_dimensions: Option<u32>,
+    /// The axes, outermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimensions: Vec<DimensionExpression>,
+}
+
+/// One axis of an [`ArrayExpression`]: LinkML's `DimensionExpression`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DimensionExpression {
+    /// The axis name, e.g. `time` or `x`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exact_cardinality: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_cardinality: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_cardinality: Option<u32>,
+}
+
+impl ArrayExpression {
+    /// How many dimensions a value may have: the minimum and, when bounded,
+    /// the maximum.
+    pub fn dimension_bounds(&self) -> (u32, Option<u32>) {
+        let listed = self.dimensions.len() as u32;
+        if let Some(n) = self.exact_number_dimensions {
+            return (n, Some(n));
+        }
+        match (
+            self.minimum_number_dimensions,
+            self.maximum_number_dimensions,
+        ) {
+            (None, None) if listed > 0 => (listed, Some(listed)),
+            (None, None) => (1, None),
+            (min, max) => (min.unwrap_or(listed).max(1), max),
+        }
+    }
+
+    /// The `index`th axis's length bounds (see
+    /// [`DimensionExpression::length_bounds`]); an unlisted axis is
+    /// unconstrained.
+    pub fn axis_bounds(&self, index: usize) -> (Option<u32>, Option<u32>) {
+        self.dimensions
+            .get(index)
+            .map_or((None, None), DimensionExpression::length_bounds)
+    }
+
+    /// Human-readable shape, e.g. `2-D array of float; time (any) × channel
+    /// (3)`.
+    pub fn describe(&self, dtype: &str) -> String {
+        let dims = match self.dimension_bounds() {
+            (min, Some(max)) if min == max => format!("{min}-D array"),
+            (min, Some(max)) => format!("{min}–{max}-D array"),
+            (min, None) => format!("array of {min} or more dimensions"),
+        };
+        if self.dimensions.is_empty() {
+            return format!("{dims} of {dtype}");
+        }
+        let axes = self
+            .dimensions
+            .iter()
+            .enumerate()
+            .map(|(i, dim)| {
+                let name = dim
+                    .alias
+                    .clone()
+                    .unwrap_or_else(|| format!("axis {}", i + 1));
+                format!("{name} ({})", dim.describe_length())
+            })
+            .collect::<Vec<_>>()
+            .join(" × ");
+        format!("{dims} of {dtype}; {axes}")
+    }
+}
+
+impl DimensionExpression {
+    /// The axis's minimum and maximum length; `exact_cardinality` fixes
+    /// both.
+    pub fn length_bounds(&self) -> (Option<u32>, Option<u32>) {
+        match self.exact_cardinality {
+            Some(n) => (Some(n), Some(n)),
+            None => (self.minimum_cardinality, self.maximum_cardinality),
+        }
+    }
+
+    /// The axis length as prose: `3`, `2–5`, `≥ 1`, `≤ 8`, or `any`.
+    pub fn describe_length(&self) -> String {
+        match self.length_bounds() {
+            (Some(min), Some(max)) if min == max => min.to_string(),
+            (Some(min), Some(max)) => format!("{min}–{max}"),
+            (Some(min), None) => format!("≥ {min}"),
+            (None, Some(max)) => format!("≤ {max}"),
+            (None, None) => "any".to_string(),
+        }
+    }
+}
+
+/// A count that LinkML also lets be `false`, meaning "no limit".
+fn count_or_false<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CountOrFlag {
+        Count(u32),
+        Flag(bool),
+    }
+    match Option::<CountOrFlag>::deserialize(deserializer)? {
+        Some(CountOrFlag::Count(n)) => Ok(Some(n)),
+        Some(CountOrFlag::Flag(false)) | None => Ok(None),
+        Some(CountOrFlag::Flag(true)) => Err(serde::de::Error::custom(
+            "`maximum_number_dimensions` is a count or `false`",
+        )),
+    }
+}
+
+/// An anonymous value-set expression: LinkML's `AnonymousEnumExpression`,
+/// the member type of an enum's `include` / `minus`. Its values are the
+/// union of its static `permissible_values`, its `concepts`, and its
+/// `reachable_from` terms, plus its own `include`s, less its own `minus`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnumExpression {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub permissible_values: BTreeMap<String, PermissibleValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachable_from: Option<ReachabilityQuery>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub concepts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<EnumExpression>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub minus: Vec<EnumExpression>,
+}
+
+/// A graph query over an ontology: LinkML's `ReachabilityQuery`.
+///
+/// Selects the terms reachable from `source_nodes` in `source_ontology` by
+/// following `relationship_types` edges (default `rdfs:subClassOf`) —
+/// downward to descendants unless `traverse_up`, one hop only when
+/// `is_direct`, with the source nodes themselves only when `include_self`.
+/// Reference: <https://linkml.io/linkml-model/latest/docs/ReachabilityQuery/>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReachabilityQuery {
+    /// The ontology to query, as a CURIE or URL of its RDF document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_ontology: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_nodes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationship_types: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_direct: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub include_self: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub traverse_up: bool,
+}
+
 /// A permissible value within an enumeration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PermissibleValue {
@@ -695,6 +1431,9 @@ pub struct PermissibleValue {
     /// URI for semantic interpretation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meaning: Option<String>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
 }
 
 impl PermissibleValue {
@@ -704,6 +1443,7 @@ impl PermissibleValue {
             text: text.into(),
             description: None,
             meaning: None,
+            metadata: CommonMetadata::default(),
         }
     }
 }
@@ -733,6 +1473,13 @@ pub struct TypeDefinition {
     /// Worked examples; see [`SchemaDefinition::examples`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>,
+    /// Names of the schema [`subsets`](SchemaDefinition::subsets) this
+    /// element belongs to (LinkML `in_subset`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub in_subset: Vec<String>,
+    /// Editorial and provenance metadata; see [`CommonMetadata`].
+    #[serde(flatten, default)]
+    pub metadata: CommonMetadata,
     /// Parent type (for type inheritance). LinkML spells this `typeof`; the
     /// field carries a trailing underscore only to dodge the Rust keyword, so
     /// it must be renamed for (de)serialization — without this, `typeof:` in a
@@ -745,6 +1492,25 @@ pub struct TypeDefinition {
     /// Regular expression pattern for validation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+    /// See [`SlotDefinition::structured_pattern`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_pattern: Option<PatternExpression>,
+    /// Inclusive numeric bounds on the type's values. Together with
+    /// `pattern` these are the facets the RDF projection restricts the
+    /// base datatype by (`xsd:minInclusive` / `xsd:maxInclusive`); a type
+    /// inherits any its `typeof` parent sets and it does not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_value: Option<f64>,
+    /// The Python type LinkML's generators represent the value as (`str`,
+    /// `Decimal`, `XSDDate`, …). Carried for round-tripping; no writer
+    /// here targets Python.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The storage representation when it differs from `base`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repr: Option<String>,
     /// Format-specific annotations
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub annotations: BTreeMap<String, String>,
@@ -760,9 +1526,16 @@ impl TypeDefinition {
             aliases: Vec::new(),
             see_also: Vec::new(),
             examples: Vec::new(),
+            in_subset: Vec::new(),
+            metadata: CommonMetadata::default(),
             typeof_: None,
             uri: None,
             pattern: None,
+            structured_pattern: None,
+            minimum_value: None,
+            maximum_value: None,
+            base: None,
+            repr: None,
             annotations: BTreeMap::new(),
         }
     }
@@ -777,6 +1550,16 @@ fn is_false(b: &bool) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn schema_equality_ignores_where_definitions_were_written() {
+        let mut located = SchemaDefinition::new("s");
+        located.source_map =
+            crate::source_map::SourceMap::from_yaml(std::path::Path::new("s.yaml"), "name: s\n");
+        assert!(!located.source_map.is_empty());
+        assert_eq!(located, SchemaDefinition::new("s"));
+        assert_ne!(located, SchemaDefinition::new("t"));
+    }
+
     #[test]
     fn identity_and_inlining_flags_round_trip_through_yaml() {
         // The declared shape of instance data lives in the schema; losing
@@ -849,6 +1632,30 @@ subclass_of: cco:ont00000958
         assert_eq!(class.subclass_of.as_deref(), Some("cco:ont00000958"));
     }
 
+    #[test]
+    fn array_dimension_bounds_follow_the_linkml_defaults() {
+        let array = |yaml: &str| -> ArrayExpression { serde_norway::from_str(yaml).unwrap() };
+        // Listed dimensions alone fix the count.
+        assert_eq!(
+            array("dimensions: [{alias: x}, {alias: y}]").dimension_bounds(),
+            (2, Some(2))
+        );
+        assert_eq!(
+            array("exact_number_dimensions: 3").dimension_bounds(),
+            (3, Some(3))
+        );
+        // `false` is LinkML's "no maximum".
+        assert_eq!(
+            array("minimum_number_dimensions: 2\nmaximum_number_dimensions: false")
+                .dimension_bounds(),
+            (2, None)
+        );
+        assert_eq!(array("{}").dimension_bounds(), (1, None));
+        assert!(
+            serde_norway::from_str::<ArrayExpression>("maximum_number_dimensions: true").is_err()
+        );
+    }
+
     #[test]
     fn is_false_serde_helper_skips_default_bools() {
         // `is_false` powers `#[serde(skip_serializing_if = "is_false")]`
@@ -1146,6 +1953,83 @@ examples:
         assert!(!bare_out.contains("examples:"), "got:\n{bare_out}");
     }
 
+    #[test]
+    fn common_metadata_deserializes_beside_the_element_keys() {
+        // The long-tail metadata keys sit flat on the element, as LinkML
+        // writes them, and land in `metadata` — a class claims them before
+        // its `unmodeled` catch-all sees them, so they raise no warning.
+        // An element without them serializes none.
+        let yaml = "
+name: Region
+comments:
+  - Cloud regions only.
+todos:
+  - add sovereign clouds
+status: bibo:draft
+rank: 3
+keywords: [cloud, geography]
+created_by: orcid:0000-0002-1825-0097
+last_updated_on: 2024-06-30T12:00:00Z
+";
+        let class: ClassDefinition = serde_norway::from_str(yaml).unwrap();
+        assert_eq!(class.metadata.comments, vec!["Cloud regions only."]);
+        assert_eq!(class.metadata.todos, vec!["add sovereign clouds"]);
+        assert_eq!(class.metadata.status.as_deref(), Some("bibo:draft"));
+        assert_eq!(class.metadata.rank, Some(3));
+        assert_eq!(class.metadata.keywords, vec!["cloud", "geography"]);
+        assert_eq!(
+            class.metadata.last_updated_on.as_deref(),
+            Some("2024-06-30T12:00:00Z")
+        );
+        assert!(class.unmodeled.is_empty(), "got {:?}", class.unmodeled);
+
+        let value: PermissibleValue =
+            serde_norway::from_str("text: large\nrank: 1\nnotes: [from the 2023 survey]\n")
+                .unwrap();
+        assert_eq!(value.metadata.rank, Some(1));
+        assert_eq!(value.metadata.notes, vec!["from the 2023 survey"]);
+
+        let out = serde_norway::to_string(&class).unwrap();
+        assert!(out.contains("rank: 3"), "got:\n{out}");
+        let bare_out = serde_norway::to_string(&ClassDefinition::new("Bare")).unwrap();
+        assert!(!bare_out.contains("comments"), "got:\n{bare_out}");
+    }
+
+    #[test]
+    fn multilingual_metadata_reads_compact_and_expanded_entries() {
+        // `local_names` / `alt_descriptions` come in LinkML's compact and
+        // expanded forms; both land as tag → text and serialize compact.
+        let yaml = "
+name: Person
+in_language: en
+local_names:
+  fr: Personne
+  de:
+    local_name_source: de
+    local_name_value: Person (de)
+alt_descriptions:
+  fr:
+    source: fr
+    description: Un être humain.
+structured_aliases:
+  - literal_form: Individu
+    in_language: fr
+    predicate: EXACT_SYNONYM
+";
+        let class: ClassDefinition = serde_norway::from_str(yaml).unwrap();
+        let m = &class.metadata;
+        assert_eq!(m.in_language.as_deref(), Some("en"));
+        assert_eq!(m.local_names["fr"], "Personne");
+        assert_eq!(m.local_names["de"], "Person (de)");
+        assert_eq!(m.alt_descriptions["fr"], "Un être humain.");
+        assert_eq!(m.structured_aliases[0].literal_form, "Individu");
+        assert_eq!(m.structured_aliases[0].in_language.as_deref(), Some("fr"));
+        assert!(class.unmodeled.is_empty(), "got {:?}", class.unmodeled);
+
+        let out = serde_norway::to_string(&class).unwrap();
+        assert!(out.contains("fr: Personne"), "got:\n{out}");
+    }
+
     #[test]
     fn class_definition_deserializes_rules() {
         // A `rules` entry's `preconditions` / `postconditions` each carry a
@@ -1199,6 +2083,42 @@ rules:
         assert!(!bare_out.contains("rules:"), "got:\n{bare_out}");
     }
 
+    #[test]
+    fn class_definition_deserializes_class_expressions() {
+        // Each combinator member is an anonymous class expression: an `is_a`
+        // and/or `slot_conditions`, nesting further combinators.
+        let yaml = "
+name: Pet
+any_of:
+  - is_a: Cat
+  - is_a: Dog
+none_of:
+  - slot_conditions:
+      chip:
+        value_presence: ABSENT
+    any_of:
+      - is_a: Stray
+union_of: [Cat, Dog]
+disjoint_with: [Machine]
+";
+        let class: ClassDefinition = serde_norway::from_str(yaml).unwrap();
+        assert_eq!(class.any_of.len(), 2);
+        assert_eq!(class.any_of[0].named_class(), Some("Cat"));
+        assert!(class.all_of.is_empty() && class.exactly_one_of.is_empty());
+
+        let excluded = &class.none_of[0];
+        assert_eq!(excluded.named_class(), None);
+        assert!(excluded.slot_conditions.contains_key("chip"));
+        assert!(excluded.mentions_class());
+        assert_eq!(class.union_of, vec!["Cat", "Dog"]);
+        assert_eq!(class.disjoint_with, vec!["Machine"]);
+
+        let bare: ClassDefinition = serde_norway::from_str("name: Pet").unwrap();
+        let bare_out = serde_norway::to_string(&bare).unwrap();
+        assert!(!bare_out.contains("any_of:"), "got:\n{bare_out}");
+        assert!(!bare_out.contains("disjoint_with:"), "got:\n{bare_out}");
+    }
+
     #[test]
     fn rule_conditions_deserialize_value_presence_and_any_of() {
         // A real-world `ImageApproval` shape: an `any_of` precondition (the
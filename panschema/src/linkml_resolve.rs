@@ -20,7 +20,10 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
-use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use crate::linkml::{
+    ClassDefinition, ClassExpression, PatternExpression, RuleConditions, SchemaDefinition,
+    SlotDefinition,
+};
 
 /// How a resolved slot reached the class it was resolved for.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -93,6 +96,11 @@ pub struct InducedRange {
     /// permits no value. Renderers show "has no value" and draw
     /// no range edge; `ranges` is empty in this case.
     pub suppressed: bool,
+    /// The slot's `all_of` / `none_of` rule out every range it would
+    /// otherwise take, so no value can conform. `ranges` is empty; the
+    /// load diagnostics report the slot rather than let each writer
+    /// degrade it differently.
+    pub contradictory: bool,
 }
 
 /// A slot definition paired with where it came from and its induced
@@ -247,16 +255,33 @@ fn inherit_unset(child: &mut SlotDefinition, parent: &SlotDefinition) {
     }
     inherit_opt!(range);
     inherit_opt!(description);
-    inherit_opt!(pattern);
+    // `pattern` and `structured_pattern` are one facet: a child stating
+    // either keeps it, rather than inheriting the other form, which would
+    // materialize over its own.
+    if child.pattern.is_none() && child.structured_pattern.is_none() {
+        child.pattern = parent.pattern.clone();
+        child.structured_pattern = parent.structured_pattern.clone();
+    }
+    inherit_opt!(equals_expression);
     inherit_opt!(ifabsent);
     inherit_opt!(minimum_cardinality);
     inherit_opt!(maximum_cardinality);
     inherit_opt!(minimum_value);
     inherit_opt!(maximum_value);
+    inherit_opt!(unit);
+    inherit_opt!(array);
+    inherit_opt!(slot_group);
     inherit_opt!(inlined);
     inherit_opt!(inlined_as_list);
-    if child.any_of.is_empty() && !parent.any_of.is_empty() {
-        child.any_of = parent.any_of.clone();
+    for (own, inherited) in [
+        (&mut child.any_of, &parent.any_of),
+        (&mut child.all_of, &parent.all_of),
+        (&mut child.exactly_one_of, &parent.exactly_one_of),
+        (&mut child.none_of, &parent.none_of),
+    ] {
+        if own.is_empty() && !inherited.is_empty() {
+            *own = inherited.clone();
+        }
     }
 }
 
@@ -322,6 +347,115 @@ pub fn materialize_deferred_default_range(schema: &mut SchemaDefinition) {
     });
 }
 
+/// Interpolate a `structured_pattern` into the concrete regex it stands
+/// for. With `interpolated`, each `{name}` whose name is an identifier is
+/// replaced by the schema setting of that name; any other brace group
+/// (`{3}`, `{2,4}`) is a regex quantifier and stays. Unless
+/// `partial_match`, the result is wrapped as `^(?:…)$` so it must match the
+/// whole value — `pattern` itself is a search. The wrap is unconditional:
+/// a syntax that already looks anchored may not be (`^a|b$`, `a\$`).
+/// `Ok(None)` when the expression has no `syntax`; `Err` lists the
+/// referenced settings the schema does not define.
+pub fn interpolate_pattern(
+    expr: &PatternExpression,
+    settings: &BTreeMap<String, String>,
+) -> Result<Option<String>, Vec<String>> {
+    let Some(syntax) = expr.syntax.as_deref() else {
+        return Ok(None);
+    };
+    let mut regex = String::new();
+    let mut missing = Vec::new();
+    let mut rest = syntax;
+    while expr.interpolated
+        && let Some(open) = rest.find('{')
+    {
+        regex.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let name = after
+            .find('}')
+            .map(|close| &after[..close])
+            .filter(|name| is_setting_name(name));
+        match name {
+            Some(name) => {
+                match settings.get(name) {
+                    Some(value) => regex.push_str(value),
+                    None => missing.push(name.to_string()),
+                }
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                regex.push('{');
+                rest = after;
+            }
+        }
+    }
+    regex.push_str(rest);
+    if !missing.is_empty() {
+        return Err(missing);
+    }
+    if !expr.partial_match {
+        regex = format!("^(?:{regex})$");
+    }
+    Ok(Some(regex))
+}
+
+fn is_setting_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Materialize every `structured_pattern` into the `pattern` it
+/// interpolates to, so validation, SHACL, JSON Schema, Postgres and HTML
+/// all see the concrete regex. Runs at load after the imports merge, so a
+/// pattern may use settings any imported file declares. A structured
+/// pattern replaces a `pattern` stated beside it, as LinkML's own
+/// materialization does; one naming an undefined setting is left
+/// unmaterialized and reported by
+/// [`crate::diagnostics::unresolved_pattern_settings`].
+pub fn materialize_structured_patterns(schema: &mut SchemaDefinition) {
+    fn slot(def: &mut SlotDefinition, settings: &BTreeMap<String, String>) {
+        if let Some(Ok(Some(regex))) = def
+            .structured_pattern
+            .as_ref()
+            .map(|expr| interpolate_pattern(expr, settings))
+        {
+            def.pattern = Some(regex);
+        }
+        for branches in [
+            &mut def.any_of,
+            &mut def.all_of,
+            &mut def.exactly_one_of,
+            &mut def.none_of,
+        ] {
+            for branch in branches.iter_mut() {
+                slot(branch, settings);
+            }
+        }
+    }
+    let settings = schema.settings.clone();
+    for def in schema.slots.values_mut() {
+        slot(def, &settings);
+    }
+    for class in schema.classes.values_mut() {
+        for def in class
+            .attributes
+            .values_mut()
+            .chain(class.slot_usage.values_mut())
+        {
+            slot(def, &settings);
+        }
+    }
+    for ty in schema.types.values_mut() {
+        if let Some(Ok(Some(regex))) = ty
+            .structured_pattern
+            .as_ref()
+            .map(|expr| interpolate_pattern(expr, &settings))
+        {
+            ty.pattern = Some(regex);
+        }
+    }
+}
+
 /// One traversal over every slot definition the default-range passes
 /// touch — top-level `slots:` and class `attributes:` — so the immediate
 /// and deferred passes can never visit different containers.
@@ -342,7 +476,12 @@ fn for_each_slot_definition(schema: &mut SchemaDefinition, mut f: impl FnMut(&mu
 /// diagnostic reports can never drift apart.
 pub fn default_range_would_fill(slot: &SlotDefinition) -> bool {
     slot.range.is_none()
-        && !slot.any_of.iter().any(|branch| branch.range.is_some())
+        && !slot
+            .combinators()
+            .into_iter()
+            .filter(|(name, _)| *name != "none_of")
+            .flat_map(|(_, branches)| branches)
+            .any(|branch| branch.range.is_some())
         && slot.maximum_cardinality != Some(0)
 }
 
@@ -415,7 +554,7 @@ fn resolve_slots_walk(
         slots.insert(
             name.clone(),
             ResolvedSlot {
-                induced: base_induced(def),
+                induced: base_induced(def, schema),
                 definition: def.clone(),
                 provenance,
             },
@@ -427,7 +566,7 @@ fn resolve_slots_walk(
             slots
                 .entry(slot_name.clone())
                 .or_insert_with(|| ResolvedSlot {
-                    induced: base_induced(def),
+                    induced: base_induced(def, schema),
                     definition: def.clone(),
                     provenance: Provenance::Direct,
                 });
@@ -442,7 +581,7 @@ fn resolve_slots_walk(
                     by_slot_usage: true,
                 };
                 merge_slot_override(&mut target.definition, override_def);
-                target.induced = induced_after_override(&target.definition, override_def);
+                target.induced = induced_after_override(&target.definition, override_def, schema);
             }
             // A `slot_usage` with no inherited base acts as the
             // slot's introduction at this class.
@@ -450,7 +589,7 @@ fn resolve_slots_walk(
                 slots.insert(
                     name.clone(),
                     ResolvedSlot {
-                        induced: base_induced(override_def),
+                        induced: base_induced(override_def, schema),
                         definition: override_def.clone(),
                         provenance: Provenance::Direct,
                     },
@@ -539,15 +678,20 @@ fn merge_slot_override(target: &mut SlotDefinition, source: &SlotDefinition) {
         aliases: _,
         see_also: _,
         examples: _,
+        in_subset: _,
+        metadata: _,
         range: _,
         domain: _,
         ifabsent: _,
         required: _,
+        recommended: _,
         multivalued: _,
         designates_type: _,
         minimum_cardinality: _,
         maximum_cardinality: _,
         pattern: _,
+        structured_pattern: _,
+        equals_expression: _,
         identifier: _,
         key: _,
         inlined: _,
@@ -562,12 +706,19 @@ fn merge_slot_override(target: &mut SlotDefinition, source: &SlotDefinition) {
         transitive: _,
         minimum_value: _,
         maximum_value: _,
+        unit: _,
+        array: _,
         any_of: _,
+        all_of: _,
+        exactly_one_of: _,
+        none_of: _,
         exact_mappings: _,
         close_mappings: _,
         related_mappings: _,
         narrow_mappings: _,
         broad_mappings: _,
+        slot_group: _,
+        is_grouping_slot: _,
         annotations: _,
     } = *source;
 
@@ -592,16 +743,31 @@ fn merge_slot_override(target: &mut SlotDefinition, source: &SlotDefinition) {
     merge_opt!(range);
     merge_opt!(description);
     merge_opt!(pattern);
+    merge_opt!(structured_pattern);
+    merge_opt!(equals_expression);
     merge_opt!(is_a);
+    merge_opt!(unit);
+    merge_opt!(array);
+    merge_opt!(slot_group);
     merge_opt_copy!(minimum_cardinality);
     merge_opt_copy!(maximum_cardinality);
 
-    if !source.any_of.is_empty() {
-        target.any_of = source.any_of.clone();
+    for (target_branches, source_branches) in [
+        (&mut target.any_of, &source.any_of),
+        (&mut target.all_of, &source.all_of),
+        (&mut target.exactly_one_of, &source.exactly_one_of),
+        (&mut target.none_of, &source.none_of),
+    ] {
+        if !source_branches.is_empty() {
+            *target_branches = source_branches.clone();
+        }
     }
     if source.required {
         target.required = true;
     }
+    if source.recommended {
+        target.recommended = true;
+    }
     if source.multivalued {
         target.multivalued = true;
     }
@@ -630,58 +796,145 @@ fn merge_slot_override(target: &mut SlotDefinition, source: &SlotDefinition) {
 
 /// The induced range of a slot from its own definition, before any
 /// `slot_usage` narrowing — an `any_of` union's member ranges, or the
-/// single `range`. `maximum_cardinality: 0` suppresses it (the class
-/// permits no value), which empties `ranges`.
-fn base_induced(def: &SlotDefinition) -> InducedRange {
+/// single `range`, narrowed by the other combinators (see
+/// [`narrow_by_combinators`]). `maximum_cardinality: 0` suppresses it
+/// (the class permits no value), which empties `ranges`.
+fn base_induced(def: &SlotDefinition, schema: &SchemaDefinition) -> InducedRange {
     if def.maximum_cardinality == Some(0) {
         return InducedRange {
-            ranges: Vec::new(),
             suppressed: true,
+            ..InducedRange::default()
         };
     }
-    let ranges = if !def.any_of.is_empty() {
-        def.any_of.iter().filter_map(|m| m.range.clone()).collect()
+    narrow_by_combinators(alternative_ranges(def).unwrap_or_default(), def, schema)
+}
+
+/// The range alternatives a definition states itself: the member ranges
+/// of an `any_of` union — or of an `exactly_one_of`, which admits the
+/// same targets and only rules out a value matching two — else the
+/// scalar `range`. `None` when it states none of them.
+fn alternative_ranges(def: &SlotDefinition) -> Option<Vec<String>> {
+    let branches = if def.any_of.is_empty() {
+        &def.exactly_one_of
     } else {
-        def.range.iter().cloned().collect()
+        &def.any_of
     };
+    if !branches.is_empty() {
+        Some(branches.iter().filter_map(|m| m.range.clone()).collect())
+    } else {
+        def.range.as_ref().map(|range| vec![range.clone()])
+    }
+}
+
+/// Apply `all_of` and `none_of` to a slot's range alternatives. An
+/// `all_of` whose ranged branches all name one range is intersected with
+/// the alternatives (a value must be that and one of them): an
+/// alternative that is, or descends from, the conjunct stays; one the
+/// conjunct descends from narrows to the conjunct; any other is
+/// dropped. With no alternatives stated the conjunct is the range.
+/// Branches naming several distinct ranges state an intersection no
+/// single target captures, so the alternatives stand and the validator
+/// checks each value against every branch. A `none_of` branch's range is
+/// removed from the alternatives. Narrowing that leaves nothing marks
+/// the result [`InducedRange::contradictory`].
+fn narrow_by_combinators(
+    mut ranges: Vec<String>,
+    def: &SlotDefinition,
+    schema: &SchemaDefinition,
+) -> InducedRange {
+    let mut conjuncts = def.all_of.iter().filter_map(|m| m.range.as_deref());
+    let mut stated = !ranges.is_empty();
+    if let Some(first) = conjuncts.next()
+        && conjuncts.all(|range| range == first)
+    {
+        ranges = if stated {
+            intersect(ranges, first, schema)
+        } else {
+            vec![first.to_string()]
+        };
+        stated = true;
+    }
+    ranges.retain(|range| {
+        !def.none_of
+            .iter()
+            .any(|m| m.range.as_deref() == Some(range.as_str()))
+    });
     InducedRange {
+        contradictory: stated && ranges.is_empty(),
         ranges,
         suppressed: false,
     }
 }
 
+/// The alternatives in `ranges` a value ranged by `conjunct` as well can
+/// take: each alternative narrowed to whichever of the two descends from
+/// the other, through `is_a` or a mixin. Names that aren't classes only
+/// meet themselves.
+fn intersect(ranges: Vec<String>, conjunct: &str, schema: &SchemaDefinition) -> Vec<String> {
+    let conjunct_lineage = ancestors_or_self(schema, conjunct);
+    let mut out: Vec<String> = Vec::new();
+    for range in ranges {
+        let kept = if ancestors_or_self(schema, &range).contains(conjunct) {
+            range
+        } else if conjunct_lineage.contains(range.as_str()) {
+            conjunct.to_string()
+        } else {
+            continue;
+        };
+        if !out.contains(&kept) {
+            out.push(kept);
+        }
+    }
+    out
+}
+
+/// `class` and every class it inherits from through `is_a` or a mixin —
+/// what a record of `class` *is*. A malformed cycle stops at the first
+/// revisit.
+pub fn ancestors_or_self<'a>(schema: &'a SchemaDefinition, class: &'a str) -> BTreeSet<&'a str> {
+    let mut seen = BTreeSet::new();
+    let mut queue = vec![class];
+    while let Some(name) = queue.pop() {
+        if !seen.insert(name) {
+            continue;
+        }
+        if let Some(def) = schema.classes.get(name) {
+            queue.extend(def.is_a.as_deref());
+            queue.extend(def.mixins.iter().map(String::as_str));
+        }
+    }
+    seen
+}
+
 /// The induced range after a class refines a slot via `slot_usage`,
 /// per LinkML induced-slot semantics. The decision keys off the
 /// *override* (not the merged definition), so the inherited union
 /// can't leak through:
 /// - `maximum_cardinality: 0` → suppressed, no ranges.
-/// - override `any_of` → replaces the inherited union with its members.
+/// - override `any_of` / `exactly_one_of` → replaces the inherited union
+///   with its members.
 /// - override scalar `range` → intersects the inherited union down to
 ///   that single range (the wide union no longer applies).
 /// - override touches neither (e.g. only tightens `required`) → the
 ///   merged definition's base induced range stands.
-fn induced_after_override(merged: &SlotDefinition, override_def: &SlotDefinition) -> InducedRange {
+///
+/// The merged definition's `all_of` / `none_of` — the override's own
+/// when it states them, else inherited — narrow the result either way.
+fn induced_after_override(
+    merged: &SlotDefinition,
+    override_def: &SlotDefinition,
+    schema: &SchemaDefinition,
+) -> InducedRange {
     if merged.maximum_cardinality == Some(0) {
         return InducedRange {
-            ranges: Vec::new(),
             suppressed: true,
+            ..InducedRange::default()
         };
     }
-    let ranges = if !override_def.any_of.is_empty() {
-        override_def
-            .any_of
-            .iter()
-            .filter_map(|m| m.range.clone())
-            .collect()
-    } else if let Some(range) = &override_def.range {
-        vec![range.clone()]
-    } else {
-        return base_induced(merged);
+    let Some(ranges) = alternative_ranges(override_def) else {
+        return base_induced(merged, schema);
     };
-    InducedRange {
-        ranges,
-        suppressed: false,
-    }
+    narrow_by_combinators(ranges, merged, schema)
 }
 
 /// Effective cardinality of a resolved slot: the answer every writer
@@ -696,6 +949,24 @@ pub struct Cardinality {
     pub max: Option<u32>,
 }
 
+impl Cardinality {
+    /// The UML-style multiplicity: `1`, `0..1`, `1..*`, `0..*`, or the
+    /// explicit bounds (`2..5`).
+    pub fn multiplicity(&self) -> String {
+        let min = self.min.unwrap_or(u32::from(self.required));
+        let max = match self.max {
+            Some(max) => max.to_string(),
+            None if self.multivalued => "*".to_string(),
+            None => "1".to_string(),
+        };
+        if max == min.to_string() {
+            max
+        } else {
+            format!("{min}..{max}")
+        }
+    }
+}
+
 /// Compute the effective cardinality of a slot. Pass a slot that has
 /// already been through [`resolve_effective_slots`] — the
 /// `slot_usage` overlay happens there, so this is a pure view with no
@@ -718,6 +989,75 @@ pub fn effective_cardinality(slot: &SlotDefinition) -> Cardinality {
     }
 }
 
+/// A class's effective slots in presentation order, each with the
+/// `slot_group` it sits in: ungrouped slots first in resolver order, then
+/// each group's members together, groups ordered like cards — by the
+/// grouping slot's `rank`, then name. A group naming no declared slot
+/// still gathers its members; it just sorts as unranked.
+pub fn grouped_slot_order<'a>(
+    effective: &'a BTreeMap<String, ResolvedSlot>,
+    schema: &SchemaDefinition,
+) -> Vec<(&'a str, Option<&'a str>)> {
+    let mut order: Vec<(&str, Option<&str>)> = effective
+        .iter()
+        .map(|(name, rs)| (name.as_str(), rs.definition.slot_group.as_deref()))
+        .collect();
+    let unranked = crate::linkml::CommonMetadata::default();
+    let metadata = |group: &str| {
+        schema
+            .slots
+            .get(group)
+            .map_or(&unranked, |slot| &slot.metadata)
+    };
+    // Stable: members keep resolver order within their group.
+    order.sort_by(|(_, a), (_, b)| match (*a, *b) {
+        (None, None) => std::cmp::Ordering::Equal,
+        (None, Some(_)) => std::cmp::Ordering::Less,
+        (Some(_), None) => std::cmp::Ordering::Greater,
+        (Some(a), Some(b)) => crate::linkml::rank_order((metadata(a), a), (metadata(b), b)),
+    });
+    order
+}
+
+/// The value facets a custom `types:` entry constrains its values by.
+/// See [`type_facets`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TypeFacets {
+    pub pattern: Option<String>,
+    pub minimum_value: Option<f64>,
+    pub maximum_value: Option<f64>,
+}
+
+impl TypeFacets {
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_none() && self.minimum_value.is_none() && self.maximum_value.is_none()
+    }
+}
+
+/// A type's `pattern` and value bounds, each taken from the type itself or,
+/// when it sets none, from the nearest `typeof` ancestor that does — a
+/// subtype narrows its parent's value space, it never widens it back. Empty
+/// for a name that is not a `types:` entry; a `typeof` cycle stops the walk.
+pub fn type_facets(schema: &SchemaDefinition, name: &str) -> TypeFacets {
+    let mut facets = TypeFacets::default();
+    let mut seen: Vec<&str> = Vec::new();
+    let mut current = name;
+    while let Some(ty) = schema.types.get(current) {
+        if seen.contains(&current) {
+            break;
+        }
+        seen.push(current);
+        facets.pattern = facets.pattern.or_else(|| ty.pattern.clone());
+        facets.minimum_value = facets.minimum_value.or(ty.minimum_value);
+        facets.maximum_value = facets.maximum_value.or(ty.maximum_value);
+        match ty.typeof_.as_deref() {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    facets
+}
+
 /// Resolve a slot's effective domain class names. LinkML lets the
 /// domain be expressed two ways: the slot's own `domain:`, or — the
 /// common case — one or more classes listing the slot in their `slots:`
@@ -767,6 +1107,246 @@ pub fn expand_curie(schema: &SchemaDefinition, value: &str) -> Option<String> {
     Some(format!("{base}{value}"))
 }
 
+/// How one schema element refers to another; see [`usage_index`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageKind {
+    /// A slot, attribute or `slot_usage` refinement ranges over it.
+    Range,
+    /// A class lists it (a slot) under `slots`.
+    Slots,
+    /// An `any_of` branch of a slot or class expression names it.
+    AnyOf,
+    /// An `all_of` branch names it.
+    AllOf,
+    /// An `exactly_one_of` branch names it.
+    ExactlyOneOf,
+    /// A `none_of` branch names it: the referrer excludes it, directly or
+    /// through a combinator nested under the `none_of`.
+    NoneOf,
+    /// A class or slot specializes it (`is_a`).
+    IsA,
+    /// A class mixes it in.
+    Mixin,
+    /// A class rule's conditions constrain it (a slot) or range over it.
+    Rule,
+    /// A class's `unique_keys` includes it (a slot).
+    UniqueKey,
+}
+
+impl UsageKind {
+    /// Short label for display, in the schema author's vocabulary.
+    pub fn label(self) -> &'static str {
+        match self {
+            UsageKind::Range => "range",
+            UsageKind::Slots => "slots",
+            UsageKind::AnyOf => "any_of",
+            UsageKind::AllOf => "all_of",
+            UsageKind::ExactlyOneOf => "exactly_one_of",
+            UsageKind::NoneOf => "excluded by none_of",
+            UsageKind::IsA => "is_a",
+            UsageKind::Mixin => "mixin",
+            UsageKind::Rule => "rule",
+            UsageKind::UniqueKey => "unique key",
+        }
+    }
+
+    /// The kind for a branch of the combinator metaslot `name`, nested
+    /// under a branch of kind `outer`. A `none_of` anywhere above keeps
+    /// every branch below it an exclusion.
+    fn branch(name: &str, outer: Option<UsageKind>) -> UsageKind {
+        match (outer, name) {
+            (Some(UsageKind::NoneOf), _) | (_, "none_of") => UsageKind::NoneOf,
+            (_, "all_of") => UsageKind::AllOf,
+            (_, "exactly_one_of") => UsageKind::ExactlyOneOf,
+            _ => UsageKind::AnyOf,
+        }
+    }
+}
+
+/// One inbound reference to a schema element.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Usage {
+    pub kind: UsageKind,
+    /// The referring element as a graph node id: `class:Person` or
+    /// `slot:owner`.
+    pub source: String,
+    /// Where inside a referring class the reference sits: the attribute
+    /// or `slot_usage` slot, the rule's title, the unique key's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Every inbound reference to each class, enum and slot, keyed by the
+/// referenced element's graph node id (`class:Person`, `enum:Status`,
+/// `slot:owner`) and sorted within each entry. Answers "what breaks if
+/// this changes?" once, for every writer that shows it: slots whose
+/// range or range branches (nested ones included) name an element,
+/// classes that list a slot under `slots`, classes and slots that
+/// specialize or mix it in, class expressions whose `is_a` or
+/// `slot_conditions` name it, and class rules and `unique_keys` that
+/// mention it. Ranges naming a type or nothing the schema defines are
+/// not indexed.
+pub fn usage_index(schema: &SchemaDefinition) -> BTreeMap<String, Vec<Usage>> {
+    let mut index: BTreeMap<String, Vec<Usage>> = BTreeMap::new();
+    let mut add = |target: String, kind: UsageKind, source: String, detail: Option<&str>| {
+        index.entry(target).or_default().push(Usage {
+            kind,
+            source,
+            detail: detail.map(str::to_string),
+        });
+    };
+    let range_target = |range: &str| {
+        if schema.classes.contains_key(range) {
+            Some(format!("class:{range}"))
+        } else if schema.enums.contains_key(range) {
+            Some(format!("enum:{range}"))
+        } else {
+            None
+        }
+    };
+    for (name, slot) in &schema.slots {
+        let source = format!("slot:{name}");
+        for (kind, range) in slot_ranges(slot) {
+            if let Some(target) = range_target(range) {
+                add(target, kind, source.clone(), None);
+            }
+        }
+        if let Some(parent) = &slot.is_a {
+            add(
+                format!("slot:{parent}"),
+                UsageKind::IsA,
+                source.clone(),
+                None,
+            );
+        }
+    }
+
+    for (name, class) in &schema.classes {
+        let source = format!("class:{name}");
+        if let Some(parent) = &class.is_a {
+            add(
+                format!("class:{parent}"),
+                UsageKind::IsA,
+                source.clone(),
+                None,
+            );
+        }
+        for mixin in &class.mixins {
+            add(
+                format!("class:{mixin}"),
+                UsageKind::Mixin,
+                source.clone(),
+                None,
+            );
+        }
+        for slot_name in &class.slots {
+            add(
+                format!("slot:{slot_name}"),
+                UsageKind::Slots,
+                source.clone(),
+                None,
+            );
+        }
+        for (slot_name, slot) in class.attributes.iter().chain(&class.slot_usage) {
+            for (kind, range) in slot_ranges(slot) {
+                if let Some(target) = range_target(range) {
+                    add(target, kind, source.clone(), Some(slot_name));
+                }
+            }
+        }
+        let mut expressions: Vec<(UsageKind, &ClassExpression)> = class
+            .combinators()
+            .into_iter()
+            .flat_map(|(name, members)| {
+                let kind = UsageKind::branch(name, None);
+                members.iter().map(move |m| (kind, m))
+            })
+            .collect();
+        while let Some((kind, expr)) = expressions.pop() {
+            if let Some(target) = expr.is_a.as_deref().and_then(range_target) {
+                add(target, kind, source.clone(), None);
+            }
+            for (slot_name, condition) in &expr.slot_conditions {
+                add(format!("slot:{slot_name}"), kind, source.clone(), None);
+                let mut alternatives = vec![condition];
+                while let Some(c) = alternatives.pop() {
+                    if let Some(target) = c.range.as_deref().and_then(range_target) {
+                        add(target, kind, source.clone(), Some(slot_name));
+                    }
+                    alternatives.extend(&c.any_of);
+                }
+            }
+            for (name, members) in expr.combinators() {
+                let inner = UsageKind::branch(name, Some(kind));
+                expressions.extend(members.iter().map(|m| (inner, m)));
+            }
+        }
+        for rule in &class.rules {
+            let mut conditions: Vec<&RuleConditions> = rule
+                .preconditions
+                .iter()
+                .chain(&rule.postconditions)
+                .collect();
+            while let Some(cond) = conditions.pop() {
+                for (slot_name, condition) in &cond.slot_conditions {
+                    let detail = rule.title.as_deref();
+                    add(
+                        format!("slot:{slot_name}"),
+                        UsageKind::Rule,
+                        source.clone(),
+                        detail,
+                    );
+                    let mut alternatives = vec![condition];
+                    while let Some(c) = alternatives.pop() {
+                        if let Some(target) = c.range.as_deref().and_then(range_target) {
+                            add(target, UsageKind::Rule, source.clone(), detail);
+                        }
+                        alternatives.extend(&c.any_of);
+                    }
+                }
+                conditions.extend(&cond.any_of);
+            }
+        }
+        for (key_name, key) in &class.unique_keys {
+            for slot_name in &key.unique_key_slots {
+                add(
+                    format!("slot:{slot_name}"),
+                    UsageKind::UniqueKey,
+                    source.clone(),
+                    Some(key_name),
+                );
+            }
+        }
+    }
+
+    for usages in index.values_mut() {
+        usages.sort();
+        usages.dedup();
+    }
+    index
+}
+
+/// A slot definition's own range, then every combinator branch's range,
+/// nested branches included, each tagged with the combinator it sits
+/// under (see [`UsageKind::branch`]).
+fn slot_ranges(slot: &SlotDefinition) -> Vec<(UsageKind, &str)> {
+    let mut ranges = Vec::new();
+    let mut pending: Vec<(Option<UsageKind>, &SlotDefinition)> = vec![(None, slot)];
+    while let Some((kind, definition)) = pending.pop() {
+        if let Some(range) = definition.range.as_deref() {
+            ranges.push((kind.unwrap_or(UsageKind::Range), range));
+        }
+        for (name, members) in definition.combinators() {
+            let inner = UsageKind::branch(name, kind);
+            pending.extend(members.iter().map(|m| (Some(inner), m)));
+        }
+    }
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1644,6 +2224,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn induced_range_honors_exactly_one_of_all_of_and_none_of() {
+        let mut schema = act_facets_schema();
+        let branches = |members: &[&str]| union_slot("hasInput", members).any_of;
+
+        // `exactly_one_of` admits the same targets an `any_of` would.
+        let mut choice = SlotDefinition::new("hasInput");
+        choice.exactly_one_of = branches(&["Question", "Dataset"]);
+        // `none_of` strikes a member from the inherited union.
+        let mut excluding = SlotDefinition::new("hasInput");
+        excluding.none_of = branches(&["Question", "Result"]);
+        // An `all_of` naming one range narrows to it.
+        let mut conjunct = SlotDefinition::new("hasInput");
+        conjunct.all_of = branches(&["Annotation"]);
+        for (name, usage) in [
+            ("Choosing", choice),
+            ("Excluding", excluding),
+            ("Conjoining", conjunct),
+        ] {
+            let mut class = ClassDefinition::new(name);
+            class.is_a = Some("Act".into());
+            class.slot_usage.insert("hasInput".into(), usage);
+            schema.classes.insert(name.into(), class);
+        }
+
+        assert_eq!(
+            induced_of(&schema, "Choosing", "hasInput").ranges,
+            vec!["Question".to_string(), "Dataset".into()]
+        );
+        assert_eq!(
+            induced_of(&schema, "Excluding", "hasInput").ranges,
+            vec![
+                "Dataset".to_string(),
+                "Annotation".into(),
+                "SourceDocument".into()
+            ]
+        );
+        assert_eq!(
+            induced_of(&schema, "Conjoining", "hasInput").ranges,
+            vec!["Annotation".to_string()]
+        );
+    }
+
+    /// `all_of` intersects with the stated alternatives rather than
+    /// replacing them, and narrowing that leaves nothing is flagged.
+    #[test]
+    fn induced_all_of_intersects_and_an_empty_result_is_contradictory() {
+        let mut schema = act_facets_schema();
+        let branches = |members: &[&str]| union_slot("hasInput", members).any_of;
+        let mut survey = ClassDefinition::new("Survey");
+        survey.is_a = Some("Dataset".into());
+        schema.classes.insert("Survey".into(), survey);
+
+        // A conjunct descending from an alternative narrows it.
+        let mut narrowing = SlotDefinition::new("hasInput");
+        narrowing.all_of = branches(&["Survey"]);
+        // A conjunct outside the alternatives leaves nothing.
+        let mut outside = SlotDefinition::new("hasInput");
+        outside.any_of = branches(&["Question", "Result"]);
+        outside.all_of = branches(&["Dataset"]);
+        // So does a `none_of` striking every alternative.
+        let mut struck = SlotDefinition::new("hasInput");
+        struck.any_of = branches(&["Question"]);
+        struck.none_of = branches(&["Question"]);
+        for (name, usage) in [
+            ("Surveying", narrowing),
+            ("Outside", outside),
+            ("Struck", struck),
+        ] {
+            let mut class = ClassDefinition::new(name);
+            class.is_a = Some("Act".into());
+            class.slot_usage.insert("hasInput".into(), usage);
+            schema.classes.insert(name.into(), class);
+        }
+
+        let surveying = induced_of(&schema, "Surveying", "hasInput");
+        assert_eq!(surveying.ranges, vec!["Survey".to_string()]);
+        assert!(!surveying.contradictory);
+        for class in ["Outside", "Struck"] {
+            let induced = induced_of(&schema, class, "hasInput");
+            assert!(induced.ranges.is_empty(), "{class}: {induced:?}");
+            assert!(induced.contradictory, "{class}: {induced:?}");
+        }
+        assert!(!induced_of(&schema, "Act", "hasInput").contradictory);
+    }
+
     #[test]
     fn induced_max_cardinality_zero_suppresses_without_dropping_slot() {
         let schema = act_facets_schema();
@@ -1756,6 +2422,23 @@ mod tests {
         assert_eq!(card.max, None);
     }
 
+    #[test]
+    fn multiplicity_reads_bounds_then_flags() {
+        let label = |required, multivalued, min, max| {
+            Cardinality {
+                required,
+                multivalued,
+                min,
+                max,
+            }
+            .multiplicity()
+        };
+        assert_eq!(label(true, false, None, None), "1");
+        assert_eq!(label(false, false, None, None), "0..1");
+        assert_eq!(label(true, true, None, None), "1..*");
+        assert_eq!(label(true, true, Some(2), Some(5)), "2..5");
+    }
+
     #[test]
     fn effective_cardinality_after_slot_usage_required_preserves_inherited_multivalued() {
         // A slot_usage that only tightens `required` must not disturb
@@ -1846,6 +2529,105 @@ mod tests {
         assert!(expand_curie(&schema, "").is_none());
     }
 
+    #[test]
+    fn interpolate_pattern_splices_settings_and_keeps_quantifiers() {
+        let settings: BTreeMap<String, String> = [
+            ("prefix".to_string(), "ORD".to_string()),
+            ("digits".to_string(), r"\d".to_string()),
+        ]
+        .into();
+        let expr = |syntax: &str, interpolated: bool, partial_match: bool| PatternExpression {
+            syntax: Some(syntax.to_string()),
+            interpolated,
+            partial_match,
+        };
+        assert_eq!(
+            interpolate_pattern(&expr("{prefix}-{digits}{6}", true, false), &settings),
+            Ok(Some(r"^(?:ORD-\d{6})$".to_string())),
+            "a numeric brace group is a quantifier, and the result is anchored"
+        );
+        assert_eq!(
+            interpolate_pattern(&expr("{prefix}", true, true), &settings),
+            Ok(Some("ORD".to_string())),
+            "partial_match leaves the regex a search"
+        );
+        assert_eq!(
+            interpolate_pattern(&expr("^{prefix}$", false, true), &settings),
+            Ok(Some("^{prefix}$".to_string())),
+            "without interpolated, the syntax is the regex verbatim"
+        );
+        assert_eq!(
+            interpolate_pattern(&expr("^a|b$", false, false), &settings),
+            Ok(Some("^(?:^a|b$)$".to_string())),
+            "an alternation anchored only at its ends still has both branches anchored"
+        );
+        assert_eq!(
+            interpolate_pattern(&expr(r"^a\$", false, false), &settings),
+            Ok(Some(r"^(?:^a\$)$".to_string())),
+            "a trailing escaped dollar is a literal, not an anchor"
+        );
+        assert_eq!(
+            interpolate_pattern(&expr("{prefix}:{id_part}", true, false), &settings),
+            Err(vec!["id_part".to_string()])
+        );
+    }
+
+    #[test]
+    fn structured_patterns_materialize_into_pattern_everywhere() {
+        let mut schema = SchemaDefinition::new("s");
+        schema.settings.insert("code".into(), "[A-Z]{3}".into());
+        let structured = PatternExpression {
+            syntax: Some("{code}".into()),
+            interpolated: true,
+            partial_match: false,
+        };
+        let mut top = SlotDefinition::new("top");
+        top.structured_pattern = Some(structured.clone());
+        schema.slots.insert("top".into(), top);
+        let mut class = ClassDefinition::new("C");
+        let mut refined = SlotDefinition::new("top");
+        refined.structured_pattern = Some(structured.clone());
+        class.slot_usage.insert("top".into(), refined);
+        schema.classes.insert("C".into(), class);
+        let mut ty = crate::linkml::TypeDefinition::new("Code");
+        ty.structured_pattern = Some(structured);
+        schema.types.insert("Code".into(), ty);
+
+        materialize_structured_patterns(&mut schema);
+        let expected = Some("^(?:[A-Z]{3})$".to_string());
+        assert_eq!(schema.slots["top"].pattern, expected);
+        assert_eq!(schema.classes["C"].slot_usage["top"].pattern, expected);
+        assert_eq!(schema.types["Code"].pattern, expected);
+    }
+
+    #[test]
+    fn a_child_pattern_is_not_overridden_by_an_inherited_structured_pattern() {
+        let mut schema = SchemaDefinition::new("s");
+        schema.settings.insert("code".into(), "[A-Z]+".into());
+        let mut parent = SlotDefinition::new("parent");
+        parent.structured_pattern = Some(PatternExpression {
+            syntax: Some("{code}".into()),
+            interpolated: true,
+            partial_match: false,
+        });
+        schema.slots.insert("parent".into(), parent);
+        let mut child = SlotDefinition::new("child");
+        child.is_a = Some("parent".into());
+        child.pattern = Some("^x$".into());
+        schema.slots.insert("child".into(), child);
+        let mut heir = SlotDefinition::new("heir");
+        heir.is_a = Some("parent".into());
+        schema.slots.insert("heir".into(), heir);
+
+        resolve_slot_inheritance(&mut schema);
+        materialize_structured_patterns(&mut schema);
+        assert_eq!(schema.slots["child"].pattern.as_deref(), Some("^x$"));
+        assert_eq!(
+            schema.slots["heir"].pattern.as_deref(),
+            Some("^(?:[A-Z]+)$")
+        );
+    }
+
     #[test]
     fn inheritance_resolves_even_when_class_names_are_unset() {
         // A schema built by any route that leaves `ClassDefinition.name`
@@ -1891,4 +2673,316 @@ mod tests {
         schema.classes.insert("B".to_string(), b);
         let _ = resolve_effective_slots(schema.classes.get("A").expect("A"), &schema);
     }
+
+    #[test]
+    fn type_facets_fall_back_along_the_typeof_chain() {
+        use crate::linkml::TypeDefinition;
+        let mut schema = SchemaDefinition::new("s");
+        let mut score = TypeDefinition::new("Score");
+        score.typeof_ = Some("integer".into());
+        score.minimum_value = Some(0.0);
+        score.maximum_value = Some(100.0);
+        let mut pass = TypeDefinition::new("Pass");
+        pass.typeof_ = Some("Score".into());
+        pass.minimum_value = Some(50.0);
+        schema.types.insert("Score".into(), score);
+        schema.types.insert("Pass".into(), pass);
+
+        let facets = type_facets(&schema, "Pass");
+        assert_eq!(facets.minimum_value, Some(50.0), "the subtype's own bound");
+        assert_eq!(facets.maximum_value, Some(100.0), "inherited from Score");
+        assert!(type_facets(&schema, "integer").is_empty());
+    }
+
+    #[test]
+    fn grouped_slot_order_gathers_groups_after_ungrouped_slots() {
+        // Groups follow the ungrouped slots, ordered by the grouping
+        // slot's rank; `slot_usage` can move a slot into a group.
+        let schema: SchemaDefinition = serde_norway::from_str(
+            "name: s
+slots:
+  contact:
+    is_grouping_slot: true
+    rank: 2
+  admin:
+    is_grouping_slot: true
+    rank: 1
+  email:
+    slot_group: contact
+  phone:
+    slot_group: contact
+  created:
+    slot_group: admin
+  name: {}
+  zip: {}
+classes:
+  Person:
+    slots: [email, phone, created, name, zip]
+    slot_usage:
+      zip:
+        slot_group: contact
+",
+        )
+        .expect("parse");
+        let effective = resolve_effective_slots_with_provenance(&schema.classes["Person"], &schema);
+        assert_eq!(
+            grouped_slot_order(&effective, &schema),
+            vec![
+                ("name", None),
+                ("created", Some("admin")),
+                ("email", Some("contact")),
+                ("phone", Some("contact")),
+                ("zip", Some("contact")),
+            ]
+        );
+    }
+
+    #[test]
+    fn usage_index_collects_every_inbound_reference() {
+        use crate::linkml::{ClassRule, EnumDefinition, SlotCondition, UniqueKey};
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .enums
+            .insert("Diet".to_string(), EnumDefinition::new("Diet"));
+        schema
+            .classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        schema
+            .classes
+            .insert("Named".to_string(), ClassDefinition::new("Named"));
+
+        let mut eats = SlotDefinition::new("eats");
+        eats.range = Some("Diet".to_string());
+        schema.slots.insert("eats".to_string(), eats);
+        let mut keeps = SlotDefinition::new("keeps");
+        let mut branch = SlotDefinition::new("keeps");
+        branch.range = Some("Animal".to_string());
+        keeps.any_of = vec![branch];
+        schema.slots.insert("keeps".to_string(), keeps);
+        let mut tag = SlotDefinition::new("tag");
+        tag.is_a = Some("eats".to_string());
+        tag.range = Some("string".to_string());
+        schema.slots.insert("tag".to_string(), tag);
+
+        let mut dog = ClassDefinition::new("Dog");
+        dog.is_a = Some("Animal".to_string());
+        dog.mixins = vec!["Named".to_string()];
+        let mut friend = SlotDefinition::new("friend");
+        friend.range = Some("Animal".to_string());
+        dog.attributes.insert("friend".to_string(), friend);
+        dog.rules = vec![ClassRule {
+            title: Some("vegans".to_string()),
+            description: None,
+            preconditions: Some(RuleConditions {
+                slot_conditions: BTreeMap::from([(
+                    "eats".to_string(),
+                    SlotCondition {
+                        range: Some("Diet".to_string()),
+                        ..SlotCondition::default()
+                    },
+                )]),
+                any_of: Vec::new(),
+            }),
+            postconditions: None,
+        }];
+        dog.unique_keys.insert(
+            "by_tag".to_string(),
+            UniqueKey {
+                unique_key_slots: vec!["tag".to_string()],
+                description: None,
+            },
+        );
+        dog.any_of = vec![ClassExpression {
+            is_a: Some("Named".to_string()),
+            ..ClassExpression::default()
+        }];
+        schema.classes.insert("Dog".to_string(), dog);
+
+        let index = usage_index(&schema);
+        let summary = |id: &str| -> Vec<(UsageKind, &str, Option<&str>)> {
+            index[id]
+                .iter()
+                .map(|u| (u.kind, u.source.as_str(), u.detail.as_deref()))
+                .collect()
+        };
+        assert_eq!(
+            summary("class:Animal"),
+            [
+                (UsageKind::Range, "class:Dog", Some("friend")),
+                (UsageKind::AnyOf, "slot:keeps", None),
+                (UsageKind::IsA, "class:Dog", None),
+            ]
+        );
+        assert_eq!(
+            summary("class:Named"),
+            [
+                (UsageKind::AnyOf, "class:Dog", None),
+                (UsageKind::Mixin, "class:Dog", None),
+            ]
+        );
+        assert_eq!(
+            summary("enum:Diet"),
+            [
+                (UsageKind::Range, "slot:eats", None),
+                (UsageKind::Rule, "class:Dog", Some("vegans")),
+            ]
+        );
+        assert_eq!(
+            summary("slot:eats"),
+            [
+                (UsageKind::IsA, "slot:tag", None),
+                (UsageKind::Rule, "class:Dog", Some("vegans")),
+            ]
+        );
+        assert_eq!(
+            summary("slot:tag"),
+            [(UsageKind::UniqueKey, "class:Dog", Some("by_tag"))]
+        );
+        assert!(
+            !index.contains_key("type:string"),
+            "type ranges are not indexed"
+        );
+    }
+
+    #[test]
+    fn usage_index_reaches_nested_slot_combinators() {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        let mut inner = SlotDefinition::new("keeps");
+        inner.range = Some("Animal".to_string());
+        let outer = SlotDefinition {
+            all_of: vec![inner],
+            ..SlotDefinition::new("keeps")
+        };
+        let keeps = SlotDefinition {
+            any_of: vec![outer],
+            ..SlotDefinition::new("keeps")
+        };
+        schema.slots.insert("keeps".to_string(), keeps);
+
+        let index = usage_index(&schema);
+        assert_eq!(
+            index["class:Animal"],
+            [Usage {
+                kind: UsageKind::AllOf,
+                source: "slot:keeps".to_string(),
+                detail: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn usage_index_walks_class_expression_slot_conditions() {
+        use crate::linkml::{EnumDefinition, SlotCondition};
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .enums
+            .insert("Diet".to_string(), EnumDefinition::new("Diet"));
+        let mut dog = ClassDefinition::new("Dog");
+        dog.any_of = vec![ClassExpression {
+            slot_conditions: BTreeMap::from([(
+                "eats".to_string(),
+                SlotCondition {
+                    any_of: vec![SlotCondition {
+                        range: Some("Diet".to_string()),
+                        ..SlotCondition::default()
+                    }],
+                    ..SlotCondition::default()
+                },
+            )]),
+            ..ClassExpression::default()
+        }];
+        schema.classes.insert("Dog".to_string(), dog);
+
+        let index = usage_index(&schema);
+        let summary = |id: &str| -> Vec<(UsageKind, &str, Option<&str>)> {
+            index[id]
+                .iter()
+                .map(|u| (u.kind, u.source.as_str(), u.detail.as_deref()))
+                .collect()
+        };
+        assert_eq!(
+            summary("enum:Diet"),
+            [(UsageKind::AnyOf, "class:Dog", Some("eats"))]
+        );
+        assert_eq!(
+            summary("slot:eats"),
+            [(UsageKind::AnyOf, "class:Dog", None)]
+        );
+    }
+
+    #[test]
+    fn usage_index_records_slots_membership() {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .slots
+            .insert("name".to_string(), SlotDefinition::new("name"));
+        let mut dog = ClassDefinition::new("Dog");
+        dog.slots = vec!["name".to_string()];
+        schema.classes.insert("Dog".to_string(), dog);
+
+        let index = usage_index(&schema);
+        assert_eq!(
+            index["slot:name"],
+            [Usage {
+                kind: UsageKind::Slots,
+                source: "class:Dog".to_string(),
+                detail: None,
+            }]
+        );
+        assert_eq!(UsageKind::Slots.label(), "slots");
+    }
+
+    #[test]
+    fn usage_index_tags_each_combinator_and_marks_none_of_as_exclusion() {
+        let mut schema = SchemaDefinition::new("zoo");
+        for name in ["Cat", "Dog", "Fish", "Wolf"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+        let branch = |range: &str| {
+            let mut b = SlotDefinition::new("pet");
+            b.range = Some(range.to_string());
+            b
+        };
+        let mut pet = SlotDefinition::new("pet");
+        pet.exactly_one_of = vec![branch("Cat"), branch("Dog")];
+        pet.none_of = vec![branch("Wolf")];
+        schema.slots.insert("pet".to_string(), pet);
+
+        let is_a = |parent: &str| ClassExpression {
+            is_a: Some(parent.to_string()),
+            ..ClassExpression::default()
+        };
+        let mut tank = ClassDefinition::new("Tank");
+        tank.all_of = vec![is_a("Fish")];
+        // Every branch nested under a `none_of` is still excluded.
+        tank.none_of = vec![ClassExpression {
+            any_of: vec![is_a("Cat")],
+            ..ClassExpression::default()
+        }];
+        schema.classes.insert("Tank".to_string(), tank);
+
+        let index = usage_index(&schema);
+        let kinds = |id: &str| -> Vec<(UsageKind, &str)> {
+            index[id]
+                .iter()
+                .map(|u| (u.kind, u.source.as_str()))
+                .collect()
+        };
+        assert_eq!(
+            kinds("class:Cat"),
+            [
+                (UsageKind::ExactlyOneOf, "slot:pet"),
+                (UsageKind::NoneOf, "class:Tank"),
+            ]
+        );
+        assert_eq!(kinds("class:Dog"), [(UsageKind::ExactlyOneOf, "slot:pet")]);
+        assert_eq!(kinds("class:Fish"), [(UsageKind::AllOf, "class:Tank")]);
+        assert_eq!(kinds("class:Wolf"), [(UsageKind::NoneOf, "slot:pet")]);
+        assert_eq!(UsageKind::NoneOf.label(), "excluded by none_of");
+    }
 }
@@ -0,0 +1,1047 @@
+//! `panschema lint` — authoring rules for idiomatic LinkML.
+//!
+//! The load diagnostics in [`crate::diagnostics`] catch what would be
+//! *lost* (an unmodeled key, a dangling reference); the rules here catch
+//! what is legal but unidiomatic — a `camelCase` class, a slot nothing
+//! uses, a mapped class without a `class_uri`. Each rule lives in the
+//! static [`RULES`] registry with a stable id and a default [`Severity`];
+//! the manifest's `[lint]` table overrides the severity per id, and `off`
+//! disables a rule outright.
+//!
+//! **Fixes.** A rule whose remedy is mechanical attaches a [`Rename`] to
+//! its finding. [`apply_renames`] rewrites a YAML schema file in place —
+//! the definition key and every reference to it (`is_a`, `mixins`,
+//! `range`, `slots`, `slot_usage`, …) — and touches nothing else, so
+//! comments, ordering, and free text survive. A rename is only offered
+//! when it is unambiguous: the old name names one element kind, and the
+//! new name is free.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::casing::{pascal_case, snake_case};
+use crate::linkml::{RuleConditions, SchemaDefinition, SlotCondition, SlotDefinition};
+use crate::source_map::SourceLocation;
+
+/// How a rule's findings are reported. Spelled lowercase in `[lint]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The rule does not run.
+    Off,
+    /// Findings print; the run still succeeds.
+    Warn,
+    /// Findings print and fail the run.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Off => "off",
+            Self::Warn => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// One registered rule.
+pub struct Rule {
+    /// Stable id — the key in `[lint]` and the tag on each finding.
+    pub id: &'static str,
+    /// One line on what the rule flags, for the reference docs.
+    pub summary: &'static str,
+    /// Severity when `[lint]` doesn't name the rule.
+    pub default: Severity,
+    check: fn(&SchemaDefinition) -> Vec<Hit>,
+}
+
+/// Every rule, in reporting order.
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "class-name-case",
+        summary: "class names are PascalCase",
+        default: Severity::Warn,
+        check: class_name_case,
+    },
+    Rule {
+        id: "slot-name-case",
+        summary: "slot and attribute names are snake_case",
+        default: Severity::Warn,
+        check: slot_name_case,
+    },
+    Rule {
+        id: "missing-description",
+        summary: "classes, slots, attributes, and enums carry a description",
+        default: Severity::Warn,
+        check: missing_description,
+    },
+    Rule {
+        id: "missing-uri",
+        summary: "an element with mappings states its own class_uri/slot_uri",
+        default: Severity::Warn,
+        check: missing_uri,
+    },
+    Rule {
+        id: "unused-slot",
+        summary: "every top-level slot is used by a class or another slot",
+        default: Severity::Warn,
+        check: unused_slot,
+    },
+    Rule {
+        id: "unused-enum",
+        summary: "every enum is the range of some slot",
+        default: Severity::Warn,
+        check: unused_enum,
+    },
+    Rule {
+        id: "enum-value-meaning",
+        summary: "every permissible value is grounded with a meaning",
+        default: Severity::Off,
+        check: enum_value_meaning,
+    },
+    Rule {
+        id: "orphan-class",
+        summary: "every class is connected: inherits, is inherited, is a range, or is the tree_root",
+        default: Severity::Warn,
+        check: orphan_class,
+    },
+];
+
+/// The registered rule with this id.
+pub fn rule(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|r| r.id == id)
+}
+
+/// Which kind of element a [`Rename`] renames — it decides which
+/// definition keys and which reference positions are rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameKind {
+    Class,
+    Slot,
+}
+
+/// A mechanical fix: rename one element everywhere it is defined or
+/// referenced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    pub kind: RenameKind,
+    pub from: String,
+    pub to: String,
+}
+
+/// One rule violation, rated by the effective severity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFinding {
+    /// The id of the rule that fired.
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    /// Where the offending element was written.
+    pub location: Option<SourceLocation>,
+    /// The mechanical fix, when the rule has one and it is unambiguous.
+    pub fix: Option<Rename>,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.rule, self.message)
+    }
+}
+
+/// What a rule reports before a severity is attached.
+struct Hit {
+    message: String,
+    location: Option<SourceLocation>,
+    fix: Option<Rename>,
+}
+
+impl Hit {
+    fn new(message: String, location: Option<SourceLocation>) -> Self {
+        Self {
+            message,
+            location,
+            fix: None,
+        }
+    }
+}
+
+/// Run every rule not turned off, rating each finding by `config`'s
+/// severity for its rule (the rule's default when unnamed).
+pub fn lint(schema: &SchemaDefinition, config: &BTreeMap<String, Severity>) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    for rule in RULES {
+        let severity = config.get(rule.id).copied().unwrap_or(rule.default);
+        if severity == Severity::Off {
+            continue;
+        }
+        findings.extend((rule.check)(schema).into_iter().map(|hit| LintFinding {
+            rule: rule.id,
+            severity,
+            message: hit.message,
+            location: hit.location,
+            fix: hit.fix,
+        }));
+    }
+    findings
+}
+
+fn locate(schema: &SchemaDefinition, path: &[&str]) -> Option<SourceLocation> {
+    schema.source_map.locate(path)
+}
+
+/// Every name an element of any kind is defined under, with how many
+/// kinds use it — a rename is only mechanical when the old name means one
+/// thing and the new one means nothing yet.
+fn defined_names(schema: &SchemaDefinition) -> BTreeMap<&str, BTreeSet<&'static str>> {
+    let mut names: BTreeMap<&str, BTreeSet<&'static str>> = BTreeMap::new();
+    for name in schema.classes.keys() {
+        names.entry(name).or_default().insert("class");
+    }
+    for name in schema.slots.keys() {
+        names.entry(name).or_default().insert("slot");
+    }
+    for class in schema.classes.values() {
+        for name in class.attributes.keys() {
+            names.entry(name).or_default().insert("slot");
+        }
+    }
+    for name in schema.enums.keys() {
+        names.entry(name).or_default().insert("enum");
+    }
+    for name in schema.types.keys() {
+        names.entry(name).or_default().insert("type");
+    }
+    names
+}
+
+fn rename_if_safe(
+    names: &BTreeMap<&str, BTreeSet<&'static str>>,
+    kind: RenameKind,
+    from: &str,
+    to: &str,
+) -> Option<Rename> {
+    let unambiguous = names.get(from).is_some_and(|kinds| kinds.len() == 1);
+    (unambiguous && !names.contains_key(to)).then(|| Rename {
+        kind,
+        from: from.to_string(),
+        to: to.to_string(),
+    })
+}
+
+fn class_name_case(schema: &SchemaDefinition) -> Vec<Hit> {
+    let names = defined_names(schema);
+    schema
+        .classes
+        .keys()
+        .filter_map(|name| {
+            let wanted = pascal_case(name);
+            (wanted != *name).then(|| Hit {
+                message: format!("class `{name}` is not PascalCase; expected `{wanted}`"),
+                location: locate(schema, &["classes", name]),
+                fix: rename_if_safe(&names, RenameKind::Class, name, &wanted),
+            })
+        })
+        .collect()
+}
+
+/// `snake_case` with hyphens read as word breaks, so `has-part` is
+/// flagged (and fixed to `has_part`) like `hasPart` is.
+fn snake(name: &str) -> String {
+    snake_case(&name.replace('-', "_"))
+}
+
+fn slot_name_case(schema: &SchemaDefinition) -> Vec<Hit> {
+    let names = defined_names(schema);
+    let hit = |name: &str, what: String, location| {
+        let wanted = snake(name);
+        (wanted != name).then(|| Hit {
+            message: format!("{what} is not snake_case; expected `{wanted}`"),
+            location,
+            fix: rename_if_safe(&names, RenameKind::Slot, name, &wanted),
+        })
+    };
+    let mut hits: Vec<Hit> = schema
+        .slots
+        .keys()
+        .filter_map(|name| {
+            hit(
+                name,
+                format!("slot `{name}`"),
+                locate(schema, &["slots", name]),
+            )
+        })
+        .collect();
+    for (class_name, class) in &schema.classes {
+        for name in class.attributes.keys() {
+            hits.extend(hit(
+                name,
+                format!("attribute `{name}` of class `{class_name}`"),
+                locate(schema, &["classes", class_name, "attributes", name]),
+            ));
+        }
+    }
+    hits
+}
+
+fn missing_description(schema: &SchemaDefinition) -> Vec<Hit> {
+    let mut hits = Vec::new();
+    for (name, class) in &schema.classes {
+        if class.description.is_none() {
+            hits.push(Hit::new(
+                format!("class `{name}` has no description"),
+                locate(schema, &["classes", name]),
+            ));
+        }
+        for (attr, def) in &class.attributes {
+            if def.description.is_none() {
+                hits.push(Hit::new(
+                    format!("attribute `{attr}` of class `{name}` has no description"),
+                    locate(schema, &["classes", name, "attributes", attr]),
+                ));
+            }
+        }
+    }
+    for (name, slot) in &schema.slots {
+        if slot.description.is_none() {
+            hits.push(Hit::new(
+                format!("slot `{name}` has no description"),
+                locate(schema, &["slots", name]),
+            ));
+        }
+    }
+    for (name, def) in &schema.enums {
+        if def.description.is_none() {
+            hits.push(Hit::new(
+                format!("enum `{name}` has no description"),
+                locate(schema, &["enums", name]),
+            ));
+        }
+    }
+    hits
+}
+
+fn has_mappings(mappings: [&Vec<String>; 5]) -> bool {
+    mappings.iter().any(|m| !m.is_empty())
+}
+
+fn missing_uri(schema: &SchemaDefinition) -> Vec<Hit> {
+    let mut hits = Vec::new();
+    for (name, class) in &schema.classes {
+        let mapped = has_mappings([
+            &class.exact_mappings,
+            &class.close_mappings,
+            &class.related_mappings,
+            &class.narrow_mappings,
+            &class.broad_mappings,
+        ]);
+        if mapped && class.class_uri.is_none() {
+            hits.push(Hit::new(
+                format!(
+                    "class `{name}` declares mappings but no `class_uri`; its IRI is minted \
+                     from the default prefix"
+                ),
+                locate(schema, &["classes", name]),
+            ));
+        }
+    }
+    for (name, slot) in &schema.slots {
+        let mapped = has_mappings([
+            &slot.exact_mappings,
+            &slot.close_mappings,
+            &slot.related_mappings,
+            &slot.narrow_mappings,
+            &slot.broad_mappings,
+        ]);
+        if mapped && slot.slot_uri.is_none() {
+            hits.push(Hit::new(
+                format!(
+                    "slot `{name}` declares mappings but no `slot_uri`; its IRI is minted \
+                     from the default prefix"
+                ),
+                locate(schema, &["slots", name]),
+            ));
+        }
+    }
+    hits
+}
+
+/// Every slot definition the schema states — top-level slots, attributes,
+/// and `slot_usage` refinements — with their combinator branches.
+fn slot_definitions(schema: &SchemaDefinition) -> Vec<&SlotDefinition> {
+    fn with_branches<'a>(def: &'a SlotDefinition, out: &mut Vec<&'a SlotDefinition>) {
+        out.push(def);
+        for (_, branches) in def.combinators() {
+            for branch in branches {
+                with_branches(branch, out);
+            }
+        }
+    }
+    let mut out = Vec::new();
+    for def in schema.slots.values() {
+        with_branches(def, &mut out);
+    }
+    for class in schema.classes.values() {
+        for def in class.attributes.values().chain(class.slot_usage.values()) {
+            with_branches(def, &mut out);
+        }
+    }
+    out
+}
+
+/// Every `range` a rule's slot conditions name.
+fn condition_ranges<'a>(conditions: &'a RuleConditions, out: &mut BTreeSet<&'a str>) {
+    fn of<'a>(condition: &'a SlotCondition, out: &mut BTreeSet<&'a str>) {
+        out.extend(condition.range.as_deref());
+        for branch in &condition.any_of {
+            of(branch, out);
+        }
+    }
+    for condition in conditions.slot_conditions.values() {
+        of(condition, out);
+    }
+    for branch in &conditions.any_of {
+        condition_ranges(branch, out);
+    }
+}
+
+/// Every slot a rule's slot conditions name.
+fn condition_slots<'a>(conditions: &'a RuleConditions, out: &mut BTreeSet<&'a str>) {
+    out.extend(conditions.slot_conditions.keys().map(String::as_str));
+    for branch in &conditions.any_of {
+        condition_slots(branch, out);
+    }
+}
+
+/// Every element name used as a `range` anywhere in the schema.
+fn ranges(schema: &SchemaDefinition) -> BTreeSet<&str> {
+    let mut out: BTreeSet<&str> = slot_definitions(schema)
+        .into_iter()
+        .filter_map(|def| def.range.as_deref())
+        .collect();
+    for class in schema.classes.values() {
+        for rule in &class.rules {
+            for conditions in [&rule.preconditions, &rule.postconditions]
+                .into_iter()
+                .flatten()
+            {
+                condition_ranges(conditions, &mut out);
+            }
+        }
+    }
+    out
+}
+
+fn unused_slot(schema: &SchemaDefinition) -> Vec<Hit> {
+    let mut used: BTreeSet<&str> = BTreeSet::new();
+    for class in schema.classes.values() {
+        used.extend(class.slots.iter().map(String::as_str));
+        used.extend(class.slot_usage.keys().map(String::as_str));
+        for key in class.unique_keys.values() {
+            used.extend(key.unique_key_slots.iter().map(String::as_str));
+        }
+        for rule in &class.rules {
+            for conditions in [&rule.preconditions, &rule.postconditions]
+                .into_iter()
+                .flatten()
+            {
+                condition_slots(conditions, &mut used);
+            }
+        }
+    }
+    for def in slot_definitions(schema) {
+        used.extend(def.is_a.as_deref());
+        used.extend(def.inverse.as_deref());
+    }
+    schema
+        .slots
+        .keys()
+        .filter(|name| !used.contains(name.as_str()))
+        .map(|name| {
+            Hit::new(
+                format!("slot `{name}` is not used by any class or slot"),
+                locate(schema, &["slots", name]),
+            )
+        })
+        .collect()
+}
+
+fn unused_enum(schema: &SchemaDefinition) -> Vec<Hit> {
+    let used = ranges(schema);
+    schema
+        .enums
+        .keys()
+        .filter(|name| !used.contains(name.as_str()))
+        .map(|name| {
+            Hit::new(
+                format!("enum `{name}` is not the range of any slot"),
+                locate(schema, &["enums", name]),
+            )
+        })
+        .collect()
+}
+
+fn enum_value_meaning(schema: &SchemaDefinition) -> Vec<Hit> {
+    let mut hits = Vec::new();
+    for (name, def) in &schema.enums {
+        for (text, value) in &def.permissible_values {
+            if value.meaning.is_none() {
+                hits.push(Hit::new(
+                    format!("value `{text}` of enum `{name}` has no `meaning`"),
+                    locate(schema, &["enums", name, "permissible_values", text]),
+                ));
+            }
+        }
+    }
+    hits
+}
+
+fn orphan_class(schema: &SchemaDefinition) -> Vec<Hit> {
+    let mut connected: BTreeSet<&str> = ranges(schema);
+    for (name, class) in &schema.classes {
+        if class.is_a.is_some() || !class.mixins.is_empty() || class.tree_root {
+            connected.insert(name);
+        }
+        connected.extend(class.is_a.as_deref());
+        connected.extend(class.mixins.iter().map(String::as_str));
+    }
+    for def in slot_definitions(schema) {
+        connected.extend(def.domain.as_deref());
+    }
+    schema
+        .classes
+        .keys()
+        .filter(|name| !connected.contains(name.as_str()))
+        .map(|name| {
+            Hit::new(
+                format!(
+                    "class `{name}` is an orphan: it has no parent or child, is no slot's \
+                     range or domain, and is not the tree_root"
+                ),
+                locate(schema, &["classes", name]),
+            )
+        })
+        .collect()
+}
+
+/// What the mapping at a document path describes, as far as renaming
+/// cares: a class (or anonymous class expression), a slot (or slot
+/// expression, slot condition), a map whose keys name one of those, or
+/// something whose keys and values name neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    Root,
+    /// The top-level `classes:` map.
+    Classes,
+    /// The top-level `slots:` map, or a class's `attributes`,
+    /// `slot_usage` or `slot_conditions`.
+    SlotMap,
+    Class,
+    Slot,
+    /// A list of class expressions under a class combinator.
+    ClassBranches,
+    /// A list of slot expressions under a slot combinator.
+    SlotBranches,
+    Rules,
+    Rule,
+    /// A rule's `preconditions` / `postconditions` / `elseconditions`.
+    Conditions,
+    ConditionBranches,
+    UniqueKeys,
+    UniqueKey,
+    Other,
+}
+
+const COMBINATORS: &[&str] = &["any_of", "all_of", "exactly_one_of", "none_of"];
+
+/// Classify the mapping at `path` by walking it from the document root.
+fn node(path: &[String]) -> Node {
+    path.iter()
+        .fold(Node::Root, |node, segment| match (node, segment.as_str()) {
+            (Node::Root, "classes") => Node::Classes,
+            (Node::Root, "slots") => Node::SlotMap,
+            (Node::Classes, _) => Node::Class,
+            (Node::SlotMap, _) => Node::Slot,
+            (Node::Class, "attributes" | "slot_usage" | "slot_conditions") => Node::SlotMap,
+            (Node::Conditions, "slot_conditions") => Node::SlotMap,
+            (Node::Class, key) if COMBINATORS.contains(&key) => Node::ClassBranches,
+            (Node::Slot, key) if COMBINATORS.contains(&key) => Node::SlotBranches,
+            (Node::ClassBranches, _) => Node::Class,
+            (Node::SlotBranches, _) => Node::Slot,
+            (Node::Class, "rules") => Node::Rules,
+            (Node::Rules, _) => Node::Rule,
+            (Node::Rule, "preconditions" | "postconditions" | "elseconditions") => Node::Conditions,
+            (Node::Conditions, "any_of") => Node::ConditionBranches,
+            (Node::ConditionBranches, _) => Node::Conditions,
+            (Node::Class, "unique_keys") => Node::UniqueKeys,
+            (Node::UniqueKeys, _) => Node::UniqueKey,
+            _ => Node::Other,
+        })
+}
+
+/// The kind of element a key's value (a scalar or a list) names, when
+/// the key sits in a mapping `node` describes and is a reference there.
+fn reference_kind(node: Node, key: &str) -> Option<RenameKind> {
+    match (node, key) {
+        (Node::Class, "is_a" | "mixins" | "union_of" | "disjoint_with" | "apply_to") => {
+            Some(RenameKind::Class)
+        }
+        (Node::Class, "slots") | (Node::UniqueKey, "unique_key_slots") => Some(RenameKind::Slot),
+        (Node::Slot, "is_a" | "mixins" | "inverse") => Some(RenameKind::Slot),
+        (Node::Slot, "range" | "domain") => Some(RenameKind::Class),
+        _ => None,
+    }
+}
+
+/// The kind of element the keys of a mapping `node` describes are names
+/// of: classes under `classes:`, slots under `slots:` and a class's slot
+/// maps.
+fn key_kind(node: Node) -> Option<RenameKind> {
+    match node {
+        Node::Classes => Some(RenameKind::Class),
+        Node::SlotMap => Some(RenameKind::Slot),
+        _ => None,
+    }
+}
+
+/// Rewrite a YAML schema document with every rename applied: the
+/// definition key under `classes:`, `slots:` or a class's slot maps, an
+/// explicit `name:` inside it, and each reference position that names
+/// the rename's kind ([`reference_kind`]), in scalar, block-list, or
+/// flow-list form. A `name:` or reference key anywhere else — an
+/// annotation, a contributor list — is left alone. The document is walked by the same scanner that
+/// builds the diagnostics' source map ([`crate::source_map::scan`]), so the
+/// two agree on what is a key. Quoting, comments, and every other line are
+/// kept byte for byte. Returns the new text and the number of edits.
+pub fn apply_renames(content: &str, renames: &[Rename]) -> (String, usize) {
+    let mut by_line: BTreeMap<usize, Vec<(usize, usize, &str)>> = BTreeMap::new();
+    for entry in crate::source_map::scan(content) {
+        let Some((own, ancestors)) = entry.path.split_last() else {
+            continue;
+        };
+        let value = strip_comment(entry.value);
+        let replacements = by_line.entry(entry.line).or_default();
+
+        if entry.is_item {
+            // A scalar sequence item: a reference when its list is.
+            if let Some((list, owner)) = ancestors.split_last()
+                && let Some(kind) = reference_kind(node(owner), list)
+            {
+                value_replacements(value, entry.value_at, kind, renames, replacements);
+            }
+            continue;
+        }
+        let here = node(ancestors);
+        if let Some(kind) = key_kind(here)
+            && let Some(r) = renames.iter().find(|r| r.kind == kind && r.from == *own)
+        {
+            // Past an opening quote, if the key was written quoted.
+            let key_at = entry.column + usize::from(entry.len != own.len());
+            replacements.push((key_at, own.len(), r.to.as_str()));
+        }
+        // An explicit `name:` restates its definition's key.
+        let own_name = ancestors
+            .split_last()
+            .filter(|_| own == "name")
+            .and_then(|(_, parent)| key_kind(node(parent)));
+        if let Some(kind) = own_name.or_else(|| reference_kind(here, own)) {
+            value_replacements(value, entry.value_at, kind, renames, replacements);
+        }
+    }
+
+    let mut out = String::with_capacity(content.len());
+    let mut edits = 0;
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        match by_line.get(&index).filter(|r| !r.is_empty()) {
+            Some(replacements) => {
+                let body = line.trim_end_matches(['\n', '\r']);
+                out.push_str(&splice(body, replacements));
+                out.push_str(&line[body.len()..]);
+                edits += replacements.len();
+            }
+            None => out.push_str(line),
+        }
+    }
+    (out, edits)
+}
+
+fn strip_comment(value: &str) -> &str {
+    if value.starts_with('#') {
+        return "";
+    }
+    match value.find(" #") {
+        Some(at) => value[..at].trim_end(),
+        None => value.trim_end(),
+    }
+}
+
+fn unquote(token: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = token
+            .strip_prefix(quote)
+            .and_then(|t| t.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    token
+}
+
+/// Queue a replacement for each token of `value` (a scalar or a
+/// `[a, b]` flow list, starting at byte `at` of the line) that names a
+/// renamed element of `kind`.
+fn value_replacements<'r>(
+    value: &str,
+    at: usize,
+    kind: RenameKind,
+    renames: &'r [Rename],
+    out: &mut Vec<(usize, usize, &'r str)>,
+) {
+    let (inner, inner_at) = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(inner) => (inner, at + 1),
+        None => (value, at),
+    };
+    let mut offset = 0;
+    for token in inner.split(',') {
+        let lead = token.len() - token.trim_start().len();
+        let bare = unquote(token.trim());
+        let quote = usize::from(bare.len() != token.trim().len());
+        if let Some(r) = renames.iter().find(|r| r.kind == kind && r.from == bare) {
+            out.push((inner_at + offset + lead + quote, bare.len(), r.to.as_str()));
+        }
+        offset += token.len() + 1;
+    }
+}
+
+/// `line` with each `(start, len, replacement)` applied, in order.
+fn splice(line: &str, replacements: &[(usize, usize, &str)]) -> String {
+    let mut sorted = replacements.to_vec();
+    sorted.sort_by_key(|(start, _, _)| *start);
+    let mut out = String::with_capacity(line.len());
+    let mut cursor = 0;
+    for (start, len, replacement) in sorted {
+        out.push_str(&line[cursor..start]);
+        out.push_str(replacement);
+        cursor = start + len;
+    }
+    out.push_str(&line[cursor..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::Reader;
+
+    fn read(yaml: &str) -> SchemaDefinition {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("schema.yaml");
+        std::fs::write(&path, yaml).expect("write");
+        crate::yaml_reader::YamlReader::new()
+            .read(&path)
+            .expect("read schema")
+    }
+
+    fn rules_fired(findings: &[LintFinding]) -> Vec<(&str, &str)> {
+        findings
+            .iter()
+            .map(|f| (f.rule, f.message.as_str()))
+            .collect()
+    }
+
+    const TIDY: &str = "\
+id: https://example.org/tidy
+name: tidy
+classes:
+  Person:
+    description: A person.
+    tree_root: true
+    slots:
+      - status
+slots:
+  status:
+    description: Standing.
+    range: Status
+enums:
+  Status:
+    description: Standings.
+    permissible_values:
+      active:
+        meaning: ex:Active
+";
+
+    #[test]
+    fn a_tidy_schema_has_no_findings() {
+        let config = BTreeMap::from([("enum-value-meaning".to_string(), Severity::Error)]);
+        assert_eq!(lint(&read(TIDY), &config), vec![]);
+    }
+
+    #[test]
+    fn naming_rules_offer_a_rename_and_point_at_the_definition() {
+        let schema = read(
+            "id: https://example.org/x\nname: x\nclasses:\n  person_record:\n    \
+             description: A record.\n    tree_root: true\n    attributes:\n      \
+             fullName:\n        description: The name.\n",
+        );
+        let findings = lint(&schema, &BTreeMap::new());
+        let class = findings
+            .iter()
+            .find(|f| f.rule == "class-name-case")
+            .unwrap();
+        assert_eq!(
+            class.fix,
+            Some(Rename {
+                kind: RenameKind::Class,
+                from: "person_record".to_string(),
+                to: "PersonRecord".to_string(),
+            })
+        );
+        assert_eq!(class.location.as_ref().map(|l| l.line), Some(4));
+        let attr = findings
+            .iter()
+            .find(|f| f.rule == "slot-name-case")
+            .unwrap();
+        assert_eq!(attr.fix.as_ref().map(|r| r.to.as_str()), Some("full_name"));
+    }
+
+    #[test]
+    fn a_rename_onto_a_taken_name_is_not_offered() {
+        let schema = read(
+            "id: https://example.org/x\nname: x\nclasses:\n  person:\n    \
+             description: A.\n    tree_root: true\n  Person:\n    description: B.\n    \
+             is_a: person\n",
+        );
+        let findings = lint(&schema, &BTreeMap::new());
+        let class = findings
+            .iter()
+            .find(|f| f.rule == "class-name-case")
+            .unwrap();
+        assert_eq!(class.fix, None);
+    }
+
+    #[test]
+    fn config_sets_severity_and_turns_rules_off() {
+        let schema = read(
+            "id: https://example.org/x\nname: x\nclasses:\n  Person:\n    tree_root: true\n\
+             slots:\n  spare:\n    description: Nothing uses this.\n",
+        );
+        let config = BTreeMap::from([
+            ("missing-description".to_string(), Severity::Off),
+            ("unused-slot".to_string(), Severity::Error),
+        ]);
+        let findings = lint(&schema, &config);
+        assert_eq!(
+            rules_fired(&findings),
+            vec![(
+                "unused-slot",
+                "slot `spare` is not used by any class or slot"
+            )]
+        );
+        assert_eq!(findings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn unused_enums_orphans_and_unmapped_uris_are_flagged() {
+        let schema = read(
+            "id: https://example.org/x\nname: x\nclasses:\n  Person:\n    description: P.\n    \
+             tree_root: true\n  Loner:\n    description: L.\n    exact_mappings:\n      \
+             - schema:Thing\nenums:\n  Unused:\n    description: U.\n    permissible_values:\n      \
+             a: {}\n",
+        );
+        let config = BTreeMap::from([("enum-value-meaning".to_string(), Severity::Warn)]);
+        let fired: Vec<&str> = lint(&schema, &config).iter().map(|f| f.rule).collect();
+        assert_eq!(
+            fired,
+            vec![
+                "missing-uri",
+                "unused-enum",
+                "enum-value-meaning",
+                "orphan-class"
+            ]
+        );
+    }
+
+    #[test]
+    fn renames_rewrite_definitions_and_references_only() {
+        let yaml = "\
+classes:
+  person_record:
+    description: A person_record is a record.
+    is_a: base_record
+    slots: [fullName, age]
+    slot_usage:
+      fullName:
+        required: true
+  base_record:
+    description: |
+      range: fullName
+    slots:
+      - fullName
+slots:
+  fullName:  # the name
+    name: fullName
+    range: \"person_record\"
+  age:
+    inverse: fullName
+";
+        let renames = [
+            Rename {
+                kind: RenameKind::Class,
+                from: "person_record".to_string(),
+                to: "PersonRecord".to_string(),
+            },
+            Rename {
+                kind: RenameKind::Slot,
+                from: "fullName".to_string(),
+                to: "full_name".to_string(),
+            },
+        ];
+        let (out, edits) = apply_renames(yaml, &renames);
+        assert_eq!(
+            out,
+            "\
+classes:
+  PersonRecord:
+    description: A person_record is a record.
+    is_a: base_record
+    slots: [full_name, age]
+    slot_usage:
+      full_name:
+        required: true
+  base_record:
+    description: |
+      range: fullName
+    slots:
+      - full_name
+slots:
+  full_name:  # the name
+    name: full_name
+    range: \"PersonRecord\"
+  age:
+    inverse: full_name
+"
+        );
+        assert_eq!(edits, 8);
+    }
+
+    /// A rename touches only the positions that name its kind: not a
+    /// `name:` in a contributor list, not an enum sharing the name, and
+    /// not a class reference to a same-named slot.
+    #[test]
+    fn renames_are_scoped_to_their_kind_and_to_schema_positions() {
+        let yaml = "\
+contributors:
+  - name: person
+annotations:
+  owner:
+    name: person
+classes:
+  person:
+    slots: [person]
+    any_of:
+      - is_a: person
+        slot_conditions:
+          person:
+            range: person
+    rules:
+      - preconditions:
+          slot_conditions:
+            person: {}
+enums:
+  person:
+    is_a: person
+slots:
+  person:
+    is_a: person
+    domain: person
+";
+        let renames = [Rename {
+            kind: RenameKind::Class,
+            from: "person".to_string(),
+            to: "Person".to_string(),
+        }];
+        let (out, edits) = apply_renames(yaml, &renames);
+        assert_eq!(
+            out,
+            yaml.replace("classes:\n  person:", "classes:\n  Person:")
+                .replace("- is_a: person", "- is_a: Person")
+                .replace("range: person", "range: Person")
+                .replace("domain: person", "domain: Person")
+        );
+        assert_eq!(edits, 4);
+
+        let renames = [Rename {
+            kind: RenameKind::Slot,
+            from: "person".to_string(),
+            to: "person_slot".to_string(),
+        }];
+        let (out, edits) = apply_renames(yaml, &renames);
+        assert_eq!(
+            out,
+            yaml.replace("slots: [person]", "slots: [person_slot]")
+                .replace("          person:\n", "          person_slot:\n")
+                .replace("person: {}", "person_slot: {}")
+                .replace(
+                    "slots:\n  person:\n    is_a: person",
+                    "slots:\n  person_slot:\n    is_a: person_slot"
+                )
+        );
+        assert_eq!(edits, 5);
+    }
+
+    #[test]
+    fn a_slot_used_only_by_a_rule_is_not_unused() {
+        let schema = read(
+            "id: https://example.org/x\nname: x\nclasses:\n  Person:\n    tree_root: true\n    \
+             rules:\n      - preconditions:\n          slot_conditions:\n            \
+             flagged: {}\nslots:\n  flagged:\n    description: Checked by a rule.\n",
+        );
+        let config = BTreeMap::from([("unused-slot".to_string(), Severity::Error)]);
+        assert!(
+            !lint(&schema, &config)
+                .iter()
+                .any(|f| f.rule == "unused-slot"),
+            "the rule's slot condition uses `flagged`"
+        );
+    }
+
+    /// Quoted and anchored keys are read the way the source map reads
+    /// them, so a rename reaches every key a diagnostic can point at.
+    #[test]
+    fn renames_follow_the_source_map_scan() {
+        let yaml = "\
+classes:
+  'person_record': &pr  # quoted, anchored
+    is_a: base_record
+enums:
+  Kind:
+    permissible_values:
+      GO:0008150:
+        description: person_record
+";
+        let map = crate::source_map::SourceMap::from_yaml(std::path::Path::new("s.yaml"), yaml);
+        assert!(map.get(&["classes", "person_record", "is_a"]).is_some());
+
+        let renames = [
+            Rename {
+                kind: RenameKind::Class,
+                from: "person_record".to_string(),
+                to: "PersonRecord".to_string(),
+            },
+            Rename {
+                kind: RenameKind::Class,
+                from: "base_record".to_string(),
+                to: "BaseRecord".to_string(),
+            },
+        ];
+        let (out, edits) = apply_renames(yaml, &renames);
+        assert_eq!(
+            out,
+            yaml.replace("'person_record'", "'PersonRecord'")
+                .replace("is_a: base_record", "is_a: BaseRecord")
+        );
+        assert_eq!(edits, 2);
+    }
+}
@@ -27,11 +27,14 @@ pub enum LockfileError {
 }
 
 /// Top-level structure of `panschema.lock`. Each `[[schema]]` array entry
-/// pins one dependency from `panschema.toml`.
+/// pins one dependency from `panschema.toml`; each `[[enum]]` entry pins
+/// the materialized value set of one dynamic enum.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct Lockfile {
     #[serde(default, rename = "schema", skip_serializing_if = "Vec::is_empty")]
     pub entries: Vec<LockEntry>,
+    #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<EnumLockEntry>,
 }
 
 /// One pinned schema dependency.
@@ -57,6 +60,22 @@ pub struct LockEntry {
     pub checksum: String,
 }
 
+/// One pinned dynamic-enum expansion (`reachable_from` and friends).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnumLockEntry {
+    /// The `[schemas]` entry whose load defines the enum.
+    pub schema: String,
+    /// Enum name.
+    pub name: String,
+    /// Ontology documents the expansion read — URLs, or paths for local
+    /// files.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+    /// Checksum of the expanded value set, e.g. `sha256:abc123…`.
+    pub checksum: String,
+}
+
 impl FromStr for Lockfile {
     type Err = LockfileError;
 
@@ -88,6 +107,13 @@ impl Lockfile {
     pub fn entry(&self, name: &str) -> Option<&LockEntry> {
         self.entries.iter().find(|e| e.name == name)
     }
+
+    /// Look up a dynamic-enum entry by schema and enum name.
+    pub fn enum_entry(&self, schema: &str, name: &str) -> Option<&EnumLockEntry> {
+        self.enums
+            .iter()
+            .find(|e| e.schema == schema && e.name == name)
+    }
 }
 
 /// Compute the SHA-256 hex digest of a file's content, returned as
@@ -186,12 +212,41 @@ checksum = "sha256:1111111111111111111111111111111111111111111111111111111111111
                     checksum: "sha256:bbbb".into(),
                 },
             ],
+            enums: vec![EnumLockEntry {
+                schema: "a".into(),
+                name: "Pet".into(),
+                sources: vec!["https://example.org/animals.ttl".into()],
+                checksum: "sha256:cccc".into(),
+            }],
         };
         let s = original.to_toml_string().expect("serialize");
         let parsed: Lockfile = s.parse().expect("parse");
         assert_eq!(parsed, original);
     }
 
+    #[test]
+    fn parses_dynamic_enum_entries_alongside_schemas() {
+        // Lockfiles written before dynamic enums have no `[[enum]]` table
+        // and must keep parsing; ones with it look entries up by schema.
+        let toml = r#"
+[[schema]]
+name = "zoo"
+source = "path:./zoo.yaml"
+checksum = "sha256:00"
+
+[[enum]]
+schema = "zoo"
+name = "AnimalKind"
+sources = ["https://example.org/animals.ttl"]
+checksum = "sha256:11"
+"#;
+        let lf: Lockfile = toml.parse().expect("parse");
+        let e = lf.enum_entry("zoo", "AnimalKind").expect("enum entry");
+        assert_eq!(e.sources, vec!["https://example.org/animals.ttl"]);
+        assert_eq!(e.checksum, "sha256:11");
+        assert!(lf.enum_entry("other", "AnimalKind").is_none());
+    }
+
     #[test]
     fn errors_on_unknown_field_in_entry() {
         let toml = r#"
@@ -260,6 +315,7 @@ unrecognized = "field"
                     checksum: "sha256:bbbb".into(),
                 },
             ],
+            enums: Vec::new(),
         };
         assert!(lf.entry("a").is_some());
         assert!(lf.entry("b").is_some());
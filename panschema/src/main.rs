@@ -78,7 +78,7 @@ enum Commands {
         #[arg(short, long, default_value = "output")]
         output: PathBuf,
 
-        /// Output format: html, ttl, jsonld, rdfxml, ntriples, graph-json, instance-graph-json, rust, postgres, shacl, json-schema, openapi
+        /// Output format: html, ttl, jsonld, rdfxml, ntriples, graph-json, instance-graph-json, rust, postgres, shacl, json-schema, openapi, markdown, mermaid, plantuml, dot, svg
         #[arg(short, long, default_value = "html")]
         format: String,
 
@@ -122,6 +122,54 @@ enum Commands {
         /// at several sites that would collide at one RDF property IRI.
         #[arg(long)]
         strict: bool,
+
+        /// Prune the schema to this named subset (its `in_subset` members
+        /// plus whatever they reference) before the writer runs — e.g. a
+        /// partner-facing JSON Schema without internal classes. Fails when
+        /// the schema declares no such subset.
+        #[arg(long)]
+        subset: Option<String>,
+
+        /// Fill computed slots (those with an `equals_expression`) that the
+        /// --instances data leaves out before rendering or exporting it.
+        /// Stored values are kept; `validate` checks those.
+        #[arg(long)]
+        infer: bool,
+
+        /// Render the HTML page in this language (a BCP 47 tag such as
+        /// `fr`): cards the schema translates through `local_names` /
+        /// `alt_descriptions` show that label and description. The page's
+        /// language switcher still offers every other language. HTML only.
+        #[arg(long)]
+        lang: Option<String>,
+
+        /// Theme directory whose `header.html`, `footer.html`,
+        /// `hero.html`, `theme.css` and `assets/` brand the page. HTML
+        /// only.
+        #[arg(long)]
+        theme: Option<PathBuf>,
+
+        /// Write the HTML page as one file that opens from `file://` with
+        /// no server: the viz bundle, graph JSON and theme CSS, assets and
+        /// fonts are inlined instead of written beside it. HTML only.
+        #[arg(long = "self-contained")]
+        self_contained: bool,
+
+        /// Draw only this class and what it reaches in the class diagram
+        /// (`mermaid`, `plantuml`, `dot`), instead of the whole schema.
+        #[arg(long)]
+        root: Option<String>,
+
+        /// With --root, how many hops from the root class to draw
+        /// (default: every reachable class). Diagram formats only.
+        #[arg(long, requires = "root")]
+        depth: Option<usize>,
+
+        /// Layout the `svg` image is drawn with (`kamada-kawai`,
+        /// `hierarchical`, `stress`, `sgd`, `force-directed`), or `auto`
+        /// for the one the HTML page would open with. SVG only.
+        #[arg(long)]
+        layout: Option<String>,
     },
     /// Scaffold `panschema-publish.toml` in the current directory.
     ///
@@ -218,13 +266,17 @@ enum Commands {
     /// Validate instance data, reporting every constraint violation.
     ///
     /// With `--schema`/`--data`, checks the given file(s) and exits non-zero
-    /// on any violation. With no flags, reads `panschema.toml` instead and
-    /// checks everything it declares — instance conformance for every
-    /// dataset, cross-graph resolution, stated absences — writing nothing;
-    /// findings warn, and `--strict` fails on them.
+    /// on any violation. With `--schema` alone, checks the schema document
+    /// itself against the LinkML metamodel — unknown keys, wrong value
+    /// types, invalid metaslot enum values — exiting non-zero on any issue.
+    /// With no flags, reads `panschema.toml` instead and checks everything
+    /// it declares — instance conformance for every dataset, cross-graph
+    /// resolution, stated absences — writing nothing; findings warn, and
+    /// `--strict` fails on them.
     Validate {
-        /// Schema file (.yaml, .yml, .ttl) the data must conform to.
-        #[arg(short, long, requires = "data")]
+        /// Schema file (.yaml, .yml, .ttl) the data must conform to. Alone,
+        /// the YAML schema document is checked against the LinkML metamodel.
+        #[arg(short, long)]
         schema: Option<PathBuf>,
         /// LinkML instance-data file (a `tree_root` container A-box).
         /// Repeatable: several datasets are each validated, and ids that mint
@@ -235,6 +287,25 @@ enum Commands {
         #[arg(long, conflicts_with = "schema")]
         strict: bool,
     },
+    /// Check a schema against authoring rules for idiomatic LinkML.
+    ///
+    /// Rules cover naming (PascalCase classes, snake_case slots), missing
+    /// descriptions, mapped elements without their own URI, unused slots
+    /// and enums, ungrounded enum values, and orphan classes. Each rule's
+    /// severity (`off`, `warn`, `error`) is set under `[lint]` in
+    /// `panschema.toml`; any `error` finding exits non-zero. With no
+    /// `--schema`, lints every local schema the manifest declares.
+    Lint {
+        /// Schema file (.yaml, .yml, .ttl). When omitted, uses the manifest.
+        #[arg(short, long)]
+        schema: Option<PathBuf>,
+
+        /// Apply the mechanical fixes in place — naming-case renames,
+        /// rewriting the definition and every reference to it across the
+        /// schema's own YAML files — then report what is left.
+        #[arg(long)]
+        fix: bool,
+    },
     /// Write the schema's Postgres DDL as a versioned migration file.
     ///
     /// This command only writes files. It never connects to a database and
@@ -619,15 +690,27 @@ fn read_instance_set(
 /// Read a LinkML instance-data file into the instance model, surfacing each
 /// dangling instance reference (the A-box analog of a dangling schema ref —
 /// the feedback signal an authoring loop uses to self-correct). Fatal under
-/// `--strict`.
+/// `--strict`. With `infer`, missing computed slots are filled first, so the
+/// conformance check sees the values the output will carry.
 fn load_instance_set(
     schema: &panschema::linkml::SchemaDefinition,
     inst_path: &Path,
     strict: bool,
+    infer: bool,
 ) -> anyhow::Result<panschema::instances::InstanceSet> {
     // Each curated graph is judged on its own size: a teaching preview and a
     // worked example sit side by side, and either can outgrow the guideline.
-    let set = read_instance_set(schema, inst_path)?;
+    let mut set = read_instance_set(schema, inst_path)?;
+    if infer {
+        let filled = set.infer_computed_slots(schema);
+        if filled > 0 {
+            eprintln!(
+                "note: filled {filled} computed slot value(s) in {} from their \
+                 `equals_expression`",
+                inst_path.display()
+            );
+        }
+    }
     // ADR-009's role boundary: an exemplar is a curated teaching artifact,
     // rendered whole. A large A-box still renders, but loudly — the
     // query-driven path (subgraph extraction) is the intended tool at scale.
@@ -677,8 +760,22 @@ struct GenerateOptions<'a> {
     html_graph_aspect: Option<&'a str>,
     html_default_layout: Option<&'a str>,
     html_page_layout: Option<panschema::html_writer::PageLayout>,
+    html_site_mode: Option<panschema::html_writer::SiteMode>,
     html_schema_sections: Option<bool>,
+    html_lang: Option<&'a str>,
+    html_theme: Option<&'a Path>,
+    /// Inline the viz bundle and theme files into one `index.html`.
+    html_self_contained: bool,
     rust_time: Option<&'a str>,
+    /// The class a diagram is drawn around, and how many hops out.
+    diagram_root: Option<&'a str>,
+    diagram_depth: Option<usize>,
+    /// The layout an SVG image is drawn with (`auto` when unset).
+    svg_layout: Option<&'a str>,
+    /// Prune the loaded schema to this named subset before rendering.
+    subset: Option<&'a str>,
+    /// Fill missing computed slots in the instance data.
+    infer: bool,
     /// Promote load-time diagnostics to hard errors.
     strict: bool,
     /// Compare a fresh generation against the declared output instead of
@@ -700,8 +797,17 @@ fn generate(
         html_graph_aspect,
         html_default_layout,
         html_page_layout,
+        html_site_mode,
         html_schema_sections,
+        html_lang,
+        html_theme,
+        html_self_contained,
         rust_time,
+        diagram_root,
+        diagram_depth,
+        svg_layout,
+        subset,
+        infer,
         strict,
         check,
     } = *opts;
@@ -739,6 +845,14 @@ fn generate(
     // across the package boundary; it's empty for a single-file `--schema`.
     let schema = panschema::import_resolve::load_schema_with_deps(input, &registry, deps)
         .map_err(|e| anyhow::anyhow!("{}", e))?;
+    // A subset prunes before anything else looks at the schema, so every
+    // writer — and its projection-gap report — sees only the subset's
+    // closure, never the internal elements it leaves out.
+    let schema = match subset {
+        Some(name) => panschema::subset::prune_to_subset(&schema, name)
+            .map_err(|e| anyhow::anyhow!("{}", e))?,
+        None => schema,
+    };
 
     // The unmodeled-construct, unresolved-unique-key, dangling-reference,
     // and untyped-slot warnings are emitted by the shared load path above
@@ -797,11 +911,36 @@ fn generate(
                 html_page_layout.unwrap_or_default(),
                 panschema::html_writer::PageLayout::InstancesFirst
             ))
-            .with_schema_sections(html_schema_sections.unwrap_or(true));
+            .with_schema_sections(html_schema_sections.unwrap_or(true))
+            .with_site_mode(html_site_mode.unwrap_or_default());
+        if let Some(lang) = html_lang {
+            // The source language (English unless `in_language` says
+            // otherwise, as the writer assumes) needs no translation.
+            let source = schema.metadata.in_language.as_deref().unwrap_or("en");
+            let available = schema.translation_languages();
+            if lang != source && !available.contains(lang) {
+                eprintln!(
+                    "warning: the schema translates nothing into `{lang}` (it carries: {}); \
+                     the page renders in the schema's own language",
+                    if available.is_empty() {
+                        "none".to_string()
+                    } else {
+                        available.into_iter().collect::<Vec<_>>().join(", ")
+                    }
+                );
+            }
+            writer = writer.with_lang(lang);
+        }
+        if let Some(dir) = html_theme {
+            let theme =
+                panschema::html_theme::Theme::load(dir).map_err(|e| anyhow::anyhow!("{}", e))?;
+            writer = writer.with_theme(theme);
+        }
+        writer = writer.with_self_contained(html_self_contained);
         // A LinkML instance-data file overrides the schema's embedded OWL
         // individuals as the source for the instance graph.
         for inst_path in instances {
-            let set = load_instance_set(&schema, inst_path, strict)?;
+            let set = load_instance_set(&schema, inst_path, strict, infer)?;
             // The file's stem labels the selector; publish names entries
             // explicitly instead.
             let label = inst_path
@@ -849,7 +988,7 @@ fn generate(
             );
         }
         use panschema::io::Writer;
-        let set = load_instance_set(&schema, inst_path, strict)?;
+        let set = load_instance_set(&schema, inst_path, strict, infer)?;
         let writer: Box<dyn Writer> = match format.to_lowercase().as_str() {
             "ttl" => Box::new(panschema::owl_writer::OwlWriter::new().with_instances(set)),
             "instance-graph-json" => {
@@ -881,6 +1020,21 @@ fn generate(
         RustWriter::with_time_crate(time)
             .write(&schema, output)
             .map_err(|e| anyhow::anyhow!("{}", e))?;
+    } else if let Some(diagram) = panschema::diagram_writer::DiagramFormat::from_id(format) {
+        use panschema::diagram_writer::DiagramWriter;
+        use panschema::io::Writer;
+        let mut writer = DiagramWriter::new(diagram);
+        if let Some(root) = diagram_root {
+            writer = writer.with_root(root);
+        }
+        if let Some(depth) = diagram_depth {
+            writer = writer.with_depth(depth);
+        }
+        writer
+            .write(&schema, output)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+    } else if format.eq_ignore_ascii_case("svg") {
+        write_svg(&schema, output, svg_layout)?;
     } else {
         let writer = registry
             .writer_for_format(format)
@@ -891,6 +1045,15 @@ fn generate(
     }
 
     if check {
+        // Markdown is a directory of pages; it matches when every fresh
+        // page and the list of them do.
+        if render_target.is_dir() {
+            return Ok(if directory_output_matches(&render_target, &declared)? {
+                Vec::new()
+            } else {
+                vec![declared]
+            });
+        }
         let fresh = std::fs::read(&render_target)
             .map_err(|e| anyhow::anyhow!("read fresh generation: {e}"))?;
         let current = std::fs::read(&declared).ok();
@@ -908,6 +1071,11 @@ fn generate(
         "jsonld" => "JSON-LD",
         "rdfxml" => "RDF/XML",
         "ntriples" => "N-Triples",
+        "markdown" => "Markdown documentation",
+        "mermaid" => "Mermaid class diagram",
+        "plantuml" => "PlantUML class diagram",
+        "dot" => "Graphviz class diagram",
+        "svg" => "SVG schema graph",
         _ => format,
     };
     println!(
@@ -919,6 +1087,73 @@ fn generate(
     Ok(Vec::new())
 }
 
+/// Draw the schema graph to `output` as SVG with panschema-viz's layout
+/// engine; `layout` is validated like the HTML page's default layout.
+#[cfg(feature = "svg")]
+fn write_svg(
+    schema: &panschema::linkml::SchemaDefinition,
+    output: &Path,
+    layout: Option<&str>,
+) -> anyhow::Result<()> {
+    use panschema::io::Writer;
+    use panschema::manifest::validate_layout_name;
+    use panschema::svg_writer::SvgWriter;
+    let layout = layout.unwrap_or("auto");
+    if layout != "auto" {
+        validate_layout_name(layout).map_err(|e| anyhow::anyhow!("{}", e))?;
+    }
+    SvgWriter::new()
+        .with_layout(layout)
+        .write(schema, output)
+        .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Without the opt-in `svg` feature there is no layout
+/// engine to draw with; say so rather than reporting an unknown format.
+#[cfg(not(feature = "svg"))]
+fn write_svg(
+    _schema: &panschema::linkml::SchemaDefinition,
+    _output: &Path,
+    _layout: Option<&str>,
+) -> anyhow::Result<()> {
+    anyhow::bail!(
+        "this panschema was built without the `svg` feature; install a release binary \
+         or `cargo install --git … --features svg` to use `--format svg`"
+    )
+}
+
+/// Whether the directory output at `declared` matches the fresh one:
+/// every fresh file is there byte-for-byte. That includes the Markdown
+/// writer's list of the pages it wrote, so a page it would prune shows
+/// up as a changed list. Files the writer never owned (an mdBook
+/// `SUMMARY.md` beside `index.md`, a hand-written page in `classes/`)
+/// don't count as drift.
+fn directory_output_matches(fresh: &Path, declared: &Path) -> anyhow::Result<bool> {
+    for entry in
+        std::fs::read_dir(fresh).map_err(|e| anyhow::anyhow!("read fresh generation: {e}"))?
+    {
+        let path = entry
+            .map_err(|e| anyhow::anyhow!("read fresh generation: {e}"))?
+            .path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let current = declared.join(name);
+        if path.is_dir() {
+            if !directory_output_matches(&path, &current)? {
+                return Ok(false);
+            }
+        } else {
+            let fresh_bytes =
+                std::fs::read(&path).map_err(|e| anyhow::anyhow!("read fresh generation: {e}"))?;
+            if std::fs::read(&current).ok().as_deref() != Some(fresh_bytes.as_slice()) {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
 /// Discover the manifest and load it. Returns the parsed manifest plus the
 /// directory it lives in (paths in the manifest are resolved relative to this).
 fn load_manifest() -> anyhow::Result<(panschema::manifest::Manifest, PathBuf)> {
@@ -1083,6 +1318,135 @@ fn migrate_from_manifest() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `panschema lint`: run the authoring rules over one schema, or (no
+/// `--schema`) over every local package the manifest declares — a fetched
+/// dependency is someone else's schema to lint. Severities come from the
+/// manifest's `[lint]` table, which a one-off `--schema` run also honours
+/// when it sits under a manifest. Any `error`-severity finding fails the run.
+fn lint_command(schema: Option<&Path>, fix: bool) -> anyhow::Result<()> {
+    use panschema::manifest::{Manifest, discover_manifest};
+
+    let (targets, config, deps) = match schema {
+        Some(path) => {
+            let cwd = std::env::current_dir()?;
+            let config = match discover_manifest(&cwd) {
+                Some(manifest_path) => Manifest::from_path(&manifest_path)?.lint,
+                None => std::collections::BTreeMap::new(),
+            };
+            (
+                vec![path.to_path_buf()],
+                config,
+                std::collections::BTreeMap::new(),
+            )
+        }
+        None => {
+            let (manifest, manifest_dir) = load_manifest()?;
+            let deps = resolved_deps(&manifest, &manifest_dir)?;
+            let targets: Vec<PathBuf> = manifest
+                .schemas
+                .iter()
+                .filter(|(_, dep)| dep.path.is_some())
+                .map(|(name, _)| deps[name].clone())
+                .collect();
+            if targets.is_empty() {
+                eprintln!("Manifest declares no local (`path`) schemas; nothing to lint.");
+            }
+            (targets, manifest.lint, deps)
+        }
+    };
+
+    let mut errors = 0usize;
+    for path in &targets {
+        errors += lint_schema(path, &config, &deps, fix)?;
+    }
+    if errors > 0 {
+        anyhow::bail!("{errors} lint error(s)");
+    }
+    Ok(())
+}
+
+/// Lint one schema, printing its findings; returns the `error` count.
+///
+/// Only findings on elements written in the package's own files — those
+/// beneath the schema file's directory — are reported or fixed: an import
+/// from another package is merged in for usage analysis, but its naming
+/// is not this author's to change. With `fix`, the offered renames are
+/// applied to those files and the schema is reloaded, so what prints is
+/// what is left.
+fn lint_schema(
+    path: &Path,
+    config: &std::collections::BTreeMap<String, panschema::lint::Severity>,
+    deps: &std::collections::BTreeMap<String, PathBuf>,
+    fix: bool,
+) -> anyhow::Result<usize> {
+    use panschema::lint::{Rename, Severity, apply_renames, lint};
+
+    let registry = FormatRegistry::with_defaults();
+    let load = || {
+        panschema::import_resolve::load_schema_with_deps(path, &registry, deps)
+            .map_err(|e| anyhow::anyhow!("{e}"))
+    };
+    let root = path.parent().unwrap_or(Path::new(""));
+    let owned = |file: &Path| file.starts_with(root);
+
+    let mut schema = load()?;
+    if fix {
+        let renames: Vec<Rename> = lint(&schema, config)
+            .into_iter()
+            .filter(|f| f.location.as_ref().is_none_or(|l| owned(&l.file)))
+            .filter_map(|f| f.fix)
+            .collect();
+        if !renames.is_empty() && schema.source_map.is_empty() {
+            eprintln!(
+                "warning: --fix rewrites YAML schemas only; {} left unchanged",
+                path.display()
+            );
+        } else if !renames.is_empty() {
+            for file in schema.source_map.files().into_iter().filter(|f| owned(f)) {
+                let content = std::fs::read_to_string(file)?;
+                let (fixed, edits) = apply_renames(&content, &renames);
+                if edits > 0 {
+                    std::fs::write(file, fixed)?;
+                    println!("fixed {}: {edits} edit(s)", file.display());
+                }
+            }
+            for rename in &renames {
+                println!("renamed `{}` to `{}`", rename.from, rename.to);
+            }
+            schema = load()?;
+        }
+    }
+
+    let findings: Vec<_> = lint(&schema, config)
+        .into_iter()
+        .filter(|f| f.location.as_ref().is_none_or(|l| owned(&l.file)))
+        .collect();
+    for finding in &findings {
+        let mut message = finding.to_string();
+        if finding.fix.is_some() {
+            message.push_str(" (fixable with --fix)");
+        }
+        eprintln!(
+            "{}",
+            panschema::source_map::annotate(message, finding.location.as_slice())
+        );
+    }
+    let errors = findings
+        .iter()
+        .filter(|f| f.severity == Severity::Error)
+        .count();
+    if findings.is_empty() {
+        println!("{}: no lint findings", path.display());
+    } else {
+        eprintln!(
+            "{}: {} warning(s), {errors} error(s)",
+            path.display(),
+            findings.len() - errors
+        );
+    }
+    Ok(errors)
+}
+
 /// `panschema generate` (no --schema): walk the manifest and run configured writers.
 fn generate_from_manifest(
     offline: bool,
@@ -1115,6 +1479,24 @@ fn generate_from_manifest(
     let deps = resolved_deps(&manifest, &manifest_dir)?;
     let registry = FormatRegistry::with_defaults();
 
+    // Dynamic enums expand from whatever the ontology cache holds now;
+    // say so when that no longer matches what `panschema fetch` pinned.
+    let lock_path = manifest_dir.join(panschema::lockfile::LOCKFILE_FILENAME);
+    if lock_path.exists() {
+        let lockfile = panschema::lockfile::Lockfile::from_path(&lock_path)?;
+        let enum_drift = verify_dynamic_enums(&manifest, &manifest_dir, &lockfile)?;
+        for line in &enum_drift {
+            eprintln!("warning: {line}");
+        }
+        if strict && !enum_drift.is_empty() {
+            anyhow::bail!(
+                "{} dynamic enum(s) differ from {}; failing because --strict is set",
+                enum_drift.len(),
+                lock_path.display()
+            );
+        }
+    }
+
     let mut produced_anything = false;
     for name in manifest.schemas.keys() {
         let schema_path = &deps[name];
@@ -1159,6 +1541,10 @@ fn generate_from_manifest(
         }
         if let Some(html_out) = &gen_cfg.html {
             let html_out = manifest_dir.join(html_out);
+            let html_theme = gen_cfg
+                .html_theme
+                .as_ref()
+                .map(|dir| manifest_dir.join(dir));
             generate(
                 schema_path,
                 &instances,
@@ -1169,8 +1555,17 @@ fn generate_from_manifest(
                     html_graph_aspect: gen_cfg.html_graph_aspect.as_deref(),
                     html_default_layout: gen_cfg.html_default_layout.as_deref(),
                     html_page_layout: gen_cfg.html_page_layout,
+                    html_site_mode: gen_cfg.html_site_mode,
                     html_schema_sections: gen_cfg.html_schema_sections,
+                    html_lang: gen_cfg.html_lang.as_deref(),
+                    html_theme: html_theme.as_deref(),
+                    html_self_contained: gen_cfg.html_self_contained,
                     rust_time: None,
+                    diagram_root: None,
+                    diagram_depth: None,
+                    svg_layout: None,
+                    subset: gen_cfg.subset.as_deref(),
+                    infer: gen_cfg.infer,
                     strict,
                     check,
                 },
@@ -1180,15 +1575,21 @@ fn generate_from_manifest(
             .with_context(|| format!("schema `{name}`, format `html`"))?;
             produced_anything = true;
         }
-        // Every non-HTML writer is a single output file with a uniform call
-        // shape; fan out over the configured ones. HTML stays separate above
-        // because it writes a directory and takes viz options.
+        // Every other writer has a uniform call shape (Markdown's output
+        // is a directory, the rest a single file); fan out over the
+        // configured ones. HTML stays separate above because it takes viz
+        // options.
         for (format, out_opt) in [
             ("rust", &gen_cfg.rust),
             ("postgres", &gen_cfg.postgres),
             ("shacl", &gen_cfg.shacl),
             ("json-schema", &gen_cfg.json_schema),
             ("openapi", &gen_cfg.openapi),
+            ("markdown", &gen_cfg.markdown),
+            ("mermaid", &gen_cfg.mermaid),
+            ("plantuml", &gen_cfg.plantuml),
+            ("dot", &gen_cfg.dot),
+            ("svg", &gen_cfg.svg),
             ("ttl", &gen_cfg.ttl),
             ("jsonld", &gen_cfg.jsonld),
             ("rdfxml", &gen_cfg.rdfxml),
@@ -1198,8 +1599,9 @@ fn generate_from_manifest(
         ] {
             let Some(out) = out_opt else { continue };
             let out = manifest_dir.join(out);
-            // `rust_time` rides along unconditionally; only the rust
-            // branch of `generate` reads it.
+            // `rust_time`, the diagram root/depth and the SVG layout ride
+            // along unconditionally; only their own branches of `generate`
+            // read them.
             drifted.extend(
                 generate(
                     schema_path,
@@ -1208,6 +1610,11 @@ fn generate_from_manifest(
                     format,
                     &GenerateOptions {
                         rust_time: gen_cfg.rust_time.as_deref(),
+                        diagram_root: gen_cfg.diagram_root.as_deref(),
+                        diagram_depth: gen_cfg.diagram_depth,
+                        svg_layout: gen_cfg.svg_layout.as_deref(),
+                        subset: gen_cfg.subset.as_deref(),
+                        infer: gen_cfg.infer,
                         strict,
                         check,
                         ..Default::default()
@@ -1794,6 +2201,7 @@ fn fetch_from_manifest() -> anyhow::Result<()> {
     let (manifest, manifest_dir) = load_manifest()?;
 
     let mut entries = Vec::with_capacity(manifest.schemas.len());
+    let mut deps = std::collections::BTreeMap::new();
     for (name, dep) in &manifest.schemas {
         let panschema::source::Resolved {
             schema_path,
@@ -1810,19 +2218,84 @@ fn fetch_from_manifest() -> anyhow::Result<()> {
             revision,
             checksum: checksum_file(&schema_path)?,
         });
+        deps.insert(name.clone(), schema_path);
     }
 
-    let lockfile = Lockfile { entries };
+    let enums = fetch_dynamic_enums(&deps)?;
+    let lockfile = Lockfile { entries, enums };
     let lock_path = manifest_dir.join(LOCKFILE_FILENAME);
     lockfile.write_to_path(&lock_path)?;
-    println!(
-        "Fetched {} schema(s); wrote {}",
-        lockfile.entries.len(),
-        lock_path.display()
-    );
+    if lockfile.enums.is_empty() {
+        println!(
+            "Fetched {} schema(s); wrote {}",
+            lockfile.entries.len(),
+            lock_path.display()
+        );
+    } else {
+        println!(
+            "Fetched {} schema(s) and expanded {} dynamic enum(s); wrote {}",
+            lockfile.entries.len(),
+            lockfile.enums.len(),
+            lock_path.display()
+        );
+    }
     Ok(())
 }
 
+/// Download every ontology a manifest schema's dynamic enums query (cache
+/// misses only), then expand the enums and return their lock entries.
+/// Expansion problems warn, as on any load; a failed download fails the
+/// fetch, since the lockfile would otherwise pin an incomplete value set.
+fn fetch_dynamic_enums(
+    deps: &std::collections::BTreeMap<String, PathBuf>,
+) -> anyhow::Result<Vec<panschema::lockfile::EnumLockEntry>> {
+    use panschema::dynamic_enums::{OntologyCache, OntologyLocation};
+
+    let registry = FormatRegistry::with_defaults();
+    let mut entries = Vec::new();
+    for (name, schema_path) in deps {
+        let mut schema =
+            panschema::import_resolve::load_schema_merged(schema_path, &registry, deps)
+                .map_err(|e| anyhow::anyhow!("{e}"))?;
+        if !schema.enums.values().any(|e| e.is_dynamic()) {
+            continue;
+        }
+        let cache = OntologyCache::open_default()
+            .ok_or_else(|| anyhow::anyhow!("no cache directory for ontology documents"))?;
+        let locations =
+            panschema::dynamic_enums::ontology_locations(&schema, base_dir(schema_path));
+        for location in &locations {
+            if let OntologyLocation::Url(url) = location
+                && cache.read(url).is_none()
+            {
+                eprintln!("Fetching ontology {url}");
+                cache.fetch(url, &panschema::labels::HttpLabelSource)?;
+            }
+        }
+        let report = panschema::dynamic_enums::expand_dynamic_enums(
+            &mut schema,
+            base_dir(schema_path),
+            &|location| cache.read_location(location),
+        );
+        for problem in &report.problems {
+            eprintln!("warning: schema `{name}`: {problem}");
+        }
+        entries.extend(report.enums.into_iter().map(|expansion| {
+            panschema::lockfile::EnumLockEntry {
+                schema: name.clone(),
+                name: expansion.name,
+                sources: expansion.sources,
+                checksum: expansion.checksum,
+            }
+        }));
+    }
+    Ok(entries)
+}
+
+fn base_dir(schema_path: &Path) -> &Path {
+    schema_path.parent().unwrap_or(Path::new("."))
+}
+
 /// Bare `validate`: read the manifest and check everything it declares,
 /// writing nothing — each schema's own diagnostics (the same ones
 /// `generate --strict` refuses), every declared dataset's conformance
@@ -1887,8 +2360,9 @@ fn validate_manifest(strict: bool) -> anyhow::Result<()> {
 /// Validate labeled datasets against a loaded schema — per-file
 /// conformance (a file that cannot be read as instance data is itself a
 /// violation, never a vacuous pass) plus the cross-dataset overlap
-/// notes — printing each violation with `prefix` and returning the
-/// count alongside the loaded sets.
+/// notes — printing each violation with `prefix` (and each missing
+/// `recommended` slot as an uncounted warning) and returning the count
+/// alongside the loaded sets.
 fn validate_datasets(
     schema: &panschema::linkml::SchemaDefinition,
     data_paths: &[PathBuf],
@@ -1904,16 +2378,18 @@ fn validate_datasets(
         let value: serde_norway::Value = serde_norway::from_str(&content)
             .map_err(|e| anyhow::anyhow!("parsing data file {}: {}", data_path.display(), e))?;
 
-        let violations = match panschema::validate::instance_set_for(schema, &value) {
+        let (violations, recommended) = match panschema::validate::instance_set_for(schema, &value)
+        {
             Ok(set) => {
                 if let Some(summary) = set.external_reference_summary() {
                     eprintln!("note: {summary}");
                 }
                 let violations = panschema::validate::validate_instances(schema, &set);
+                let recommended = panschema::validate::missing_recommended(schema, &set);
                 sets.push((data_path.display().to_string(), set));
-                violations
+                (violations, recommended)
             }
-            Err(v) => vec![v],
+            Err(v) => (vec![v], Vec::new()),
         };
         for v in &violations {
             if label_lines {
@@ -1923,6 +2399,15 @@ fn validate_datasets(
             }
         }
         violation_count += violations.len();
+        // A missing `recommended` slot is a gap, not a failure: always a
+        // warning, and never counted toward the exit status.
+        for w in &recommended {
+            if label_lines {
+                eprintln!("warning: {}: {w}", data_path.display());
+            } else {
+                eprintln!("warning: {w}");
+            }
+        }
     }
 
     // Overlap across datasets is legitimate when it is deliberate — a teaching
@@ -1943,6 +2428,63 @@ fn validate_datasets(
     Ok((violation_count, sets))
 }
 
+/// `validate --schema` with no data: check the schema document against
+/// the LinkML metamodel — the root file first, so a document the IR parse
+/// would reject (`multivalued: "yes"`) still gets its issues named, then
+/// every local file the load pulled in through `imports:`. The load runs
+/// through the shared path, so its diagnostics print as they do
+/// everywhere. Any metamodel issue fails the run.
+fn validate_schema_document(schema_path: &Path) -> anyhow::Result<()> {
+    use panschema::metaschema::check_file;
+
+    if !matches!(
+        schema_path.extension().and_then(|e| e.to_str()),
+        Some("yaml" | "yml")
+    ) {
+        anyhow::bail!(
+            "metamodel validation reads LinkML YAML; {} is not a YAML schema \
+             (pass --data to validate instance data against it)",
+            schema_path.display()
+        );
+    }
+    let report = |file: &Path| -> anyhow::Result<usize> {
+        let issues = check_file(file).map_err(|e| anyhow::anyhow!("{}: {e}", file.display()))?;
+        for issue in &issues {
+            eprintln!(
+                "{}",
+                panschema::source_map::annotate(
+                    format!("error: {issue}"),
+                    issue.location.as_slice()
+                )
+            );
+        }
+        Ok(issues.len())
+    };
+
+    let mut issues = report(schema_path)?;
+    let registry = FormatRegistry::with_defaults();
+    let no_deps = std::collections::BTreeMap::new();
+    match panschema::import_resolve::load_schema_with_deps(schema_path, &registry, &no_deps) {
+        Ok(schema) => {
+            for file in schema.source_map.files() {
+                if file != schema_path {
+                    issues += report(file)?;
+                }
+            }
+        }
+        Err(e) if issues > 0 => eprintln!("note: the schema does not load: {e}"),
+        Err(e) => anyhow::bail!("{e}"),
+    }
+    if issues > 0 {
+        anyhow::bail!(
+            "{issues} metamodel issue(s) in {} and its imports",
+            schema_path.display()
+        );
+    }
+    println!("{} conforms to the LinkML metamodel", schema_path.display());
+    Ok(())
+}
+
 /// Validate LinkML instance-data files against their schema, printing every
 /// violation and exiting non-zero when the data does not conform. Given more
 /// than one file, each is validated on its own and the set is then checked for
@@ -2035,8 +2577,13 @@ fn verify_from_manifest() -> anyhow::Result<()> {
         .filter(|e| !manifest.schemas.contains_key(&e.name))
         .map(|e| e.name.clone())
         .collect();
+    let enum_drift = verify_dynamic_enums(&manifest, &manifest_dir, &lockfile)?;
 
-    if drift.is_empty() && missing_in_lock.is_empty() && lockfile_only.is_empty() {
+    if drift.is_empty()
+        && missing_in_lock.is_empty()
+        && lockfile_only.is_empty()
+        && enum_drift.is_empty()
+    {
         println!("Verified {} schema(s).", manifest.schemas.len());
         return Ok(());
     }
@@ -2057,9 +2604,59 @@ fn verify_from_manifest() -> anyhow::Result<()> {
             "  - `{name}`: in lockfile but not in manifest (stale; run `panschema fetch` to refresh)\n"
         ));
     }
+    for line in &enum_drift {
+        msg.push_str(&format!("  - {line}\n"));
+    }
     anyhow::bail!("{msg}");
 }
 
+/// Re-expand every manifest schema's dynamic enums from the ontology
+/// cache and compare each value set with its `[[enum]]` lockfile pin.
+/// Returns one line per drifted, unpinned or stale enum.
+fn verify_dynamic_enums(
+    manifest: &panschema::manifest::Manifest,
+    manifest_dir: &Path,
+    lockfile: &panschema::lockfile::Lockfile,
+) -> anyhow::Result<Vec<String>> {
+    let deps = resolved_deps(manifest, manifest_dir)?;
+    let registry = FormatRegistry::with_defaults();
+    let mut lines = Vec::new();
+    let mut seen = std::collections::BTreeSet::new();
+    for (name, schema_path) in &deps {
+        let mut schema =
+            panschema::import_resolve::load_schema_merged(schema_path, &registry, &deps)
+                .map_err(|e| anyhow::anyhow!("{e}"))?;
+        let report = panschema::dynamic_enums::expand_from_default_cache(&mut schema, schema_path);
+        for problem in &report.problems {
+            eprintln!("warning: schema `{name}`: {problem}");
+        }
+        for expansion in &report.enums {
+            seen.insert((name.clone(), expansion.name.clone()));
+            match lockfile.enum_entry(name, &expansion.name) {
+                Some(entry) if entry.checksum == expansion.checksum => {}
+                Some(entry) => lines.push(format!(
+                    "enum `{}` of `{name}`: lockfile has {}, expansion is {}",
+                    expansion.name, entry.checksum, expansion.checksum
+                )),
+                None => lines.push(format!(
+                    "enum `{}` of `{name}`: dynamic but not in lockfile (run `panschema fetch`)",
+                    expansion.name
+                )),
+            }
+        }
+    }
+    for entry in &lockfile.enums {
+        if !seen.contains(&(entry.schema.clone(), entry.name.clone())) {
+            lines.push(format!(
+                "enum `{}` of `{}`: in lockfile but no longer dynamic (stale; run `panschema \
+                 fetch` to refresh)",
+                entry.name, entry.schema
+            ));
+        }
+    }
+    Ok(lines)
+}
+
 /// `panschema publish`: build versioned HTML docs from a
 /// `panschema-publish.toml` with a `[publishing]` section. Each entry
 /// in `versions` (and `edge` if set) becomes `<output>/<ref>/`, and
@@ -2160,6 +2757,14 @@ async fn main() -> anyhow::Result<()> {
             offline,
             refresh_labels,
             strict,
+            subset,
+            infer,
+            lang,
+            theme,
+            self_contained,
+            root,
+            depth,
+            layout,
         } => match schema {
             Some(schema_path) => {
                 if format.to_lowercase() == "html" && !no_graph {
@@ -2182,6 +2787,9 @@ async fn main() -> anyhow::Result<()> {
                         format
                     );
                 }
+                if infer && instances.is_empty() {
+                    eprintln!("warning: --infer fills instance data; ignored without --instances");
+                }
                 // The graph-rendering flags shape the HTML artifact only;
                 // like --instances above, deviating from their defaults for
                 // another format is ignored loudly, not silently.
@@ -2200,6 +2808,42 @@ async fn main() -> anyhow::Result<()> {
                             format
                         );
                     }
+                    if lang.is_some() {
+                        eprintln!(
+                            "warning: --lang only affects HTML output; \
+                             ignored for format `{}`",
+                            format
+                        );
+                    }
+                    if theme.is_some() {
+                        eprintln!(
+                            "warning: --theme only affects HTML output; \
+                             ignored for format `{}`",
+                            format
+                        );
+                    }
+                    if self_contained {
+                        eprintln!(
+                            "warning: --self-contained only affects HTML output; \
+                             ignored for format `{}`",
+                            format
+                        );
+                    }
+                }
+                if root.is_some()
+                    && panschema::diagram_writer::DiagramFormat::from_id(&format).is_none()
+                {
+                    eprintln!(
+                        "warning: --root and --depth only affect the mermaid, plantuml, and dot \
+                         outputs; ignored for format `{}`",
+                        format
+                    );
+                }
+                if layout.is_some() && !format.eq_ignore_ascii_case("svg") {
+                    eprintln!(
+                        "warning: --layout only affects SVG output; ignored for format `{}`",
+                        format
+                    );
                 }
                 let no_overrides = std::collections::BTreeMap::new();
                 let labels = LabelOptions {
@@ -2215,7 +2859,15 @@ async fn main() -> anyhow::Result<()> {
                     &format,
                     &GenerateOptions {
                         include_graph: !no_graph,
+                        html_lang: lang.as_deref(),
+                        html_theme: theme.as_deref(),
+                        html_self_contained: self_contained,
                         rust_time: rust_time.as_deref(),
+                        diagram_root: root.as_deref(),
+                        diagram_depth: depth,
+                        svg_layout: layout.as_deref(),
+                        subset: subset.as_deref(),
+                        infer,
                         strict,
                         check,
                         ..Default::default()
@@ -2235,7 +2887,54 @@ async fn main() -> anyhow::Result<()> {
                     );
                 }
             }
-            None => generate_from_manifest(offline, refresh_labels, strict, check)?,
+            None => {
+                // Ignoring the flag here would publish the whole schema
+                // where a pruned one was asked for, so refuse instead.
+                if let Some(name) = subset {
+                    anyhow::bail!(
+                        "--subset `{name}` needs --schema; in manifest mode set \
+                         `subset = \"{name}\"` in the `[generate.<schema>]` table"
+                    );
+                }
+                if infer {
+                    anyhow::bail!(
+                        "--infer needs --schema; in manifest mode set `infer = true` in the \
+                         `[generate.<schema>]` table"
+                    );
+                }
+                if let Some(lang) = lang {
+                    anyhow::bail!(
+                        "--lang needs --schema; in manifest mode set `html_lang = \"{lang}\"` \
+                         in the `[generate.<schema>]` table"
+                    );
+                }
+                if let Some(theme) = theme {
+                    anyhow::bail!(
+                        "--theme needs --schema; in manifest mode set `html_theme = \"{}\"` \
+                         in the `[generate.<schema>]` table",
+                        theme.display()
+                    );
+                }
+                if self_contained {
+                    anyhow::bail!(
+                        "--self-contained needs --schema; in manifest mode set \
+                         `html_self_contained = true` in the `[generate.<schema>]` table"
+                    );
+                }
+                if let Some(root) = root {
+                    anyhow::bail!(
+                        "--root needs --schema; in manifest mode set `diagram_root = \"{root}\"` \
+                         in the `[generate.<schema>]` table"
+                    );
+                }
+                if let Some(layout) = layout {
+                    anyhow::bail!(
+                        "--layout needs --schema; in manifest mode set `svg_layout = \"{layout}\"` \
+                         in the `[generate.<schema>]` table"
+                    );
+                }
+                generate_from_manifest(offline, refresh_labels, strict, check)?
+            }
         },
         Commands::Init {
             name,
@@ -2267,9 +2966,11 @@ async fn main() -> anyhow::Result<()> {
             data,
             strict,
         } => match schema {
+            Some(schema) if data.is_empty() => validate_schema_document(&schema)?,
             Some(schema) => validate_data(&schema, &data)?,
             None => validate_manifest(strict)?,
         },
+        Commands::Lint { schema, fix } => lint_command(schema.as_deref(), fix)?,
         Commands::Migrate { schema, migrations } => match (schema, migrations) {
             (Some(schema_path), Some(dir)) => {
                 emit_initial_migration(&schema_path, &dir, &std::collections::BTreeMap::new())?;
@@ -2382,6 +3083,14 @@ mod tests {
                 offline,
                 refresh_labels,
                 strict,
+                subset,
+                infer,
+                lang,
+                theme,
+                self_contained,
+                root,
+                depth,
+                layout,
             } => {
                 assert_eq!(schema, Some(PathBuf::from("test.ttl")));
                 assert_eq!(rust_time, None, "rust_time defaults to unset");
@@ -2394,6 +3103,14 @@ mod tests {
                 assert!(!offline); // default false (labels fetched)
                 assert!(!refresh_labels); // default false (cache reused)
                 assert!(!strict); // default false (warn, don't fail)
+                assert_eq!(subset, None); // default unset (whole schema)
+                assert!(!infer); // default false (instance data as authored)
+                assert_eq!(lang, None); // default unset (the schema's own language)
+                assert_eq!(theme, None); // default unset (the built-in look)
+                assert!(!self_contained); // default off (viz bundle beside the page)
+                assert_eq!(root, None); // default: the whole schema
+                assert_eq!(depth, None);
+                assert_eq!(layout, None); // default unset (auto)
             }
             _ => panic!("Expected Generate command"),
         }
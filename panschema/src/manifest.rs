@@ -23,6 +23,8 @@ pub enum ManifestError {
     Parse(#[from] toml::de::Error),
     #[error("invalid manifest field for `[generate.{schema}]`: {message}")]
     InvalidField { schema: String, message: String },
+    #[error("unknown lint rule `{rule}` in `[lint]`; expected one of: {known}")]
+    UnknownLintRule { rule: String, known: String },
 }
 
 /// Top-level structure of `panschema.toml`.
@@ -44,6 +46,11 @@ pub struct Manifest {
     /// keyed by prefix name. Entries win over the built-in map.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub label_sources: BTreeMap<String, String>,
+    /// Severity per `panschema lint` rule id (`off`, `warn`, `error`),
+    /// overriding the rule's default. Applies to every local schema the
+    /// manifest declares.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lint: BTreeMap<String, crate::lint::Severity>,
 }
 
 impl Manifest {
@@ -126,6 +133,16 @@ pub struct GenerateConfig {
     /// A-box (ADR-009 decision 6).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub instances: Vec<PathBuf>,
+    /// Prune the schema to this named LinkML subset (plus whatever its
+    /// members reference) before any writer in this table runs — the
+    /// manifest analog of `--subset`. Applies to every output here, so a
+    /// partner-facing profile lives in its own `[generate.<name>]` table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subset: Option<String>,
+    /// Fill computed slots (`equals_expression`) the `instances` leave out
+    /// before rendering them — the manifest analog of `--infer`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub infer: bool,
     /// Override the schema graph viz aspect ratio in HTML output. Format
     /// `"W:H"` (e.g. `"16:9"`, `"4:3"`). Only meaningful when `html` is set.
     /// Default is 16:8, chosen so a laptop screen fits the graph + browser
@@ -148,12 +165,34 @@ pub struct GenerateConfig {
     /// here. Only meaningful when `html` is set.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub html_page_layout: Option<crate::html_writer::PageLayout>,
+    /// `"single-page"` (default) or `"multi-page"`: a page per class,
+    /// slot, enum and type at a stable URL, plus a sitemap. Only
+    /// meaningful when `html` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_site_mode: Option<crate::html_writer::SiteMode>,
     /// Render the schema reference sections in HTML output — the schema
     /// graph and class/slot/enumeration/type cards. `false` builds the
     /// page around its instance data alone. Only meaningful when `html`
     /// is set.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub html_schema_sections: Option<bool>,
+    /// Page language for HTML output, a BCP 47 tag such as `"fr"`:
+    /// cards the schema translates (`local_names` / `alt_descriptions`)
+    /// show that label and description. Unset renders the schema's own
+    /// language. Only meaningful when `html` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_lang: Option<String>,
+    /// Theme directory, relative to the manifest: `header.html`,
+    /// `footer.html` and `hero.html` overrides, a `theme.css`, and
+    /// `assets/` (see [`crate::html_theme`]). Only meaningful when
+    /// `html` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_theme: Option<PathBuf>,
+    /// Write the HTML page as one self-contained file, with the viz
+    /// bundle and theme files inlined (see [`crate::self_contained`]).
+    /// Only meaningful when `html` is set.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub html_self_contained: bool,
     /// Rust module output file path.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rust: Option<PathBuf>,
@@ -176,6 +215,34 @@ pub struct GenerateConfig {
     /// OpenAPI 3.1 (`components/schemas`) output file path.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub openapi: Option<PathBuf>,
+    /// Markdown documentation output directory (`index.md` plus a page
+    /// per class, enum and type).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<PathBuf>,
+    /// Mermaid class-diagram output file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mermaid: Option<PathBuf>,
+    /// PlantUML class-diagram output file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plantuml: Option<PathBuf>,
+    /// Graphviz DOT class-diagram output file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dot: Option<PathBuf>,
+    /// Draw the class diagrams around this class only (what it reaches)
+    /// rather than the whole schema. Mirrors `generate --root`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagram_root: Option<String>,
+    /// With `diagram_root`, how many hops out to draw; unset draws every
+    /// reachable class. Mirrors `generate --depth`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagram_depth: Option<usize>,
+    /// Static SVG image of the schema graph, output file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub svg: Option<PathBuf>,
+    /// Layout the `svg` image is drawn with, or `auto` (the default) for
+    /// the one the HTML page opens with. Mirrors `generate --layout`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub svg_layout: Option<String>,
     /// OWL/Turtle output file path.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ttl: Option<PathBuf>,
@@ -315,16 +382,30 @@ impl GenerateConfig {
         let populated = GenerateConfig {
             html: Some(PathBuf::from("x")),
             instances: vec![PathBuf::from("x")],
+            subset: Some("x".to_string()),
+            infer: true,
             html_graph_aspect: Some("16:9".to_string()),
             html_default_layout: Some("sgd".to_string()),
             html_page_layout: Some(crate::html_writer::PageLayout::SchemaFirst),
+            html_site_mode: Some(crate::html_writer::SiteMode::SinglePage),
             html_schema_sections: Some(true),
+            html_lang: Some("fr".to_string()),
+            html_theme: Some(PathBuf::from("x")),
+            html_self_contained: true,
             rust: Some(PathBuf::from("x")),
             rust_time: Some("jiff".to_string()),
             postgres: Some(PathBuf::from("x")),
             shacl: Some(PathBuf::from("x")),
             json_schema: Some(PathBuf::from("x")),
             openapi: Some(PathBuf::from("x")),
+            markdown: Some(PathBuf::from("x")),
+            mermaid: Some(PathBuf::from("x")),
+            plantuml: Some(PathBuf::from("x")),
+            dot: Some(PathBuf::from("x")),
+            diagram_root: Some("x".to_string()),
+            diagram_depth: Some(1),
+            svg: Some(PathBuf::from("x")),
+            svg_layout: Some("auto".to_string()),
             ttl: Some(PathBuf::from("x")),
             jsonld: Some(PathBuf::from("x")),
             rdfxml: Some(PathBuf::from("x")),
@@ -341,8 +422,8 @@ impl GenerateConfig {
 /// [`GenerateConfig::html_default_layout`]. Kept in sync with
 /// `panschema-viz`'s `layout::LayoutAlgorithm::ALL` — when a new
 /// variant lands there, add the same identifier here. The dual source
-/// of truth is intentional: `panschema-viz` is a wasm-target crate
-/// and `panschema` doesn't currently depend on it as a Rust crate, so
+/// of truth is intentional: `panschema` only depends on `panschema-viz`
+/// behind the optional `svg` feature (the crates.io build lacks it), so
 /// validation lives in both crates separately.
 const KNOWN_LAYOUT_ALGORITHMS: &[&str] = &[
     "force-directed",
@@ -374,6 +455,22 @@ impl FromStr for Manifest {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let manifest: Manifest = toml::from_str(s)?;
+        // A typo'd rule id would otherwise leave the rule at its default
+        // while the author believes it configured.
+        if let Some(rule) = manifest
+            .lint
+            .keys()
+            .find(|id| crate::lint::rule(id).is_none())
+        {
+            return Err(ManifestError::UnknownLintRule {
+                rule: rule.clone(),
+                known: crate::lint::RULES
+                    .iter()
+                    .map(|r| r.id)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
         for (name, cfg) in &manifest.generate {
             if let Some(layout) = cfg.html_default_layout.as_deref() {
                 validate_layout_name(layout).map_err(|message| ManifestError::InvalidField {
@@ -381,7 +478,13 @@ impl FromStr for Manifest {
                     message,
                 })?;
             }
-            // Eager, like the layout check above: a typo'd `rust_time`
+            if let Some(layout) = cfg.svg_layout.as_deref().filter(|l| *l != "auto") {
+                validate_layout_name(layout).map_err(|message| ManifestError::InvalidField {
+                    schema: name.clone(),
+                    message,
+                })?;
+            }
+            // Eager, like the layout checks above: a typo'd `rust_time`
             // errors at parse — before any output is written — even when
             // no `rust` output is configured beside it.
             if let Some(value) = cfg.rust_time.as_deref()
@@ -790,6 +893,31 @@ my-local = { path = "./local-pkg" }
         assert!(m.label_sources.is_empty());
     }
 
+    #[test]
+    fn parses_lint_severities_and_rejects_unknown_rules() {
+        use crate::lint::Severity;
+        let m: Manifest = "[lint]\norphan-class = \"error\"\nmissing-description = \"off\"\n"
+            .parse()
+            .expect("should parse");
+        assert_eq!(m.lint.get("orphan-class"), Some(&Severity::Error));
+        assert_eq!(m.lint.get("missing-description"), Some(&Severity::Off));
+
+        let err = "[lint]\norphan-classes = \"error\"\n"
+            .parse::<Manifest>()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown lint rule `orphan-classes`"),
+            "{err}"
+        );
+        assert!(
+            "[lint]\norphan-class = \"fatal\"\n"
+                .parse::<Manifest>()
+                .is_err(),
+            "a severity outside off/warn/error is a parse error"
+        );
+    }
+
     #[test]
     fn parses_generate_with_rust_writer_alone_and_alongside_html() {
         // Rust-only.
@@ -934,6 +1062,36 @@ html_default_layout = "wat"
         }
     }
 
+    #[test]
+    fn svg_layout_accepts_auto_and_rejects_unknown_value() {
+        let manifest = |layout: &str| {
+            format!(
+                r#"
+[schemas]
+foo = {{ path = "./foo-pkg" }}
+
+[generate.foo]
+svg = "docs/graph.svg"
+svg_layout = "{layout}"
+"#
+            )
+        };
+        for layout in ["auto", "hierarchical"] {
+            let m = manifest(layout).parse::<Manifest>().expect("should parse");
+            assert_eq!(
+                m.generate.get("foo").unwrap().svg_layout.as_deref(),
+                Some(layout)
+            );
+        }
+        let err = manifest("wat")
+            .parse::<Manifest>()
+            .expect_err("should reject `wat`");
+        assert!(
+            matches!(err, ManifestError::InvalidField { ref message, .. } if message.contains("wat")),
+            "{err:?}"
+        );
+    }
+
     /// A typo'd `rust_time` fails at parse — before any output is
     /// written — even when no `rust` output is configured beside it.
     #[test]
@@ -1469,6 +1627,8 @@ resolve_against = []
         let expected = [
             "html",
             "instances",
+            "subset",
+            "infer",
             "html_graph_aspect",
             "html_default_layout",
             "rust",
@@ -1477,8 +1637,20 @@ resolve_against = []
             "shacl",
             "json_schema",
             "openapi",
+            "markdown",
+            "mermaid",
+            "plantuml",
+            "dot",
+            "diagram_root",
+            "diagram_depth",
+            "svg",
+            "svg_layout",
             "html_page_layout",
+            "html_site_mode",
             "html_schema_sections",
+            "html_lang",
+            "html_theme",
+            "html_self_contained",
             "ttl",
             "jsonld",
             "rdfxml",
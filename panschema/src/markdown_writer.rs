@@ -0,0 +1,756 @@
+//! Markdown writer
+//!
+//! Renders the LinkML IR as a directory of CommonMark pages: `index.md`,
+//! plus one page per class (`classes/`), enum (`enums/`) and type
+//! (`types/`). The pages are meant to live inside an mdBook, MkDocs site or
+//! GitHub wiki and to be reviewed as plain diffs.
+//!
+//! A class page's slot table reads the same resolved view as the HTML class
+//! card: effective slots after inheritance and `slot_usage`, each with its
+//! induced range and cardinality, and `slot_group`s in the card's order.
+//! Descriptions are already Markdown and pass through, with `[[Name]]`
+//! cross-references turned into links. Elements sort by `rank`, then name,
+//! so a regenerated page changes only where the schema did.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::html_writer::element_page_paths;
+use crate::io::{IoError, IoResult, Writer};
+use crate::linkml::{
+    ClassDefinition, CommonMetadata, EnumDefinition, SchemaDefinition, TypeDefinition, rank_order,
+};
+use crate::linkml_resolve::{
+    effective_cardinality, grouped_slot_order, resolve_effective_slots_with_provenance,
+};
+
+/// The per-element page directories.
+const PAGE_DIRS: [&str; 3] = ["classes", "enums", "types"];
+
+/// The list of pages the last run wrote, one relative path per line, at
+/// the output root. The next run deletes the ones it no longer writes.
+const PAGE_LIST: &str = ".panschema-pages";
+
+/// Writer for Markdown documentation (a directory of `.md` pages).
+pub struct MarkdownWriter;
+
+impl MarkdownWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MarkdownWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer for MarkdownWriter {
+    /// Writes every page from [`render_pages`], then [`PAGE_LIST`]. A
+    /// page the previous run listed that this run doesn't produce — a
+    /// removed class's — is deleted rather than left stale; any other
+    /// file in the output, such as a hand-written page, is left alone.
+    fn write(&self, schema: &SchemaDefinition, output: &Path) -> IoResult<()> {
+        let pages = render_pages(schema);
+        if let Ok(previous) = fs::read_to_string(output.join(PAGE_LIST)) {
+            for relative in previous.lines().filter(|p| !pages.contains_key(*p)) {
+                if !is_element_page(relative) {
+                    continue;
+                }
+                match fs::remove_file(output.join(relative)) {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                        return Err(IoError::Io(e));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        for (relative, body) in &pages {
+            let path = output.join(relative);
+            crate::io::ensure_output_parent(&path)?;
+            fs::write(&path, body).map_err(IoError::Io)?;
+        }
+        let list: String = pages.keys().map(|p| format!("{p}\n")).collect();
+        fs::write(output.join(PAGE_LIST), list).map_err(IoError::Io)
+    }
+
+    fn format_id(&self) -> &str {
+        "markdown"
+    }
+}
+
+/// Whether `relative`, a line of a previous run's [`PAGE_LIST`], names a
+/// page this writer could have written: `<dir>/<file>.md` in one of
+/// [`PAGE_DIRS`]. A hand-edited list can't reach anything else.
+fn is_element_page(relative: &str) -> bool {
+    relative.split_once('/').is_some_and(|(dir, file)| {
+        PAGE_DIRS.contains(&dir)
+            && file.ends_with(".md")
+            && !file.contains(['/', '\\'])
+            && file != ".."
+    })
+}
+
+/// Every page of the Markdown docs, keyed by its path relative to the
+/// output directory (`index.md`, `classes/Person.md`, …). Page names come
+/// from [`element_page_paths`], so names that would share a file get
+/// numbered pages as in the HTML site.
+pub fn render_pages(schema: &SchemaDefinition) -> BTreeMap<String, String> {
+    let paths = element_page_paths(schema);
+    let here = Pages {
+        paths: &paths,
+        root: "",
+    };
+    let up = Pages {
+        paths: &paths,
+        root: "../",
+    };
+    let file = |kind: &str, name: &str| format!("{}.md", paths[&format!("{kind}-{name}")]);
+    let mut pages = BTreeMap::new();
+    pages.insert("index.md".to_string(), render_index(schema, here));
+    for (name, class) in ranked(&schema.classes, |c| &c.metadata) {
+        pages.insert(
+            file("class", name),
+            render_class(schema, name, class, Some(up)),
+        );
+    }
+    for (name, en) in ranked(&schema.enums, |e| &e.metadata) {
+        pages.insert(file("enum", name), render_enum(schema, name, en, Some(up)));
+    }
+    for (name, ty) in ranked(&schema.types, |t| &t.metadata) {
+        pages.insert(file("type", name), render_type(schema, name, ty, Some(up)));
+    }
+    pages
+}
+
+/// One class, enum or type page on its own, for embedding in another
+/// document — the mdBook preprocessor's `{{#panschema class Person}}`.
+/// `root` is the way from that document to a directory of these pages,
+/// or `None` to render element names as code instead of links. `None`
+/// when `kind` is not `class`, `enum` or `type`, or names no such element.
+pub fn render_element(
+    schema: &SchemaDefinition,
+    kind: &str,
+    name: &str,
+    root: Option<&str>,
+) -> Option<String> {
+    let paths = root.map(|_| element_page_paths(schema));
+    let pages = root
+        .zip(paths.as_ref())
+        .map(|(root, paths)| Pages { paths, root });
+    match kind {
+        "class" => Some(render_class(schema, name, schema.classes.get(name)?, pages)),
+        "enum" => Some(render_enum(schema, name, schema.enums.get(name)?, pages)),
+        "type" => Some(render_type(schema, name, schema.types.get(name)?, pages)),
+        _ => None,
+    }
+}
+
+/// Where element names link: each element's page path (see
+/// [`element_page_paths`]) and `root`, the way from the current page to
+/// the output directory (`""` from the index, `"../"` from an element
+/// page). Element-rendering functions take `Option<Pages>`, `None`
+/// rendering names as code instead.
+#[derive(Clone, Copy)]
+struct Pages<'a> {
+    paths: &'a HashMap<String, String>,
+    root: &'a str,
+}
+
+/// A map's entries in card order: `rank`, then name.
+fn ranked<'a, T>(
+    elements: &'a BTreeMap<String, T>,
+    metadata: impl Fn(&T) -> &CommonMetadata,
+) -> Vec<(&'a str, &'a T)> {
+    let mut sorted: Vec<(&str, &T)> = elements.iter().map(|(k, v)| (k.as_str(), v)).collect();
+    sorted.sort_by(|a, b| rank_order((metadata(a.1), a.0), (metadata(b.1), b.0)));
+    sorted
+}
+
+fn render_index(schema: &SchemaDefinition, pages: Pages<'_>) -> String {
+    let here = Some(pages);
+    let title = schema.title.as_deref().unwrap_or(&schema.name);
+    let mut blocks = vec![format!("# {title}")];
+    if let Some(desc) = &schema.description {
+        blocks.push(link_xrefs(desc, schema, here));
+    }
+    let mut facts = Vec::new();
+    if let Some(id) = &schema.id {
+        facts.push(format!("- **ID:** `{id}`"));
+    }
+    if let Some(version) = &schema.version {
+        facts.push(format!("- **Version:** {version}"));
+    }
+    if let Some(license) = &schema.license {
+        facts.push(format!("- **License:** {license}"));
+    }
+    if !facts.is_empty() {
+        blocks.push(facts.join("\n"));
+    }
+
+    if !schema.classes.is_empty() {
+        let rows = ranked(&schema.classes, |c| &c.metadata)
+            .into_iter()
+            .map(|(name, class)| {
+                vec![
+                    element_link(schema, name, here),
+                    summary(class.description.as_deref(), schema, here),
+                ]
+            });
+        blocks.push("## Classes".to_string());
+        blocks.push(table(&["Class", "Description"], rows));
+    }
+    if !schema.slots.is_empty() {
+        let rows = ranked(&schema.slots, |s| &s.metadata)
+            .into_iter()
+            .map(|(name, slot)| {
+                vec![
+                    format!("`{name}`"),
+                    slot.range
+                        .as_deref()
+                        .map_or_else(|| "—".to_string(), |r| element_link(schema, r, here)),
+                    summary(slot.description.as_deref(), schema, here),
+                ]
+            });
+        blocks.push("## Slots".to_string());
+        blocks.push(table(&["Slot", "Range", "Description"], rows));
+    }
+    if !schema.enums.is_empty() {
+        let rows = ranked(&schema.enums, |e| &e.metadata)
+            .into_iter()
+            .map(|(name, en)| {
+                vec![
+                    element_link(schema, name, here),
+                    summary(en.description.as_deref(), schema, here),
+                ]
+            });
+        blocks.push("## Enumerations".to_string());
+        blocks.push(table(&["Enumeration", "Description"], rows));
+    }
+    if !schema.types.is_empty() {
+        let rows = ranked(&schema.types, |t| &t.metadata)
+            .into_iter()
+            .map(|(name, ty)| {
+                vec![
+                    element_link(schema, name, here),
+                    ty.typeof_
+                        .as_deref()
+                        .or(ty.uri.as_deref())
+                        .map_or_else(|| "—".to_string(), |b| element_link(schema, b, here)),
+                    summary(ty.description.as_deref(), schema, here),
+                ]
+            });
+        blocks.push("## Types".to_string());
+        blocks.push(table(&["Type", "Base", "Description"], rows));
+    }
+    finish(blocks)
+}
+
+fn render_class(
+    schema: &SchemaDefinition,
+    name: &str,
+    class: &ClassDefinition,
+    pages: Option<Pages<'_>>,
+) -> String {
+    let mut blocks = vec![format!("# {}", display_name(&class.annotations, name))];
+    let kind = if class.r#abstract {
+        "Abstract class"
+    } else {
+        "Class"
+    };
+    blocks.push(match &class.class_uri {
+        Some(uri) => format!("{kind} · `{uri}`"),
+        None => kind.to_string(),
+    });
+    push_header_notes(
+        &mut blocks,
+        schema,
+        class.deprecated.as_deref(),
+        class.description.as_deref(),
+        &class.aliases,
+        &class.in_subset,
+        pages,
+    );
+
+    let subclasses: Vec<&str> = ranked(&schema.classes, |c| &c.metadata)
+        .into_iter()
+        .filter(|(_, c)| c.is_a.as_deref() == Some(name))
+        .map(|(n, _)| n)
+        .collect();
+    let mut lineage = Vec::new();
+    if let Some(parent) = &class.is_a {
+        lineage.push(format!(
+            "- **Is a:** {}",
+            element_link(schema, parent, pages)
+        ));
+    }
+    if !class.mixins.is_empty() {
+        lineage.push(format!(
+            "- **Mixins:** {}",
+            links(schema, class.mixins.iter().map(String::as_str), pages)
+        ));
+    }
+    if !subclasses.is_empty() {
+        lineage.push(format!(
+            "- **Subclasses:** {}",
+            links(schema, subclasses.into_iter(), pages)
+        ));
+    }
+    if !lineage.is_empty() {
+        blocks.push("## Inheritance".to_string());
+        blocks.push(lineage.join("\n"));
+    }
+
+    let resolved = resolve_effective_slots_with_provenance(class, schema);
+    if !resolved.is_empty() {
+        blocks.push("## Slots".to_string());
+        // One table per group, ungrouped slots first — the card's order.
+        let mut groups: Vec<(Option<&str>, Vec<Vec<String>>)> = Vec::new();
+        for (slot_name, group) in grouped_slot_order(&resolved, schema) {
+            let rs = &resolved[slot_name];
+            let slot = &rs.definition;
+            let cardinality = effective_cardinality(slot);
+            let (range, cardinality) = if rs.induced.suppressed {
+                ("—".to_string(), "0 (no value)".to_string())
+            } else if rs.induced.ranges.is_empty() {
+                ("—".to_string(), cardinality.multiplicity())
+            } else {
+                let range = rs
+                    .induced
+                    .ranges
+                    .iter()
+                    .map(|r| element_link(schema, r, pages))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                let mut label = cardinality.multiplicity();
+                if slot.recommended && !cardinality.required {
+                    label.push_str(" (recommended)");
+                }
+                (range, label)
+            };
+            let row = vec![
+                format!("`{slot_name}`"),
+                range,
+                cardinality,
+                rs.provenance
+                    .origin_label(name)
+                    .map(|o| cell(&o))
+                    .unwrap_or_default(),
+                summary(slot.description.as_deref(), schema, pages),
+            ];
+            match groups.last_mut() {
+                Some((g, rows)) if *g == group => rows.push(row),
+                _ => groups.push((group, vec![row])),
+            }
+        }
+        for (group, rows) in groups {
+            if let Some(group) = group {
+                blocks.push(format!("### {group}"));
+            }
+            blocks.push(table(
+                &["Slot", "Range", "Cardinality", "From", "Description"],
+                rows.into_iter(),
+            ));
+        }
+    }
+
+    let mappings: Vec<String> = [
+        ("exact", &class.exact_mappings),
+        ("close", &class.close_mappings),
+        ("related", &class.related_mappings),
+        ("narrow", &class.narrow_mappings),
+        ("broad", &class.broad_mappings),
+    ]
+    .into_iter()
+    .flat_map(|(kind, curies)| curies.iter().map(move |c| format!("- {kind}: `{c}`")))
+    .collect();
+    if !mappings.is_empty() {
+        blocks.push("## Mappings".to_string());
+        blocks.push(mappings.join("\n"));
+    }
+    push_see_also(&mut blocks, &class.see_also);
+    finish(blocks)
+}
+
+fn render_enum(
+    schema: &SchemaDefinition,
+    name: &str,
+    en: &EnumDefinition,
+    pages: Option<Pages<'_>>,
+) -> String {
+    let mut blocks = vec![
+        format!("# {}", display_name(&en.annotations, name)),
+        "Enumeration".to_string(),
+    ];
+    push_header_notes(
+        &mut blocks,
+        schema,
+        en.deprecated.as_deref(),
+        en.description.as_deref(),
+        &en.aliases,
+        &en.in_subset,
+        pages,
+    );
+    if !en.permissible_values.is_empty() {
+        let rows = ranked(&en.permissible_values, |pv| &pv.metadata)
+            .into_iter()
+            .map(|(_, pv)| {
+                vec![
+                    format!("`{}`", pv.text),
+                    pv.meaning
+                        .as_deref()
+                        .map(|m| format!("`{m}`"))
+                        .unwrap_or_default(),
+                    summary(pv.description.as_deref(), schema, pages),
+                ]
+            });
+        blocks.push("## Permissible values".to_string());
+        blocks.push(table(&["Value", "Meaning", "Description"], rows));
+    }
+    push_see_also(&mut blocks, &en.see_also);
+    finish(blocks)
+}
+
+fn render_type(
+    schema: &SchemaDefinition,
+    name: &str,
+    ty: &TypeDefinition,
+    pages: Option<Pages<'_>>,
+) -> String {
+    let mut blocks = vec![
+        format!("# {}", display_name(&ty.annotations, name)),
+        "Type".to_string(),
+    ];
+    push_header_notes(
+        &mut blocks,
+        schema,
+        ty.deprecated.as_deref(),
+        ty.description.as_deref(),
+        &ty.aliases,
+        &ty.in_subset,
+        pages,
+    );
+    let mut facts = Vec::new();
+    if let Some(base) = &ty.typeof_ {
+        facts.push(format!(
+            "- **Type of:** {}",
+            element_link(schema, base, pages)
+        ));
+    }
+    if let Some(uri) = &ty.uri {
+        facts.push(format!("- **URI:** `{uri}`"));
+    }
+    if let Some(base) = &ty.base {
+        facts.push(format!("- **Base:** `{base}`"));
+    }
+    if let Some(pattern) = &ty.pattern {
+        facts.push(format!("- **Pattern:** `{pattern}`"));
+    }
+    if let Some(min) = ty.minimum_value {
+        facts.push(format!("- **Minimum:** {min}"));
+    }
+    if let Some(max) = ty.maximum_value {
+        facts.push(format!("- **Maximum:** {max}"));
+    }
+    if !facts.is_empty() {
+        blocks.push(facts.join("\n"));
+    }
+    push_see_also(&mut blocks, &ty.see_also);
+    finish(blocks)
+}
+
+/// The blocks every element page opens with after its kind line: the
+/// deprecation note, the description, then aliases and subsets.
+fn push_header_notes(
+    blocks: &mut Vec<String>,
+    schema: &SchemaDefinition,
+    deprecated: Option<&str>,
+    description: Option<&str>,
+    aliases: &[String],
+    in_subset: &[String],
+    pages: Option<Pages<'_>>,
+) {
+    if let Some(note) = deprecated {
+        blocks.push(format!("> **Deprecated:** {}", cell(note)));
+    }
+    if let Some(desc) = description {
+        blocks.push(link_xrefs(desc, schema, pages));
+    }
+    let mut facts = Vec::new();
+    if !aliases.is_empty() {
+        facts.push(format!("- **Aliases:** {}", aliases.join(", ")));
+    }
+    if !in_subset.is_empty() {
+        facts.push(format!("- **Subsets:** {}", in_subset.join(", ")));
+    }
+    if !facts.is_empty() {
+        blocks.push(facts.join("\n"));
+    }
+}
+
+fn push_see_also(blocks: &mut Vec<String>, see_also: &[String]) {
+    if see_also.is_empty() {
+        return;
+    }
+    blocks.push("## See also".to_string());
+    blocks.push(
+        see_also
+            .iter()
+            .map(|link| {
+                if link.contains("://") {
+                    format!("- <{link}>")
+                } else {
+                    format!("- `{link}`")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+}
+
+/// The page heading: the `panschema:label` annotation when declared, as
+/// on the HTML cards, else the name.
+fn display_name<'a>(annotations: &'a BTreeMap<String, String>, name: &'a str) -> &'a str {
+    annotations
+        .get("panschema:label")
+        .map_or(name, String::as_str)
+}
+
+/// `name` as a link to its page, from the page `pages` describes. A
+/// slot, a built-in type or an unknown name has no page and renders as
+/// code, as does every name when there are no pages to link (`pages` is
+/// `None`).
+fn element_link(schema: &SchemaDefinition, name: &str, pages: Option<Pages<'_>>) -> String {
+    let kind = if schema.classes.contains_key(name) {
+        "class"
+    } else if schema.enums.contains_key(name) {
+        "enum"
+    } else if schema.types.contains_key(name) {
+        "type"
+    } else {
+        return format!("`{name}`");
+    };
+    match pages.and_then(|p| Some((p.root, p.paths.get(&format!("{kind}-{name}"))?))) {
+        Some((root, path)) => format!("[{name}]({root}{path}.md)"),
+        None => format!("`{name}`"),
+    }
+}
+
+/// Element-page links to several elements, comma-separated.
+fn links<'a>(
+    schema: &SchemaDefinition,
+    names: impl Iterator<Item = &'a str>,
+    pages: Option<Pages<'_>>,
+) -> String {
+    names
+        .map(|n| element_link(schema, n, pages))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Expand the `[[Name]]` cross-references the HTML writer links into
+/// Markdown links; a name with no page to link keeps its brackets.
+fn link_xrefs(text: &str, schema: &SchemaDefinition, pages: Option<Pages<'_>>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        let Some(len) = rest[start + 2..].find("]]") else {
+            break;
+        };
+        let name = &rest[start + 2..start + 2 + len];
+        let end = start + 4 + len;
+        out.push_str(&rest[..start]);
+        let link = element_link(schema, name, pages);
+        if link.starts_with('[') {
+            out.push_str(&link);
+        } else {
+            out.push_str(&rest[start..end]);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A description's first paragraph, flattened into a table cell.
+fn summary(
+    description: Option<&str>,
+    schema: &SchemaDefinition,
+    pages: Option<Pages<'_>>,
+) -> String {
+    description
+        .and_then(|d| d.split("\n\n").next())
+        .map(|first| cell(&link_xrefs(first, schema, pages)))
+        .unwrap_or_default()
+}
+
+/// Text made safe for one table cell: whitespace runs (newlines
+/// included) collapse to a space and `|` is escaped.
+fn cell(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
+fn table(header: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", "---|".repeat(header.len())),
+    ];
+    lines.extend(rows.map(|row| format!("| {} |", row.join(" | "))));
+    lines.join("\n")
+}
+
+/// Blocks separated by a blank line, ending in a newline.
+fn finish(blocks: Vec<String>) -> String {
+    let mut page = blocks.join("\n\n");
+    page.push('\n');
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{PermissibleValue, SlotDefinition};
+
+    fn sample() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        schema.title = Some("People".to_string());
+        let mut status = EnumDefinition::new("Status");
+        let mut active = PermissibleValue::new("ACTIVE");
+        active.meaning = Some("obo:0001".to_string());
+        status
+            .permissible_values
+            .insert("ACTIVE".to_string(), active);
+        schema.enums.insert("Status".to_string(), status);
+
+        let mut named = ClassDefinition::new("Named");
+        let mut name = SlotDefinition::new("name");
+        name.range = Some("string".to_string());
+        name.required = true;
+        name.description = Some("What it's called | the label.\n\nMore.".to_string());
+        named.attributes.insert("name".to_string(), name);
+        schema.classes.insert("Named".to_string(), named);
+
+        let mut person = ClassDefinition::new("Person");
+        person.is_a = Some("Named".to_string());
+        person.description = Some("Someone with a [[Status]].".to_string());
+        person.exact_mappings = vec!["schema:Person".to_string()];
+        let mut state = SlotDefinition::new("state");
+        state.range = Some("Status".to_string());
+        state.recommended = true;
+        let mut email = SlotDefinition::new("email");
+        email.range = Some("string".to_string());
+        email.multivalued = true;
+        email.slot_group = Some("contact".to_string());
+        person.attributes.insert("state".to_string(), state);
+        person.attributes.insert("email".to_string(), email);
+        schema.classes.insert("Person".to_string(), person);
+        schema
+    }
+
+    #[test]
+    fn class_page_tables_the_resolved_slots_in_card_order() {
+        let pages = render_pages(&sample());
+        let person = &pages["classes/Person.md"];
+        assert!(
+            person
+                .starts_with("# Person\n\nClass\n\nSomeone with a [Status](../enums/Status.md).\n")
+        );
+        assert!(person.contains("- **Is a:** [Named](../classes/Named.md)"));
+        assert!(
+            person.contains("| `name` | `string` | 1 | Named | What it's called \\| the label. |")
+        );
+        assert!(
+            person
+                .contains("| `state` | [Status](../enums/Status.md) | 0..1 (recommended) |  |  |")
+        );
+        let group = person
+            .find("### contact")
+            .expect("a heading per slot_group");
+        assert!(
+            person.find("| `state`").unwrap() < group,
+            "ungrouped slots first"
+        );
+        assert!(person[group..].contains("| `email` | `string` | 0..* |"));
+        assert!(person.contains("- exact: `schema:Person`"));
+        assert!(
+            pages["classes/Named.md"].contains("- **Subclasses:** [Person](../classes/Person.md)")
+        );
+    }
+
+    #[test]
+    fn index_and_enum_pages_link_every_element() {
+        let pages = render_pages(&sample());
+        let index = &pages["index.md"];
+        assert!(index.starts_with("# People\n"));
+        assert!(index.contains(
+            "| [Person](classes/Person.md) | Someone with a [Status](enums/Status.md). |"
+        ));
+        assert!(index.contains("| [Status](enums/Status.md) |  |"));
+        assert!(
+            pages["enums/Status.md"].contains("| `ACTIVE` | `obo:0001` |  |"),
+            "{}",
+            pages["enums/Status.md"]
+        );
+    }
+
+    #[test]
+    fn rewriting_prunes_the_pages_of_removed_elements() {
+        let out = tempfile::tempdir().unwrap();
+        let mut schema = sample();
+        MarkdownWriter::new().write(&schema, out.path()).unwrap();
+        assert!(out.path().join("classes/Named.md").is_file());
+        let notes = out.path().join("classes/notes.txt");
+        fs::write(&notes, "kept").unwrap();
+        let guide = out.path().join("classes/Guide.md");
+        fs::write(&guide, "# A hand-written page").unwrap();
+
+        schema.classes.remove("Named");
+        schema.classes.get_mut("Person").unwrap().is_a = None;
+        MarkdownWriter::new().write(&schema, out.path()).unwrap();
+        assert!(!out.path().join("classes/Named.md").exists());
+        assert!(out.path().join("classes/Person.md").is_file());
+        assert!(notes.is_file(), "only stale pages go");
+        assert!(guide.is_file(), "a page the writer never wrote stays");
+        assert_eq!(
+            fs::read_to_string(out.path().join(PAGE_LIST)).unwrap(),
+            "classes/Person.md\nenums/Status.md\nindex.md\n"
+        );
+
+        // A hand-edited list can't point the cleanup elsewhere.
+        fs::write(
+            out.path().join(PAGE_LIST),
+            "index.md\n../outside.md\nclasses/notes.txt\n",
+        )
+        .unwrap();
+        MarkdownWriter::new().write(&schema, out.path()).unwrap();
+        assert!(notes.is_file());
+    }
+
+    #[test]
+    fn colliding_names_get_numbered_pages_and_links() {
+        let mut schema = SchemaDefinition::new("people");
+        for name in ["Person", "person", "a b", "a_b"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+        let pages = render_pages(&schema);
+        for page in [
+            "classes/Person.md",
+            "classes/person-2.md",
+            "classes/a_b.md",
+            "classes/a_b-2.md",
+        ] {
+            assert!(pages.contains_key(page), "{page}: {:?}", pages.keys());
+        }
+        let index = &pages["index.md"];
+        assert!(index.contains("[person](classes/person-2.md)"), "{index}");
+        assert!(index.contains("[a_b](classes/a_b-2.md)"), "{index}");
+        assert!(pages["classes/person-2.md"].starts_with("# person\n"));
+    }
+}
@@ -0,0 +1,397 @@
+//! The `mdbook-panschema` preprocessor: schema elements embedded inline
+//! in book chapters.
+//!
+//! mdBook asks `mdbook-panschema supports <renderer>`, then pipes the
+//! `[context, book]` JSON through `mdbook-panschema` and reads the
+//! rewritten book back from stdout. Each directive in a chapter is
+//! replaced with what it names, resolved against the schema `book.toml`
+//! points at (the path is relative to the book root):
+//!
+//! ```toml
+//! [preprocessor.panschema]
+//! schema = "../schema/people.yaml"
+//! ```
+//!
+//! - `{{#panschema class Person}}`, `{{#panschema enum Status}}` and
+//!   `{{#panschema type Email}}` render the element's card: the page
+//!   `generate --format markdown` writes for it, one heading level down so
+//!   it sits under the chapter's title.
+//! - `{{#panschema graph Person depth=2}}` renders the element's
+//!   neighborhood (see [`GraphData::neighborhood`]) as an inline SVG,
+//!   drawn as `generate --format svg` draws the whole schema; `depth`
+//!   defaults to 1. It needs the `svg` feature.
+//!
+//! A directive naming an element the schema doesn't have fails the build,
+//! with every bad directive listed by chapter. A leading backslash
+//! (`\{{#panschema …}}`) keeps a directive literal, as it does for
+//! mdBook's own `{{#include}}`.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde_json::Value;
+
+use crate::graph_writer::{GraphData, GraphWriter};
+use crate::io::FormatRegistry;
+use crate::linkml::SchemaDefinition;
+
+/// The preprocessor's table in `book.toml`: `[preprocessor.panschema]`.
+pub const PREPROCESSOR_NAME: &str = "panschema";
+
+const DIRECTIVE: &str = "{{#panschema";
+
+/// Whether the preprocessor runs for `renderer`. Cards are Markdown and
+/// the graph is inline SVG, which both built-in renderers carry through.
+pub fn supports_renderer(renderer: &str) -> bool {
+    matches!(renderer, "html" | "markdown")
+}
+
+/// Run the preprocessor over mdBook's `[context, book]` JSON and return
+/// the rewritten book's JSON. Errors when `book.toml` names no schema,
+/// the schema doesn't load, or any directive doesn't resolve.
+pub fn preprocess(input: &str) -> anyhow::Result<String> {
+    let (context, mut book): (Value, Value) =
+        serde_json::from_str(input).context("parsing mdBook's preprocessor input")?;
+    let root = context["root"]
+        .as_str()
+        .context("mdBook's context carries no book `root`")?;
+    let schema_path = context["config"]["preprocessor"][PREPROCESSOR_NAME]["schema"]
+        .as_str()
+        .with_context(|| {
+            format!(
+                "book.toml sets no schema for the preprocessor; add \
+                 `schema = \"<path>\"` under [preprocessor.{PREPROCESSOR_NAME}]"
+            )
+        })?;
+    let schema_path = Path::new(root).join(schema_path);
+    let schema = crate::import_resolve::load_schema(&schema_path, &FormatRegistry::with_defaults())
+        .map_err(|e| anyhow::anyhow!("{e}"))
+        .with_context(|| format!("loading schema {}", schema_path.display()))?;
+
+    let embedder = Embedder::new(&schema);
+    let mut errors = Vec::new();
+    rewrite_chapters(
+        &mut book,
+        &mut |name, content| match embedder.expand(content) {
+            Ok(expanded) => *content = expanded,
+            Err(problems) => errors.extend(
+                problems
+                    .into_iter()
+                    .map(|p| format!("chapter `{name}`: {p}")),
+            ),
+        },
+    );
+    if !errors.is_empty() {
+        anyhow::bail!(
+            "{} unresolved panschema directive(s):\n  {}",
+            errors.len(),
+            errors.join("\n  ")
+        );
+    }
+    serde_json::to_string(&book).context("serializing the rewritten book")
+}
+
+/// Apply `f` to the name and content of every chapter in `book`, however
+/// deeply nested. The book is walked as plain JSON, so the parts of its
+/// shape this preprocessor doesn't touch (separators, part titles,
+/// fields a newer mdBook adds) pass through unchanged.
+fn rewrite_chapters(book: &mut Value, f: &mut dyn FnMut(&str, &mut String)) {
+    match book {
+        Value::Object(map) => {
+            if let Some(Value::Object(chapter)) = map.get_mut("Chapter") {
+                let name = chapter
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                if let Some(Value::String(content)) = chapter.get_mut("content") {
+                    f(&name, content);
+                }
+            }
+            for value in map.values_mut() {
+                rewrite_chapters(value, f);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_chapters(item, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolves directives against one schema. The schema graph is built on
+/// the first `graph` directive and reused for the rest of the book.
+pub struct Embedder<'a> {
+    schema: &'a SchemaDefinition,
+    graph: std::cell::OnceCell<GraphData>,
+}
+
+impl<'a> Embedder<'a> {
+    pub fn new(schema: &'a SchemaDefinition) -> Self {
+        Self {
+            schema,
+            graph: std::cell::OnceCell::new(),
+        }
+    }
+
+    /// `content` with every directive replaced, or one message per
+    /// directive that doesn't resolve.
+    pub fn expand(&self, content: &str) -> Result<String, Vec<String>> {
+        let mut out = String::with_capacity(content.len());
+        let mut errors = Vec::new();
+        let mut rest = content;
+        while let Some(start) = rest.find(DIRECTIVE) {
+            let Some(len) = rest[start..].find("}}") else {
+                errors.push(format!(
+                    "`{}` is never closed with `}}}}`",
+                    rest[start..].lines().next().unwrap_or_default()
+                ));
+                break;
+            };
+            let end = start + len + 2;
+            let directive = &rest[start..end];
+            if rest[..start].ends_with('\\') {
+                out.push_str(&rest[..start - 1]);
+                out.push_str(directive);
+            } else {
+                out.push_str(&rest[..start]);
+                match self.render(&directive[DIRECTIVE.len()..directive.len() - 2]) {
+                    Ok(rendered) => out.push_str(&rendered),
+                    Err(problem) => errors.push(format!("`{directive}`: {problem}")),
+                }
+            }
+            rest = &rest[end..];
+        }
+        out.push_str(rest);
+        if errors.is_empty() {
+            Ok(out)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// What one directive's arguments (`class Person`, `graph Person
+    /// depth=2`) render to.
+    fn render(&self, args: &str) -> Result<String, String> {
+        let mut words = args.split_whitespace();
+        let (Some(kind), Some(name)) = (words.next(), words.next()) else {
+            return Err("expected a kind and an element name".to_string());
+        };
+        let options: Vec<&str> = words.collect();
+        match kind {
+            "class" | "enum" | "type" => {
+                if let Some(option) = options.first() {
+                    return Err(format!("`{kind}` takes no options; got `{option}`"));
+                }
+                crate::markdown_writer::render_element(self.schema, kind, name, None)
+                    .map(|page| demote_headings(&page))
+                    .ok_or_else(|| format!("the schema has no {kind} `{name}`"))
+            }
+            "graph" => {
+                let mut depth = 1;
+                for option in options {
+                    depth = option
+                        .strip_prefix("depth=")
+                        .and_then(|d| d.parse().ok())
+                        .ok_or_else(|| {
+                            format!("unknown option `{option}`; `graph` takes `depth=<n>`")
+                        })?;
+                }
+                let center = if self.schema.classes.contains_key(name) {
+                    format!("class:{name}")
+                } else if self.schema.enums.contains_key(name) {
+                    format!("enum:{name}")
+                } else if self.schema.types.contains_key(name) {
+                    format!("type:{name}")
+                } else {
+                    return Err(format!("the schema has no class, enum or type `{name}`"));
+                };
+                let graph = self
+                    .graph
+                    .get_or_init(|| GraphWriter::new().schema_to_graph(self.schema));
+                draw(&graph.neighborhood(&center, depth))
+            }
+            _ => Err(format!(
+                "unknown kind `{kind}`; expected class, enum, type or graph"
+            )),
+        }
+    }
+}
+
+/// Every ATX heading one level deeper (`#` → `##`), leaving fenced code
+/// alone, so an embedded page nests under the chapter's own title.
+fn demote_headings(page: &str) -> String {
+    let mut fenced = false;
+    let mut out = String::with_capacity(page.len() + 16);
+    for line in page.lines() {
+        if line.starts_with("```") {
+            fenced = !fenced;
+        }
+        if !fenced && line.starts_with('#') {
+            out.push('#');
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// `local`, a neighborhood of the element a `graph` directive names,
+/// drawn by the `--format svg` renderer in the HTML graph's notation.
+#[cfg(feature = "svg")]
+fn draw(local: &GraphData) -> Result<String, String> {
+    crate::svg_writer::render_graph(local, "auto").map_err(|e| e.to_string())
+}
+
+/// Without the `svg` feature there is no layout engine to draw with.
+#[cfg(not(feature = "svg"))]
+fn draw(_local: &GraphData) -> Result<String, String> {
+    Err("this panschema was built without the `svg` feature, which `graph` needs".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{ClassDefinition, EnumDefinition, PermissibleValue, SlotDefinition};
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        let mut status = EnumDefinition::new("Status");
+        status
+            .permissible_values
+            .insert("ACTIVE".to_string(), PermissibleValue::new("ACTIVE"));
+        schema.enums.insert("Status".to_string(), status);
+        schema
+            .classes
+            .insert("Agent".to_string(), ClassDefinition::new("Agent"));
+        let mut person = ClassDefinition::new("Person");
+        person.is_a = Some("Agent".to_string());
+        let mut state = SlotDefinition::new("state");
+        state.range = Some("Status".to_string());
+        person.attributes.insert("state".to_string(), state);
+        schema.classes.insert("Person".to_string(), person);
+        schema
+    }
+
+    #[test]
+    fn card_directives_embed_the_markdown_page_a_level_down() {
+        let schema = schema();
+        let chapter =
+            "# People\n\n{{#panschema class Person}}\nAfter.\n\n{{#panschema enum Status}}";
+        let out = Embedder::new(&schema).expand(chapter).unwrap();
+        assert!(out.starts_with("# People\n\n## Person\n\nClass\n"), "{out}");
+        assert!(out.contains("### Slots"), "{out}");
+        assert!(
+            out.contains("| `state` | `Status` | 0..1 |"),
+            "names render as code, with no pages to link; got:\n{out}"
+        );
+        assert!(out.contains("\nAfter.\n"));
+        assert!(out.contains("## Status\n\nEnumeration"));
+    }
+
+    #[test]
+    fn every_unresolved_directive_is_reported() {
+        let schema = schema();
+        let errors = Embedder::new(&schema)
+            .expand(
+                "{{#panschema class Persn}} {{#panschema enum Person}} \
+                 {{#panschema table Person}} {{#panschema graph Person depth=two}}",
+            )
+            .unwrap_err();
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(errors[0].contains("no class `Persn`"), "{errors:?}");
+        assert!(errors[1].contains("no enum `Person`"), "{errors:?}");
+        assert!(errors[2].contains("unknown kind `table`"), "{errors:?}");
+        assert!(errors[3].contains("`depth=<n>`"), "{errors:?}");
+    }
+
+    #[test]
+    fn an_escaped_directive_stays_literal() {
+        let schema = schema();
+        let out = Embedder::new(&schema)
+            .expand("Write `\\{{#panschema class Nope}}` to embed a class.")
+            .unwrap();
+        assert_eq!(out, "Write `{{#panschema class Nope}}` to embed a class.");
+    }
+
+    #[test]
+    #[cfg(feature = "svg")]
+    fn graph_directive_draws_the_neighborhood_to_its_depth() {
+        let schema = schema();
+        let embedder = Embedder::new(&schema);
+        let near = embedder.expand("{{#panschema graph Status}}").unwrap();
+        assert!(
+            near.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""),
+            "{near}"
+        );
+        assert!(near.contains(">Person</text>"), "{near}");
+        assert!(near.contains(">state</text>"), "{near}");
+        assert!(!near.contains(">Agent</text>"), "two hops out at depth 1");
+        let far = embedder
+            .expand("{{#panschema graph Status depth=2}}")
+            .unwrap();
+        assert!(far.contains(">Agent</text>"), "{far}");
+        assert_eq!(
+            far,
+            crate::svg_writer::render_graph(
+                &GraphWriter::new()
+                    .schema_to_graph(&schema)
+                    .neighborhood("enum:Status", 2),
+                "auto"
+            )
+            .unwrap(),
+            "the `--format svg` renderer draws it"
+        );
+    }
+
+    #[test]
+    fn preprocess_rewrites_nested_chapters_and_fails_on_a_bad_reference() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("people.yaml"),
+            "id: https://example.org/people\nname: people\n\
+             classes:\n  Person:\n    description: A human being.\n",
+        )
+        .unwrap();
+        let input = |content: &str| {
+            serde_json::json!([
+                {
+                    "root": dir.path(),
+                    "config": {"preprocessor": {"panschema": {"schema": "people.yaml"}}},
+                    "renderer": "html",
+                    "mdbook_version": "0.4.40"
+                },
+                {
+                    "sections": [
+                        {"Chapter": {"name": "Intro", "content": "Intro", "sub_items": [
+                            {"Chapter": {"name": "Model", "content": content, "sub_items": []}}
+                        ]}},
+                        "Separator"
+                    ],
+                    "__non_exhaustive": null
+                }
+            ])
+            .to_string()
+        };
+
+        let book: Value =
+            serde_json::from_str(&preprocess(&input("{{#panschema class Person}}")).unwrap())
+                .unwrap();
+        let model = book["sections"][0]["Chapter"]["sub_items"][0]["Chapter"]["content"]
+            .as_str()
+            .unwrap();
+        assert!(
+            model.starts_with("## Person\n\nClass\n\nA human being.\n"),
+            "{model}"
+        );
+        assert_eq!(book["sections"][1], "Separator");
+
+        let err = preprocess(&input("{{#panschema class Robot}}")).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("chapter `Model`: `{{#panschema class Robot}}`"),
+            "{err:#}"
+        );
+    }
+}
@@ -0,0 +1,776 @@
+//! Structural validation of a LinkML schema document against the LinkML
+//! metamodel.
+//!
+//! `serde` ignores every key it doesn't model, so `mulitvalued: true` on a
+//! slot loads as if the line weren't there — the schema reads clean and
+//! the slot is quietly single-valued. [`check`] walks the raw YAML
+//! document instead of the IR, element kind by element kind, against a
+//! vendored subset of the LinkML 1.7 metamodel: which metaslots each kind
+//! accepts, what shape of value each takes, and the permissible values of
+//! the enum-valued ones. A misspelled key gets a "did you mean"; a key
+//! that belongs on a different element kind (`permissible_values` on a
+//! class) says so.
+//!
+//! The tables list the metamodel's slots, not panschema's: a real LinkML
+//! metaslot panschema doesn't model passes here (the unmodeled-construct
+//! diagnostic is its guard), so this check answers "is this LinkML?" and
+//! nothing else. The walk runs on the document before it is deserialized,
+//! so it still reports when the IR parse would fail.
+//!
+//! Reference: [`docs/features/07-schema-validation.md`](../../docs/features/07-schema-validation.md)
+
+use std::fmt;
+use std::path::Path;
+
+use serde_norway::Value;
+
+use crate::io::{IoError, IoResult};
+use crate::source_map::{SourceLocation, SourceMap};
+
+/// One way a schema document departs from the metamodel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaschemaIssue {
+    /// Key path from the document root (`classes` → `Person` → `slots`);
+    /// sequence items are indexed from `0`.
+    pub path: Vec<String>,
+    pub message: String,
+    /// Where the offending key was written.
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for MetaschemaIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// The metamodel classes whose instances appear in a schema document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Schema,
+    Class,
+    Slot,
+    Enum,
+    PermissibleValue,
+    Type,
+    Subset,
+    /// An anonymous class expression (`any_of` branch, rule condition).
+    ClassExpression,
+    /// An anonymous slot expression (`any_of` branch, slot condition).
+    SlotExpression,
+    Rule,
+    UniqueKey,
+    Example,
+}
+
+impl Kind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Schema => "schema",
+            Self::Class => "class",
+            Self::Slot => "slot",
+            Self::Enum => "enum",
+            Self::PermissibleValue => "permissible value",
+            Self::Type => "type",
+            Self::Subset => "subset",
+            Self::ClassExpression => "class expression",
+            Self::SlotExpression => "slot expression",
+            Self::Rule => "rule",
+            Self::UniqueKey => "unique key",
+            Self::Example => "example",
+        }
+    }
+
+    /// The named element kinds, in the order a misplaced key is matched
+    /// against them.
+    const ELEMENTS: [Kind; 6] = [
+        Self::Schema,
+        Self::Class,
+        Self::Slot,
+        Self::Enum,
+        Self::Type,
+        Self::PermissibleValue,
+    ];
+
+    /// Every metaslot this kind accepts.
+    fn metaslots(self) -> Vec<&'static (&'static str, Shape)> {
+        let own: &[(&str, Shape)] = match self {
+            Self::Schema => SCHEMA,
+            Self::Class => CLASS,
+            Self::Slot => SLOT,
+            Self::Enum => ENUM,
+            Self::PermissibleValue => PERMISSIBLE_VALUE,
+            Self::Type => TYPE,
+            Self::Subset => &[],
+            Self::ClassExpression => CLASS_EXPRESSION,
+            Self::SlotExpression => SLOT_EXPRESSION,
+            Self::Rule => RULE,
+            Self::UniqueKey => UNIQUE_KEY,
+            Self::Example => EXAMPLE,
+        };
+        let mut all: Vec<&(&str, Shape)> = own.iter().collect();
+        match self {
+            Self::Class => all.extend(DEFINITION.iter().chain(CLASS_EXPRESSION)),
+            Self::Slot => all.extend(DEFINITION.iter().chain(SLOT_EXPRESSION)),
+            Self::Enum => all.extend(DEFINITION),
+            _ => {}
+        }
+        if !matches!(
+            self,
+            Self::ClassExpression | Self::SlotExpression | Self::Example
+        ) {
+            all.extend(COMMON);
+        }
+        all
+    }
+
+    fn shape_of(self, key: &str) -> Option<Shape> {
+        self.metaslots()
+            .into_iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, shape)| *shape)
+    }
+}
+
+/// The value shape a metaslot takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Str,
+    /// A string, number, or boolean — `version: 1.0` is as good as `"1.0"`.
+    Scalar,
+    Bool,
+    Int,
+    Num,
+    /// A list of strings, or one string (LinkML loaders promote it).
+    Strs,
+    /// One of a metamodel enum's permissible values.
+    OneOf(&'static [&'static str]),
+    /// A mapping from names to elements of the kind.
+    Named(Kind),
+    /// A list of elements of the kind.
+    Items(Kind),
+    /// One element of the kind.
+    One(Kind),
+    /// Not checked (annotations, settings, structured values).
+    Any,
+}
+
+impl Shape {
+    fn describe(self) -> String {
+        match self {
+            Self::Str => "a string".to_string(),
+            Self::Scalar => "a scalar".to_string(),
+            Self::Bool => "a boolean".to_string(),
+            Self::Int => "an integer".to_string(),
+            Self::Num => "a number".to_string(),
+            Self::Strs => "a list of strings".to_string(),
+            Self::OneOf(values) => format!("one of {}", values.join(", ")),
+            Self::Named(kind) => format!("a mapping of {} definitions", kind.label()),
+            Self::Items(kind) => format!("a list of {}s", kind.label()),
+            Self::One(kind) => format!("a {} mapping", kind.label()),
+            Self::Any => "anything".to_string(),
+        }
+    }
+}
+
+const VALUE_PRESENCE: &[&str] = &["UNCOMMITTED", "PRESENT", "ABSENT"];
+const RELATIONAL_ROLE: &[&str] = &["SUBJECT", "OBJECT", "PREDICATE", "NODE", "OTHER_ROLE"];
+const PV_FORMULA: &[&str] = &["CODE", "CURIE", "URI", "FHIR_CODING", "LABEL"];
+
+/// Metaslots every named element accepts (`element` and its mixins).
+const COMMON: &[(&str, Shape)] = &[
+    ("name", Shape::Str),
+    ("id_prefixes", Shape::Strs),
+    ("id_prefixes_are_closed", Shape::Bool),
+    ("definition_uri", Shape::Str),
+    ("local_names", Shape::Any),
+    ("conforms_to", Shape::Str),
+    ("implements", Shape::Strs),
+    ("instantiates", Shape::Strs),
+    ("extensions", Shape::Any),
+    ("annotations", Shape::Any),
+    ("description", Shape::Str),
+    ("alt_descriptions", Shape::Any),
+    ("title", Shape::Str),
+    ("deprecated", Shape::Str),
+    ("todos", Shape::Strs),
+    ("notes", Shape::Strs),
+    ("comments", Shape::Strs),
+    ("examples", Shape::Items(Kind::Example)),
+    ("in_subset", Shape::Strs),
+    ("from_schema", Shape::Str),
+    ("imported_from", Shape::Str),
+    ("source", Shape::Str),
+    ("in_language", Shape::Str),
+    ("see_also", Shape::Strs),
+    ("deprecated_element_has_exact_replacement", Shape::Str),
+    ("deprecated_element_has_possible_replacement", Shape::Str),
+    ("aliases", Shape::Strs),
+    ("structured_aliases", Shape::Any),
+    ("mappings", Shape::Strs),
+    ("exact_mappings", Shape::Strs),
+    ("close_mappings", Shape::Strs),
+    ("related_mappings", Shape::Strs),
+    ("narrow_mappings", Shape::Strs),
+    ("broad_mappings", Shape::Strs),
+    ("created_by", Shape::Str),
+    ("contributors", Shape::Any),
+    ("created_on", Shape::Scalar),
+    ("last_updated_on", Shape::Scalar),
+    ("modified_by", Shape::Str),
+    ("status", Shape::Str),
+    ("rank", Shape::Int),
+    ("categories", Shape::Strs),
+    ("keywords", Shape::Strs),
+];
+
+const SCHEMA: &[(&str, Shape)] = &[
+    ("id", Shape::Str),
+    ("version", Shape::Scalar),
+    ("imports", Shape::Strs),
+    ("license", Shape::Str),
+    ("prefixes", Shape::Any),
+    ("emit_prefixes", Shape::Strs),
+    ("default_curi_maps", Shape::Strs),
+    ("default_prefix", Shape::Str),
+    ("default_range", Shape::Str),
+    ("subsets", Shape::Named(Kind::Subset)),
+    ("types", Shape::Named(Kind::Type)),
+    ("enums", Shape::Named(Kind::Enum)),
+    ("slots", Shape::Named(Kind::Slot)),
+    ("slot_definitions", Shape::Named(Kind::Slot)),
+    ("classes", Shape::Named(Kind::Class)),
+    ("metamodel_version", Shape::Str),
+    ("source_file", Shape::Str),
+    ("source_file_date", Shape::Scalar),
+    ("source_file_size", Shape::Int),
+    ("generation_date", Shape::Scalar),
+    ("slot_names_unique", Shape::Bool),
+    ("settings", Shape::Any),
+    ("bindings", Shape::Any),
+    // Dublin Core lifecycle dates, read by panschema's metadata card.
+    ("created", Shape::Scalar),
+    ("modified", Shape::Scalar),
+];
+
+/// `definition` metaslots shared by classes, slots, and enums.
+const DEFINITION: &[(&str, Shape)] = &[
+    ("is_a", Shape::Str),
+    ("abstract", Shape::Bool),
+    ("mixin", Shape::Bool),
+    ("mixins", Shape::Strs),
+    ("apply_to", Shape::Strs),
+    ("values_from", Shape::Strs),
+    ("string_serialization", Shape::Str),
+];
+
+const CLASS: &[(&str, Shape)] = &[
+    ("slots", Shape::Strs),
+    ("slot_usage", Shape::Named(Kind::Slot)),
+    ("attributes", Shape::Named(Kind::Slot)),
+    ("class_uri", Shape::Str),
+    ("subclass_of", Shape::Str),
+    ("union_of", Shape::Strs),
+    ("defining_slots", Shape::Strs),
+    ("tree_root", Shape::Bool),
+    ("unique_keys", Shape::Named(Kind::UniqueKey)),
+    ("rules", Shape::Items(Kind::Rule)),
+    ("classification_rules", Shape::Items(Kind::ClassExpression)),
+    ("slot_names_unique", Shape::Bool),
+    ("represents_relationship", Shape::Bool),
+    ("disjoint_with", Shape::Strs),
+    ("children_are_mutually_disjoint", Shape::Bool),
+    ("extra_slots", Shape::Any),
+    ("alias", Shape::Str),
+];
+
+const CLASS_EXPRESSION: &[(&str, Shape)] = &[
+    ("is_a", Shape::Str),
+    ("any_of", Shape::Items(Kind::ClassExpression)),
+    ("exactly_one_of", Shape::Items(Kind::ClassExpression)),
+    ("none_of", Shape::Items(Kind::ClassExpression)),
+    ("all_of", Shape::Items(Kind::ClassExpression)),
+    ("slot_conditions", Shape::Named(Kind::SlotExpression)),
+];
+
+const SLOT: &[(&str, Shape)] = &[
+    ("singular_name", Shape::Str),
+    ("domain", Shape::Str),
+    ("slot_uri", Shape::Str),
+    ("array", Shape::Any),
+    ("inherited", Shape::Bool),
+    ("readonly", Shape::Str),
+    ("ifabsent", Shape::Str),
+    ("list_elements_unique", Shape::Bool),
+    ("list_elements_ordered", Shape::Bool),
+    ("shared", Shape::Bool),
+    ("key", Shape::Bool),
+    ("identifier", Shape::Bool),
+    ("designates_type", Shape::Bool),
+    ("alias", Shape::Str),
+    ("owner", Shape::Str),
+    ("domain_of", Shape::Strs),
+    ("subproperty_of", Shape::Str),
+    ("symmetric", Shape::Bool),
+    ("reflexive", Shape::Bool),
+    ("locally_reflexive", Shape::Bool),
+    ("irreflexive", Shape::Bool),
+    ("asymmetric", Shape::Bool),
+    ("transitive", Shape::Bool),
+    ("inverse", Shape::Str),
+    ("is_class_field", Shape::Bool),
+    ("transitive_form_of", Shape::Str),
+    ("reflexive_transitive_form_of", Shape::Str),
+    ("role", Shape::Str),
+    ("is_usage_slot", Shape::Bool),
+    ("usage_slot_name", Shape::Str),
+    ("relational_role", Shape::OneOf(RELATIONAL_ROLE)),
+    ("slot_group", Shape::Str),
+    ("is_grouping_slot", Shape::Bool),
+    ("path_rule", Shape::Any),
+    ("disjoint_with", Shape::Strs),
+    ("children_are_mutually_disjoint", Shape::Bool),
+    ("union_of", Shape::Strs),
+    ("type_mappings", Shape::Any),
+];
+
+const SLOT_EXPRESSION: &[(&str, Shape)] = &[
+    ("range", Shape::Str),
+    ("range_expression", Shape::One(Kind::ClassExpression)),
+    ("enum_range", Shape::Any),
+    ("bindings", Shape::Any),
+    ("required", Shape::Bool),
+    ("recommended", Shape::Bool),
+    ("multivalued", Shape::Bool),
+    ("inlined", Shape::Bool),
+    ("inlined_as_list", Shape::Bool),
+    ("minimum_value", Shape::Scalar),
+    ("maximum_value", Shape::Scalar),
+    ("pattern", Shape::Str),
+    ("structured_pattern", Shape::Any),
+    ("unit", Shape::Any),
+    ("implicit_prefix", Shape::Str),
+    ("value_presence", Shape::OneOf(VALUE_PRESENCE)),
+    ("equals_string", Shape::Str),
+    ("equals_string_in", Shape::Strs),
+    ("equals_number", Shape::Num),
+    ("equals_expression", Shape::Str),
+    ("exact_cardinality", Shape::Int),
+    ("minimum_cardinality", Shape::Int),
+    ("maximum_cardinality", Shape::Int),
+    ("has_member", Shape::One(Kind::SlotExpression)),
+    ("all_members", Shape::One(Kind::SlotExpression)),
+    ("none_of", Shape::Items(Kind::SlotExpression)),
+    ("exactly_one_of", Shape::Items(Kind::SlotExpression)),
+    ("any_of", Shape::Items(Kind::SlotExpression)),
+    ("all_of", Shape::Items(Kind::SlotExpression)),
+];
+
+const ENUM: &[(&str, Shape)] = &[
+    ("enum_uri", Shape::Str),
+    ("code_set", Shape::Str),
+    ("code_set_tag", Shape::Str),
+    ("code_set_version", Shape::Str),
+    ("pv_formula", Shape::OneOf(PV_FORMULA)),
+    ("permissible_values", Shape::Named(Kind::PermissibleValue)),
+    ("include", Shape::Any),
+    ("minus", Shape::Any),
+    ("inherits", Shape::Strs),
+    ("reachable_from", Shape::Any),
+    ("matches", Shape::Any),
+    ("concepts", Shape::Strs),
+];
+
+const PERMISSIBLE_VALUE: &[(&str, Shape)] = &[
+    ("text", Shape::Str),
+    ("meaning", Shape::Str),
+    ("unit", Shape::Any),
+    ("is_a", Shape::Str),
+    ("mixins", Shape::Strs),
+];
+
+const TYPE: &[(&str, Shape)] = &[
+    ("typeof", Shape::Str),
+    ("base", Shape::Str),
+    ("uri", Shape::Str),
+    ("repr", Shape::Str),
+    ("union_of", Shape::Strs),
+    ("pattern", Shape::Str),
+    ("structured_pattern", Shape::Any),
+    ("unit", Shape::Any),
+    ("implicit_prefix", Shape::Str),
+    ("equals_string", Shape::Str),
+    ("equals_string_in", Shape::Strs),
+    ("equals_number", Shape::Num),
+    ("minimum_value", Shape::Scalar),
+    ("maximum_value", Shape::Scalar),
+    ("none_of", Shape::Any),
+    ("exactly_one_of", Shape::Any),
+    ("any_of", Shape::Any),
+    ("all_of", Shape::Any),
+];
+
+const RULE: &[(&str, Shape)] = &[
+    ("preconditions", Shape::One(Kind::ClassExpression)),
+    ("postconditions", Shape::One(Kind::ClassExpression)),
+    ("elseconditions", Shape::One(Kind::ClassExpression)),
+    ("bidirectional", Shape::Bool),
+    ("open_world", Shape::Bool),
+    ("deactivated", Shape::Bool),
+];
+
+const UNIQUE_KEY: &[(&str, Shape)] = &[
+    ("unique_key_name", Shape::Str),
+    ("unique_key_slots", Shape::Strs),
+    ("consider_nulls_inequal", Shape::Bool),
+];
+
+const EXAMPLE: &[(&str, Shape)] = &[
+    ("value", Shape::Scalar),
+    ("description", Shape::Str),
+    ("object", Shape::Any),
+];
+
+/// Check a parsed schema document against the metamodel, locating each
+/// issue through `source_map` (built from the same text).
+pub fn check(document: &Value, source_map: &SourceMap) -> Vec<MetaschemaIssue> {
+    let mut walk = Walk {
+        source_map,
+        path: Vec::new(),
+        issues: Vec::new(),
+    };
+    if document.as_mapping().is_none() {
+        walk.report("a LinkML schema document must be a mapping".to_string());
+    } else {
+        walk.element(Kind::Schema, document, "the schema");
+    }
+    walk.issues
+}
+
+/// Read a YAML schema file and [`check`] it. Fails only when the file
+/// can't be read or isn't YAML at all.
+pub fn check_file(path: &Path) -> IoResult<Vec<MetaschemaIssue>> {
+    let content = std::fs::read_to_string(path)?;
+    let document: Value =
+        serde_norway::from_str(&content).map_err(|e| IoError::Parse(e.to_string()))?;
+    Ok(check(&document, &SourceMap::from_yaml(path, &content)))
+}
+
+struct Walk<'a> {
+    source_map: &'a SourceMap,
+    path: Vec<String>,
+    issues: Vec<MetaschemaIssue>,
+}
+
+impl Walk<'_> {
+    fn report(&mut self, message: String) {
+        let path: Vec<&str> = self.path.iter().map(String::as_str).collect();
+        self.issues.push(MetaschemaIssue {
+            path: self.path.clone(),
+            message,
+            location: self.source_map.locate(&path),
+        });
+    }
+
+    fn element(&mut self, kind: Kind, value: &Value, label: &str) {
+        let Some(mapping) = value.as_mapping() else {
+            // A bare name (`Person:` with no body) is an empty definition.
+            if !value.is_null() {
+                self.report(format!(
+                    "{label} must be a mapping, found {}",
+                    describe(value)
+                ));
+            }
+            return;
+        };
+        for (key, child) in mapping {
+            let Some(key) = key.as_str() else {
+                continue;
+            };
+            self.path.push(key.to_string());
+            match kind.shape_of(key) {
+                Some(shape) => self.value(shape, key, child, label),
+                None => self.report(unknown_key(kind, key, label)),
+            }
+            self.path.pop();
+        }
+    }
+
+    fn value(&mut self, shape: Shape, key: &str, value: &Value, label: &str) {
+        let ok = match shape {
+            Shape::Any => true,
+            Shape::Str => value.is_string(),
+            Shape::Scalar => value.is_string() || value.is_number() || value.is_bool(),
+            Shape::Bool => value.is_bool(),
+            Shape::Int => value.is_i64() || value.is_u64(),
+            Shape::Num => value.is_number(),
+            Shape::Strs => {
+                value.is_string()
+                    || value
+                        .as_sequence()
+                        .is_some_and(|items| items.iter().all(Value::is_string))
+            }
+            Shape::OneOf(allowed) => {
+                if let Some(text) = value.as_str()
+                    && !allowed.contains(&text)
+                {
+                    let hint = allowed
+                        .iter()
+                        .find(|a| a.eq_ignore_ascii_case(text))
+                        .map(|a| format!("; did you mean `{a}`?"))
+                        .unwrap_or_default();
+                    self.report(format!(
+                        "`{key}` on {label} must be one of {}, found `{text}`{hint}",
+                        allowed.join(", ")
+                    ));
+                    return;
+                }
+                value.is_string()
+            }
+            Shape::Named(kind) => match value.as_mapping() {
+                Some(mapping) => {
+                    for (name, child) in mapping {
+                        let name = name_of(name);
+                        self.path.push(name.clone());
+                        let child_label = match (kind, key) {
+                            (_, "attributes") => format!("attribute `{name}` of {label}"),
+                            (_, "slot_usage") => format!("slot_usage `{name}` of {label}"),
+                            (
+                                Kind::PermissibleValue | Kind::UniqueKey | Kind::SlotExpression,
+                                _,
+                            ) => format!("{} `{name}` of {label}", kind.label()),
+                            _ => format!("{} `{name}`", kind.label()),
+                        };
+                        self.element(kind, child, &child_label);
+                        self.path.pop();
+                    }
+                    true
+                }
+                None => value.is_null(),
+            },
+            Shape::Items(kind) => match value.as_sequence() {
+                Some(items) => {
+                    for (i, item) in items.iter().enumerate() {
+                        self.path.push(i.to_string());
+                        self.element(kind, item, &format!("{key}[{i}] of {label}"));
+                        self.path.pop();
+                    }
+                    true
+                }
+                None => false,
+            },
+            Shape::One(kind) => {
+                self.element(kind, value, &format!("{key} of {label}"));
+                return;
+            }
+        };
+        if !ok {
+            self.report(format!(
+                "`{key}` on {label} must be {}, found {}",
+                shape.describe(),
+                describe(value)
+            ));
+        }
+    }
+}
+
+fn name_of(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => serde_norway::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => format!("a boolean (`{b}`)"),
+        Value::Number(n) => format!("a number (`{n}`)"),
+        Value::String(s) => format!("a string (`{s}`)"),
+        Value::Sequence(_) => "a list".to_string(),
+        Value::Mapping(_) => "a mapping".to_string(),
+        Value::Tagged(_) => "a tagged value".to_string(),
+    }
+}
+
+/// The message for a key `kind` doesn't accept: the closest metaslot of
+/// the same kind when one is near enough to be a typo, else the element
+/// kind the key does belong to.
+fn unknown_key(kind: Kind, key: &str, label: &str) -> String {
+    let mut message = format!("unknown key `{key}` on {label}");
+    if let Some(suggestion) = closest(key, kind.metaslots().iter().map(|(name, _)| *name)) {
+        message.push_str(&format!("; did you mean `{suggestion}`?"));
+    } else if let Some(home) = Kind::ELEMENTS
+        .iter()
+        .find(|other| **other != kind && other.shape_of(key).is_some())
+    {
+        message.push_str(&format!(
+            "; `{key}` belongs on {}, not {}",
+            with_article(home.label()),
+            with_article(kind.label())
+        ));
+    }
+    message
+}
+
+fn with_article(noun: &str) -> String {
+    let article = if noun.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    format!("{article} {noun}")
+}
+
+/// The candidate nearest `key` by edit distance, if within a typo's reach
+/// (a third of the key's length, at least one edit).
+fn closest<'a>(key: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let reach = (key.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= reach)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance, with an adjacent transposition counted as one
+/// edit — `mulitvalued` is one slip from `multivalued`, not two.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        rows[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn issues(yaml: &str) -> Vec<String> {
+        let document: Value = serde_norway::from_str(yaml).expect("yaml");
+        let map = SourceMap::from_yaml(Path::new("schema.yaml"), yaml);
+        check(&document, &map)
+            .into_iter()
+            .map(|issue| issue.message)
+            .collect()
+    }
+
+    #[test]
+    fn a_well_formed_schema_has_no_issues() {
+        let yaml = "\
+id: https://example.org/x
+name: x
+version: 1.0
+prefixes:
+  ex: https://example.org/
+classes:
+  Person:
+    description: A person.
+    slots: [age]
+    slot_usage:
+      age:
+        required: true
+    rules:
+      - preconditions:
+          slot_conditions:
+            age:
+              minimum_value: 18
+slots:
+  age:
+    range: integer
+    any_of:
+      - range: integer
+enums:
+  Status:
+    permissible_values:
+      active:
+      retired:
+        meaning: ex:Retired
+";
+        assert_eq!(issues(yaml), Vec::<String>::new());
+    }
+
+    #[test]
+    fn a_misspelled_metaslot_is_reported_with_a_suggestion() {
+        let found = issues("name: x\nslots:\n  tags:\n    mulitvalued: true\n");
+        assert_eq!(
+            found,
+            vec!["unknown key `mulitvalued` on slot `tags`; did you mean `multivalued`?"]
+        );
+    }
+
+    #[test]
+    fn a_key_from_another_element_kind_names_its_home() {
+        let found = issues("name: x\nclasses:\n  Status:\n    permissible_values: {}\n");
+        assert_eq!(
+            found,
+            vec![
+                "unknown key `permissible_values` on class `Status`; \
+                 `permissible_values` belongs on an enum, not a class"
+            ]
+        );
+    }
+
+    #[test]
+    fn wrong_value_types_and_enum_values_are_reported() {
+        let found = issues(
+            "name: x\nclasses:\n  Person:\n    attributes:\n      age:\n        \
+             multivalued: \"true\"\n        minimum_cardinality: many\n        \
+             value_presence: present\n",
+        );
+        assert_eq!(
+            found,
+            vec![
+                "`multivalued` on attribute `age` of class `Person` must be a boolean, \
+                 found a string (`true`)",
+                "`minimum_cardinality` on attribute `age` of class `Person` must be an \
+                 integer, found a string (`many`)",
+                "`value_presence` on attribute `age` of class `Person` must be one of \
+                 UNCOMMITTED, PRESENT, ABSENT, found `present`; did you mean `PRESENT`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn issues_point_at_the_offending_key() {
+        let yaml = "name: x\nslots:\n  tags:\n    mulitvalued: true\n";
+        let document: Value = serde_norway::from_str(yaml).unwrap();
+        let map = SourceMap::from_yaml(Path::new("schema.yaml"), yaml);
+        let issue = &check(&document, &map)[0];
+        let location = issue.location.as_ref().expect("located");
+        assert_eq!((location.line, location.column), (4, 5));
+        assert_eq!(issue.path, vec!["slots", "tags", "mulitvalued"]);
+    }
+
+    #[test]
+    fn transpositions_cost_one_edit() {
+        assert_eq!(edit_distance("mulitvalued", "multivalued"), 1);
+        assert_eq!(edit_distance("range", "range"), 0);
+        assert_eq!(
+            closest("rnage", ["range", "domain"].into_iter()),
+            Some("range")
+        );
+        assert_eq!(closest("colour", ["range", "domain"].into_iter()), None);
+    }
+}
@@ -3,6 +3,8 @@
 //! These types represent the OWL ontology structure as parsed from Turtle files.
 //! They are internal to the OwlReader implementation.
 
+use crate::linkml::CommonMetadata;
+
 /// Metadata extracted from an ontology
 #[derive(Debug, Clone)]
 pub struct OntologyMetadata {
@@ -22,13 +24,18 @@ pub struct OntologyMetadata {
     pub individuals: Vec<OntologyIndividual>,
     /// Enumerations (`owl:oneOf`-closed classes) defined in the ontology
     pub enums: Vec<OntologyEnum>,
+    /// Named datatypes (`rdfs:Datatype`) defined in the ontology
+    pub datatypes: Vec<OntologyDatatype>,
+    /// Editorial and provenance annotations on the ontology IRI
+    pub metadata: CommonMetadata,
 }
 
 /// SKOS / editorial cross-references attached to a class or property.
 ///
 /// Mirrors the RDF the writer emits for these constructs so they survive
 /// a Turtle→IR→Turtle round-trip: `owl:deprecated`, `skos:altLabel`,
-/// `rdfs:seeAlso`, and the five SKOS mapping predicates.
+/// `rdfs:seeAlso`, the five SKOS mapping predicates, and the common
+/// metadata predicates.
 #[derive(Debug, Clone, Default)]
 pub struct Annotations {
     /// True when `owl:deprecated true` is asserted on the subject.
@@ -47,6 +54,9 @@ pub struct Annotations {
     pub narrow_mappings: Vec<String>,
     /// skos:broadMatch target IRIs.
     pub broad_mappings: Vec<String>,
+    /// The editorial and provenance long tail (`skos:note`, `pav:createdBy`,
+    /// `sh:order`, …).
+    pub metadata: CommonMetadata,
 }
 
 /// OWL relationship characteristics asserted on an object property via
@@ -159,6 +169,8 @@ pub struct OntologyEnum {
     pub comment: Option<String>,
     /// The `owl:oneOf` members, in list order
     pub values: Vec<OntologyEnumValue>,
+    /// Editorial and provenance annotations on the enum class
+    pub metadata: CommonMetadata,
 }
 
 /// One permissible value: a member of an enum's `owl:oneOf` list.
@@ -170,6 +182,36 @@ pub struct OntologyEnumValue {
     pub label: Option<String>,
     /// The value description (rdfs:comment)
     pub comment: Option<String>,
+    /// Editorial and provenance annotations on the value individual
+    pub metadata: CommonMetadata,
+}
+
+/// A named datatype (`rdfs:Datatype`) extracted from an ontology — a
+/// custom type, equivalent to a base datatype or to a facet restriction of
+/// one (`owl:onDatatype` + `owl:withRestrictions`).
+#[derive(Debug, Clone)]
+pub struct OntologyDatatype {
+    /// The datatype IRI
+    pub iri: String,
+    /// A short identifier derived from the IRI
+    pub id: String,
+    /// The datatype label (rdfs:label)
+    pub label: Option<String>,
+    /// The datatype description (rdfs:comment)
+    pub comment: Option<String>,
+    /// The datatype it is equivalent to, or restricts
+    pub base_iri: Option<String>,
+    /// `xsd:pattern` facet
+    pub pattern: Option<String>,
+    /// `xsd:minInclusive` facet
+    pub min_inclusive: Option<f64>,
+    /// `xsd:maxInclusive` facet
+    pub max_inclusive: Option<f64>,
+    /// Local names of facets LinkML has no field for (`minExclusive`,
+    /// `length`, …), so the reader can say what it dropped.
+    pub unsupported_facets: Vec<String>,
+    /// SKOS / editorial cross-references (deprecated, aliases, see_also, mappings).
+    pub annotations: Annotations,
 }
 
 /// A named individual (owl:NamedIndividual) extracted from an ontology
@@ -218,6 +260,8 @@ mod tests {
             properties: vec![],
             individuals: vec![],
             enums: vec![],
+            datatypes: vec![],
+            metadata: CommonMetadata::default(),
         };
         assert_eq!(meta.title(), "My Ontology");
     }
@@ -233,6 +277,8 @@ mod tests {
             properties: vec![],
             individuals: vec![],
             enums: vec![],
+            datatypes: vec![],
+            metadata: CommonMetadata::default(),
         };
         assert_eq!(meta.title(), "http://example.org/onto");
     }
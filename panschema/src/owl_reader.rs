@@ -14,31 +14,69 @@ use sophia::inmem::graph::FastGraph;
 use sophia::turtle::parser::turtle;
 
 use crate::io::{IoError, IoResult, Reader};
-use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use crate::linkml::{
+    ClassDefinition, CommonMetadata, SchemaDefinition, SlotDefinition, TypeDefinition,
+};
 use crate::owl_model::{
-    Annotations, OntologyClass, OntologyIndividual, OntologyMetadata, OntologyProperty,
-    PropertyCharacteristics, PropertyType, PropertyValue,
+    Annotations, OntologyClass, OntologyDatatype, OntologyIndividual, OntologyMetadata,
+    OntologyProperty, PropertyCharacteristics, PropertyType, PropertyValue,
 };
 
 /// OWL namespace
 const OWL_NS: &str = "http://www.w3.org/2002/07/owl#";
 
+/// XSD namespace
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
+
 /// SKOS namespace
 const SKOS_NS: &str = "http://www.w3.org/2004/02/skos/core#";
 
-/// Collect every literal object for a (subject, predicate) pair.
-fn collect_literal_values<T: Term>(
+/// Dublin Core terms namespace
+const DCTERMS_NS: &str = "http://purl.org/dc/terms/";
+
+/// Every literal object for a (subject, predicate) pair with its language
+/// tag, if any.
+fn collect_tagged_literals<T: Term>(
     graph: &FastGraph,
     subject: &SimpleTerm,
     predicate: T,
-) -> Vec<String> {
+) -> Vec<(Option<String>, String)> {
     graph
         .triples_matching([subject], [predicate], Any)
         .filter_map(Result::ok)
-        .filter_map(|t| t.o().lexical_form().map(|l| l.to_string()))
+        .filter_map(|t| {
+            let o = t.o();
+            let text = o.lexical_form()?.to_string();
+            Some((o.language_tag().map(|tag| tag.as_str().to_string()), text))
+        })
         .collect()
 }
 
+/// Which of `literals` reads as the primary value: an untagged one, else
+/// `@en`, else the first tag in sort order — so a graph that tags every
+/// label still reads deterministically. The rest are translations.
+fn preferred_literal(literals: &[(Option<String>, String)]) -> Option<&(Option<String>, String)> {
+    literals.iter().min_by_key(|(tag, text)| {
+        let rank = match tag.as_deref() {
+            None => 0,
+            Some(t) if t.eq_ignore_ascii_case("en") => 1,
+            Some(_) => 2,
+        };
+        (rank, tag.clone(), text.clone())
+    })
+}
+
+/// The primary literal for a (subject, predicate) pair; see
+/// [`preferred_literal`].
+fn get_literal_value<T: Term>(
+    graph: &FastGraph,
+    subject: &SimpleTerm,
+    predicate: T,
+) -> Option<String> {
+    preferred_literal(&collect_tagged_literals(graph, subject, predicate))
+        .map(|(_, text)| text.clone())
+}
+
 /// Collect every IRI object for a (subject, predicate) pair.
 fn collect_iri_values<T: Term>(
     graph: &FastGraph,
@@ -80,11 +118,23 @@ fn read_annotations(graph: &FastGraph, subject: &SimpleTerm, owl: &Namespace<&st
             })
             .unwrap_or_default()
     };
+    let metadata = read_common_metadata(graph, subject);
+    // A tagged alias, or one a structured alias node restates, is a
+    // structured one (see `read_common_metadata`).
     let aliases = skos
         .get("altLabel")
         .map(|p| {
             let p: SimpleTerm = p.into_term();
-            collect_literal_values(graph, subject, &p)
+            collect_tagged_literals(graph, subject, &p)
+                .into_iter()
+                .filter_map(|(tag, text)| tag.is_none().then_some(text))
+                .filter(|text| {
+                    !metadata
+                        .structured_aliases
+                        .iter()
+                        .any(|a| a.literal_form == *text)
+                })
+                .collect()
         })
         .unwrap_or_default();
 
@@ -97,6 +147,207 @@ fn read_annotations(graph: &FastGraph, subject: &SimpleTerm, owl: &Namespace<&st
         related_mappings: mapping_iris("relatedMatch"),
         narrow_mappings: mapping_iris("narrowMatch"),
         broad_mappings: mapping_iris("broadMatch"),
+        metadata,
+    }
+}
+
+/// Read back the [`CommonMetadata`] the writer emits onto an element IRI
+/// (see `rdf_serializers::emit_common_metadata` for the predicates). The
+/// CURIE-valued fields come back as whichever IRI or literal was written;
+/// an `sh:order` that is no integer is ignored. `local_names`,
+/// `alt_descriptions` and `structured_aliases` come back from their
+/// source-keyed nodes. An ontology panschema didn't write tags translated
+/// labels and aliases by language instead: a language-tagged `rdfs:label`
+/// / `rdfs:comment` other than the primary one becomes a `local_names` /
+/// `alt_descriptions` entry keyed by its tag, and a tagged
+/// `skos:altLabel` no alias node restates becomes a structured alias.
+fn read_common_metadata(graph: &FastGraph, subject: &SimpleTerm) -> CommonMetadata {
+    use crate::linkml::StructuredAlias;
+    use crate::rdf_serializers::{
+        ALIAS_PREDICATES, BIBO_NS, LINKML_NS, OSLC_NS, PAV_NS, RDF_NS, SCHEMA_ORG_NS, SH_NS,
+        SKOSXL_NS,
+    };
+    let values_of = |node: &SimpleTerm, ns: &str, name: &str| -> Vec<String> {
+        let Ok(p) = sophia::api::term::IriRef::new(format!("{ns}{name}")) else {
+            return Vec::new();
+        };
+        graph
+            .triples_matching([node], [&p], Any)
+            .filter_map(Result::ok)
+            .filter_map(|t| {
+                let o = t.o();
+                o.iri()
+                    .map(|i| i.to_string())
+                    .or_else(|| o.lexical_form().map(|l| l.to_string()))
+            })
+            .collect()
+    };
+    let first_of =
+        |node: &SimpleTerm, ns: &str, name: &str| values_of(node, ns, name).into_iter().next();
+    let values = |ns: &str, name: &str| values_of(subject, ns, name);
+    let first = |ns: &str, name: &str| first_of(subject, ns, name);
+    // The nodes `linkml:{field}` links, in the order their `{…}{j}` IRIs
+    // were minted.
+    let nodes = |field: &str| {
+        let mut nodes: Vec<String> = values(LINKML_NS, field);
+        nodes.sort_by_key(|iri| (iri.len(), iri.clone()));
+        nodes
+            .into_iter()
+            .map(|iri| {
+                SimpleTerm::Iri(sophia::api::term::IriRef::new_unchecked(
+                    sophia::api::MownStr::from(iri),
+                ))
+            })
+            .collect::<Vec<_>>()
+    };
+    let translations = |predicate| {
+        let literals = collect_tagged_literals(graph, subject, predicate);
+        let primary = preferred_literal(&literals).cloned();
+        let mut by_tag = std::collections::BTreeMap::new();
+        for literal in literals {
+            if Some(&literal) == primary.as_ref() {
+                continue;
+            }
+            if let (Some(tag), text) = literal {
+                by_tag.entry(tag).or_insert(text);
+            }
+        }
+        by_tag
+    };
+    let keyed = |field: &str, source: &str, text: &str, fallback| {
+        let mut entries: std::collections::BTreeMap<String, String> = nodes(field)
+            .iter()
+            .filter_map(|node| {
+                Some((
+                    first_of(node, LINKML_NS, source)?,
+                    first_of(node, LINKML_NS, text)?,
+                ))
+            })
+            .collect();
+        for (tag, text) in translations(fallback) {
+            entries.entry(tag).or_insert(text);
+        }
+        entries
+    };
+    let mut structured_aliases: Vec<StructuredAlias> = nodes("structured_aliases")
+        .iter()
+        .filter_map(|node| {
+            let predicate = first_of(node, RDF_NS, "predicate").map(|p| {
+                ALIAS_PREDICATES
+                    .iter()
+                    .find(|(_, skos)| p == format!("{SKOS_NS}{skos}"))
+                    .map_or(p, |(name, _)| (*name).to_string())
+            });
+            Some(StructuredAlias {
+                literal_form: first_of(node, SKOSXL_NS, "literalForm")?,
+                predicate,
+                in_language: first_of(node, SCHEMA_ORG_NS, "inLanguage"),
+            })
+        })
+        .collect();
+    let mut tagged: Vec<StructuredAlias> = collect_tagged_literals(
+        graph,
+        subject,
+        sophia::api::term::IriRef::new_unchecked(format!("{SKOS_NS}altLabel")),
+    )
+    .into_iter()
+    .filter(|(_, text)| !structured_aliases.iter().any(|a| a.literal_form == *text))
+    .filter_map(|(tag, text)| {
+        tag.map(|tag| StructuredAlias {
+            literal_form: text,
+            predicate: None,
+            in_language: Some(tag),
+        })
+    })
+    .collect();
+    tagged
+        .sort_by(|a, b| (&a.in_language, &a.literal_form).cmp(&(&b.in_language, &b.literal_form)));
+    structured_aliases.extend(tagged);
+
+    CommonMetadata {
+        comments: values(SKOS_NS, "note"),
+        notes: values(SKOS_NS, "editorialNote"),
+        todos: values(LINKML_NS, "todos"),
+        status: first(BIBO_NS, "status"),
+        rank: first(SH_NS, "order").and_then(|r| r.parse().ok()),
+        keywords: values(SCHEMA_ORG_NS, "keywords"),
+        source: first(DCTERMS_NS, "source"),
+        created_by: first(PAV_NS, "createdBy"),
+        modified_by: first(OSLC_NS, "modifiedBy"),
+        created_on: first(PAV_NS, "createdOn"),
+        last_updated_on: first(PAV_NS, "lastUpdateOn"),
+        in_language: first(DCTERMS_NS, "language"),
+        local_names: keyed(
+            "local_names",
+            "local_name_source",
+            "local_name_value",
+            rdfs::label,
+        ),
+        alt_descriptions: keyed(
+            "alt_descriptions",
+            "alt_description_source",
+            "alt_description_text",
+            rdfs::comment,
+        ),
+        structured_aliases,
+    }
+}
+
+/// The members of the RDF collection starting at `head`, in list order.
+/// A `rdf:rest` cycle in a malformed graph ends the walk at the first
+/// revisited cell.
+fn rdf_list_members<'g>(graph: &'g FastGraph, head: SimpleTerm<'g>) -> Vec<SimpleTerm<'g>> {
+    let mut members = Vec::new();
+    let mut seen: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    let mut cell = head;
+    while seen.insert(format!("{cell:?}")) {
+        let Some(first) = graph
+            .triples_matching([&cell], [rdf::first], Any)
+            .filter_map(Result::ok)
+            .map(|t| t.o().into_term::<SimpleTerm>())
+            .next()
+        else {
+            break;
+        };
+        members.push(first);
+        match graph
+            .triples_matching([&cell], [rdf::rest], Any)
+            .filter_map(Result::ok)
+            .map(|t| t.o().into_term::<SimpleTerm>())
+            .next()
+        {
+            Some(next) if !Term::eq(&rdf::nil, next.borrow_term()) => cell = next,
+            _ => break,
+        }
+    }
+    members
+}
+
+/// Read one `owl:withRestrictions` facet node onto `datatype`: the
+/// pattern and inclusive bounds LinkML models, and the name of any other
+/// facet, which it cannot hold.
+fn read_facet(graph: &FastGraph, facet: &SimpleTerm<'_>, datatype: &mut OntologyDatatype) {
+    for t in graph
+        .triples_matching([facet], Any, Any)
+        .filter_map(Result::ok)
+    {
+        let Some(predicate) = t.p().iri().map(|p| p.to_string()) else {
+            continue;
+        };
+        let Some(name) = predicate.strip_prefix(XSD_NS).map(str::to_string) else {
+            continue;
+        };
+        let lexical = t.o().lexical_form().map(|l| l.to_string());
+        match (name.as_str(), lexical) {
+            ("pattern", Some(pattern)) => datatype.pattern = Some(pattern),
+            ("minInclusive", Some(v)) if v.parse::<f64>().is_ok() => {
+                datatype.min_inclusive = v.parse().ok();
+            }
+            ("maxInclusive", Some(v)) if v.parse::<f64>().is_ok() => {
+                datatype.max_inclusive = v.parse().ok();
+            }
+            _ => datatype.unsupported_facets.push(name),
+        }
     }
 }
 
@@ -184,19 +435,6 @@ impl OwlReader {
             .ok_or_else(|| anyhow::anyhow!("Ontology subject is not an IRI"))?
             .to_string();
 
-        // Helper to get a string literal for a predicate
-        fn get_literal_value<T: Term>(
-            graph: &FastGraph,
-            subject: &SimpleTerm,
-            predicate: T,
-        ) -> Option<String> {
-            graph
-                .triples_matching([subject], [predicate], Any)
-                .filter_map(Result::ok)
-                .filter_map(|t| t.o().lexical_form().map(|l| l.to_string()))
-                .next()
-        }
-
         let owl_version_info = owl.get("versionInfo")?;
 
         let label = get_literal_value(&graph, &ontology_iri, rdfs::label);
@@ -234,6 +472,8 @@ impl OwlReader {
         // labels/comments straight off what was already read.
         let (classes, individuals, enums) = Self::extract_enums(&graph, classes, individuals, &owl);
 
+        let datatypes = Self::extract_datatypes(&graph, &owl);
+
         Ok(OntologyMetadata {
             iri,
             label,
@@ -243,6 +483,8 @@ impl OwlReader {
             properties,
             individuals,
             enums,
+            datatypes,
+            metadata: read_common_metadata(&graph, &ontology_iri),
         })
     }
 
@@ -324,10 +566,15 @@ impl OwlReader {
                     // individuals when the value is one; an external
                     // `meaning:` IRI may not be, and stays bare.
                     let ind = individuals.iter().find(|i| &i.iri == iri);
+                    let value_term: SimpleTerm = sophia::api::term::IriRef::new_unchecked(
+                        sophia::api::MownStr::from(iri.as_str()),
+                    )
+                    .into_term();
                     crate::owl_model::OntologyEnumValue {
                         iri: iri.clone(),
                         label: ind.and_then(|i| i.label.clone()),
                         comment: ind.and_then(|i| i.comment.clone()),
+                        metadata: read_common_metadata(graph, &value_term),
                     }
                 })
                 .collect();
@@ -338,6 +585,7 @@ impl OwlReader {
                 label: class.label,
                 comment: class.comment,
                 values,
+                metadata: class.annotations.metadata,
             });
         }
 
@@ -348,25 +596,83 @@ impl OwlReader {
         (kept_classes, kept_individuals, enums)
     }
 
-    /// Extract all owl:Class entities from the graph
-    fn extract_classes(
-        graph: &FastGraph,
-        owl_class: &SimpleTerm<'_>,
-        owl: &Namespace<&str>,
-    ) -> anyhow::Result<Vec<OntologyClass>> {
-        // Helper to get a string literal for a predicate
-        fn get_literal_value<T: Term>(
-            graph: &FastGraph,
-            subject: &SimpleTerm,
-            predicate: T,
-        ) -> Option<String> {
+    /// Extract every named `rdfs:Datatype` with its definition: the
+    /// datatype its `owl:equivalentClass` names, or the base and facets of
+    /// the `owl:onDatatype` restriction it names. A datatype carrying its
+    /// own `owl:onDatatype` is such a restriction (the writer mints them
+    /// IRIs rather than blank nodes) and is not a type of its own; XSD's
+    /// built-ins are never redeclared.
+    fn extract_datatypes(graph: &FastGraph, owl: &Namespace<&str>) -> Vec<OntologyDatatype> {
+        let on_datatype: SimpleTerm = owl
+            .get("onDatatype")
+            .expect("owl:onDatatype is a valid IRI")
+            .into_term();
+        let with_restrictions: SimpleTerm = owl
+            .get("withRestrictions")
+            .expect("owl:withRestrictions is a valid IRI")
+            .into_term();
+        let equivalent_class: SimpleTerm = owl
+            .get("equivalentClass")
+            .expect("owl:equivalentClass is a valid IRI")
+            .into_term();
+        let object = |subject: &SimpleTerm<'_>, predicate: &SimpleTerm<'_>| {
             graph
                 .triples_matching([subject], [predicate], Any)
                 .filter_map(Result::ok)
-                .filter_map(|t| t.o().lexical_form().map(|l| l.to_string()))
+                .map(|t| t.o().into_term::<SimpleTerm>())
                 .next()
+        };
+
+        let subjects: Vec<SimpleTerm> = graph
+            .triples_matching(Any, [rdf::type_], [rdfs::Datatype])
+            .filter_map(Result::ok)
+            .map(|t| t.s().into_term::<SimpleTerm>())
+            .collect();
+        let mut datatypes = Vec::new();
+        for subject in subjects {
+            let Some(iri) = subject.iri().map(|i| i.to_string()) else {
+                continue;
+            };
+            if iri.starts_with(XSD_NS) || object(&subject, &on_datatype).is_some() {
+                continue;
+            }
+            let mut datatype = OntologyDatatype {
+                id: extract_id_from_iri(&iri),
+                iri,
+                label: get_literal_value(graph, &subject, rdfs::label),
+                comment: get_literal_value(graph, &subject, rdfs::comment),
+                base_iri: None,
+                pattern: None,
+                min_inclusive: None,
+                max_inclusive: None,
+                unsupported_facets: Vec::new(),
+                annotations: read_annotations(graph, &subject, owl),
+            };
+            if let Some(definition) = object(&subject, &equivalent_class) {
+                match object(&definition, &on_datatype) {
+                    Some(base) => {
+                        datatype.base_iri = base.iri().map(|i| i.to_string());
+                        if let Some(head) = object(&definition, &with_restrictions) {
+                            for facet in rdf_list_members(graph, head) {
+                                read_facet(graph, &facet, &mut datatype);
+                            }
+                        }
+                    }
+                    None => datatype.base_iri = definition.iri().map(|i| i.to_string()),
+                }
+            }
+            datatypes.push(datatype);
         }
+        datatypes.sort_by(|a, b| a.id.cmp(&b.id));
+        datatypes
+    }
 
+    /// Extract all owl:Class entities from the graph
+    fn extract_classes(
+        graph: &FastGraph,
+        owl_class: &SimpleTerm<'_>,
+        owl: &Namespace<&str>,
+    ) -> anyhow::Result<Vec<OntologyClass>> {
         // Helper to get an IRI value for a predicate
         fn get_iri_value<T: Term>(
             graph: &FastGraph,
@@ -430,19 +736,6 @@ impl OwlReader {
         owl_inverse_of: &SimpleTerm<'_>,
         owl: &Namespace<&str>,
     ) -> anyhow::Result<Vec<OntologyProperty>> {
-        // Helper to get a string literal for a predicate
-        fn get_literal_value<T: Term>(
-            graph: &FastGraph,
-            subject: &SimpleTerm,
-            predicate: T,
-        ) -> Option<String> {
-            graph
-                .triples_matching([subject], [predicate], Any)
-                .filter_map(Result::ok)
-                .filter_map(|t| t.o().lexical_form().map(|l| l.to_string()))
-                .next()
-        }
-
         // Helper to get an IRI value for a predicate
         fn get_iri_value<T: Term>(
             graph: &FastGraph,
@@ -564,19 +857,6 @@ impl OwlReader {
         graph: &FastGraph,
         owl_named_individual: &SimpleTerm<'_>,
     ) -> anyhow::Result<Vec<OntologyIndividual>> {
-        // Helper to get a string literal for a predicate
-        fn get_literal_value<T: Term>(
-            graph: &FastGraph,
-            subject: &SimpleTerm,
-            predicate: T,
-        ) -> Option<String> {
-            graph
-                .triples_matching([subject], [predicate], Any)
-                .filter_map(Result::ok)
-                .filter_map(|t| t.o().lexical_form().map(|l| l.to_string()))
-                .next()
-        }
-
         // RDF/RDFS/OWL namespace prefixes for filtering metadata predicates
         const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
         const RDFS_NS: &str = "http://www.w3.org/2000/01/rdf-schema#";
@@ -678,6 +958,7 @@ impl OwlReader {
         schema.title = Some(metadata.title().to_string());
         schema.description = metadata.comment.clone();
         schema.version = metadata.version.clone();
+        schema.metadata = metadata.metadata.clone();
 
         // Record source format in annotations
         schema
@@ -699,6 +980,7 @@ impl OwlReader {
         for owl_enum in &metadata.enums {
             let mut enum_def = crate::linkml::EnumDefinition::new(&owl_enum.id);
             enum_def.description = owl_enum.comment.clone();
+            enum_def.metadata = owl_enum.metadata.clone();
             let derived_prefix = format!("{}/", owl_enum.iri);
             for value in &owl_enum.values {
                 let (key, meaning) = match value.iri.strip_prefix(&derived_prefix) {
@@ -717,6 +999,7 @@ impl OwlReader {
                         text: value.label.clone().unwrap_or(key),
                         description: value.comment.clone(),
                         meaning,
+                        metadata: value.metadata.clone(),
                     },
                 );
             }
@@ -742,6 +1025,7 @@ impl OwlReader {
             }
             class_def.aliases = ann.aliases.clone();
             class_def.see_also = ann.see_also.clone();
+            class_def.metadata = ann.metadata.clone();
             class_def.exact_mappings = ann.exact_mappings.clone();
             class_def.close_mappings = ann.close_mappings.clone();
             class_def.related_mappings = ann.related_mappings.clone();
@@ -760,6 +1044,59 @@ impl OwlReader {
             schema.classes.insert(owl_class.id.clone(), class_def);
         }
 
+        // Map named datatypes to custom types. A base that is a LinkML
+        // primitive's own XSD datatype reads back as `typeof:` that
+        // primitive, a datatype this ontology defines as `typeof:` its type,
+        // and any other datatype (`xsd:nonNegativeInteger`) as the type's
+        // `uri:`. A facet LinkML has no field for is warned, not dropped
+        // silently.
+        for datatype in &metadata.datatypes {
+            let mut type_def = TypeDefinition::new(&datatype.id);
+            type_def.description = datatype.comment.clone();
+            if let Some(base) = &datatype.base_iri {
+                if let Some(parent) = metadata.datatypes.iter().find(|d| &d.iri == base) {
+                    type_def.typeof_ = Some(parent.id.clone());
+                } else if let Some(primitive) = [
+                    "string", "integer", "boolean", "float", "double", "decimal", "date",
+                    "datetime", "time", "uri", "ncname",
+                ]
+                .into_iter()
+                .find(|p| crate::primitives::xsd_datatype(p) == Some(base.as_str()))
+                {
+                    type_def.typeof_ = Some(primitive.to_string());
+                } else {
+                    type_def.uri = Some(base.clone());
+                }
+            }
+            type_def.pattern = datatype.pattern.clone();
+            type_def.minimum_value = datatype.min_inclusive;
+            type_def.maximum_value = datatype.max_inclusive;
+            for facet in &datatype.unsupported_facets {
+                warnings.push(format!(
+                    "datatype `{}` is restricted by xsd:{facet}, which a LinkML type cannot \
+                     hold — the facet was dropped",
+                    datatype.id,
+                ));
+            }
+            let ann = &datatype.annotations;
+            if ann.deprecated {
+                type_def.deprecated = Some(String::new());
+            }
+            type_def.aliases = ann.aliases.clone();
+            type_def.see_also = ann.see_also.clone();
+            type_def.metadata = ann.metadata.clone();
+            if let Some(ref label) = datatype.label
+                && label != &datatype.id
+            {
+                type_def
+                    .annotations
+                    .insert("panschema:label".to_string(), label.clone());
+            }
+            schema.types.insert(datatype.id.clone(), type_def);
+        }
+        let datatype_iris: std::collections::HashSet<_> =
+            metadata.datatypes.iter().map(|d| d.iri.as_str()).collect();
+
         // Map properties to slots
         let property_iris: std::collections::BTreeSet<&str> =
             metadata.properties.iter().map(|p| p.iri.as_str()).collect();
@@ -778,9 +1115,11 @@ impl OwlReader {
             if let Some(ref range_iri) = owl_prop.range_iri {
                 let range_id = extract_id_from_iri(range_iri);
 
-                // If range is a known class or enum, use its name
-                // Otherwise, it's probably an XSD datatype
-                if class_iris.contains(range_iri.as_str()) || enum_iris.contains(range_iri.as_str())
+                // If range is a known class, enum, or datatype, use its
+                // name. Otherwise, it's probably an XSD datatype
+                if class_iris.contains(range_iri.as_str())
+                    || enum_iris.contains(range_iri.as_str())
+                    || datatype_iris.contains(range_iri.as_str())
                 {
                     slot_def.range = Some(range_id);
                 } else {
@@ -848,6 +1187,7 @@ impl OwlReader {
             }
             slot_def.aliases = ann.aliases.clone();
             slot_def.see_also = ann.see_also.clone();
+            slot_def.metadata = ann.metadata.clone();
             slot_def.exact_mappings = ann.exact_mappings.clone();
             slot_def.close_mappings = ann.close_mappings.clone();
             slot_def.related_mappings = ann.related_mappings.clone();
@@ -1050,6 +1390,92 @@ mod tests {
         assert_eq!(schema.slots["hasName"].is_a, None);
     }
 
+    /// Language-tagged labels and comments are translations, not rivals
+    /// for the primary text: the untagged literal (else `@en`) stays the
+    /// label and description whatever order the graph yields them in, and
+    /// the others land in `local_names` / `alt_descriptions`.
+    #[test]
+    fn language_tagged_literals_read_as_translations() {
+        let schema = read_ttl(
+            r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix : <https://example.org/s#> .
+<https://example.org/s> a owl:Ontology .
+:Person a owl:Class ;
+    rdfs:label "Personne"@fr, "Person"@en, "Mensch"@de ;
+    rdfs:comment "Un être humain."@fr, "A human being." ;
+    skos:altLabel "Individu"@fr, "Human" .
+"#,
+        );
+        let person = &schema.classes["Person"];
+        assert_eq!(person.description.as_deref(), Some("A human being."));
+        assert_eq!(person.aliases, vec!["Human"]);
+        let m = &person.metadata;
+        let names: Vec<(&str, &str)> = m
+            .local_names
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [("de", "Mensch"), ("fr", "Personne")],
+            "`@en` is the primary label when nothing is untagged"
+        );
+        assert_eq!(m.alt_descriptions["fr"], "Un être humain.");
+        assert_eq!(m.structured_aliases.len(), 1);
+        assert_eq!(m.structured_aliases[0].literal_form, "Individu");
+        assert_eq!(m.structured_aliases[0].in_language.as_deref(), Some("fr"));
+    }
+
+    /// A named datatype defined as a blank-node facet restriction — the
+    /// form ontology editors write — reads back as a custom type carrying
+    /// the facets LinkML models, ranges its properties, and warns about
+    /// the facets it cannot hold.
+    #[test]
+    fn a_restricted_datatype_reads_back_as_a_custom_type() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("schema.ttl");
+        std::fs::write(
+            &path,
+            r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix : <https://example.org/s#> .
+<https://example.org/s> a owl:Ontology .
+:Percent a rdfs:Datatype ;
+    rdfs:comment "A share of a whole." ;
+    owl:equivalentClass [
+        a rdfs:Datatype ;
+        owl:onDatatype xsd:decimal ;
+        owl:withRestrictions ( [ xsd:minInclusive 0 ] [ xsd:maxExclusive 101 ] )
+    ] .
+:share a owl:DatatypeProperty ; rdfs:range :Percent .
+"#,
+        )
+        .expect("write ttl");
+        let (schema, warnings) = OwlReader::new()
+            .read_with_warnings(&path)
+            .expect("read ttl");
+
+        let percent = &schema.types["Percent"];
+        assert_eq!(percent.typeof_.as_deref(), Some("decimal"));
+        assert_eq!(percent.description.as_deref(), Some("A share of a whole."));
+        assert_eq!(percent.minimum_value, Some(0.0));
+        assert_eq!(
+            percent.maximum_value, None,
+            "an exclusive bound is not held"
+        );
+        assert_eq!(schema.types.len(), 1, "the restriction is not a type");
+        assert_eq!(schema.slots["share"].range.as_deref(), Some("Percent"));
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.contains("`Percent`") && w.contains("xsd:maxExclusive")),
+            "the dropped facet is named: {warnings:?}"
+        );
+    }
+
     /// A projection the IR cannot hold is warned, not silent: surplus
     /// `rdfs:subPropertyOf` axioms (LinkML `is_a` is single-valued) name
     /// what was kept and what was dropped, and an external parent names
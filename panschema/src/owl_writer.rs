@@ -517,6 +517,193 @@ mod tests {
         assert_eq!(amount2.range.as_deref(), Some("integer"));
     }
 
+    #[test]
+    fn roundtrip_preserves_custom_types_and_their_facets() {
+        use crate::linkml::TypeDefinition;
+        use crate::owl_reader::OwlReader;
+        // A custom type writes as a restricted `rdfs:Datatype` and reads
+        // back as the same `types:` entry, with the slot still ranged on it.
+        let mut schema = create_test_schema();
+        let mut score = TypeDefinition::new("Score");
+        score.description = Some("A mark out of 100.".to_string());
+        score.typeof_ = Some("integer".to_string());
+        score.minimum_value = Some(0.0);
+        score.maximum_value = Some(100.0);
+        schema.types.insert("Score".to_string(), score);
+        let mut code = TypeDefinition::new("Code");
+        code.typeof_ = Some("string".to_string());
+        code.pattern = Some("^[A-Z]{3}$".to_string());
+        schema.types.insert("Code".to_string(), code);
+        let mut count = TypeDefinition::new("Count");
+        count.uri = Some("http://www.w3.org/2001/XMLSchema#nonNegativeInteger".to_string());
+        schema.types.insert("Count".to_string(), count);
+        let mut exam = ClassDefinition::new("Exam");
+        let mut mark = SlotDefinition::new("mark");
+        mark.range = Some("Score".to_string());
+        exam.attributes.insert("mark".to_string(), mark);
+        schema.classes.insert("Exam".to_string(), exam);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("output.ttl");
+        OwlWriter::new()
+            .write(&schema, &output_path)
+            .expect("Failed to write");
+        let (schema2, warnings) = OwlReader::new()
+            .read_with_warnings(&output_path)
+            .expect("Failed to read back");
+
+        assert!(warnings.is_empty(), "nothing dropped: {warnings:?}");
+        for name in ["Score", "Code", "Count"] {
+            assert_eq!(schema2.types.get(name), schema.types.get(name), "{name}");
+        }
+        assert_eq!(schema2.slots["mark"].range.as_deref(), Some("Score"));
+    }
+
+    #[test]
+    fn roundtrip_preserves_common_metadata() {
+        use crate::linkml::{CommonMetadata, EnumDefinition, PermissibleValue};
+        use crate::owl_reader::OwlReader;
+        // The editorial and provenance long tail survives Turtle on every
+        // element kind: notes, status, rank, keywords and provenance
+        // annotate the element IRI and read back field for field.
+        let curated = CommonMetadata {
+            comments: vec!["Kept for legacy feeds.".to_string()],
+            notes: vec!["Reviewed at the spring sync.".to_string()],
+            todos: vec!["merge with Dossier".to_string()],
+            status: Some("bibo:draft".to_string()),
+            rank: Some(2),
+            keywords: vec!["records".to_string()],
+            source: Some("https://example.org/spec#records".to_string()),
+            created_by: Some("https://orcid.org/0000-0002-1825-0097".to_string()),
+            modified_by: Some("Curation team".to_string()),
+            created_on: Some("2024-03-01".to_string()),
+            last_updated_on: Some("2024-06-30T12:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let mut schema = create_test_schema();
+        schema.metadata.keywords = vec!["archives".to_string()];
+        let mut record = ClassDefinition::new("Record");
+        record.metadata = curated.clone();
+        let mut filed = SlotDefinition::new("filed_on");
+        filed.metadata.rank = Some(1);
+        filed.metadata.comments = vec!["Local time.".to_string()];
+        record.attributes.insert("filed_on".to_string(), filed);
+        schema.classes.insert("Record".to_string(), record);
+        let mut grade = EnumDefinition::new("Grade");
+        grade.metadata.status = Some("bibo:published".to_string());
+        let mut top = PermissibleValue::new("top");
+        top.metadata.rank = Some(1);
+        top.metadata.todos = vec!["define the threshold".to_string()];
+        grade.permissible_values.insert("top".to_string(), top);
+        schema.enums.insert("Grade".to_string(), grade);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("output.ttl");
+        OwlWriter::new()
+            .write(&schema, &output_path)
+            .expect("Failed to write");
+        let schema2 = OwlReader::new()
+            .read(&output_path)
+            .expect("Failed to read back");
+
+        assert_eq!(schema2.metadata, schema.metadata);
+        assert_eq!(schema2.classes["Record"].metadata, curated);
+        assert_eq!(
+            schema2.slots["filed_on"].metadata,
+            schema.classes["Record"].attributes["filed_on"].metadata
+        );
+        assert_eq!(
+            schema2.enums["Grade"].metadata,
+            schema.enums["Grade"].metadata
+        );
+        assert_eq!(
+            schema2.enums["Grade"].permissible_values["top"].metadata,
+            schema.enums["Grade"].permissible_values["top"].metadata
+        );
+    }
+
+    #[test]
+    fn roundtrip_preserves_source_keyed_text_and_structured_aliases() {
+        use crate::linkml::{PermissibleValue, StructuredAlias};
+        use crate::owl_reader::OwlReader;
+        // `local_names` / `alt_descriptions` are keyed by naming source,
+        // not language, and structured aliases keep their predicate and
+        // language; all of it reads back into the same fields, and the
+        // primary label and description stay the untagged ones.
+        let mut schema = create_test_schema();
+        schema.metadata.in_language = Some("en".to_string());
+        schema
+            .metadata
+            .local_names
+            .insert("wikidata".to_string(), "Test ontology".to_string());
+        let mut person = ClassDefinition::new("Person");
+        person.description = Some("A human being.".to_string());
+        person.aliases = vec!["Human".to_string()];
+        for (source, name) in [("ncit", "Person"), ("wikidata", "human")] {
+            person
+                .metadata
+                .local_names
+                .insert(source.to_string(), name.to_string());
+        }
+        person
+            .metadata
+            .alt_descriptions
+            .insert("ncit".to_string(), "A human being.".to_string());
+        person.metadata.structured_aliases = vec![
+            StructuredAlias {
+                literal_form: "Individu".to_string(),
+                predicate: Some("EXACT_SYNONYM".to_string()),
+                in_language: Some("fr".to_string()),
+            },
+            StructuredAlias {
+                literal_form: "Homo sapiens".to_string(),
+                predicate: Some("NARROW_SYNONYM".to_string()),
+                in_language: None,
+            },
+        ];
+        schema.classes.insert("Person".to_string(), person);
+        let mut kind = crate::linkml::EnumDefinition::new("Kind");
+        let mut large = PermissibleValue::new("large");
+        large
+            .metadata
+            .local_names
+            .insert("ncit".to_string(), "Large".to_string());
+        kind.permissible_values.insert("large".to_string(), large);
+        schema.enums.insert("Kind".to_string(), kind);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("output.ttl");
+        OwlWriter::new()
+            .write(&schema, &output_path)
+            .expect("Failed to write");
+        let ttl = std::fs::read_to_string(&output_path).unwrap();
+        assert!(ttl.contains("local_name_source"), "got:\n{ttl}");
+        assert!(
+            !ttl.contains("@ncit"),
+            "a source is no language tag; got:\n{ttl}"
+        );
+        assert!(ttl.contains("\"Individu\"@fr"), "got:\n{ttl}");
+        assert!(ttl.contains("narrowMatch"), "got:\n{ttl}");
+        let schema2 = OwlReader::new()
+            .read(&output_path)
+            .expect("Failed to read back");
+
+        assert_eq!(schema2.metadata, schema.metadata);
+        assert_eq!(schema2.title, schema.title);
+        let person2 = &schema2.classes["Person"];
+        assert_eq!(person2.metadata, schema.classes["Person"].metadata);
+        assert_eq!(person2.description.as_deref(), Some("A human being."));
+        assert_eq!(
+            person2.aliases,
+            vec!["Human".to_string()],
+            "a structured alias, tagged or not, is not a plain one"
+        );
+        assert_eq!(
+            schema2.enums["Kind"].permissible_values["large"].metadata,
+            schema.enums["Kind"].permissible_values["large"].metadata
+        );
+    }
+
     #[test]
     fn ttl_output_declares_prefixes_from_schema_prefixes_block() {
         // The schema's prefixes block must round-trip into TTL `PREFIX`
@@ -59,8 +59,8 @@ impl Writer for PostgresWriter {
     /// typed diagnostics ([`skipped_classes`], [`skipped_rules`],
     /// [`skipped_constraints`]) expose: a class with no table, a rule with
     /// no `CHECK` form, and a per-element constraint with no form over an
-    /// array column. The cross-format default contributes nothing here —
-    /// this format projects `rules` and `unique_keys`.
+    /// array column. The cross-format default contributes only the class
+    /// expressions — this format projects `rules` and `unique_keys`.
     fn projection_gaps(&self, schema: &SchemaDefinition) -> Vec<String> {
         let mut gaps = crate::diagnostics::classes_with_unprojected_constructs(schema, "postgres")
             .into_iter()
@@ -182,6 +182,9 @@ fn render_body(schema: &SchemaDefinition) -> String {
         // `skipped_rules` so its skip decision can't drift from what `render`
         // actually emits.
         let slot_columns = slot_column_map(class, schema);
+        // Scalar columns whose slot declares a `unit`, commented once the
+        // table exists so the unit travels with the column in the catalog.
+        let mut unit_comments: Vec<(String, String)> = Vec::new();
         for (slot_name, slot) in &effective {
             if pk_slot.as_deref() == Some(slot_name.as_str()) {
                 continue;
@@ -224,18 +227,36 @@ fn render_body(schema: &SchemaDefinition) -> String {
                 // Its value constraints are per-element and have no CHECK
                 // form over an array, so they are dropped here and reported
                 // by `skipped_constraints`.
-                let sql_type = match slot.multivalued {
-                    true => format!("{}[]", sql_type_for_slot_range(range, schema)),
-                    false => sql_type_for_slot_range(range, schema),
-                };
-                let checks = match slot.multivalued {
-                    true => String::new(),
-                    false => column_checks(col, slot),
+                // An `array` slot is a multidimensional array column,
+                // its shape checked with `array_ndims`/`array_length`.
+                let (sql_type, checks) = if let Some(array) = &slot.array {
+                    let depth = array.dimension_bounds().0.max(1) as usize;
+                    (
+                        format!(
+                            "{}{}",
+                            sql_type_for_slot_range(range, schema),
+                            "[]".repeat(depth)
+                        ),
+                        array_shape_checks(col, array),
+                    )
+                } else if slot.multivalued {
+                    (
+                        format!("{}[]", sql_type_for_slot_range(range, schema)),
+                        String::new(),
+                    )
+                } else {
+                    (
+                        sql_type_for_slot_range(range, schema),
+                        column_checks(col, slot),
+                    )
                 };
                 lines.push(format!(
                     "    {} {sql_type}{not_null}{checks}",
                     quote_ident(col)
                 ));
+                if let Some(unit) = slot.unit.as_ref().and_then(|u| u.display()) {
+                    unit_comments.push((col.clone(), unit));
+                }
             }
         }
         // Table-level UNIQUE constraints from `unique_keys`. A key that
@@ -286,6 +307,16 @@ fn render_body(schema: &SchemaDefinition) -> String {
         }
         writeln!(out, "{}", lines.join(",\n")).ok();
         writeln!(out, ");").ok();
+        for (col, unit) in &unit_comments {
+            writeln!(
+                out,
+                "COMMENT ON COLUMN {}.{} IS 'Unit: {}';",
+                quote_ident(&table),
+                quote_ident(col),
+                unit.replace('\'', "''")
+            )
+            .ok();
+        }
         writeln!(out).ok();
     }
 
@@ -384,6 +415,42 @@ fn column_checks(col: &str, slot: &crate::linkml::SlotDefinition) -> String {
     checks
 }
 
+/// Inline `CHECK` clauses for an `array` slot's column: the dimension
+/// count via `array_ndims` and each bounded axis's length via
+/// `array_length`. Postgres sizes in the column type are documentation
+/// only, so these are what enforce the shape; its arrays are always
+/// rectangular. An empty array has no dimensions and passes, as a NULL
+/// check result does.
+fn array_shape_checks(col: &str, array: &crate::linkml::ArrayExpression) -> String {
+    let col = quote_ident(col);
+    let mut conditions = Vec::new();
+    match array.dimension_bounds() {
+        (min, Some(max)) if min == max => conditions.push(format!("array_ndims({col}) = {min}")),
+        (min, Some(max)) => conditions.push(format!("array_ndims({col}) BETWEEN {min} AND {max}")),
+        (min, None) if min > 1 => conditions.push(format!("array_ndims({col}) >= {min}")),
+        (_, None) => {}
+    }
+    for axis in 0..array.dimensions.len() {
+        let length = format!("array_length({col}, {})", axis + 1);
+        match array.axis_bounds(axis) {
+            (Some(lo), Some(hi)) if lo == hi => conditions.push(format!("{length} = {lo}")),
+            (lo, hi) => {
+                if let Some(lo) = lo {
+                    conditions.push(format!("{length} >= {lo}"));
+                }
+                if let Some(hi) = hi {
+                    conditions.push(format!("{length} <= {hi}"));
+                }
+            }
+        }
+    }
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" CHECK ({})", conditions.join(" AND "))
+    }
+}
+
 /// Whether the slot's range names a class in this schema, as opposed to a
 /// scalar or an enum. The distinction decides both the column form (foreign
 /// key or linking table, versus a plain or array column) and whether a
@@ -569,7 +636,7 @@ fn rule_conditions_to_sql(
 }
 
 /// The class's slots that render as array columns — multivalued scalars
-/// and enums — where equality means membership and per-element facet
+/// and enums, and `array` slots — where equality means membership and per-element facet
 /// forms don't exist.
 fn array_column_slots(
     class: &ClassDefinition,
@@ -577,7 +644,9 @@ fn array_column_slots(
 ) -> std::collections::BTreeSet<String> {
     crate::linkml_resolve::resolve_effective_slots(class, schema)
         .into_iter()
-        .filter(|(_, slot)| slot.multivalued && !is_class_range(slot, schema))
+        .filter(|(_, slot)| {
+            (slot.multivalued || slot.array.is_some()) && !is_class_range(slot, schema)
+        })
         .map(|(name, _)| name)
         .collect()
 }
@@ -638,7 +707,7 @@ pub fn skipped_constraints(schema: &SchemaDefinition) -> Vec<SkippedConstraint>
         }
         let effective = crate::linkml_resolve::resolve_effective_slots(class, schema);
         for (slot_name, slot) in &effective {
-            if !slot.multivalued || is_class_range(slot, schema) {
+            if !(slot.multivalued || slot.array.is_some()) || is_class_range(slot, schema) {
                 continue;
             }
             let dropped = [
@@ -656,9 +725,14 @@ pub fn skipped_constraints(schema: &SchemaDefinition) -> Vec<SkippedConstraint>
                 class: class_name.clone(),
                 slot: slot_name.clone(),
                 reason: format!(
-                    "{} on a multivalued slot constrains each element, which has no Postgres \
+                    "{} on {} slot constrains each element, which has no Postgres \
                      CHECK form over an array column",
-                    dropped.join(" and ")
+                    dropped.join(" and "),
+                    if slot.array.is_some() {
+                        "an `array`"
+                    } else {
+                        "a multivalued"
+                    }
                 ),
             });
         }
@@ -750,11 +824,20 @@ fn compute_skips(schema: &SchemaDefinition) -> BTreeMap<String, String> {
             continue;
         }
         let effective = crate::linkml_resolve::resolve_effective_slots(class, schema);
-        if let Some((slot_name, _)) = effective.iter().find(|(_, s)| !s.any_of.is_empty()) {
+        let union = effective.iter().find_map(|(slot_name, s)| {
+            if !s.any_of.is_empty() {
+                Some((slot_name, "any_of"))
+            } else if !s.exactly_one_of.is_empty() {
+                Some((slot_name, "exactly_one_of"))
+            } else {
+                None
+            }
+        });
+        if let Some((slot_name, combinator)) = union {
             skips.insert(
                 name.clone(),
                 format!(
-                    "has polymorphic `any_of` slot `{slot_name}`, which this writer does not yet support"
+                    "has polymorphic `{combinator}` slot `{slot_name}`, which this writer does not yet support"
                 ),
             );
         }
@@ -1063,6 +1146,63 @@ mod tests {
         assert_eq!(written, PostgresWriter::new().render(&schema));
     }
 
+    #[test]
+    fn slot_unit_becomes_a_column_comment() {
+        let mut class = ClassDefinition::new("Parcel");
+        let mut weight = SlotDefinition::new("weight");
+        weight.range = Some("decimal".to_string());
+        weight.unit = Some(crate::linkml::UnitOfMeasure {
+            ucum_code: Some("[lb_av]".to_string()),
+            descriptive_name: Some("pound (avoirdupois, int'l)".to_string()),
+            ..Default::default()
+        });
+        class.attributes.insert("weight".to_string(), weight);
+        let schema = schema_with_class(class);
+
+        let out = PostgresWriter::new().render(&schema);
+        assert_valid_postgres_sql(&out);
+        assert!(
+            out.contains(
+                "COMMENT ON COLUMN \"parcel\".\"weight\" IS 'Unit: [lb_av] (pound (avoirdupois, int''l))';"
+            ),
+            "the unit is a column comment with its quote escaped; got:\n{out}"
+        );
+    }
+
+    #[test]
+    fn array_slot_becomes_a_shape_checked_multidimensional_column() {
+        let mut class = ClassDefinition::new("Image");
+        let mut pixels = SlotDefinition::new("pixels");
+        pixels.range = Some("integer".to_string());
+        pixels.array = Some(crate::linkml::ArrayExpression {
+            dimensions: vec![
+                crate::linkml::DimensionExpression {
+                    minimum_cardinality: Some(1),
+                    maximum_cardinality: Some(4096),
+                    ..Default::default()
+                },
+                crate::linkml::DimensionExpression {
+                    exact_cardinality: Some(3),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        class.attributes.insert("pixels".to_string(), pixels);
+        let schema = schema_with_class(class);
+
+        let out = PostgresWriter::new().render(&schema);
+        assert_valid_postgres_sql(&out);
+        assert!(
+            out.contains(
+                "\"pixels\" integer[][] CHECK (array_ndims(\"pixels\") = 2 AND \
+                 array_length(\"pixels\", 1) >= 1 AND array_length(\"pixels\", 1) <= 4096 AND \
+                 array_length(\"pixels\", 2) = 3)"
+            ),
+            "got:\n{out}"
+        );
+    }
+
     #[test]
     fn reserved_word_class_name_emits_quoted_applicable_ddl() {
         // `ORDER` is a fully reserved Postgres keyword: unquoted
@@ -1090,8 +1090,12 @@ fn render_page(
 ) -> Result<(), PublishError> {
     let page = plan.page;
     let cohort = cohort_for(publishing, publish_cfg, page, &plan.present(), links);
+    // Every version's schema is loaded before any renders: each one's
+    // Changes page diffs it against its predecessor. The extracted file
+    // is dropped once read, so this holds no more open than before.
+    let mut schemas = Vec::with_capacity(plan.plans.len());
     for rp in &plan.plans {
-        let schema = match &rp.dep_schema {
+        let file = match &rp.dep_schema {
             Some(path) => Materialized::OnDisk(path.clone()),
             None => match rp.source {
                 BuildSource::GitRef(ref_) => Materialized::Extracted(extract_main_at_ref(
@@ -1107,6 +1111,11 @@ fn render_page(
                 }
             },
         };
+        schemas.push(load_version_schema(rp.label, file.path())?);
+    }
+    let labels = plan.present();
+    let mut changes = version_changes(&labels, &schemas, publishing.edge.as_deref(), &cohort)?;
+    for (i, rp) in plan.plans.iter().enumerate() {
         let extracted: Vec<(Materialized, &InstanceEntry)> = rp
             .datasets
             .iter()
@@ -1137,7 +1146,14 @@ fn render_page(
             .iter()
             .map(|(file, entry)| (file.path(), *entry))
             .collect();
-        generate_html_for_version(rp.label, schema.path(), &version_out, &cohort, &datasets)?;
+        generate_html_for_version(
+            rp.label,
+            &schemas[i],
+            &version_out,
+            &cohort,
+            &datasets,
+            changes[i].take(),
+        )?;
     }
 
     // current/ is a copy of the page-current version's output, not a
@@ -1365,36 +1381,124 @@ impl CohortContext {
     }
 }
 
-/// Run the HTML generator against a single extracted schema file with
-/// the cohort's version context attached, so the rendered page gets
-/// the dropdown + banner UX. Wraps the Reader/Writer pipeline so any
-/// failure is surfaced as [`PublishError::GenerateFailed`] tagged with
-/// the version that failed.
-fn generate_html_for_version(
+/// The order releases happened in: released versions by semver when
+/// every label parses as one (a leading `v` allowed), else in manifest
+/// order, then the edge build last — it is ahead of every release.
+/// Returns indices into `labels`.
+fn chronological_order(labels: &[String], edge: Option<&str>) -> Vec<usize> {
+    let parse = |label: &str| semver::Version::parse(label.strip_prefix('v').unwrap_or(label));
+    let mut released: Vec<usize> = (0..labels.len())
+        .filter(|&i| Some(labels[i].as_str()) != edge)
+        .collect();
+    if released.iter().all(|&i| parse(&labels[i]).is_ok()) {
+        released.sort_by_key(|&i| parse(&labels[i]).ok());
+    }
+    released.extend((0..labels.len()).filter(|&i| Some(labels[i].as_str()) == edge));
+    released
+}
+
+/// Each version's changes since its predecessor in
+/// [`chronological_order`], indexed like `labels`; the oldest version
+/// has none. A version's badges carry forward every element's latest
+/// addition or modification up to it, each linking that version's
+/// Changes page through the cohort's `url_pattern`. A pair of versions
+/// that can't be compared fails the publish, tagged with the newer one.
+fn version_changes(
+    labels: &[String],
+    schemas: &[crate::linkml::SchemaDefinition],
+    edge: Option<&str>,
+    cohort: &CohortContext,
+) -> Result<Vec<Option<crate::html_writer::VersionChanges>>, PublishError> {
+    use crate::html_writer::{ChangeBadge, VersionChanges};
+    use crate::schema_diff::{ChangeKind, diff_schemas};
+
+    let mut out = vec![None; labels.len()];
+    // Card anchor → (version, change) of the element's latest change.
+    let mut latest: std::collections::BTreeMap<String, (usize, ChangeKind)> =
+        std::collections::BTreeMap::new();
+    let order = chronological_order(labels, edge);
+    for pair in order.windows(2) {
+        let (prev, cur) = (pair[0], pair[1]);
+        let changes = diff_schemas(&schemas[prev], &schemas[cur]).map_err(|e| {
+            PublishError::GenerateFailed {
+                version: labels[cur].clone(),
+                message: format!("diffing against `{}`: {e}", labels[prev]),
+            }
+        })?;
+        for change in &changes {
+            if change.change == ChangeKind::Removed {
+                latest.remove(&change.anchor());
+            } else {
+                latest.insert(change.anchor(), (cur, change.change));
+            }
+        }
+        let ctx = cohort.context_for(&labels[cur]);
+        let badges = latest
+            .iter()
+            .map(|(anchor, &(v, change))| {
+                let page = if v == cur {
+                    String::new()
+                } else {
+                    ctx.url_for(&labels[v])
+                };
+                let badge = ChangeBadge {
+                    version: labels[v].clone(),
+                    change,
+                    href: format!("{page}changes.html#{anchor}"),
+                };
+                (anchor.clone(), badge)
+            })
+            .collect();
+        out[cur] = Some(VersionChanges {
+            previous: labels[prev].clone(),
+            previous_href: ctx.url_for(&labels[prev]),
+            changes,
+            badges,
+        });
+    }
+    Ok(out)
+}
+
+/// Read one version's schema file, resolving local `imports:` through
+/// the shared load path so a published version renders the same merged
+/// schema as `generate`/`serve`. A failure is tagged with the version.
+fn load_version_schema(
     version: &str,
     input: &Path,
+) -> Result<crate::linkml::SchemaDefinition, PublishError> {
+    let registry = crate::io::FormatRegistry::with_defaults();
+    crate::import_resolve::load_schema(input, &registry).map_err(|e| PublishError::GenerateFailed {
+        version: version.to_string(),
+        message: e.to_string(),
+    })
+}
+
+/// Run the HTML generator against one version's schema with the
+/// cohort's version context attached, so the rendered page gets the
+/// dropdown + banner UX, and with its changes since the previous
+/// version, if any. Wraps the writer pipeline so any failure is
+/// surfaced as [`PublishError::GenerateFailed`] tagged with the version
+/// that failed.
+fn generate_html_for_version(
+    version: &str,
+    schema: &crate::linkml::SchemaDefinition,
     output: &Path,
     cohort: &CohortContext,
     instances: &[(&Path, &InstanceEntry)],
+    changes: Option<crate::html_writer::VersionChanges>,
 ) -> Result<(), PublishError> {
     use crate::html_writer::HtmlWriter;
-    use crate::io::{FormatRegistry, Writer};
+    use crate::io::Writer;
 
-    let registry = FormatRegistry::with_defaults();
-    // Read + resolve local `imports:` through the shared load path, so a
-    // published version renders the same merged schema as `generate`/`serve`.
-    let schema = crate::import_resolve::load_schema(input, &registry).map_err(|e| {
-        PublishError::GenerateFailed {
-            version: version.to_string(),
-            message: e.to_string(),
-        }
-    })?;
     let mut writer = HtmlWriter::with_options(true)
         .with_version_context(cohort.context_for(version))
         .with_site_root_href(cohort.site_root_href.clone())
         .with_instances_first(cohort.instances_first)
         .with_schema_sections(cohort.schema_sections)
         .with_page_links(cohort.page_links.clone());
+    if let Some(changes) = changes {
+        writer = writer.with_changes(changes);
+    }
     // The file is read from the first path (a per-ref extraction lands in a
     // tempfile) while provenance shows the declared name.
     let mut loaded: Vec<(String, crate::instances::InstanceSet, &InstanceEntry)> = Vec::new();
@@ -1410,11 +1514,11 @@ fn generate_html_for_version(
                 version: version.to_string(),
                 message: format!("parsing instance data {}: {e}", declared.display()),
             })?;
-        let set = crate::instances::InstanceSet::from_linkml_data(&schema, &data);
+        let set = crate::instances::InstanceSet::from_linkml_data(schema, &data);
         // Same check as `generate --instances` and `validate --data`: a
         // curated A-box is published page content, so it gets the conformance
         // gate rather than only a reference-integrity look.
-        for v in crate::validate::validate_instances(&schema, &set) {
+        for v in crate::validate::validate_instances(schema, &set) {
             eprintln!("warning: {version}: {v}");
         }
         loaded.push((declared.display().to_string(), set, entry));
@@ -1427,10 +1531,10 @@ fn generate_html_for_version(
         .iter()
         .map(|(label, set, _)| (label.as_str(), set))
         .collect();
-    for c in crate::diagnostics::cross_dataset_iri_collisions(&schema, &borrowed) {
+    for c in crate::diagnostics::cross_dataset_iri_collisions(schema, &borrowed) {
         eprintln!("note: {version}: {}", c.message());
     }
-    for split in crate::diagnostics::cross_dataset_unintended_splits(&schema, &borrowed) {
+    for split in crate::diagnostics::cross_dataset_unintended_splits(schema, &borrowed) {
         eprintln!("note: {version}: {}", split.message());
     }
 
@@ -1449,12 +1553,12 @@ fn generate_html_for_version(
         writer = writer.with_instance_dataset(dataset);
     }
     if let Some(store) =
-        crate::labels::open_default_store(&schema, false, &cohort.label_sources, false)
+        crate::labels::open_default_store(schema, false, &cohort.label_sources, false)
     {
         writer = writer.with_label_store(store);
     }
     writer
-        .write(&schema, output)
+        .write(schema, output)
         .map_err(|e| PublishError::GenerateFailed {
             version: version.to_string(),
             message: e.to_string(),
@@ -3849,7 +3953,8 @@ current = "v0.1.0"
             page_links: Vec::new(),
         };
 
-        generate_html_for_version("1.0.0", &main, &out, &cohort, &[])
+        let schema = load_version_schema("1.0.0", &main).expect("schema should load");
+        generate_html_for_version("1.0.0", &schema, &out, &cohort, &[], None)
             .expect("publish generation should succeed");
         let html = std::fs::read_to_string(out.join("index.html")).expect("index.html");
         assert!(
@@ -3857,4 +3962,63 @@ current = "v0.1.0"
             "published docs must include imported classes; `ImportedThing` was missing"
         );
     }
+
+    #[test]
+    fn releases_diff_in_semver_order_with_the_edge_last() {
+        let labels: Vec<String> = ["main", "v0.10.0", "v0.2.0", "v0.9.1"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(chronological_order(&labels, Some("main")), [2, 3, 1, 0]);
+        // A label semver can't read leaves the manifest order standing.
+        let labels: Vec<String> = ["first", "v2.0.0"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(chronological_order(&labels, None), [0, 1]);
+    }
+
+    #[test]
+    fn version_changes_carry_badges_forward_to_later_versions() {
+        use crate::linkml::{ClassDefinition, SchemaDefinition};
+        use crate::schema_diff::ChangeKind;
+
+        let v1 = SchemaDefinition::new("zoo");
+        let mut v2 = v1.clone();
+        v2.classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        let mut edge = v2.clone();
+        edge.classes
+            .insert("Keeper".to_string(), ClassDefinition::new("Keeper"));
+
+        // Dropdown order: edge first, then releases.
+        let labels: Vec<String> = ["main", "v1", "v2"].iter().map(|s| s.to_string()).collect();
+        let cohort = CohortContext {
+            all_versions: labels.clone(),
+            current: "v2".to_string(),
+            edge: Some("main".to_string()),
+            url_pattern: "../{version}/".to_string(),
+            site_root_href: "../current/".to_string(),
+            label_sources: std::collections::BTreeMap::new(),
+            instances_first: false,
+            schema_sections: true,
+            page_links: Vec::new(),
+        };
+        let changes = version_changes(&labels, &[edge, v1, v2], Some("main"), &cohort).unwrap();
+
+        assert!(
+            changes[1].is_none(),
+            "the oldest release has no predecessor"
+        );
+        let v2 = changes[2].as_ref().unwrap();
+        assert_eq!(v2.previous, "v1");
+        assert_eq!(v2.previous_href, "../v1/");
+        assert_eq!(v2.badges["class-Animal"].href, "changes.html#class-Animal");
+
+        let edge = changes[0].as_ref().unwrap();
+        assert_eq!(edge.previous, "v2");
+        assert_eq!(edge.changes.len(), 1, "only Keeper is new since v2");
+        let animal = &edge.badges["class-Animal"];
+        assert_eq!(animal.version, "v2");
+        assert_eq!(animal.change, ChangeKind::Added);
+        assert_eq!(animal.href, "../v2/changes.html#class-Animal");
+        assert_eq!(edge.badges["class-Keeper"].version, "main");
+    }
 }
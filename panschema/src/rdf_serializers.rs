@@ -14,7 +14,7 @@ use sophia::inmem::graph::FastGraph;
 use sophia::iri::Iri;
 
 use crate::io::{IoError, IoResult, Writer};
-use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use crate::linkml::{ClassDefinition, SchemaDefinition, SlotDefinition, UnitOfMeasure};
 
 // Namespace constants
 pub(crate) const OWL_NS: &str = "http://www.w3.org/2002/07/owl#";
@@ -24,6 +24,22 @@ pub(crate) const SH_NS: &str = "http://www.w3.org/ns/shacl#";
 pub(crate) const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
 pub(crate) const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
 pub(crate) const RDFS_NS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const QUDT_NS: &str = "http://qudt.org/schema/qudt/";
+pub(crate) const PAV_NS: &str = "http://purl.org/pav/";
+pub(crate) const BIBO_NS: &str = "http://purl.org/ontology/bibo/";
+pub(crate) const SCHEMA_ORG_NS: &str = "http://schema.org/";
+pub(crate) const OSLC_NS: &str = "http://open-services.net/ns/core#";
+pub(crate) const LINKML_NS: &str = "https://w3id.org/linkml/";
+pub(crate) const SKOSXL_NS: &str = "http://www.w3.org/2008/05/skos-xl#";
+
+/// LinkML's `AliasPredicateEnum` values and the SKOS mapping predicate
+/// each is emitted as, the `rdf:predicate` of a structured alias.
+pub(crate) const ALIAS_PREDICATES: &[(&str, &str)] = &[
+    ("EXACT_SYNONYM", "exactMatch"),
+    ("RELATED_SYNONYM", "closeMatch"),
+    ("BROAD_SYNONYM", "broadMatch"),
+    ("NARROW_SYNONYM", "narrowMatch"),
+];
 
 /// Build a sophia Turtle prefix map from the schema's `prefixes:` block plus
 /// the given per-writer builtin prefixes (e.g. `xsd:` for OWL, `sh:` for
@@ -233,6 +249,34 @@ fn enum_iri_string(name: &str, schema: &SchemaDefinition) -> String {
     fallback_element_iri(name, schema)
 }
 
+/// Absolute IRI for a custom type's `rdfs:Datatype`, minted as an enum's
+/// is. The type's `uri:` names the datatype it restricts, not the type.
+fn type_iri_string(name: &str, schema: &SchemaDefinition) -> String {
+    fallback_element_iri(name, schema)
+}
+
+/// The datatype a custom type restricts, as an absolute IRI: the nearest
+/// `uri:` along its `typeof` chain, else the XSD datatype of the built-in
+/// primitive the chain reaches. `None` for an unknown parent or a cycle.
+fn type_base_datatype(name: &str, schema: &SchemaDefinition) -> Option<String> {
+    let mut seen: Vec<&str> = Vec::new();
+    let mut current = name;
+    loop {
+        if let Some(xsd) = crate::primitives::xsd_datatype(current) {
+            return Some(xsd.to_string());
+        }
+        if seen.contains(&current) {
+            return None;
+        }
+        seen.push(current);
+        let type_def = schema.types.get(current)?;
+        if let Some(uri) = &type_def.uri {
+            return Some(expand_curie(uri, schema));
+        }
+        current = type_def.typeof_.as_deref()?;
+    }
+}
+
 /// Absolute IRI for a slot: its `slot_uri` (CURIE-expanded) or
 /// `{ontology}#{name}`. Shared by the OWL graph and the SHACL shapes graph.
 fn slot_iri_string(name: &str, slot_def: &SlotDefinition, schema: &SchemaDefinition) -> String {
@@ -327,6 +371,212 @@ fn emit_aliases_and_see_also(
     Ok(())
 }
 
+/// Emit an element's [`CommonMetadata`] on its subject IRI, each field
+/// through the predicate the LinkML metamodel gives its slot: `skos:note`
+/// per comment, `skos:editorialNote` per note, `linkml:todos` per todo,
+/// `schema:keywords` per keyword, `bibo:status`, `sh:order` (the rank, as
+/// an `xsd:integer`), `dcterms:source`, `pav:createdBy`, `oslc:modifiedBy`,
+/// `pav:createdOn` and `pav:lastUpdateOn`. The CURIE-valued fields become
+/// IRIs when they expand against the schema's prefixes, literals
+/// otherwise; timestamps are typed `xsd:dateTime` / `xsd:date` when they
+/// are one.
+///
+/// `local_names` and `alt_descriptions` are keyed by their source (a
+/// naming authority such as `ncit`), so each entry is a node of its own,
+/// the way the LinkML metamodel shapes it: `linkml:local_names` to a node
+/// carrying `linkml:local_name_source` and `linkml:local_name_value`, and
+/// `linkml:alt_descriptions` to one carrying
+/// `linkml:alt_description_source` and `linkml:alt_description_text`.
+/// Each structured alias is a node under `linkml:structured_aliases` with
+/// its `skosxl:literalForm`, `schema:inLanguage`, and an `rdf:predicate`
+/// naming the SKOS mapping its synonym relation means
+/// ([`ALIAS_PREDICATES`]); the alias is also a `skos:altLabel`, tagged
+/// when its language is a well-formed tag, for SKOS consumers. The nodes
+/// get IRIs under the subject (`{subject}/local_name{j}`, …) rather than
+/// blank nodes, as the class expressions do. `in_language` is
+/// `dcterms:language`.
+///
+/// [`CommonMetadata`]: crate::linkml::CommonMetadata
+fn emit_common_metadata(
+    graph: &mut FastGraph,
+    subject_iri: &Iri<String>,
+    schema: &SchemaDefinition,
+    metadata: &crate::linkml::CommonMetadata,
+) -> IoResult<()> {
+    use crate::instances::ScalarValue;
+    let predicate = |ns: &'static str, name: &str| make_iri(&format!("{ns}{name}"));
+
+    for (ns, name, values) in [
+        (SKOS_NS, "note", &metadata.comments),
+        (SKOS_NS, "editorialNote", &metadata.notes),
+        (LINKML_NS, "todos", &metadata.todos),
+        (SCHEMA_ORG_NS, "keywords", &metadata.keywords),
+    ] {
+        if values.is_empty() {
+            continue;
+        }
+        let p = predicate(ns, name)?;
+        for value in values {
+            triple(graph, subject_iri, &p, value.as_str())?;
+        }
+    }
+
+    for (ns, name, value) in [
+        (BIBO_NS, "status", &metadata.status),
+        (DCTERMS_NS, "source", &metadata.source),
+        (PAV_NS, "createdBy", &metadata.created_by),
+        (OSLC_NS, "modifiedBy", &metadata.modified_by),
+    ] {
+        let Some(value) = value else { continue };
+        let p = predicate(ns, name)?;
+        let expanded = value
+            .contains(':')
+            .then(|| crate::linkml_resolve::expand_curie(schema, value))
+            .flatten()
+            .and_then(|iri| Iri::new(iri).ok());
+        match expanded {
+            Some(object_iri) => triple(graph, subject_iri, &p, &object_iri)?,
+            None => triple(graph, subject_iri, &p, value.as_str())?,
+        }
+    }
+
+    if let (Some(rank), Some(integer)) = (metadata.rank, crate::primitives::xsd_datatype("integer"))
+    {
+        let lexical = rank.to_string();
+        triple(
+            graph,
+            subject_iri,
+            &predicate(SH_NS, "order")?,
+            typed_literal(&lexical, integer),
+        )?;
+    }
+
+    for (name, value) in [
+        ("createdOn", &metadata.created_on),
+        ("lastUpdateOn", &metadata.last_updated_on),
+    ] {
+        let Some(value) = value else { continue };
+        let p = predicate(PAV_NS, name)?;
+        let scalar = ScalarValue::String(value.clone());
+        let typed = ["datetime", "date"]
+            .into_iter()
+            .find_map(|range| crate::primitives::range_typed_literal(range, &scalar));
+        match typed {
+            Some((lexical, datatype)) => {
+                triple(graph, subject_iri, &p, typed_literal(&lexical, datatype))?
+            }
+            None => triple(graph, subject_iri, &p, value.as_str())?,
+        }
+    }
+
+    if let Some(language) = &metadata.in_language {
+        triple(
+            graph,
+            subject_iri,
+            &predicate(DCTERMS_NS, "language")?,
+            language.as_str(),
+        )?;
+    }
+    for (field, node, entries) in [
+        ("local_names", "local_name", &metadata.local_names),
+        (
+            "alt_descriptions",
+            "alt_description",
+            &metadata.alt_descriptions,
+        ),
+    ] {
+        let (source_name, text_name) = match node {
+            "local_name" => ("local_name_source", "local_name_value"),
+            _ => ("alt_description_source", "alt_description_text"),
+        };
+        let (link, source_p, text_p) = (
+            predicate(LINKML_NS, field)?,
+            predicate(LINKML_NS, source_name)?,
+            predicate(LINKML_NS, text_name)?,
+        );
+        for (j, (source, text)) in entries.iter().enumerate() {
+            let node_iri = make_iri(&format!("{}/{node}{j}", subject_iri.as_str()))?;
+            triple(graph, subject_iri, &link, &node_iri)?;
+            triple(graph, &node_iri, &source_p, source.as_str())?;
+            triple(graph, &node_iri, &text_p, text.as_str())?;
+        }
+    }
+    if !metadata.structured_aliases.is_empty() {
+        let alt_label = predicate(SKOS_NS, "altLabel")?;
+        let link = predicate(LINKML_NS, "structured_aliases")?;
+        let literal_form = predicate(SKOSXL_NS, "literalForm")?;
+        let in_language = predicate(SCHEMA_ORG_NS, "inLanguage")?;
+        let rdf_predicate = predicate(RDF_NS, "predicate")?;
+        for (j, alias) in metadata.structured_aliases.iter().enumerate() {
+            let text = alias.literal_form.as_str();
+            let node_iri = make_iri(&format!("{}/structured_alias{j}", subject_iri.as_str()))?;
+            triple(graph, subject_iri, &link, &node_iri)?;
+            triple(graph, &node_iri, &literal_form, text)?;
+            if let Some(tag) = &alias.in_language {
+                triple(graph, &node_iri, &in_language, tag.as_str())?;
+            }
+            if let Some(name) = &alias.predicate {
+                match ALIAS_PREDICATES
+                    .iter()
+                    .find(|(enum_name, _)| enum_name == name)
+                {
+                    Some((_, skos_name)) => triple(
+                        graph,
+                        &node_iri,
+                        &rdf_predicate,
+                        &predicate(SKOS_NS, skos_name)?,
+                    )?,
+                    None => triple(graph, &node_iri, &rdf_predicate, name.as_str())?,
+                }
+            }
+            match alias
+                .in_language
+                .as_deref()
+                .and_then(|tag| language_literal(text, tag))
+            {
+                Some(literal) => triple(graph, subject_iri, &alt_label, literal)?,
+                None => triple(graph, subject_iri, &alt_label, text)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `text` as an `rdf:langString` tagged `tag`, or `None` when `tag` is no
+/// well-formed BCP 47 language tag.
+fn language_literal<'a>(text: &'a str, tag: &'a str) -> Option<sophia::api::term::SimpleTerm<'a>> {
+    let tag = sophia::api::term::LanguageTag::new(sophia::api::MownStr::from(tag)).ok()?;
+    Some(sophia::api::term::SimpleTerm::LiteralLanguage(
+        sophia::api::MownStr::from(text),
+        tag,
+    ))
+}
+
+/// Emit a property's unit of measure as QUDT annotations: one
+/// `qudt:unit` IRI per `exact_mappings` entry (CURIE-expanded), plus the
+/// `qudt:ucumCode` and `qudt:symbol` literals when set.
+fn emit_unit(
+    graph: &mut FastGraph,
+    subject_iri: &Iri<String>,
+    schema: &SchemaDefinition,
+    unit: &UnitOfMeasure,
+) -> IoResult<()> {
+    let qudt = Namespace::new_unchecked(QUDT_NS);
+    let get = |name: &str| qudt.get(name).map_err(|e| IoError::Parse(e.to_string()));
+    let (qudt_unit, qudt_ucum_code, qudt_symbol) = (get("unit")?, get("ucumCode")?, get("symbol")?);
+    for mapping in &unit.exact_mappings {
+        let unit_iri = make_iri(&expand_curie(mapping, schema))?;
+        triple(graph, subject_iri, qudt_unit, &unit_iri)?;
+    }
+    if let Some(code) = &unit.ucum_code {
+        triple(graph, subject_iri, qudt_ucum_code, code.as_str())?;
+    }
+    if let Some(symbol) = &unit.symbol {
+        triple(graph, subject_iri, qudt_symbol, symbol.as_str())?;
+    }
+    Ok(())
+}
+
 /// Build an RDF graph from a SchemaDefinition
 pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
     let mut graph = FastGraph::new();
@@ -425,6 +675,7 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             modified.as_str(),
         )?;
     }
+    emit_common_metadata(&mut graph, &ontology_iri, schema, &schema.metadata)?;
 
     // Classes
     let owl_class = owl
@@ -436,6 +687,15 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
     let owl_union_of = owl
         .get("unionOf")
         .map_err(|e| IoError::Parse(e.to_string()))?;
+    let owl_intersection_of = owl
+        .get("intersectionOf")
+        .map_err(|e| IoError::Parse(e.to_string()))?;
+    let owl_disjoint_with = owl
+        .get("disjointWith")
+        .map_err(|e| IoError::Parse(e.to_string()))?;
+    let owl_equivalent_class = owl
+        .get("equivalentClass")
+        .map_err(|e| IoError::Parse(e.to_string()))?;
     let rdfs_subclass_of = rdfs::subClassOf;
 
     for (name, class_def) in &schema.classes {
@@ -483,6 +743,60 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             triple(&mut graph, &class_iri, rdfs_subclass_of, &target_iri)?;
         }
 
+        // Class expressions over named classes: `any_of` / `all_of` name a
+        // superclass that is the union / intersection of the members,
+        // `none_of` and `disjoint_with` are `owl:disjointWith` (C ⊑ ¬A ⊓ ¬B
+        // is C disjoint from each), and `union_of` makes the class
+        // equivalent to the union. A combinator with a slot-conditioned
+        // member has no axiom here — `exactly_one_of` never does — and is
+        // reported by `classes_with_unprojected_constructs`; the SHACL
+        // shapes carry it.
+        let named = |members: &[crate::linkml::ClassExpression]| -> Option<Vec<String>> {
+            members
+                .iter()
+                .map(|m| m.named_class().map(str::to_string))
+                .collect()
+        };
+        for (combinator, members, predicate) in [
+            ("any_of", &class_def.any_of, owl_union_of),
+            ("all_of", &class_def.all_of, owl_intersection_of),
+        ] {
+            if let Some(members) = named(members.as_slice()).filter(|m| !m.is_empty()) {
+                let node_str = format!("{class_iri_str}/{combinator}");
+                emit_class_list_node(
+                    &mut graph, &node_str, owl_class, predicate, &members, schema,
+                )?;
+                triple(
+                    &mut graph,
+                    &class_iri,
+                    rdfs_subclass_of,
+                    &make_iri(&node_str)?,
+                )?;
+            }
+        }
+        let excluded = named(class_def.none_of.as_slice()).unwrap_or_default();
+        for other in excluded.iter().chain(&class_def.disjoint_with) {
+            let other_iri = make_iri(&class_iri_by_name(other, schema))?;
+            triple(&mut graph, &class_iri, owl_disjoint_with, &other_iri)?;
+        }
+        if !class_def.union_of.is_empty() {
+            let node_str = format!("{class_iri_str}/union_of");
+            emit_class_list_node(
+                &mut graph,
+                &node_str,
+                owl_class,
+                owl_union_of,
+                &class_def.union_of,
+                schema,
+            )?;
+            triple(
+                &mut graph,
+                &class_iri,
+                owl_equivalent_class,
+                &make_iri(&node_str)?,
+            )?;
+        }
+
         emit_mappings(
             &mut graph,
             &class_iri,
@@ -501,6 +815,7 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             &class_def.aliases,
             &class_def.see_also,
         )?;
+        emit_common_metadata(&mut graph, &class_iri, schema, &class_def.metadata)?;
     }
 
     // Enums. A permissible value set is a class whose members are the named
@@ -524,6 +839,7 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
         if enum_def.deprecated.is_some() {
             triple(&mut graph, &enum_iri, owl_deprecated, true)?;
         }
+        emit_common_metadata(&mut graph, &enum_iri, schema, &enum_def.metadata)?;
 
         let mut value_iris = Vec::new();
         for (key, pv) in &enum_def.permissible_values {
@@ -544,6 +860,7 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             if let Some(description) = &pv.description {
                 triple(&mut graph, &value_iri, rdfs::comment, description.as_str())?;
             }
+            emit_common_metadata(&mut graph, &value_iri, schema, &pv.metadata)?;
             value_iris.push(value_iri);
         }
         if !value_iris.is_empty() {
@@ -558,6 +875,98 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
         }
     }
 
+    // Types. A custom `types:` entry is a named `rdfs:Datatype` equivalent
+    // to its base datatype restricted by the type's facets — OWL 2's
+    // datatype definition, `owl:onDatatype` + `owl:withRestrictions`. OWL
+    // restricts only built-in datatypes, so a `typeof` chain through other
+    // custom types is flattened onto the datatype the chain reaches, with
+    // the facets it accumulates. A primitive imported from `linkml:types`
+    // is an XSD datatype already and emits nothing.
+    let owl_on_datatype = owl
+        .get("onDatatype")
+        .map_err(|e| IoError::Parse(e.to_string()))?;
+    let owl_with_restrictions = owl
+        .get("withRestrictions")
+        .map_err(|e| IoError::Parse(e.to_string()))?;
+    for (type_name, type_def) in &schema.types {
+        if crate::primitives::canonical_primitive(type_name).is_some() {
+            continue;
+        }
+        let type_iri_str = type_iri_string(type_name, schema);
+        let type_iri = make_iri(&type_iri_str)?;
+        triple(&mut graph, &type_iri, rdf::type_, rdfs::Datatype)?;
+        triple(&mut graph, &type_iri, rdfs::label, type_name.as_str())?;
+        if let Some(description) = &type_def.description {
+            triple(&mut graph, &type_iri, rdfs::comment, description.as_str())?;
+        }
+        if type_def.deprecated.is_some() {
+            triple(&mut graph, &type_iri, owl_deprecated, true)?;
+        }
+        emit_aliases_and_see_also(
+            &mut graph,
+            &type_iri,
+            schema,
+            &type_def.aliases,
+            &type_def.see_also,
+        )?;
+        emit_common_metadata(&mut graph, &type_iri, schema, &type_def.metadata)?;
+
+        let Some(base) = type_base_datatype(type_name, schema) else {
+            continue;
+        };
+        let base_iri = make_iri(&base)?;
+        let facets = crate::linkml_resolve::type_facets(schema, type_name);
+        if facets.is_empty() {
+            triple(&mut graph, &type_iri, owl_equivalent_class, &base_iri)?;
+            continue;
+        }
+        let restriction_str = format!("{type_iri_str}/restriction");
+        let restriction = make_iri(&restriction_str)?;
+        triple(&mut graph, &restriction, rdf::type_, rdfs::Datatype)?;
+        triple(&mut graph, &restriction, owl_on_datatype, &base_iri)?;
+        let mut facet_nodes = Vec::new();
+        if let Some(pattern) = &facets.pattern {
+            let node = make_iri(&format!("{restriction_str}/pattern"))?;
+            let predicate = make_iri(&format!("{XSD_NS}pattern"))?;
+            triple(&mut graph, &node, &predicate, pattern.as_str())?;
+            facet_nodes.push(node);
+        }
+        // A bound is a literal of the base's own datatype where it has a
+        // faithful one (`0` under an integer type is `"0"^^xsd:integer`,
+        // not a double), so the restriction stays within OWL 2 DL.
+        let primitive = crate::primitives::effective_primitive(schema, type_name);
+        for (facet, bound) in [
+            ("minInclusive", facets.minimum_value),
+            ("maxInclusive", facets.maximum_value),
+        ] {
+            let Some(bound) = bound else {
+                continue;
+            };
+            let node = make_iri(&format!("{restriction_str}/{facet}"))?;
+            let predicate = make_iri(&format!("{XSD_NS}{facet}"))?;
+            let scalar = crate::instances::ScalarValue::Float(bound);
+            match primitive.and_then(|p| crate::primitives::range_typed_literal(p, &scalar)) {
+                Some((lexical, datatype)) => triple(
+                    &mut graph,
+                    &node,
+                    &predicate,
+                    typed_literal(&lexical, datatype),
+                )?,
+                None => triple(&mut graph, &node, &predicate, bound)?,
+            }
+            facet_nodes.push(node);
+        }
+        emit_rdf_list(
+            &mut graph,
+            &restriction,
+            owl_with_restrictions,
+            &restriction_str,
+            "cell",
+            facet_nodes,
+        )?;
+        triple(&mut graph, &type_iri, owl_equivalent_class, &restriction)?;
+    }
+
     // Properties (slots)
     let owl_object_property = owl
         .get("ObjectProperty")
@@ -628,17 +1037,22 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
         let prop_iri_str = slot_iri_string(name, slot_def, schema);
         let prop_iri = make_iri(&prop_iri_str)?;
 
-        // An `any_of` union whose every member names a class. Such a slot has
-        // no scalar `range:`, so without this it would fall through as a
-        // datatype property while its instances assert IRI objects.
-        let union_classes: Vec<&String> = slot_def
-            .any_of
+        // An `any_of` (or `exactly_one_of`) union whose every member names a
+        // class. Such a slot has no scalar `range:`, so without this it would
+        // fall through as a datatype property while its instances assert IRI
+        // objects.
+        let union_branches = if slot_def.any_of.is_empty() {
+            &slot_def.exactly_one_of
+        } else {
+            &slot_def.any_of
+        };
+        let union_classes: Vec<&String> = union_branches
             .iter()
             .filter_map(|branch| branch.range.as_ref())
             .filter(|r| schema.classes.contains_key(*r))
             .collect();
         let all_union_classes =
-            !slot_def.any_of.is_empty() && union_classes.len() == slot_def.any_of.len();
+            !union_branches.is_empty() && union_classes.len() == union_branches.len();
 
         // Determine property type
         let is_object_property = slot_def
@@ -706,10 +1120,10 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
         }
 
         // rdfs:range. For a datatype property the range must be a built-in
-        // primitive to get an `rdfs:range` — an enum, a class the writer
-        // didn't recognize as an object property, or a typo has no XSD
-        // datatype, so emit none rather than fabricating a nonexistent
-        // `xsd:{name}` (`xsd_datatype_iri` returns `None` for all of those).
+        // primitive or a custom type (its `rdfs:Datatype` above) to get an
+        // `rdfs:range` — a class the writer didn't recognize as an object
+        // property, or a typo, has no datatype, so emit none rather than
+        // fabricating a nonexistent `xsd:{name}`.
         if let Some(ref range) = slot_def.range {
             let range_iri_str = if schema.enums.contains_key(range) {
                 // An enum range names the enum's class, now that enums are
@@ -718,7 +1132,12 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             } else if is_object_property {
                 Some(class_iri_by_name(range, schema))
             } else {
-                crate::primitives::xsd_datatype_iri(range)
+                crate::primitives::xsd_datatype_iri(range).or_else(|| {
+                    schema
+                        .types
+                        .contains_key(range)
+                        .then(|| type_iri_string(range, schema))
+                })
             };
             if let Some(range_iri_str) = range_iri_str {
                 let range_iri = make_iri(&range_iri_str)?;
@@ -776,6 +1195,11 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             &slot_def.aliases,
             &slot_def.see_also,
         )?;
+        emit_common_metadata(&mut graph, &prop_iri, schema, &slot_def.metadata)?;
+
+        if let Some(unit) = &slot_def.unit {
+            emit_unit(&mut graph, &prop_iri, schema, unit)?;
+        }
     }
 
     // Individuals
@@ -833,6 +1257,31 @@ pub fn build_rdf_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
 
 /// A typed literal carrying one of [`crate::primitives`]' static XSD
 /// datatype IRIs — table-validated, so no per-value IRI parse.
+/// Emit an anonymous `owl:Class` at `node` whose `predicate`
+/// (`owl:unionOf` / `owl:intersectionOf`) lists the named `members`. The
+/// node and its list cells get IRIs under `node` rather than blank nodes,
+/// as the slot-range unions do.
+fn emit_class_list_node<C, P>(
+    graph: &mut FastGraph,
+    node: &str,
+    owl_class: C,
+    predicate: P,
+    members: &[String],
+    schema: &SchemaDefinition,
+) -> IoResult<()>
+where
+    C: sophia::api::term::Term,
+    P: sophia::api::term::Term + Copy,
+{
+    let node_iri = make_iri(node)?;
+    let members = members
+        .iter()
+        .map(|member| make_iri(&class_iri_by_name(member, schema)))
+        .collect::<Result<Vec<_>, _>>()?;
+    triple(graph, &node_iri, rdf::type_, owl_class)?;
+    emit_rdf_list(graph, &node_iri, predicate, node, "cell", members)
+}
+
 fn typed_literal<'a>(
     lexical: &'a str,
     datatype: &'static str,
@@ -1028,12 +1477,14 @@ fn emit_instances(
                 }
                 if !range_is_class
                     && range_enum.is_none()
-                    && let Some((lexical, datatype)) =
-                        range.and_then(|r| crate::primitives::range_typed_literal(r, scalar))
+                    && let Some((lexical, datatype)) = range
+                        .and_then(|r| crate::primitives::effective_primitive(schema, r))
+                        .and_then(|p| crate::primitives::range_typed_literal(p, scalar))
                 {
-                    // A conforming value under a primitive range: the literal
-                    // carries the datatype the shapes constrain the property
-                    // to, derived from the same table (`xsd_datatype`).
+                    // A conforming value under a primitive range, or a custom
+                    // type reaching one: the literal carries the datatype the
+                    // shapes constrain the property to, derived from the same
+                    // table (`xsd_datatype`).
                     triple(
                         graph,
                         &subject,
@@ -1042,11 +1493,12 @@ fn emit_instances(
                     )?;
                     continue;
                 }
-                // The authored value-kind form: rangeless and custom-typed
-                // slots (which the shapes leave unconstrained), and values a
-                // primitive range cannot faithfully type — present in the
-                // output for the shapes and the conformance check to report,
-                // with nothing invented and nothing dropped.
+                // The authored value-kind form: rangeless slots and types
+                // reaching no primitive (which the shapes leave
+                // unconstrained), and values a primitive range cannot
+                // faithfully type — present in the output for the shapes and
+                // the conformance check to report, with nothing invented and
+                // nothing dropped.
                 match scalar {
                     ScalarValue::String(s) => graph.insert(&subject, &predicate, s.as_str()),
                     ScalarValue::Boolean(b) => graph.insert(&subject, &predicate, *b),
@@ -1182,6 +1634,62 @@ pub fn build_shacl_graph(schema: &SchemaDefinition) -> IoResult<FastGraph> {
             w(&mut graph, &or1, rdf::first, &post_iri)?;
             triple(&mut graph, &or1, rdf::rest, rdf::nil)?;
         }
+
+        // Class expressions → logical constraints on the node shape:
+        // `any_of` → `sh:or`, `all_of` → `sh:and`, `exactly_one_of` →
+        // `sh:xone`, and each `none_of` member an `sh:not`. A member's
+        // `is_a` is `sh:class`; its slot conditions are property shapes
+        // built as a rule side's are. A combinator naming a slot the class
+        // lacks is skipped for the same reason a rule is, and reported by
+        // `shacl_skipped_expressions`.
+        for (combinator, members) in class_def.combinators() {
+            if members.is_empty() || shacl_expression_skip_reason(members, &slot_names).is_some() {
+                continue;
+            }
+            emit_combinator(
+                &mut graph,
+                &t,
+                &shape_iri,
+                &shape_iri_str,
+                combinator,
+                members,
+                &effective,
+                schema,
+            )?;
+        }
+        // `disjoint_with` → `sh:not [ sh:class D ]`; `union_of` → an
+        // `sh:or` over `[ sh:class M ]` per member.
+        for (k, other) in class_def.disjoint_with.iter().enumerate() {
+            let node = make_iri(&format!("{shape_iri_str}/disjoint_with{k}"))?;
+            triple(
+                &mut graph,
+                &node,
+                &t.class,
+                &make_iri(&class_iri_by_name(other, schema))?,
+            )?;
+            triple(&mut graph, &shape_iri, &t.not_, &node)?;
+        }
+        if !class_def.union_of.is_empty() {
+            let mut members = Vec::new();
+            for (k, member) in class_def.union_of.iter().enumerate() {
+                let node = make_iri(&format!("{shape_iri_str}/union_of{k}"))?;
+                triple(
+                    &mut graph,
+                    &node,
+                    &t.class,
+                    &make_iri(&class_iri_by_name(member, schema))?,
+                )?;
+                members.push(node);
+            }
+            emit_rdf_list(
+                &mut graph,
+                &shape_iri,
+                &t.or_,
+                &format!("{shape_iri_str}/union_of"),
+                "cell",
+                members,
+            )?;
+        }
     }
 
     Ok(graph)
@@ -1203,6 +1711,8 @@ struct ShaclTerms {
     max_inclusive: Iri<String>,
     has_value: Iri<String>,
     or_: Iri<String>,
+    and_: Iri<String>,
+    xone: Iri<String>,
     not_: Iri<String>,
     in_: Iri<String>,
 }
@@ -1224,6 +1734,8 @@ impl ShaclTerms {
             max_inclusive: sh("maxInclusive")?,
             has_value: sh("hasValue")?,
             or_: sh("or")?,
+            and_: sh("and")?,
+            xone: sh("xone")?,
             not_: sh("not")?,
             in_: sh("in")?,
         })
@@ -1255,7 +1767,15 @@ struct PropertyConstraints<'a> {
     /// Slot-level `any_of`: the value satisfies at least one alternative —
     /// emitted as `sh:or` over per-alternative constraint shapes.
     any_of: Vec<PropertyConstraints<'a>>,
-    /// Whether an enum `range` should close the value set with `sh:in`.
+    /// Slot-level `all_of` / `exactly_one_of` / `none_of`, emitted as
+    /// `sh:and`, `sh:xone`, and one `sh:not` per branch over the same kind
+    /// of per-branch constraint shapes.
+    all_of: Vec<PropertyConstraints<'a>>,
+    exactly_one_of: Vec<PropertyConstraints<'a>>,
+    none_of: Vec<PropertyConstraints<'a>>,
+    /// Whether the `range` constrains the value set: an enum range closes
+    /// it with `sh:in`, and a custom type adds its facets (`sh:pattern`,
+    /// bounds).
     ///
     /// True for a slot's own property shape, which is where the range is a
     /// constraint. False inside a rule-condition shape: there the range is
@@ -1265,7 +1785,7 @@ struct PropertyConstraints<'a> {
     /// makes the whole rule vacuously true instead of enforced.
     ///
     /// [`with_range`]: PropertyConstraints::with_range
-    close_enum_range: bool,
+    constrain_range: bool,
 }
 
 impl<'a> PropertyConstraints<'a> {
@@ -1278,11 +1798,40 @@ impl<'a> PropertyConstraints<'a> {
             max_value: slot.maximum_value,
             min_cardinality: slot.minimum_cardinality,
             max_cardinality: slot.maximum_cardinality,
-            close_enum_range: true,
+            any_of: Self::from_branches(&slot.any_of, slot.range.as_deref()),
+            all_of: Self::from_branches(&slot.all_of, slot.range.as_deref()),
+            exactly_one_of: Self::from_branches(&slot.exactly_one_of, slot.range.as_deref()),
+            none_of: Self::from_branches(&slot.none_of, slot.range.as_deref()),
+            constrain_range: true,
             ..Default::default()
         }
     }
 
+    /// A slot combinator's branches as value-node constraints. A branch
+    /// tests each value, not the property, so only its value facets carry
+    /// over — range, pattern, bounds — never a cardinality, which has no
+    /// meaning off a path. A rangeless branch inherits the slot's range.
+    fn from_branches(branches: &'a [SlotDefinition], outer_range: Option<&'a str>) -> Vec<Self> {
+        branches
+            .iter()
+            .map(|branch| {
+                let range = branch.range.as_deref().or(outer_range);
+                Self {
+                    range,
+                    pattern: branch.pattern.as_deref(),
+                    min_value: branch.minimum_value,
+                    max_value: branch.maximum_value,
+                    any_of: Self::from_branches(&branch.any_of, range),
+                    all_of: Self::from_branches(&branch.all_of, range),
+                    exactly_one_of: Self::from_branches(&branch.exactly_one_of, range),
+                    none_of: Self::from_branches(&branch.none_of, range),
+                    constrain_range: true,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
     fn from_condition(cond: &'a crate::linkml::SlotCondition) -> Self {
         Self {
             range: cond.range.as_deref(),
@@ -1296,11 +1845,14 @@ impl<'a> PropertyConstraints<'a> {
             equals_number: cond.equals_number,
             value_presence: cond.value_presence,
             any_of: cond.any_of.iter().map(Self::from_condition).collect(),
+            all_of: Vec::new(),
+            exactly_one_of: Vec::new(),
+            none_of: Vec::new(),
             // A condition's range types its literals; it does not re-assert
             // the slot's value set. The class-level property shape already
             // carries that, and duplicating it here would contradict the
             // condition's own `sh:hasValue`.
-            close_enum_range: false,
+            constrain_range: false,
         }
     }
 
@@ -1378,7 +1930,48 @@ fn emit_condition_shape(
     effective: &std::collections::BTreeMap<String, SlotDefinition>,
     schema: &SchemaDefinition,
 ) -> IoResult<()> {
-    for (slot, cond) in &conditions.slot_conditions {
+    emit_slot_condition_shapes(
+        graph,
+        t,
+        cond_iri,
+        base,
+        &conditions.slot_conditions,
+        effective,
+        schema,
+    )?;
+    if !conditions.any_of.is_empty() {
+        let mut alts = Vec::new();
+        for (k, alt) in conditions.any_of.iter().enumerate() {
+            let alt_iri = make_iri(&format!("{base}/alt{k}"))?;
+            emit_condition_shape(
+                graph,
+                t,
+                &alt_iri,
+                &format!("{base}/alt{k}"),
+                alt,
+                effective,
+                schema,
+            )?;
+            alts.push(alt_iri);
+        }
+        emit_or_list(graph, t, cond_iri, base, alts)?;
+    }
+    Ok(())
+}
+
+/// A property shape on `node` per `slot_conditions` entry — the body
+/// shared by a rule side and a class-expression member. The caller's skip
+/// check guarantees every slot resolves in `effective`.
+fn emit_slot_condition_shapes(
+    graph: &mut FastGraph,
+    t: &ShaclTerms,
+    node: &Iri<String>,
+    base: &str,
+    slot_conditions: &std::collections::BTreeMap<String, crate::linkml::SlotCondition>,
+    effective: &std::collections::BTreeMap<String, SlotDefinition>,
+    schema: &SchemaDefinition,
+) -> IoResult<()> {
+    for (slot, cond) in slot_conditions {
         let def = effective
             .get(slot)
             .expect("skip check guarantees the slot resolves");
@@ -1387,7 +1980,7 @@ fn emit_condition_shape(
         emit_property_shape(
             graph,
             t,
-            cond_iri,
+            node,
             &ps,
             &path,
             schema,
@@ -1397,24 +1990,134 @@ fn emit_condition_shape(
             PropertyConstraints::from_condition(cond).with_range(def.range.as_deref()),
         )?;
     }
-    if !conditions.any_of.is_empty() {
-        let mut alts = Vec::new();
-        for (k, alt) in conditions.any_of.iter().enumerate() {
-            let alt_iri = make_iri(&format!("{base}/alt{k}"))?;
-            emit_condition_shape(
-                graph,
-                t,
-                &alt_iri,
-                &format!("{base}/alt{k}"),
-                alt,
-                effective,
-                schema,
-            )?;
-            alts.push(alt_iri);
+    Ok(())
+}
+
+/// Emit one class-expression combinator onto `node`: a member shape at
+/// `{base}/{combinator}{k}` per member, then `sh:or` / `sh:and` /
+/// `sh:xone` over them (list cells under `{base}/{combinator}`) or, for
+/// `none_of`, one `sh:not` per member.
+#[allow(clippy::too_many_arguments)]
+fn emit_combinator(
+    graph: &mut FastGraph,
+    t: &ShaclTerms,
+    node: &Iri<String>,
+    base: &str,
+    combinator: &str,
+    members: &[crate::linkml::ClassExpression],
+    effective: &std::collections::BTreeMap<String, SlotDefinition>,
+    schema: &SchemaDefinition,
+) -> IoResult<()> {
+    let mut shapes = Vec::new();
+    for (k, member) in members.iter().enumerate() {
+        let member_base = format!("{base}/{combinator}{k}");
+        let member_iri = make_iri(&member_base)?;
+        if let Some(class) = &member.is_a {
+            let class_iri = make_iri(&class_iri_by_name(class, schema))?;
+            triple(graph, &member_iri, &t.class, &class_iri)?;
         }
-        emit_or_list(graph, t, cond_iri, base, alts)?;
+        emit_slot_condition_shapes(
+            graph,
+            t,
+            &member_iri,
+            &member_base,
+            &member.slot_conditions,
+            effective,
+            schema,
+        )?;
+        for (nested, nested_members) in member.combinators() {
+            if !nested_members.is_empty() {
+                emit_combinator(
+                    graph,
+                    t,
+                    &member_iri,
+                    &member_base,
+                    nested,
+                    nested_members,
+                    effective,
+                    schema,
+                )?;
+            }
+        }
+        shapes.push(member_iri);
     }
-    Ok(())
+    let predicate = match combinator {
+        "any_of" => &t.or_,
+        "all_of" => &t.and_,
+        "exactly_one_of" => &t.xone,
+        _ => {
+            for shape in &shapes {
+                triple(graph, node, &t.not_, shape)?;
+            }
+            return Ok(());
+        }
+    };
+    emit_rdf_list(
+        graph,
+        node,
+        predicate,
+        &format!("{base}/{combinator}"),
+        "cell",
+        shapes,
+    )
+}
+
+/// A class-expression combinator [`build_shacl_graph`] can't project, and
+/// why — the expression counterpart of [`ShaclSkippedRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaclSkippedExpression {
+    pub class: String,
+    /// The combinator: `any_of`, `all_of`, `exactly_one_of` or `none_of`.
+    pub construct: &'static str,
+    pub reason: String,
+}
+
+/// Class-expression combinators the SHACL writer drops: those whose members
+/// (at any depth) name a slot the class doesn't have. Shares
+/// [`shacl_expression_skip_reason`] with `build_shacl_graph`.
+pub fn shacl_skipped_expressions(schema: &SchemaDefinition) -> Vec<ShaclSkippedExpression> {
+    let mut out = Vec::new();
+    for (class_name, class) in &schema.classes {
+        let combinators = class.combinators();
+        if combinators.iter().all(|(_, members)| members.is_empty()) {
+            continue;
+        }
+        let effective = crate::linkml_resolve::resolve_effective_slots(class, schema);
+        let slot_names: std::collections::BTreeSet<&str> =
+            effective.keys().map(String::as_str).collect();
+        for (construct, members) in combinators {
+            if let Some(reason) = shacl_expression_skip_reason(members, &slot_names) {
+                out.push(ShaclSkippedExpression {
+                    class: class_name.clone(),
+                    construct,
+                    reason,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Why the SHACL writer skips a combinator over `members`, or `None` if it
+/// emits one: the first slot a member names that `slot_names` (the class's
+/// effective slots) lacks.
+fn shacl_expression_skip_reason(
+    members: &[crate::linkml::ClassExpression],
+    slot_names: &std::collections::BTreeSet<&str>,
+) -> Option<String> {
+    members.iter().find_map(|member| {
+        member
+            .slot_conditions
+            .keys()
+            .find(|slot| !slot_names.contains(slot.as_str()))
+            .map(|slot| format!("references slot `{slot}`, which the class does not have"))
+            .or_else(|| {
+                member
+                    .combinators()
+                    .iter()
+                    .find_map(|(_, nested)| shacl_expression_skip_reason(nested, slot_names))
+            })
+    })
 }
 
 fn shacl_rule_skip_reason(
@@ -1536,8 +2239,10 @@ fn emit_property_shape(
 /// Emit `c`'s value constraints onto `node` — the body shared by a full
 /// property shape and each `sh:or` alternative (which applies to the same
 /// value nodes and so carries no `sh:path` of its own). Slot-level
-/// `any_of` recurses here: alternatives become an `sh:or` list of
-/// constraint shapes at `{node}/or{j}`.
+/// combinators recurse here: `any_of` alternatives become an `sh:or` list
+/// of constraint shapes at `{node}/or{j}`, `all_of` an `sh:and` at
+/// `{node}/and{j}`, `exactly_one_of` an `sh:xone` at `{node}/xone{j}`, and
+/// each `none_of` branch an `sh:not` at `{node}/not{j}`.
 fn emit_constraint_fields(
     graph: &mut FastGraph,
     t: &ShaclTerms,
@@ -1549,7 +2254,7 @@ fn emit_constraint_fields(
         if let Some(target) = schema.classes.get(range) {
             let target_iri = make_iri(&class_iri_string(range, target, schema))?;
             triple(graph, prop_shape, &t.class, &target_iri)?;
-        } else if c.close_enum_range
+        } else if c.constrain_range
             && let Some(enum_def) = schema.enums.get(range)
         {
             // An enum range closes the value set over the IRIs the A-box
@@ -1571,8 +2276,24 @@ fn emit_constraint_fields(
             // `xsd:{name}`.
             let xsd_iri = make_iri(&xsd)?;
             triple(graph, prop_shape, &t.datatype, &xsd_iri)?;
+        } else if let Some(xsd) = crate::primitives::effective_primitive(schema, range)
+            .and_then(crate::primitives::xsd_datatype)
+        {
+            // A custom type's values are literals of the primitive it
+            // reaches — the datatype the A-box types them with, not the
+            // type's own `rdfs:Datatype`, which `sh:datatype` would compare
+            // by IRI and never match. Its facets follow below.
+            let xsd_iri = make_iri(xsd)?;
+            triple(graph, prop_shape, &t.datatype, &xsd_iri)?;
         }
     }
+    // A custom type's `pattern` and bounds constrain every value it types;
+    // the slot's own, when set, are the narrower statement and win.
+    let type_facets = c
+        .range
+        .filter(|_| c.constrain_range)
+        .map(|range| crate::linkml_resolve::type_facets(schema, range))
+        .unwrap_or_default();
     // `required` and `minimum_cardinality` are two spellings of the same
     // lower bound; emitting a `sh:minCount` for each would contradict itself.
     // Reconcile to one, with an explicit cardinality winning over the flag —
@@ -1598,13 +2319,13 @@ fn emit_constraint_fields(
     if let Some(max) = effective_max {
         triple(graph, prop_shape, &t.max_count, max as i32)?;
     }
-    if let Some(pattern) = c.pattern {
+    if let Some(pattern) = c.pattern.or(type_facets.pattern.as_deref()) {
         triple(graph, prop_shape, &t.pattern, pattern)?;
     }
-    if let Some(min) = c.min_value {
+    if let Some(min) = c.min_value.or(type_facets.minimum_value) {
         triple(graph, prop_shape, &t.min_inclusive, min)?;
     }
-    if let Some(max) = c.max_value {
+    if let Some(max) = c.max_value.or(type_facets.maximum_value) {
         triple(graph, prop_shape, &t.max_inclusive, max)?;
     }
     // `sh:hasValue` is term equality (datatype-sensitive), so the literal
@@ -1617,7 +2338,8 @@ fn emit_constraint_fields(
         let scalar = crate::instances::ScalarValue::String(v.to_string());
         match c
             .range
-            .and_then(|r| crate::primitives::range_typed_literal(r, &scalar))
+            .and_then(|r| crate::primitives::effective_primitive(schema, r))
+            .and_then(|p| crate::primitives::range_typed_literal(p, &scalar))
         {
             Some((lexical, datatype)) => {
                 triple(
@@ -1634,7 +2356,8 @@ fn emit_constraint_fields(
         let scalar = crate::instances::ScalarValue::Float(n);
         match c
             .range
-            .and_then(|r| crate::primitives::range_typed_literal(r, &scalar))
+            .and_then(|r| crate::primitives::effective_primitive(schema, r))
+            .and_then(|p| crate::primitives::range_typed_literal(p, &scalar))
         {
             Some((lexical, datatype)) => {
                 triple(
@@ -1656,6 +2379,33 @@ fn emit_constraint_fields(
         }
         emit_or_list(graph, t, prop_shape, &format!("{prop_shape}"), alts)?;
     }
+    for (name, predicate, branches) in [
+        ("and", &t.and_, c.all_of),
+        ("xone", &t.xone, c.exactly_one_of),
+    ] {
+        if branches.is_empty() {
+            continue;
+        }
+        let mut members = Vec::new();
+        for (j, branch) in branches.into_iter().enumerate() {
+            let member = make_iri(&format!("{prop_shape}/{name}{j}"))?;
+            emit_constraint_fields(graph, t, &member, schema, branch)?;
+            members.push(member);
+        }
+        emit_rdf_list(
+            graph,
+            prop_shape,
+            predicate,
+            &format!("{prop_shape}/{name}"),
+            "cell",
+            members,
+        )?;
+    }
+    for (j, branch) in c.none_of.into_iter().enumerate() {
+        let member = make_iri(&format!("{prop_shape}/not{j}"))?;
+        emit_constraint_fields(graph, t, &member, schema, branch)?;
+        triple(graph, prop_shape, &t.not_, &member)?;
+    }
     Ok(())
 }
 
@@ -2599,6 +3349,78 @@ mod tests {
         );
     }
 
+    /// A custom type is an `rdfs:Datatype` defined as its base restricted
+    /// by its facets, a slot ranged on it names that datatype, and the
+    /// shapes check the slot's values as literals of the base carrying the
+    /// type's facets — the datatype the A-box types them with.
+    #[test]
+    fn a_custom_type_is_a_restricted_datatype_the_shapes_check_by_its_facets() {
+        const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+        let mut schema = SchemaDefinition::new("s");
+        schema.id = Some("https://example.org/s".to_string());
+        let mut score = crate::linkml::TypeDefinition::new("Score");
+        score.typeof_ = Some("integer".to_string());
+        score.minimum_value = Some(0.0);
+        score.maximum_value = Some(100.0);
+        schema.types.insert("Score".to_string(), score);
+        let mut slot = SlotDefinition::new("score");
+        slot.range = Some("Score".to_string());
+        let mut exam = ClassDefinition::new("Exam");
+        exam.attributes.insert("score".to_string(), slot);
+        schema.classes.insert("Exam".to_string(), exam);
+
+        let graph = build_rdf_graph(&schema).expect("build graph");
+        let ty = "https://example.org/s#Score";
+        let restriction = format!("{ty}/restriction");
+        assert!(has_iri_triple(
+            &graph,
+            ty,
+            &format!("{RDF_NS}type"),
+            &format!("{RDFS_NS}Datatype")
+        ));
+        assert!(has_iri_triple(
+            &graph,
+            ty,
+            &format!("{OWL_NS}equivalentClass"),
+            &restriction
+        ));
+        assert!(has_iri_triple(
+            &graph,
+            &restriction,
+            &format!("{OWL_NS}onDatatype"),
+            XSD_INTEGER
+        ));
+        assert_eq!(
+            literal_parts(
+                &graph,
+                &format!("{restriction}/minInclusive"),
+                &format!("{XSD_NS}minInclusive")
+            ),
+            vec![("0".to_string(), XSD_INTEGER.to_string())],
+            "a bound is a literal of the base datatype"
+        );
+        assert_eq!(
+            objects_of(
+                &graph,
+                "https://example.org/s#score",
+                &format!("{RDFS_NS}range")
+            ),
+            vec![ty.to_string()]
+        );
+
+        let shapes = build_shacl_graph(&schema).expect("build shapes");
+        let property = "https://example.org/s#ExamShape/score";
+        assert_eq!(
+            objects_of(&shapes, property, &format!("{SH_NS}datatype")),
+            vec![XSD_INTEGER.to_string()]
+        );
+        assert_eq!(
+            literal_parts(&shapes, property, &format!("{SH_NS}maxInclusive")).len(),
+            1,
+            "the type's bound constrains the slot"
+        );
+    }
+
     /// A top-level slot typed only by `default_range` still emits its
     /// `rdfs:range`: loading materializes the default into the slot
     /// definition, and the writer emits what the definition carries —
@@ -2929,6 +3751,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn class_expressions_over_named_classes_project_to_owl_axioms() {
+        // `any_of` of bare classes is a union superclass, `none_of` and
+        // `disjoint_with` are disjointness, `union_of` an equivalence — and
+        // a slot-conditioned `all_of` member has no axiom at all.
+        let mut schema = union_tbox_fixture();
+        let named = |class: &str| crate::linkml::ClassExpression {
+            is_a: Some(class.to_string()),
+            ..Default::default()
+        };
+        let mut pet = ClassDefinition::new("Pet");
+        pet.any_of = vec![named("Claim"), named("Method")];
+        pet.none_of = vec![named("Method")];
+        pet.disjoint_with = vec!["Claim".to_string()];
+        pet.union_of = vec!["Claim".to_string()];
+        pet.all_of = vec![crate::linkml::ClassExpression {
+            slot_conditions: [("id".to_string(), Default::default())].into(),
+            ..Default::default()
+        }];
+        schema.classes.insert("Pet".to_string(), pet);
+
+        let graph = build_rdf_graph(&schema).expect("graph");
+        let pet_iri = "https://example.org/prov/Pet";
+        let owl = |local: &str| format!("http://www.w3.org/2002/07/owl#{local}");
+        let supers = objects_of(
+            &graph,
+            pet_iri,
+            "http://www.w3.org/2000/01/rdf-schema#subClassOf",
+        );
+        assert_eq!(supers, vec![format!("{pet_iri}/any_of")], "no all_of node");
+        assert_eq!(
+            objects_of(&graph, &supers[0], &owl("unionOf")),
+            vec![format!("{pet_iri}/any_of/cell0")]
+        );
+        let mut disjoint = objects_of(&graph, pet_iri, &owl("disjointWith"));
+        disjoint.sort();
+        assert_eq!(
+            disjoint,
+            vec![
+                "https://example.org/prov/Claim".to_string(),
+                "https://example.org/prov/Method".to_string()
+            ]
+        );
+        assert_eq!(
+            objects_of(&graph, pet_iri, &owl("equivalentClass")),
+            vec![format!("{pet_iri}/union_of")]
+        );
+    }
+
     #[test]
     fn a_union_ranged_slot_emits_an_object_property_assertion() {
         // A slot whose range is an `any_of` class union carries references,
@@ -3456,6 +4327,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_rdf_graph_emits_slot_units_as_qudt_annotations() {
+        let mut schema = schema_with_prefixes();
+        schema.prefixes.insert(
+            "unit".to_string(),
+            "http://qudt.org/vocab/unit/".to_string(),
+        );
+        let mut mass = SlotDefinition::new("mass");
+        mass.unit = Some(UnitOfMeasure {
+            ucum_code: Some("kg".to_string()),
+            symbol: Some("kg".to_string()),
+            exact_mappings: vec!["unit:KiloGM".to_string()],
+            ..UnitOfMeasure::default()
+        });
+        schema.slots.insert("mass".to_string(), mass);
+
+        let graph = build_rdf_graph(&schema).unwrap();
+        let mass_iri = slot_iri_by_name("mass", &schema);
+        let objects = |predicate: &str| -> Vec<String> {
+            use sophia::api::graph::Graph;
+            use sophia::api::term::Term;
+            use sophia::api::triple::Triple;
+            let subject = make_iri(&mass_iri).unwrap();
+            let predicate = make_iri(&format!("{QUDT_NS}{predicate}")).unwrap();
+            graph
+                .triples_matching([subject], [predicate], sophia::api::term::matcher::Any)
+                .map(|t| {
+                    let t = t.unwrap();
+                    let o = t.o();
+                    o.iri()
+                        .map(|i| i.as_str().to_string())
+                        .or_else(|| o.lexical_form().map(|l| l.to_string()))
+                        .unwrap()
+                })
+                .collect()
+        };
+        assert_eq!(objects("unit"), vec!["http://qudt.org/vocab/unit/KiloGM"]);
+        assert_eq!(objects("ucumCode"), vec!["kg"]);
+        assert_eq!(objects("symbol"), vec!["kg"]);
+    }
+
     #[test]
     fn build_rdf_graph_emits_alt_label_and_see_also() {
         // A class or slot with `aliases:` emits one `skos:altLabel`
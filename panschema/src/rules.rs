@@ -4,9 +4,11 @@
 //! pre/postconditions, so every writer that surfaces rules (the HTML card,
 //! the graph hover payload) describes them identically — covering
 //! `equals_string` / `equals_number`, `value_presence`, `required`, `range`,
-//! `pattern`, value bounds, cardinality, and `any_of` alternatives.
+//! `pattern`, value bounds, cardinality, and `any_of` alternatives. A class
+//! expression member (`any_of` / `all_of` / … on a class) reads through the
+//! same clauses, so the card row and the validator's violation agree.
 
-use crate::linkml::{ClassRule, RuleConditions, SlotCondition, ValuePresence};
+use crate::linkml::{ClassExpression, ClassRule, RuleConditions, SlotCondition, ValuePresence};
 
 /// The slots a rule names, split by side: `trigger` slots appear in its
 /// preconditions (what makes the rule fire), `governed` slots in its
@@ -73,6 +75,36 @@ pub fn rule_summary(rule: &ClassRule) -> Option<String> {
     }
 }
 
+/// Render one anonymous class expression as markdown clauses joined with
+/// "and", e.g. "is a `Cat`" or "`chip` is required and `age` >= 1". Nested
+/// combinators read as parenthesized members: `any_of` joined with "or",
+/// `all_of` with "and", the others prefixed "exactly one of" / "none of".
+/// `None` when the expression renders nothing.
+pub fn class_expression_summary(expr: &ClassExpression) -> Option<String> {
+    let mut clauses = Vec::new();
+    if let Some(class) = &expr.is_a {
+        clauses.push(format!("is a `{class}`"));
+    }
+    clauses.extend(describe_slot_conditions(&expr.slot_conditions));
+    for (combinator, members) in expr.combinators() {
+        let parts: Vec<String> = members
+            .iter()
+            .filter_map(class_expression_summary)
+            .map(|s| format!("({s})"))
+            .collect();
+        if parts.is_empty() {
+            continue;
+        }
+        clauses.push(match combinator {
+            "any_of" => parts.join(" or "),
+            "all_of" => parts.join(" and "),
+            "exactly_one_of" => format!("exactly one of {}", parts.join(", ")),
+            _ => format!("none of {}", parts.join(", ")),
+        });
+    }
+    (!clauses.is_empty()).then(|| clauses.join(" and "))
+}
+
 /// Describe a whole condition set as markdown clauses: its `slot_conditions`
 /// plus any `any_of` alternatives. Each `any_of` branch is parenthesized and
 /// the branches are joined with "or", so a precondition that fires when
@@ -254,6 +286,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn class_expression_summary_reads_is_a_slot_conditions_and_nesting() {
+        let required = SlotCondition {
+            required: true,
+            ..Default::default()
+        };
+        let expr = ClassExpression {
+            is_a: Some("Pet".into()),
+            slot_conditions: [("chip".to_string(), required)].into(),
+            none_of: vec![ClassExpression {
+                is_a: Some("Stray".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            class_expression_summary(&expr).as_deref(),
+            Some("is a `Pet` and `chip` is required and none of (is a `Stray`)")
+        );
+        assert_eq!(class_expression_summary(&ClassExpression::default()), None);
+    }
+
     #[test]
     fn rule_participants_are_empty_for_a_conditionless_rule() {
         let rule = ClassRule {
@@ -17,7 +17,9 @@ use crate::casing::{pascal_case, snake_case};
 use std::path::Path;
 
 use crate::io::{IoError, IoResult, Writer};
-use crate::linkml::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+use crate::linkml::{
+    ArrayExpression, ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition,
+};
 
 /// Writes a Rust module representing the schema's classes, enums, and
 /// inheritance structure.
@@ -153,7 +155,17 @@ impl RustWriter {
             )?;
         }
 
-        render_header(out, schema, self.time_crate, designated)?;
+        // A variable-dimension array field is a `serde_json::Value` too.
+        let json_arrays = schema
+            .classes
+            .iter()
+            .filter(|(name, _)| roles.get(*name) == Some(&ClassRole::Struct))
+            .any(|(_, class)| {
+                resolve_slots(class, schema)
+                    .values()
+                    .any(|slot| slot.array.as_ref().is_some_and(|a| !has_static_shape(a)))
+            });
+        render_header(out, schema, self.time_crate, designated || json_arrays)?;
         out.write_str(&body)
     }
 }
@@ -344,8 +356,8 @@ fn trait_descendants_support(
     })
 }
 
-/// Does this slot's field type support `Eq + Hash`? Handles `any_of`
-/// unions (every branch must), bare ranges (look up the type), and the
+/// Does this slot's field type support `Eq + Hash`? Handles `any_of` /
+/// `exactly_one_of` unions (every member must), bare ranges (look up the type), and the
 /// implicit `default_range = string` fallback.
 fn field_supports_eq_hash(
     slot: &SlotDefinition,
@@ -353,14 +365,14 @@ fn field_supports_eq_hash(
     roles: &BTreeMap<String, ClassRole>,
     support: &BTreeMap<String, bool>,
 ) -> bool {
-    if !slot.any_of.is_empty() {
-        let outer_range = slot.range.as_deref();
-        return slot.any_of.iter().all(|b| {
-            b.range
-                .as_deref()
-                .or(outer_range)
-                .is_some_and(|r| type_supports_eq_hash(r, schema, roles, support))
-        });
+    if let Some(members) = union_members(slot) {
+        return members
+            .iter()
+            .all(|r| type_supports_eq_hash(r, schema, roles, support));
+    }
+    // `serde_json::Value` is `Eq` but not `Hash`.
+    if slot.array.as_ref().is_some_and(|a| !has_static_shape(a)) {
+        return false;
     }
     let range = slot.range.as_deref().unwrap_or("string");
     type_supports_eq_hash(range, schema, roles, support)
@@ -478,7 +490,7 @@ fn render_header<W: Write>(
     out: &mut W,
     schema: &SchemaDefinition,
     time: TimeCrate,
-    designated: bool,
+    needs_json: bool,
 ) -> fmt::Result {
     let version = env!("CARGO_PKG_VERSION");
     writeln!(out, "// @generated by panschema v{version}")?;
@@ -494,9 +506,9 @@ fn render_header<W: Write>(
         TimeCrate::Jiff => "jiff = { version = \"0.2\", features = [\"serde\"] }",
     };
     // A designated enum's generated deserializer buffers through
-    // `serde_json::Value` to peek the designator, so those modules need
-    // serde_json too.
-    let json_dep = if designated {
+    // `serde_json::Value` to peek the designator, and a variable-dimension
+    // array field is one, so those modules need serde_json too.
+    let json_dep = if needs_json {
         ", serde_json = \"1\""
     } else {
         ""
@@ -647,6 +659,34 @@ fn render_class<W: Write>(
             )?;
         }
 
+        // A union whose every member `none_of` strikes would be an enum no
+        // value deserializes into; the field falls back to the plain range.
+        if has_union(slot) && union_members(slot).is_none() {
+            write!(
+                out,
+                "    // WARNING: slot `{slot_name}`'s `none_of` excludes every union\n\
+                 //          member; field falls back to its plain `range`.\n"
+            )?;
+        }
+        // Outside a union there is no enum member to strike, so a
+        // `none_of` has no Rust rendering either.
+        if !slot.none_of.is_empty() && !has_union(slot) {
+            write!(
+                out,
+                "    // WARNING: slot `{slot_name}` declares `none_of`, which has no\n\
+                 //          Rust rendering; the constraint is not enforced.\n"
+            )?;
+        }
+        // `all_of` intersects ranges, which no Rust type spells; say so
+        // rather than drop the constraint unremarked.
+        if !slot.all_of.is_empty() {
+            write!(
+                out,
+                "    // WARNING: slot `{slot_name}` declares `all_of`, which has no\n\
+                 //          Rust rendering; the constraint is not enforced.\n"
+            )?;
+        }
+
         let rust_type = match &ifabsent_default {
             // A resolved default is always present, so the faithful shape
             // is the bare type, not `Option<T>`.
@@ -654,6 +694,13 @@ fn render_class<W: Write>(
             None => field_type_for(name, slot_name, slot, ctx, any_of_enums),
         };
         render_doc_comment(out, "    ", slot.description.as_deref())?;
+        if let Some(unit) = slot.unit.as_ref().and_then(|u| u.display()) {
+            writeln!(out, "    /// Unit: {unit}.")?;
+        }
+        if let Some(array) = &slot.array {
+            let dtype = slot.range.as_deref().unwrap_or("string");
+            writeln!(out, "    /// Shape: {}.", array.describe(dtype))?;
+        }
         if let Some(origin) = resolved_p[slot_name].provenance.origin_label(name) {
             writeln!(out, "    /// Inherited from {origin}.")?;
         }
@@ -1335,7 +1382,7 @@ fn supports_default(slot: &SlotDefinition) -> bool {
     // Required + single. `any_of` ranges resolve to a generated enum;
     // those enums don't derive `Default`, so a required bare any_of
     // field disqualifies the containing struct.
-    if !slot.any_of.is_empty() {
+    if union_members(slot).is_some() {
         return false;
     }
     // `range: None` falls back to LinkML's implicit `default_range`,
@@ -1380,18 +1427,8 @@ fn field_type_for(
     any_of_enums: &mut BTreeMap<String, Vec<String>>,
 ) -> String {
     let roles = ctx.roles;
-    if !slot.any_of.is_empty() {
+    if let Some(members) = union_members(slot) {
         let enum_name = format!("{class_name}{}", pascal_case(slot_name));
-        // LinkML spec: an `any_of` branch can omit its `range`, in
-        // which case it inherits the slot's outer `range`. Without the
-        // fallback those branches would be silently dropped from the
-        // generated enum.
-        let outer_range = slot.range.as_deref();
-        let members: Vec<String> = slot
-            .any_of
-            .iter()
-            .filter_map(|b| b.range.as_deref().or(outer_range).map(str::to_string))
-            .collect();
         any_of_enums.insert(enum_name.clone(), members);
         // any_of enums Box their variants internally → field stays sized.
         // The map key stays the raw synthesized name; `render_any_of_enum`
@@ -1400,6 +1437,14 @@ fn field_type_for(
         return framed_sized(&type_ident(&enum_name), slot);
     }
 
+    if let Some(array) = &slot.array {
+        let element = slot
+            .range
+            .as_deref()
+            .map_or_else(|| "String".to_string(), |r| type_for_range(r, ctx));
+        return framed_sized(&array_field_type(array, &element), slot);
+    }
+
     let Some(range) = &slot.range else {
         return framed_sized("String", slot);
     };
@@ -1414,6 +1459,69 @@ fn field_type_for(
     }
 }
 
+/// The Rust type of an `array` slot's value, before framing: a `Vec` per
+/// axis, outermost first, except that an axis of fixed length up to 32
+/// (serde's largest) is a fixed-size array — `Vec<[f64; 3]>` for "any
+/// number of rows of three". A dimension count that may vary has no static
+/// Rust shape, so the field falls back to `serde_json::Value`.
+fn array_field_type(array: &ArrayExpression, element: &str) -> String {
+    if !has_static_shape(array) {
+        return "serde_json::Value".to_string();
+    }
+    let (depth, _) = array.dimension_bounds();
+    (0..depth as usize)
+        .rev()
+        .fold(element.to_string(), |inner, axis| {
+            match array.axis_bounds(axis) {
+                (Some(lo), Some(hi)) if lo == hi && hi <= 32 => format!("[{inner}; {hi}]"),
+                _ => format!("Vec<{inner}>"),
+            }
+        })
+}
+
+/// Whether an `array` slot's dimension count is fixed, so nested types can
+/// spell its shape.
+fn has_static_shape(array: &ArrayExpression) -> bool {
+    matches!(array.dimension_bounds(), (min, Some(max)) if min == max)
+}
+
+/// Whether a slot declares a union (`any_of` or `exactly_one_of`).
+fn has_union(slot: &SlotDefinition) -> bool {
+    !slot.any_of.is_empty() || !slot.exactly_one_of.is_empty()
+}
+
+/// The members of the union enum a slot's field takes, or `None` for a
+/// slot with no union. `any_of` and `exactly_one_of` both become one: a
+/// `#[serde(untagged)]` enum deserializes a value into exactly one
+/// variant, so the two read alike here. A `none_of` branch's range is
+/// struck from the members; when that leaves none, the slot is treated as
+/// having no union (an empty enum could never deserialize) and its field
+/// takes the plain range.
+///
+/// LinkML spec: a union branch can omit its `range`, in which case it
+/// inherits the slot's outer `range`. Without the fallback those branches
+/// would be silently dropped from the generated enum.
+fn union_members(slot: &SlotDefinition) -> Option<Vec<String>> {
+    let branches = if slot.any_of.is_empty() {
+        &slot.exactly_one_of
+    } else {
+        &slot.any_of
+    };
+    let outer_range = slot.range.as_deref();
+    let excluded: Vec<&str> = slot
+        .none_of
+        .iter()
+        .filter_map(|b| b.range.as_deref())
+        .collect();
+    let members: Vec<String> = branches
+        .iter()
+        .filter_map(|b| b.range.as_deref().or(outer_range))
+        .filter(|r| !excluded.contains(r))
+        .map(str::to_string)
+        .collect();
+    (!members.is_empty()).then_some(members)
+}
+
 /// Framing for a type that's sized on its own (primitive, enum, Kind
 /// enum, any_of enum, or a struct used inside a `Vec`).
 fn framed_sized(base: &str, slot: &SlotDefinition) -> String {
@@ -3979,6 +4087,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn exactly_one_of_renders_an_untagged_enum_less_its_none_of_members() {
+        let mut def = ClassDefinition::new("Question");
+        let mut slot = SlotDefinition::new("source");
+        slot.exactly_one_of = vec![
+            slot_with_range("", "Question"),
+            slot_with_range("", "Annotation"),
+            slot_with_range("", "Dataset"),
+        ];
+        slot.none_of = vec![slot_with_range("", "Dataset")];
+        def.attributes.insert("source".to_string(), slot);
+
+        let mut schema = SchemaDefinition::new("s");
+        schema.classes.insert("Question".to_string(), def.clone());
+        for name in ["Annotation", "Dataset"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+
+        let roles = compute_class_roles(&schema);
+        let mut any_of_enums = BTreeMap::new();
+        let mut out = String::new();
+        render_class(
+            &mut out,
+            "Question",
+            &def,
+            &chrono_ctx(&schema, &roles),
+            &mut any_of_enums,
+        )
+        .unwrap();
+        assert!(
+            out.contains("pub source: Option<QuestionSource>"),
+            "got: {out}"
+        );
+        assert_eq!(
+            any_of_enums.get("QuestionSource"),
+            Some(&vec!["Question".to_string(), "Annotation".to_string()])
+        );
+    }
+
+    #[test]
+    fn a_union_none_of_empties_falls_back_to_the_plain_range_with_a_warning() {
+        let mut def = ClassDefinition::new("Question");
+        let mut slot = SlotDefinition::new("source");
+        slot.range = Some("string".to_string());
+        slot.any_of = vec![slot_with_range("", "Annotation")];
+        slot.none_of = vec![slot_with_range("", "Annotation")];
+        def.attributes.insert("source".to_string(), slot);
+
+        let mut schema = SchemaDefinition::new("s");
+        schema.classes.insert("Question".to_string(), def.clone());
+        schema
+            .classes
+            .insert("Annotation".to_string(), ClassDefinition::new("Annotation"));
+
+        let roles = compute_class_roles(&schema);
+        let mut any_of_enums = BTreeMap::new();
+        let mut out = String::new();
+        render_class(
+            &mut out,
+            "Question",
+            &def,
+            &chrono_ctx(&schema, &roles),
+            &mut any_of_enums,
+        )
+        .unwrap();
+        assert!(
+            any_of_enums.is_empty(),
+            "no empty union enum: {any_of_enums:?}"
+        );
+        assert!(out.contains("pub source: Option<String>"), "got: {out}");
+        assert!(
+            out.contains("// WARNING") && out.contains("excludes every union"),
+            "got: {out}"
+        );
+    }
+
+    #[test]
+    fn an_all_of_range_is_flagged_rather_than_dropped_silently() {
+        let mut def = ClassDefinition::new("Question");
+        let mut slot = SlotDefinition::new("source");
+        slot.range = Some("string".to_string());
+        slot.all_of = vec![slot_with_range("", "string")];
+        def.attributes.insert("source".to_string(), slot);
+
+        let mut schema = SchemaDefinition::new("s");
+        schema.classes.insert("Question".to_string(), def.clone());
+
+        let roles = compute_class_roles(&schema);
+        let mut any_of_enums = BTreeMap::new();
+        let mut out = String::new();
+        render_class(
+            &mut out,
+            "Question",
+            &def,
+            &chrono_ctx(&schema, &roles),
+            &mut any_of_enums,
+        )
+        .unwrap();
+        assert!(out.contains("pub source: Option<String>"), "got: {out}");
+        assert!(
+            out.contains("// WARNING") && out.contains("`all_of`"),
+            "got: {out}"
+        );
+    }
+
+    #[test]
+    fn a_none_of_outside_a_union_is_flagged_rather_than_dropped_silently() {
+        let mut def = ClassDefinition::new("Question");
+        let mut slot = SlotDefinition::new("source");
+        slot.range = Some("string".to_string());
+        slot.none_of = vec![slot_with_range("", "integer")];
+        def.attributes.insert("source".to_string(), slot);
+
+        let mut schema = SchemaDefinition::new("s");
+        schema.classes.insert("Question".to_string(), def.clone());
+
+        let roles = compute_class_roles(&schema);
+        let mut any_of_enums = BTreeMap::new();
+        let mut out = String::new();
+        render_class(
+            &mut out,
+            "Question",
+            &def,
+            &chrono_ctx(&schema, &roles),
+            &mut any_of_enums,
+        )
+        .unwrap();
+        assert!(out.contains("pub source: Option<String>"), "got: {out}");
+        assert!(
+            out.contains("// WARNING") && out.contains("declares `none_of`"),
+            "got: {out}"
+        );
+    }
+
     // ----- Eq + Hash support analysis ---------------------------------
 
     /// Helper: build a one-class schema with a single attribute slot of
@@ -4428,6 +4672,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn struct_fields_document_their_unit() {
+        let mut schema = SchemaDefinition::new("demo");
+        let mut reading = ClassDefinition::new("Reading");
+        let mut temp = SlotDefinition::new("temp");
+        temp.range = Some("float".into());
+        temp.description = Some("Air temperature.".into());
+        temp.unit = Some(crate::linkml::UnitOfMeasure {
+            ucum_code: Some("Cel".into()),
+            descriptive_name: Some("degree Celsius".into()),
+            ..Default::default()
+        });
+        reading.attributes.insert("temp".into(), temp);
+        schema.classes.insert("Reading".into(), reading);
+
+        let out = RustWriter::new().render(&schema);
+        assert!(
+            out.contains("    /// Air temperature.\n    /// Unit: Cel (degree Celsius).\n"),
+            "the unit line follows the description; got: {out}"
+        );
+    }
+
+    #[test]
+    fn array_slots_become_nested_vecs_and_fixed_arrays() {
+        use crate::linkml::DimensionExpression;
+        let mut schema = SchemaDefinition::new("demo");
+        let mut series = ClassDefinition::new("Series");
+        let mut samples = SlotDefinition::new("samples");
+        samples.range = Some("float".into());
+        samples.required = true;
+        samples.array = Some(ArrayExpression {
+            dimensions: vec![
+                DimensionExpression {
+                    alias: Some("time".into()),
+                    ..Default::default()
+                },
+                DimensionExpression {
+                    alias: Some("channel".into()),
+                    exact_cardinality: Some(3),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        series.attributes.insert("samples".into(), samples);
+        let mut cube = SlotDefinition::new("cube");
+        cube.range = Some("integer".into());
+        cube.array = Some(ArrayExpression {
+            minimum_number_dimensions: Some(2),
+            ..Default::default()
+        });
+        series.attributes.insert("cube".into(), cube);
+        schema.classes.insert("Series".into(), series);
+
+        let out = RustWriter::new().render(&schema);
+        assert!(
+            out.contains(
+                "    /// Shape: 2-D array of float; time (any) × channel (3).\n    pub samples: Vec<[f64; 3]>,"
+            ),
+            "got: {out}"
+        );
+        assert!(
+            out.contains("    pub cube: Option<serde_json::Value>,"),
+            "a variable dimension count has no static shape; got: {out}"
+        );
+        assert!(out.contains("serde_json = \"1\""), "got: {out}");
+    }
+
     // ----- header + Writer trait surface ------------------------------
 
     #[test]
@@ -0,0 +1,437 @@
+//! Element-level differences between two versions of a schema
+//!
+//! [`diff_schemas`] compares the classes, slots, enums and types of two
+//! [`SchemaDefinition`]s by name: an element only one side declares is
+//! added or removed, and one both sides declare but with different
+//! definitions is modified, with each differing field listed. Fields are
+//! compared through their serialized (LinkML YAML) form, so the diff
+//! speaks the schema author's vocabulary — `required`, `range`,
+//! `permissible_values.ACTIVE` — and picks up every field the model
+//! carries without a hand-kept list. A map-valued field (`attributes`,
+//! `slot_usage`, `permissible_values`, `unique_keys`, …) is compared
+//! entry by entry, one level down.
+//!
+//! `panschema publish` renders the result as each version's Changes page
+//! and the "changed in" badges on its cards.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::linkml::SchemaDefinition;
+
+/// Fields that constrain instance data, as opposed to documenting it.
+/// A change to one of these can turn valid data invalid (or back), so
+/// the Changes page calls it out.
+const CONSTRAINT_FIELDS: &[&str] = &[
+    "is_a",
+    "mixins",
+    "slots",
+    "range",
+    "required",
+    "recommended",
+    "multivalued",
+    "minimum_cardinality",
+    "maximum_cardinality",
+    "pattern",
+    "structured_pattern",
+    "equals_expression",
+    "identifier",
+    "key",
+    "minimum_value",
+    "maximum_value",
+    "attributes",
+    "any_of",
+    "all_of",
+    "exactly_one_of",
+    "none_of",
+    "rules",
+    "unique_keys",
+    "slot_usage",
+    "permissible_values",
+    "array",
+    "equals_string",
+    "equals_number",
+    "inlined",
+    "inlined_as_list",
+    "designates_type",
+    "ifabsent",
+    "union_of",
+    "disjoint_with",
+    "typeof",
+    "unit",
+    "enum_range",
+];
+
+/// An element whose definition could not be serialized for comparison.
+/// Reported rather than skipped, since an element that can't be compared
+/// must not read as unchanged.
+#[derive(Debug, Error)]
+#[error("comparing {kind} `{name}`: {source}")]
+pub struct DiffError {
+    pub kind: &'static str,
+    pub name: String,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+/// Longest rendered field value before it is elided.
+const MAX_VALUE_CHARS: usize = 60;
+
+/// The kinds of schema element a diff covers, in page order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ElementKind {
+    Class,
+    Slot,
+    Enum,
+    Type,
+}
+
+impl ElementKind {
+    /// The prefix of the element's card anchor on the docs page
+    /// (`class-Person`).
+    pub fn anchor_prefix(self) -> &'static str {
+        match self {
+            ElementKind::Class => "class",
+            ElementKind::Slot => "slot",
+            ElementKind::Enum => "enum",
+            ElementKind::Type => "type",
+        }
+    }
+
+    /// The section heading, e.g. `Classes`.
+    pub fn title(self) -> &'static str {
+        match self {
+            ElementKind::Class => "Classes",
+            ElementKind::Slot => "Slots",
+            ElementKind::Enum => "Enumerations",
+            ElementKind::Type => "Types",
+        }
+    }
+}
+
+/// What happened to an element between the two versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+        }
+    }
+}
+
+/// One differing field of a modified element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The field's LinkML key; a map entry is `field.entry`.
+    pub path: String,
+    /// The old value, rendered short; `None` when unset.
+    pub before: Option<String>,
+    /// The new value, rendered short; `None` when unset.
+    pub after: Option<String>,
+    /// The field constrains instance data (`range`, `required`, …).
+    pub constraint: bool,
+}
+
+/// One element that differs between the two versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementChange {
+    pub kind: ElementKind,
+    pub name: String,
+    pub change: ChangeKind,
+    /// The differing fields; empty unless `change` is
+    /// [`ChangeKind::Modified`].
+    pub fields: Vec<FieldChange>,
+}
+
+impl ElementChange {
+    /// The element's card anchor, e.g. `class-Person`.
+    pub fn anchor(&self) -> String {
+        format!("{}-{}", self.kind.anchor_prefix(), self.name)
+    }
+
+    /// Whether any changed field constrains instance data.
+    pub fn touches_constraints(&self) -> bool {
+        self.fields.iter().any(|f| f.constraint)
+    }
+}
+
+/// Every element that differs from `old` to `new`, ordered by kind
+/// (classes, slots, enums, types) and then by name.
+pub fn diff_schemas(
+    old: &SchemaDefinition,
+    new: &SchemaDefinition,
+) -> Result<Vec<ElementChange>, DiffError> {
+    let mut changes = Vec::new();
+    diff_kind(ElementKind::Class, &old.classes, &new.classes, &mut changes)?;
+    diff_kind(ElementKind::Slot, &old.slots, &new.slots, &mut changes)?;
+    diff_kind(ElementKind::Enum, &old.enums, &new.enums, &mut changes)?;
+    diff_kind(ElementKind::Type, &old.types, &new.types, &mut changes)?;
+    Ok(changes)
+}
+
+fn diff_kind<T: Serialize>(
+    kind: ElementKind,
+    old: &BTreeMap<String, T>,
+    new: &BTreeMap<String, T>,
+    out: &mut Vec<ElementChange>,
+) -> Result<(), DiffError> {
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for name in names {
+        let (change, fields) = match (old.get(name), new.get(name)) {
+            (None, Some(_)) => (ChangeKind::Added, Vec::new()),
+            (Some(_), None) => (ChangeKind::Removed, Vec::new()),
+            (Some(before), Some(after)) => {
+                let object = |definition: &T| {
+                    to_object(definition).map_err(|source| DiffError {
+                        kind: kind.anchor_prefix(),
+                        name: name.clone(),
+                        source,
+                    })
+                };
+                let fields = diff_fields(&object(before)?, &object(after)?);
+                if fields.is_empty() {
+                    continue;
+                }
+                (ChangeKind::Modified, fields)
+            }
+            (None, None) => continue,
+        };
+        out.push(ElementChange {
+            kind,
+            name: name.clone(),
+            change,
+            fields,
+        });
+    }
+    Ok(())
+}
+
+/// A definition's serialized fields. Every definition serializes to a
+/// mapping; anything else is an error rather than an empty map, which
+/// would compare equal to the other side.
+fn to_object<T: Serialize>(
+    definition: &T,
+) -> Result<serde_json::Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(definition)? {
+        Value::Object(map) => Ok(map),
+        other => Err(serde::ser::Error::custom(format!(
+            "serialized to {other} rather than a mapping"
+        ))),
+    }
+}
+
+/// The differing top-level fields, with map-valued fields compared one
+/// entry at a time.
+fn diff_fields(
+    before: &serde_json::Map<String, Value>,
+    after: &serde_json::Map<String, Value>,
+) -> Vec<FieldChange> {
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    let mut fields = Vec::new();
+    for key in keys {
+        let (old, new) = (before.get(key), after.get(key));
+        if old == new {
+            continue;
+        }
+        let constraint = CONSTRAINT_FIELDS.contains(&key.as_str());
+        // Unset maps are skipped on serialization, so an absent side
+        // counts as an empty map.
+        let empty = serde_json::Map::new();
+        let as_map = |v: Option<&Value>| match v {
+            Some(Value::Object(map)) => Some(map),
+            None => Some(&empty),
+            Some(_) => None,
+        };
+        match (as_map(old), as_map(new)) {
+            (Some(old_map), Some(new_map)) => {
+                let entries: BTreeSet<&String> = old_map.keys().chain(new_map.keys()).collect();
+                for entry in entries {
+                    let (a, b) = (old_map.get(entry), new_map.get(entry));
+                    if a != b {
+                        fields.push(FieldChange {
+                            path: format!("{key}.{entry}"),
+                            before: a.map(render_value),
+                            after: b.map(render_value),
+                            constraint,
+                        });
+                    }
+                }
+            }
+            _ => fields.push(FieldChange {
+                path: key.clone(),
+                before: old.map(render_value),
+                after: new.map(render_value),
+                constraint,
+            }),
+        }
+    }
+    fields
+}
+
+/// A field value as one short line: scalars as themselves, a list of
+/// scalars bracketed, anything nested summarized by its size.
+fn render_value(value: &Value) -> String {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.iter().all(|i| !i.is_object() && !i.is_array()) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(render_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Array(items) => format!("{} entries", items.len()),
+        Value::Object(map) => format!("{} fields", map.len()),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_VALUE_CHARS {
+        let cut: String = text.chars().take(MAX_VALUE_CHARS - 1).collect();
+        format!("{cut}…")
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{
+        ArrayExpression, ClassDefinition, EnumDefinition, PermissibleValue, SlotDefinition,
+        TypeDefinition,
+    };
+
+    fn v1() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        schema
+            .classes
+            .insert("Keeper".to_string(), ClassDefinition::new("Keeper"));
+        let mut name = SlotDefinition::new("name");
+        name.range = Some("string".to_string());
+        schema.slots.insert("name".to_string(), name);
+        let mut diet = EnumDefinition::new("Diet");
+        diet.permissible_values
+            .insert("HERBIVORE".to_string(), PermissibleValue::new("HERBIVORE"));
+        schema.enums.insert("Diet".to_string(), diet);
+        schema
+    }
+
+    #[test]
+    fn identical_schemas_have_no_changes() {
+        assert!(diff_schemas(&v1(), &v1()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_added_removed_and_modified_elements_in_page_order() {
+        let mut v2 = v1();
+        v2.classes.remove("Keeper");
+        let mut animal = ClassDefinition::new("Animal");
+        animal.description = Some("Anything in the zoo.".to_string());
+        v2.classes.insert("Animal".to_string(), animal);
+        v2.slots.get_mut("name").unwrap().required = true;
+        v2.enums
+            .get_mut("Diet")
+            .unwrap()
+            .permissible_values
+            .insert("CARNIVORE".to_string(), PermissibleValue::new("CARNIVORE"));
+        v2.types
+            .insert("Weight".to_string(), TypeDefinition::new("Weight"));
+
+        let changes = diff_schemas(&v1(), &v2).unwrap();
+        let summary: Vec<(&str, &str, &str)> = changes
+            .iter()
+            .map(|c| (c.kind.anchor_prefix(), c.name.as_str(), c.change.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("class", "Animal", "modified"),
+                ("class", "Keeper", "removed"),
+                ("slot", "name", "modified"),
+                ("enum", "Diet", "modified"),
+                ("type", "Weight", "added"),
+            ]
+        );
+
+        let animal = &changes[0];
+        assert_eq!(animal.fields.len(), 1);
+        assert_eq!(animal.fields[0].path, "description");
+        assert_eq!(animal.fields[0].before, None);
+        assert!(!animal.touches_constraints(), "documentation only");
+
+        let name = &changes[2];
+        assert_eq!(name.anchor(), "slot-name");
+        assert_eq!(
+            name.fields,
+            [FieldChange {
+                path: "required".to_string(),
+                before: None,
+                after: Some("true".to_string()),
+                constraint: true,
+            }]
+        );
+
+        let diet = &changes[3];
+        assert_eq!(diet.fields[0].path, "permissible_values.CARNIVORE");
+        assert!(diet.fields[0].before.is_none() && diet.fields[0].after.is_some());
+        assert!(diet.touches_constraints());
+    }
+
+    #[test]
+    fn validation_fields_count_as_constraint_changes() {
+        let mut v2 = v1();
+        let name = v2.slots.get_mut("name").unwrap();
+        name.array = Some(ArrayExpression {
+            exact_number_dimensions: Some(2),
+            ..ArrayExpression::default()
+        });
+        name.inlined = Some(true);
+
+        let changes = diff_schemas(&v1(), &v2).unwrap();
+        let fields: Vec<(&str, bool)> = changes[0]
+            .fields
+            .iter()
+            .map(|f| (f.path.as_str(), f.constraint))
+            .collect();
+        assert_eq!(
+            fields,
+            [("array.exact_number_dimensions", true), ("inlined", true)]
+        );
+        assert!(changes[0].touches_constraints());
+    }
+
+    #[test]
+    fn a_definition_that_is_not_a_mapping_is_an_error() {
+        let old = BTreeMap::from([("x".to_string(), 1)]);
+        let new = BTreeMap::from([("x".to_string(), 2)]);
+        let err = diff_kind(ElementKind::Type, &old, &new, &mut Vec::new()).unwrap_err();
+        assert_eq!(err.name, "x");
+        assert!(err.to_string().contains("rather than a mapping"), "{err}");
+    }
+
+    #[test]
+    fn long_values_are_elided() {
+        let text = "x".repeat(100);
+        let rendered = render_value(&Value::String(text));
+        assert_eq!(rendered.chars().count(), MAX_VALUE_CHARS);
+        assert!(rendered.ends_with('…'));
+        assert_eq!(
+            render_value(&serde_json::json!(["a", "b"])),
+            "[a, b]".to_string()
+        );
+    }
+}
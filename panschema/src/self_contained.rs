@@ -0,0 +1,335 @@
+//! Single-file HTML export (`generate --self-contained`)
+//!
+//! The HTML writer normally leaves side files beside `index.html`: the
+//! wasm viz bundle (`panschema_viz.js`, `panschema_viz_bg.wasm`) and a
+//! theme's `theme/` directory. A self-contained page carries them inline
+//! instead, so the one file can be emailed or archived and still opens
+//! from `file://` with no server. The bundle `build.rs` already embeds
+//! rides along base64-encoded and is imported from a `blob:` URL; a
+//! theme's stylesheet becomes a `<style>` block and its assets (logos,
+//! fonts, images) `data:` URIs. Only the URLs the page loads are
+//! rewritten — the `href`/`src` of its link, script and image tags and
+//! the stylesheet's `url(…)`s — so a description or code sample that
+//! mentions an asset path keeps it. The built-in styles, graph JSON and
+//! search index are inline on every page already.
+
+use std::path::Path;
+
+/// Make the viz bundle available to the page's graph scripts without a
+/// server: `window.__PANSCHEMA_VIZ_BUNDLE__.load()` resolves to an
+/// initialized module, as `import('./panschema_viz.js')` plus its `init`
+/// would. Each call imports a fresh instance, as the separate imports it
+/// replaces do.
+const LOADER: &str = r#"<script>
+    window.__PANSCHEMA_VIZ_BUNDLE__ = (function () {
+        const js = "{JS}";
+        const wasm = "{WASM}";
+        const bytes = (b64) => Uint8Array.from(atob(b64), (c) => c.charCodeAt(0));
+        return {
+            async load() {
+                const url = URL.createObjectURL(new Blob([bytes(js)], { type: 'text/javascript' }));
+                const mod = await import(url);
+                await mod.default({ module_or_path: bytes(wasm) });
+                return mod;
+            },
+        };
+    })();
+</script>
+"#;
+
+/// Insert the viz bundle loader into `html`'s `<head>`, so it is defined
+/// before any graph script runs.
+pub(crate) fn inline_viz_bundle(html: &str, js: &str, wasm: &[u8]) -> String {
+    let loader = LOADER
+        .replace("{JS}", &base64(js.as_bytes()))
+        .replace("{WASM}", &base64(wasm));
+    match html.find("</head>") {
+        Some(at) => format!("{}{loader}{}", &html[..at], &html[at..]),
+        None => format!("{loader}{html}"),
+    }
+}
+
+/// What [`inline_tags`] puts in place of a tag that loads a file.
+pub(crate) enum Inline {
+    /// A new `href`/`src` value, e.g. a `data:` URI.
+    Value(String),
+    /// Markup replacing the whole tag, e.g. a `<style>` block for a
+    /// stylesheet `<link>`.
+    Tag(String),
+}
+
+/// The elements whose `href`/`src` names a file the page loads.
+const LOADING_TAGS: [&str; 3] = ["link", "script", "img"];
+
+/// Rewrite the files `html`'s `<link>`, `<script>` and `<img>` tags
+/// load. `inline(tag, url)` gets each such tag's lowercase name and its
+/// `href` or `src` value, and returns the replacement or `None` to keep
+/// the tag. Text, other elements' attributes, comments and script and
+/// style bodies pass through untouched.
+pub(crate) fn inline_tags(
+    html: &str,
+    mut inline: impl FnMut(&str, &str) -> Option<Inline>,
+) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(open) = rest.find('<') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+        if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(rest.len(), |e| e + 3);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let Some(tag) = parse_tag(rest) else {
+            out.push('<');
+            rest = &rest[1..];
+            continue;
+        };
+        let replacement = tag
+            .url
+            .filter(|_| LOADING_TAGS.contains(&tag.name.as_str()))
+            .and_then(|(start, end)| {
+                Some((start, end, inline(&tag.name, unquote(&rest[start..end]))?))
+            });
+        match replacement {
+            Some((start, end, Inline::Value(value))) => {
+                out.push_str(&rest[..start]);
+                out.push('"');
+                out.push_str(&value.replace('"', "&quot;"));
+                out.push('"');
+                out.push_str(&rest[end..tag.len]);
+            }
+            Some((_, _, Inline::Tag(markup))) => out.push_str(&markup),
+            None => out.push_str(&rest[..tag.len]),
+        }
+        rest = &rest[tag.len..];
+        // A script or style body is code, not markup.
+        if matches!(tag.name.as_str(), "script" | "style") {
+            let close = format!("</{}", tag.name);
+            let end = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// An opening tag at the start of `html`: its lowercase name, the byte
+/// range of its first `href` or `src` value (quotes included) and its
+/// length through `>`.
+struct Tag {
+    name: String,
+    url: Option<(usize, usize)>,
+    len: usize,
+}
+
+/// Parse the opening tag `html` starts with; `None` for anything else —
+/// a closing tag, a doctype, a stray `<` — or a tag left unclosed.
+fn parse_tag(html: &str) -> Option<Tag> {
+    let bytes = html.as_bytes();
+    let skip = |mut i: usize, stop: &dyn Fn(u8) -> bool| {
+        while bytes.get(i).is_some_and(|&b| !stop(b)) {
+            i += 1;
+        }
+        i
+    };
+    let mut i = skip(1, &|b| !b.is_ascii_alphanumeric());
+    if i == 1 {
+        return None;
+    }
+    let name = html[1..i].to_ascii_lowercase();
+    let mut url = None;
+    loop {
+        i = skip(i, &|b| !(b.is_ascii_whitespace() || b == b'/'));
+        if *bytes.get(i)? == b'>' {
+            return Some(Tag {
+                name,
+                url,
+                len: i + 1,
+            });
+        }
+        let attr_start = i;
+        i = skip(i, &|b| {
+            matches!(b, b'=' | b'>' | b'/') || b.is_ascii_whitespace()
+        });
+        let attr = &html[attr_start..i];
+        i = skip(i, &|b| !b.is_ascii_whitespace());
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i = skip(i + 1, &|b| !b.is_ascii_whitespace());
+        let start = i;
+        i = match *bytes.get(i)? {
+            quote @ (b'"' | b'\'') => skip(i + 1, &|b| b == quote) + 1,
+            _ => skip(i, &|b| b == b'>' || b.is_ascii_whitespace()),
+        };
+        if i > bytes.len() {
+            return None;
+        }
+        if url.is_none() && (attr.eq_ignore_ascii_case("href") || attr.eq_ignore_ascii_case("src"))
+        {
+            url = Some((start, i));
+        }
+    }
+}
+
+/// An attribute value without its quotes.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|q| value.strip_prefix(q)?.strip_suffix(q))
+        .unwrap_or(value)
+}
+
+/// Rewrite the `url(…)` references in `css`: `inline(url)` gets each
+/// one's target, unquoted, and returns its replacement or `None` to keep
+/// it.
+pub(crate) fn inline_css_urls(css: &str, mut inline: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(open) = rest.find("url(") {
+        let args = &rest[open + 4..];
+        let Some(close) = args.find(')') else {
+            break;
+        };
+        out.push_str(&rest[..open + 4]);
+        let target = args[..close].trim();
+        match inline(unquote(target)) {
+            Some(uri) => out.push_str(&uri),
+            None => out.push_str(&args[..close]),
+        }
+        rest = &args[close..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `bytes` as a `data:` URI, typed by `path`'s extension.
+pub(crate) fn data_uri(path: &Path, bytes: &[u8]) -> String {
+    format!("data:{};base64,{}", mime_type(path), base64(bytes))
+}
+
+/// The media type a browser needs for a theme asset; anything
+/// unrecognized is sent as opaque bytes.
+fn mime_type(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match ext.as_deref() {
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("ico") => "image/x-icon",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        Some("ttf") => "font/ttf",
+        Some("otf") => "font/otf",
+        Some("css") => "text/css",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Standard, padded base64 (RFC 4648 §4), as `atob` and `data:` URIs
+/// read it.
+pub(crate) fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_matches_the_rfc_vectors() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64(input.as_bytes()), expected, "{input:?}");
+        }
+        assert_eq!(base64(&[0xff, 0xfe]), "//4=");
+    }
+
+    #[test]
+    fn only_the_urls_a_tag_loads_are_inlined() {
+        let html = concat!(
+            r#"<link rel="stylesheet" href="theme/theme.css">"#,
+            r#"<IMG class=logo SRC='theme/assets/logo.svg' alt="logo">"#,
+            r#"<p>Put it at <code>theme/assets/logo.svg</code>, not "#,
+            r#"<a href="theme/assets/logo.svg">here</a>.</p>"#,
+            r#"<!-- <img src="theme/assets/logo.svg"> -->"#,
+            r#"<script>const s = '<img src="theme/assets/logo.svg">';</script>"#,
+            "<p>1 < 2</p>",
+        );
+        let out = inline_tags(html, |tag, url| match (tag, url) {
+            ("link", "theme/theme.css") => Some(Inline::Tag("<style></style>".to_string())),
+            ("img", "theme/assets/logo.svg") => Some(Inline::Value("data:x".to_string())),
+            _ => None,
+        });
+        assert_eq!(
+            out,
+            concat!(
+                "<style></style>",
+                r#"<IMG class=logo SRC="data:x" alt="logo">"#,
+                r#"<p>Put it at <code>theme/assets/logo.svg</code>, not "#,
+                r#"<a href="theme/assets/logo.svg">here</a>.</p>"#,
+                r#"<!-- <img src="theme/assets/logo.svg"> -->"#,
+                r#"<script>const s = '<img src="theme/assets/logo.svg">';</script>"#,
+                "<p>1 < 2</p>",
+            )
+        );
+
+        let css = r#"a { background: url( "assets/a.png" ) } /* assets/a.png */ b { src: url(other.woff) }"#;
+        let out = inline_css_urls(css, |url| {
+            (url == "assets/a.png").then(|| "data:a".to_string())
+        });
+        assert_eq!(
+            out,
+            "a { background: url(data:a) } /* assets/a.png */ b { src: url(other.woff) }"
+        );
+    }
+
+    #[test]
+    fn the_loader_lands_in_the_head_with_the_bundle_encoded() {
+        let html = "<html><head><title>t</title></head><body></body></html>";
+        let out = inline_viz_bundle(html, "export default 1;", b"\0asm");
+        let loader = out.find("__PANSCHEMA_VIZ_BUNDLE__").unwrap();
+        assert!(loader < out.find("</head>").unwrap());
+        assert!(out.contains(&format!(
+            r#"const js = "{}";"#,
+            base64(b"export default 1;")
+        )));
+        assert!(out.contains(r#"const wasm = "AGFzbQ==";"#));
+        assert_eq!(
+            data_uri(Path::new("logo.SVG"), b"<svg/>"),
+            "data:image/svg+xml;base64,PHN2Zy8+"
+        );
+    }
+}
@@ -63,11 +63,13 @@ impl Writer for ShaclWriter {
     }
 
     /// The cross-format default (`unique_keys` has no SHACL Core form)
-    /// plus this writer's own gap: a rule with no conditional-shape form —
+    /// plus this writer's own gaps: a rule with no conditional-shape form —
     /// one-sided, an empty condition side, or a condition naming a slot
-    /// the class doesn't have — dropped rather than emitted over a
-    /// fabricated property IRI. Prose over the same data
-    /// [`crate::rdf_serializers::shacl_skipped_rules`] exposes.
+    /// the class doesn't have — and a class-expression combinator naming
+    /// such a slot, each dropped rather than emitted over a fabricated
+    /// property IRI. Prose over the same data
+    /// [`crate::rdf_serializers::shacl_skipped_rules`] and
+    /// [`crate::rdf_serializers::shacl_skipped_expressions`] expose.
     fn projection_gaps(&self, schema: &SchemaDefinition) -> Vec<String> {
         let mut gaps = crate::diagnostics::classes_with_unprojected_constructs(schema, "shacl")
             .into_iter()
@@ -83,6 +85,16 @@ impl Writer for ShaclWriter {
                     )
                 }),
         );
+        gaps.extend(
+            crate::rdf_serializers::shacl_skipped_expressions(schema)
+                .into_iter()
+                .map(|s| {
+                    format!(
+                        "`{}` on class `{}` is not emitted as a SHACL shape: {}",
+                        s.construct, s.class, s.reason
+                    )
+                }),
+        );
         // SHACL Core's property-pair constraints (sh:equals, sh:disjoint,
         // sh:lessThan[OrEquals]) include no subset form, so a slot-level
         // `is_a` has no shape to land in — the shapes accept data the
@@ -210,6 +222,7 @@ mod tests {
                 text: "red".to_string(),
                 description: None,
                 meaning: None,
+                metadata: Default::default(),
             },
         );
         color.permissible_values.insert(
@@ -218,6 +231,7 @@ mod tests {
                 text: "white".to_string(),
                 description: None,
                 meaning: Some("vocab:White".to_string()),
+                metadata: Default::default(),
             },
         );
         schema.enums.insert("Color".to_string(), color);
@@ -297,6 +311,7 @@ mod tests {
                     text: v.to_string(),
                     description: None,
                     meaning: None,
+                    metadata: Default::default(),
                 },
             );
         }
@@ -666,6 +681,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn class_expressions_project_to_logical_shapes() {
+        use crate::linkml::{ClassExpression, SlotCondition};
+        let mut schema = schema_with_rule(required_approved_by(), required_approved_by());
+        let mut draft = ClassDefinition::new("Draft");
+        draft.class_uri = Some(format!("{EX}#Draft"));
+        schema.classes.insert("Draft".to_string(), draft);
+        let requires = |slot: &str| ClassExpression {
+            slot_conditions: [(
+                slot.to_string(),
+                SlotCondition {
+                    required: true,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
+        let image = schema.classes.get_mut("Image").unwrap();
+        image.exactly_one_of = vec![requires("verdict"), requires("approved_by")];
+        image.none_of = vec![ClassExpression {
+            is_a: Some("Draft".to_string()),
+            ..Default::default()
+        }];
+        image.disjoint_with = vec!["Draft".to_string()];
+        // Names a slot Image lacks: dropped and reported, never emitted
+        // over a fabricated path.
+        image.any_of = vec![requires("phantom")];
+
+        let store = render_to_store(&schema);
+        let shape = format!("{EX}#ImageShape");
+        assert!(ask(
+            &store,
+            &format!(
+                "PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>\n\
+                 ASK {{ <{shape}> <{SH}xone> ?l . ?l rdf:first <{shape}/exactly_one_of0> .\n\
+                 <{shape}/exactly_one_of0> <{SH}property> ?p . ?p <{SH}minCount> 1 }}"
+            ),
+        ));
+        assert!(ask(
+            &store,
+            &format!(
+                "ASK {{ <{shape}> <{SH}not> <{shape}/none_of0> .\n\
+                 <{shape}/none_of0> <{SH}class> <{EX}#Draft> .\n\
+                 <{shape}> <{SH}not> <{shape}/disjoint_with0> }}"
+            ),
+        ));
+        assert!(!ask(&store, &format!("ASK {{ <{shape}/any_of0> ?p ?o }}")));
+        let gaps = ShaclWriter::new().projection_gaps(&schema);
+        assert!(
+            gaps.iter().any(|g| g
+                == "`any_of` on class `Image` is not emitted as a SHACL shape: references \
+                    slot `phantom`, which the class does not have"),
+            "got: {gaps:?}"
+        );
+    }
+
+    #[test]
+    fn slot_combinators_project_to_and_xone_and_not_value_shapes() {
+        let mut schema = schema_with_rule(required_approved_by(), required_approved_by());
+        let pattern = |p: &str| {
+            let mut branch = SlotDefinition::new("");
+            branch.pattern = Some(p.to_string());
+            branch
+        };
+        let verdict = schema
+            .classes
+            .get_mut("Image")
+            .unwrap()
+            .attributes
+            .get_mut("verdict")
+            .unwrap();
+        verdict.all_of = vec![pattern("^[A-Z]")];
+        verdict.exactly_one_of = vec![pattern("^A"), pattern("1$")];
+        verdict.none_of = vec![pattern("^X")];
+        // A branch tests values: its own `required` is no value facet.
+        verdict.exactly_one_of[1].required = true;
+
+        let store = render_to_store(&schema);
+        let prop = format!("{EX}#ImageShape/verdict");
+        assert!(ask(
+            &store,
+            &format!(
+                "PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>\n\
+                 ASK {{ <{prop}> <{SH}xone> ?l . ?l rdf:first <{prop}/xone0> ; rdf:rest ?r .\n\
+                 ?r rdf:first <{prop}/xone1> .\n\
+                 <{prop}/xone0> <{SH}pattern> \"^A\" ;\n\
+                   <{SH}datatype> <http://www.w3.org/2001/XMLSchema#string> .\n\
+                 <{prop}> <{SH}and> ?a . ?a rdf:first <{prop}/and0> .\n\
+                 <{prop}/and0> <{SH}pattern> \"^[A-Z]\" .\n\
+                 <{prop}> <{SH}not> <{prop}/not0> . <{prop}/not0> <{SH}pattern> \"^X\" }}"
+            ),
+        ));
+        assert!(!ask(
+            &store,
+            &format!("ASK {{ <{prop}/xone1> <{SH}minCount> ?n }}")
+        ));
+    }
+
     #[test]
     fn slot_any_of_projects_to_sh_or_of_alternatives() {
         use crate::linkml::{RuleConditions, SlotCondition};
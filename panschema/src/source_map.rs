@@ -0,0 +1,462 @@
+//! Source locations for the elements of a LinkML YAML schema.
+//!
+//! `serde` discards positions, so a diagnostic can name the class or slot
+//! it is about but not where that element lives — and once a schema is
+//! split across a dozen imported files, the name alone doesn't say which
+//! file to open. The YAML reader scans each document into a [`SourceMap`]
+//! keyed by *key path* (`classes` → `Person` → `attributes` → `name` →
+//! `range`), the map rides on the schema through
+//! [`crate::import_resolve`]'s merge, and [`crate::diagnostics`] looks
+//! each finding's referrer up to render `file:line:col` plus the source
+//! line.
+//!
+//! The scan is line-based over block-style YAML — the style every LinkML
+//! schema is written in. A flow collection (`Person: {is_a: Agent}`) or a
+//! multi-line scalar is located at its key; the keys inside it are not, and
+//! a lookup for one falls back to the nearest enclosing key that was
+//! ([`SourceMap::locate`]). Positions are a rendering aid, never schema
+//! content: a reader that has none (the OWL reader) leaves the map empty
+//! and every diagnostic still renders, just without a location.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Where one key was written: the file, the 1-based line and column of the
+/// key's first character, and the source line itself for the snippet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The file as the loader named it (not canonicalized), so the rendered
+    /// path is the one the author typed or the manifest declared.
+    pub file: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of the key's first character.
+    pub column: usize,
+    /// Width of the key token, underlined in the snippet.
+    pub len: usize,
+    /// The source line verbatim (trailing whitespace trimmed).
+    pub text: String,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+impl SourceLocation {
+    /// The `-->`-headed snippet for this location: the `file:line:col`
+    /// pointer, the source line under a line-number gutter, and a caret
+    /// underline beneath the key.
+    pub fn snippet(&self) -> String {
+        let gutter = self.line.to_string();
+        let pad = " ".repeat(gutter.len());
+        format!(
+            "{pad}--> {pointer}\n{pad} |\n{gutter} | {}\n{pad} | {}{}",
+            self.text,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.len.max(1)),
+            pointer = self,
+        )
+    }
+}
+
+/// Append the snippet of each location beneath `message`. With no
+/// locations the message is returned unchanged, so a diagnostic about a
+/// schema read from a format without positions renders exactly as before.
+pub fn annotate(message: String, locations: &[SourceLocation]) -> String {
+    let mut out = message;
+    for location in locations {
+        out.push('\n');
+        out.push_str(&location.snippet());
+    }
+    out
+}
+
+/// Key path → location, for every key a YAML schema document declares.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    entries: BTreeMap<Vec<String>, SourceLocation>,
+}
+
+impl SourceMap {
+    /// Scan a YAML document read from `file` for the position of every
+    /// block-style mapping key and sequence item. Never fails: a line the
+    /// scanner doesn't understand is skipped, costing only its location.
+    pub fn from_yaml(file: &Path, content: &str) -> Self {
+        let lines: Vec<&str> = content.lines().collect();
+        let entries = scan(content)
+            .into_iter()
+            .map(|entry| {
+                let location = SourceLocation {
+                    file: file.to_path_buf(),
+                    line: entry.line + 1,
+                    column: entry.column + 1,
+                    len: entry.len,
+                    text: lines[entry.line].trim_end().to_string(),
+                };
+                (entry.path, location)
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Whether no positions were recorded (a non-YAML source).
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every file a position was recorded in — the files a schema was
+    /// read from.
+    pub fn files(&self) -> BTreeSet<&Path> {
+        self.entries
+            .values()
+            .map(|location| location.file.as_path())
+            .collect()
+    }
+
+    /// The location of exactly `path`, if that key was written.
+    pub fn get(&self, path: &[&str]) -> Option<&SourceLocation> {
+        let key: Vec<String> = path.iter().map(|s| s.to_string()).collect();
+        self.entries.get(&key)
+    }
+
+    /// The location of `path`, or — when that key wasn't written (an
+    /// inherited or materialized value, a key inside a flow collection) —
+    /// of its nearest written ancestor. Never climbs above the element
+    /// itself (the first two segments, e.g. `classes` → `Person`), so a
+    /// finding is never pinned on the bare `classes:` line.
+    pub fn locate(&self, path: &[&str]) -> Option<SourceLocation> {
+        let floor = path.len().min(2);
+        (floor..=path.len())
+            .rev()
+            .find_map(|len| self.get(&path[..len]))
+            .cloned()
+    }
+
+    /// Fold `other`'s positions in, keeping this map's wherever it already
+    /// located an element — the whole element, not just the key, so a
+    /// surviving root definition is never pinned to lines of the import
+    /// whose differing copy the merge dropped. The same root-wins
+    /// precedence the import merge gives the definitions themselves.
+    pub fn absorb(&mut self, other: &SourceMap) {
+        let located: BTreeSet<Vec<String>> = self
+            .entries
+            .keys()
+            .filter(|path| path.len() == 2)
+            .cloned()
+            .collect();
+        for (path, location) in &other.entries {
+            if path.len() > 2 && located.contains(&path[..2]) {
+                continue;
+            }
+            self.entries
+                .entry(path.clone())
+                .or_insert_with(|| location.clone());
+        }
+    }
+}
+
+/// One block-style mapping key or sequence item, as [`scan`] finds it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct YamlEntry<'a> {
+    /// 0-based index of the line the entry is on.
+    pub line: usize,
+    /// Key path to the entry: its ancestors' segments, then its own — the
+    /// unquoted key, or a sequence item's index.
+    pub path: Vec<String>,
+    /// 0-based byte column of the key token (an opening quote included) or
+    /// of a sequence item's `-`.
+    pub column: usize,
+    /// Byte width of the key token as written, quotes included; 1 for a
+    /// sequence item.
+    pub len: usize,
+    /// Whether the entry is a sequence item (its path segment an index)
+    /// rather than a mapping key.
+    pub is_item: bool,
+    /// The entry's inline value, trailing comment included: the text after
+    /// a key's colon, or a scalar sequence item's text. Empty when the
+    /// entry's children follow on deeper lines, and for an item whose
+    /// content is itself a key or a nested item.
+    pub value: &'a str,
+    /// Byte offset of `value` within its line.
+    pub value_at: usize,
+}
+
+/// Walk a YAML document's block structure: every mapping key and sequence
+/// item, in document order, with its key path. The one scanner behind
+/// [`SourceMap::from_yaml`] and the lint renamer's rewrite
+/// ([`crate::lint::apply_renames`]), so both read a document the same way.
+pub(crate) fn scan(content: &str) -> Vec<YamlEntry<'_>> {
+    let mut out = Vec::new();
+    // Open ancestors of the current line: (indent, path segment, whether
+    // the entry is a sequence item rather than a mapping key).
+    let mut stack: Vec<(usize, String, bool)> = Vec::new();
+    let mut next_index: BTreeMap<Vec<String>, usize> = BTreeMap::new();
+    // Lines indented deeper than this belong to the previous key's
+    // scalar (block scalar, wrapped plain scalar, multi-line flow
+    // collection) and declare no keys of their own.
+    let mut skip_deeper_than: Option<usize> = None;
+
+    for (line, raw) in content.lines().enumerate() {
+        let raw = raw.trim_end();
+        let trimmed = raw.trim_start_matches(' ');
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = raw.len() - trimmed.len();
+        if let Some(limit) = skip_deeper_than {
+            if indent > limit {
+                continue;
+            }
+            skip_deeper_than = None;
+        }
+        if trimmed == "---" || trimmed == "..." {
+            stack.clear();
+            continue;
+        }
+        // The byte offset of a suffix of this line.
+        let offset = |suffix: &str| raw.len() - suffix.len();
+
+        let mut col = indent;
+        let mut rest = trimmed;
+        let mut item_col = None;
+        // Sequence items, including `- - x` nesting on one line. An item
+        // may sit at its parent key's own indent (`mixins:\n- A`), so
+        // only a deeper entry or a sibling item closes here.
+        while rest == "-" || rest.starts_with("- ") {
+            while stack
+                .last()
+                .is_some_and(|top| top.0 > col || (top.0 == col && top.2))
+            {
+                stack.pop();
+            }
+            let parent = path_of(&stack);
+            let slot = next_index.entry(parent.clone()).or_insert(0);
+            let index = *slot;
+            *slot += 1;
+            let mut path = parent;
+            path.push(index.to_string());
+            out.push(YamlEntry {
+                line,
+                path,
+                column: col,
+                len: 1,
+                is_item: true,
+                value: "",
+                value_at: raw.len(),
+            });
+            stack.push((col, index.to_string(), true));
+            item_col = Some(col);
+            let after = &rest[1..];
+            let item = after.trim_start_matches(' ');
+            col += 1 + (after.len() - item.len());
+            rest = item;
+        }
+        if rest.is_empty() || rest.starts_with('#') {
+            continue;
+        }
+
+        match split_key(rest) {
+            Some((key, len, value)) => {
+                while stack.last().is_some_and(|top| top.0 >= col) {
+                    stack.pop();
+                }
+                let mut path = path_of(&stack);
+                path.push(key.clone());
+                let value = value.trim_start_matches(' ');
+                out.push(YamlEntry {
+                    line,
+                    path,
+                    column: col,
+                    len,
+                    is_item: false,
+                    value,
+                    value_at: offset(value),
+                });
+                if opens_nested_block(value) {
+                    stack.push((col, key, false));
+                } else {
+                    skip_deeper_than = Some(col);
+                }
+            }
+            // A scalar sequence item (`- Agent`); deeper lines continue it.
+            None => {
+                if let Some(item) = out.last_mut().filter(|e| e.line == line) {
+                    item.value = rest;
+                    item.value_at = offset(rest);
+                }
+                skip_deeper_than = item_col;
+            }
+        }
+    }
+    out
+}
+
+fn path_of(stack: &[(usize, String, bool)]) -> Vec<String> {
+    stack
+        .iter()
+        .map(|(_, segment, _)| segment.clone())
+        .collect()
+}
+
+/// Whether a key's inline value leaves its children to the following,
+/// deeper lines: nothing after the colon, a trailing comment, or only an
+/// anchor/tag (`Person: &person`).
+fn opens_nested_block(value: &str) -> bool {
+    let value = value.trim();
+    if value.is_empty() || value.starts_with('#') {
+        return true;
+    }
+    (value.starts_with('&') || value.starts_with('!'))
+        && value
+            .split_once(' ')
+            .is_none_or(|(_, after)| after.trim().is_empty() || after.trim().starts_with('#'))
+}
+
+/// Split `key: value` into the key, the key token's width in the source,
+/// and the text after the colon. `None` for a line that is not a mapping
+/// entry (a scalar, a flow collection). A plain key ends at the first
+/// `": "` or a trailing `:`, so a CURIE key (`GO:0008150:`) keeps its
+/// colon; a quoted key ends at its closing quote.
+fn split_key(rest: &str) -> Option<(String, usize, &str)> {
+    if let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let close = rest[1..].find(quote)? + 1;
+        let value = rest[close + 1..].strip_prefix(':')?;
+        if !(value.is_empty() || value.starts_with(' ')) {
+            return None;
+        }
+        return Some((rest[1..close].to_string(), close + 1, value));
+    }
+    if rest.starts_with('[') || rest.starts_with('{') {
+        return None;
+    }
+    let colon = match rest.find(": ") {
+        Some(i) => i,
+        None if rest.ends_with(':') => rest.len() - 1,
+        None => return None,
+    };
+    let key = rest[..colon].trim_end();
+    Some((key.to_string(), key.len(), &rest[colon + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "\
+id: https://example.org/s
+name: s
+default_range: string
+classes:
+  Person:
+    description: >
+      A human: being, wrapped
+      over two lines.
+    is_a: Agent
+    mixins:
+    - Named
+    - Aged
+    attributes:
+      name:
+        range: string
+  Agent: {description: flow-style}
+slots:
+  'age':
+    range: integer
+";
+
+    fn map() -> SourceMap {
+        SourceMap::from_yaml(Path::new("s.yaml"), SCHEMA)
+    }
+
+    #[test]
+    fn nested_keys_are_located_by_path() {
+        let map = map();
+        let range = map
+            .get(&["classes", "Person", "attributes", "name", "range"])
+            .expect("nested key located");
+        assert_eq!((range.line, range.column), (15, 9));
+        assert_eq!(range.to_string(), "s.yaml:15:9");
+        assert_eq!(range.text, "        range: string");
+    }
+
+    #[test]
+    fn folded_scalar_lines_declare_no_keys() {
+        // `A human: being` looks like a key but is the description's text.
+        let map = map();
+        assert!(
+            map.get(&["classes", "Person", "description", "A human"])
+                .is_none()
+        );
+        let is_a = map
+            .get(&["classes", "Person", "is_a"])
+            .expect("is_a located");
+        assert_eq!(is_a.line, 9);
+    }
+
+    #[test]
+    fn sequence_items_at_the_parent_indent_are_indexed() {
+        let map = map();
+        let aged = map
+            .get(&["classes", "Person", "mixins", "1"])
+            .expect("second mixin located");
+        assert_eq!((aged.line, aged.column), (12, 5));
+        assert!(
+            map.get(&["classes", "Person", "attributes"]).is_some(),
+            "the key after the sequence is the class's, not an item's"
+        );
+    }
+
+    #[test]
+    fn quoted_keys_and_flow_maps_are_located_at_their_key() {
+        let map = map();
+        assert_eq!(
+            map.get(&["slots", "age", "range"]).map(|l| l.line),
+            Some(19)
+        );
+        assert!(map.get(&["classes", "Agent", "description"]).is_none());
+        let located = map
+            .locate(&["classes", "Agent", "description"])
+            .expect("falls back to the flow map's key");
+        assert_eq!(located.line, 16);
+    }
+
+    #[test]
+    fn locate_never_climbs_above_the_element() {
+        assert!(map().locate(&["classes", "Ghost", "is_a"]).is_none());
+    }
+
+    #[test]
+    fn snippet_points_at_the_key() {
+        let location = map().get(&["classes", "Person", "is_a"]).cloned().unwrap();
+        assert_eq!(
+            location.snippet(),
+            " --> s.yaml:9:5\n  |\n9 |     is_a: Agent\n  |     ^^^^"
+        );
+        assert_eq!(annotate("msg".to_string(), &[]), "msg");
+    }
+
+    #[test]
+    fn absorb_keeps_the_existing_position() {
+        let mut root = map();
+        let other = SourceMap::from_yaml(
+            Path::new("o.yaml"),
+            "classes:\n  Person:\n    tree_root: true\n  Extra:\n",
+        );
+        root.absorb(&other);
+        assert_eq!(
+            root.get(&["classes", "Person"]).map(|l| l.file.clone()),
+            Some(PathBuf::from("s.yaml"))
+        );
+        assert!(
+            root.get(&["classes", "Person", "tree_root"]).is_none(),
+            "a located element takes none of the dropped copy's keys"
+        );
+        assert_eq!(
+            root.get(&["classes", "Extra"]).map(|l| l.file.clone()),
+            Some(PathBuf::from("o.yaml"))
+        );
+    }
+}
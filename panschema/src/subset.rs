@@ -0,0 +1,398 @@
+//! Subset pruning: narrow a schema to one named LinkML subset.
+//!
+//! A subset is declared under the schema's `subsets:` and joined by each
+//! element through `in_subset`. Pruning keeps the members plus everything
+//! they reference — parents, mixins, slots, ranges, union members, parent
+//! types — so the pruned schema stays self-contained and every writer can
+//! render it unchanged. Everything else is dropped. This is what lets
+//! `generate --subset public` publish a partner-facing JSON Schema that
+//! carries no internal classes.
+
+use std::collections::BTreeSet;
+
+use thiserror::Error;
+
+use crate::linkml::{
+    ClassExpression, RuleConditions, SchemaDefinition, SlotCondition, SlotDefinition,
+};
+
+/// Errors raised while pruning a schema to a subset.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SubsetError {
+    /// The requested subset is not declared under the schema's `subsets:`.
+    #[error("unknown subset `{name}`; the schema declares: {}", declared_list(.declared))]
+    Unknown { name: String, declared: Vec<String> },
+}
+
+fn declared_list(declared: &[String]) -> String {
+    if declared.is_empty() {
+        "(none)".to_string()
+    } else {
+        declared.join(", ")
+    }
+}
+
+/// The schema's element names that declare `subset` in their `in_subset`,
+/// by kind: `(classes, slots, enums, types)`. Class attributes are not
+/// listed on their own; they travel with their class.
+pub fn members(
+    schema: &SchemaDefinition,
+    subset: &str,
+) -> (Vec<String>, Vec<String>, Vec<String>, Vec<String>) {
+    fn named<'a, T: 'a>(
+        map: impl IntoIterator<Item = (&'a String, &'a T)>,
+        in_subset: impl Fn(&T) -> &[String],
+        subset: &str,
+    ) -> Vec<String> {
+        map.into_iter()
+            .filter(|(_, def)| in_subset(def).iter().any(|s| s == subset))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+    (
+        named(&schema.classes, |c| c.in_subset.as_slice(), subset),
+        named(&schema.slots, |s| s.in_subset.as_slice(), subset),
+        named(&schema.enums, |e| e.in_subset.as_slice(), subset),
+        named(&schema.types, |t| t.in_subset.as_slice(), subset),
+    )
+}
+
+/// Prune `schema` to the closure of `subset`: its members plus everything
+/// they reference, transitively. Schema-level metadata (prefixes, id,
+/// version, the subset declarations themselves) is kept as is, and
+/// `default_range` stays resolvable. A `disjoint_with` naming a pruned
+/// class is dropped rather than pulling the class back in, since
+/// disjointness constrains nothing the pruned schema can express; a slot
+/// `domain` naming a pruned class is cleared the same way, so a public
+/// slot shared with an internal class doesn't drag that class along.
+pub fn prune_to_subset(
+    schema: &SchemaDefinition,
+    subset: &str,
+) -> Result<SchemaDefinition, SubsetError> {
+    if !schema.subsets.contains_key(subset) {
+        return Err(SubsetError::Unknown {
+            name: subset.to_string(),
+            declared: schema.subsets.keys().cloned().collect(),
+        });
+    }
+
+    let (classes, slots, enums, types) = members(schema, subset);
+    let mut closure = Closure::default();
+    let tagged = |kind: Ref, names: Vec<String>| names.into_iter().map(move |n| (kind, n));
+    let mut pending: Vec<(Ref, String)> = tagged(Ref::Class, classes)
+        .chain(tagged(Ref::Slot, slots))
+        .chain(tagged(Ref::Enum, enums))
+        .chain(tagged(Ref::Type, types))
+        .chain(tagged(
+            Ref::Range,
+            schema.default_range.iter().cloned().collect(),
+        ))
+        .collect();
+
+    // Each name is looked up only in the index its reference points into,
+    // so a slot and a class sharing a name are kept independently.
+    while let Some((kind, name)) = pending.pop() {
+        match kind {
+            Ref::Class => {
+                if let Some(class) = schema.classes.get(&name)
+                    && closure.classes.insert(name)
+                {
+                    class_references(class, &mut pending);
+                }
+            }
+            Ref::Slot => {
+                if let Some(slot) = schema.slots.get(&name)
+                    && closure.slots.insert(name)
+                {
+                    slot_references(slot, &mut pending);
+                }
+            }
+            Ref::Enum => {
+                if schema.enums.contains_key(&name) {
+                    closure.enums.insert(name);
+                }
+            }
+            Ref::Type => {
+                if let Some(ty) = schema.types.get(&name)
+                    && closure.types.insert(name)
+                {
+                    pending.extend(ty.typeof_.iter().map(|t| (Ref::Type, t.clone())));
+                }
+            }
+            // A range may name a class, an enum or a type alike.
+            Ref::Range => {
+                let kind = if schema.classes.contains_key(&name) {
+                    Ref::Class
+                } else if schema.enums.contains_key(&name) {
+                    Ref::Enum
+                } else {
+                    Ref::Type
+                };
+                pending.push((kind, name));
+            }
+        }
+    }
+
+    let mut pruned = schema.clone();
+    pruned
+        .classes
+        .retain(|name, _| closure.classes.contains(name));
+    pruned.slots.retain(|name, _| closure.slots.contains(name));
+    pruned.enums.retain(|name, _| closure.enums.contains(name));
+    pruned.types.retain(|name, _| closure.types.contains(name));
+    for class in pruned.classes.values_mut() {
+        class
+            .disjoint_with
+            .retain(|other| closure.classes.contains(other));
+    }
+    let attributes = pruned.classes.values_mut().flat_map(|class| {
+        class
+            .attributes
+            .values_mut()
+            .chain(class.slot_usage.values_mut())
+    });
+    for slot in pruned.slots.values_mut().chain(attributes) {
+        if slot
+            .domain
+            .as_ref()
+            .is_some_and(|domain| !closure.classes.contains(domain))
+        {
+            slot.domain = None;
+        }
+    }
+    Ok(pruned)
+}
+
+/// The element names kept by a prune, by kind.
+#[derive(Default)]
+struct Closure {
+    classes: BTreeSet<String>,
+    slots: BTreeSet<String>,
+    enums: BTreeSet<String>,
+    types: BTreeSet<String>,
+}
+
+/// Which index a referenced name is looked up in.
+#[derive(Debug, Clone, Copy)]
+enum Ref {
+    Class,
+    Slot,
+    Enum,
+    Type,
+    /// A `range`: a class, an enum or a type.
+    Range,
+}
+
+fn push(out: &mut Vec<(Ref, String)>, kind: Ref, names: impl IntoIterator<Item = String>) {
+    out.extend(names.into_iter().map(|name| (kind, name)));
+}
+
+fn class_references(class: &crate::linkml::ClassDefinition, out: &mut Vec<(Ref, String)>) {
+    push(out, Ref::Class, class.is_a.iter().cloned());
+    push(out, Ref::Class, class.mixins.iter().cloned());
+    push(out, Ref::Class, class.union_of.iter().cloned());
+    push(out, Ref::Slot, class.slots.iter().cloned());
+    push(out, Ref::Slot, class.slot_usage.keys().cloned());
+    for slot in class.attributes.values().chain(class.slot_usage.values()) {
+        slot_references(slot, out);
+    }
+    for (_, members) in class.combinators() {
+        for member in members {
+            expression_references(member, out);
+        }
+    }
+    for rule in &class.rules {
+        for side in rule.preconditions.iter().chain(&rule.postconditions) {
+            rule_references(side, out);
+        }
+    }
+}
+
+fn slot_references(slot: &SlotDefinition, out: &mut Vec<(Ref, String)>) {
+    push(out, Ref::Range, slot.range.iter().cloned());
+    push(out, Ref::Slot, slot.is_a.iter().cloned());
+    push(out, Ref::Slot, slot.inverse.iter().cloned());
+    for (_, branches) in slot.combinators() {
+        for branch in branches {
+            slot_references(branch, out);
+        }
+    }
+}
+
+fn expression_references(expr: &ClassExpression, out: &mut Vec<(Ref, String)>) {
+    push(out, Ref::Class, expr.is_a.iter().cloned());
+    condition_references(&expr.slot_conditions, out);
+    for (_, members) in expr.combinators() {
+        for member in members {
+            expression_references(member, out);
+        }
+    }
+}
+
+fn rule_references(conditions: &RuleConditions, out: &mut Vec<(Ref, String)>) {
+    condition_references(&conditions.slot_conditions, out);
+    for alternative in &conditions.any_of {
+        rule_references(alternative, out);
+    }
+}
+
+fn condition_references(
+    conditions: &std::collections::BTreeMap<String, SlotCondition>,
+    out: &mut Vec<(Ref, String)>,
+) {
+    fn ranges(condition: &SlotCondition, out: &mut Vec<(Ref, String)>) {
+        push(out, Ref::Range, condition.range.iter().cloned());
+        for alternative in &condition.any_of {
+            ranges(alternative, out);
+        }
+    }
+    for (slot, condition) in conditions {
+        out.push((Ref::Slot, slot.clone()));
+        ranges(condition, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{ClassDefinition, EnumDefinition, SubsetDefinition, TypeDefinition};
+
+    /// `Order` is public; it references `Customer` (range), `Status`
+    /// (enum), and `sku` (a slot typed by `Sku`). `AuditLog` is internal
+    /// and referenced by nothing public.
+    fn shop() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("shop");
+        schema
+            .subsets
+            .insert("public".to_string(), SubsetDefinition::new("public"));
+
+        let mut order = ClassDefinition::new("Order");
+        order.in_subset = vec!["public".to_string()];
+        order.slots = vec!["sku".to_string()];
+        let mut customer = SlotDefinition::new("customer");
+        customer.range = Some("Customer".to_string());
+        order.attributes.insert("customer".to_string(), customer);
+        let mut status = SlotDefinition::new("status");
+        status.range = Some("Status".to_string());
+        order.attributes.insert("status".to_string(), status);
+        order.disjoint_with = vec!["AuditLog".to_string()];
+        schema.classes.insert("Order".to_string(), order);
+
+        let mut customer = ClassDefinition::new("Customer");
+        customer.is_a = Some("Party".to_string());
+        schema.classes.insert("Customer".to_string(), customer);
+        schema
+            .classes
+            .insert("Party".to_string(), ClassDefinition::new("Party"));
+        schema
+            .classes
+            .insert("AuditLog".to_string(), ClassDefinition::new("AuditLog"));
+
+        let mut sku = SlotDefinition::new("sku");
+        sku.range = Some("Sku".to_string());
+        schema.slots.insert("sku".to_string(), sku);
+        schema
+            .slots
+            .insert("audit_note".to_string(), SlotDefinition::new("audit_note"));
+
+        schema
+            .enums
+            .insert("Status".to_string(), EnumDefinition::new("Status"));
+        schema
+            .enums
+            .insert("Severity".to_string(), EnumDefinition::new("Severity"));
+        let mut sku_type = TypeDefinition::new("Sku");
+        sku_type.typeof_ = Some("string".to_string());
+        schema.types.insert("Sku".to_string(), sku_type);
+        schema
+    }
+
+    #[test]
+    fn prune_keeps_members_and_their_references_only() {
+        let pruned = prune_to_subset(&shop(), "public").unwrap();
+        assert_eq!(
+            pruned.classes.keys().collect::<Vec<_>>(),
+            vec!["Customer", "Order", "Party"],
+            "the member, its attribute range, and that range's parent"
+        );
+        assert_eq!(pruned.slots.keys().collect::<Vec<_>>(), vec!["sku"]);
+        assert_eq!(pruned.enums.keys().collect::<Vec<_>>(), vec!["Status"]);
+        assert_eq!(pruned.types.keys().collect::<Vec<_>>(), vec!["Sku"]);
+        assert!(
+            pruned.classes["Order"].disjoint_with.is_empty(),
+            "a disjointness axiom naming a pruned class is dropped, not followed"
+        );
+        assert!(pruned.subsets.contains_key("public"));
+    }
+
+    #[test]
+    fn unknown_subset_is_an_error_naming_the_declared_ones() {
+        let err = prune_to_subset(&shop(), "partner").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown subset `partner`; the schema declares: public"
+        );
+    }
+
+    /// A slot reference keeps the slot, not an unrelated class that
+    /// happens to share its name.
+    #[test]
+    fn a_slot_sharing_a_class_name_is_kept_as_the_slot() {
+        let mut schema = shop();
+        schema
+            .classes
+            .get_mut("Order")
+            .unwrap()
+            .slots
+            .push("note".to_string());
+        schema
+            .slots
+            .insert("note".to_string(), SlotDefinition::new("note"));
+        schema
+            .classes
+            .insert("note".to_string(), ClassDefinition::new("note"));
+
+        let pruned = prune_to_subset(&schema, "public").unwrap();
+        assert_eq!(pruned.slots.keys().collect::<Vec<_>>(), vec!["note", "sku"]);
+        assert!(
+            !pruned.classes.contains_key("note"),
+            "the same-named class is referenced by nothing public"
+        );
+    }
+
+    /// A kept slot's `domain` is not followed: a slot shared by a public
+    /// and an internal class would otherwise leak the internal one.
+    #[test]
+    fn a_pruned_domain_is_cleared_rather_than_followed() {
+        let mut schema = shop();
+        schema.slots.get_mut("sku").unwrap().domain = Some("AuditLog".to_string());
+        let mut kept = SlotDefinition::new("placed_by");
+        kept.domain = Some("Order".to_string());
+        schema
+            .classes
+            .get_mut("Order")
+            .unwrap()
+            .slots
+            .push("placed_by".to_string());
+        schema.slots.insert("placed_by".to_string(), kept);
+
+        let pruned = prune_to_subset(&schema, "public").unwrap();
+        assert!(!pruned.classes.contains_key("AuditLog"));
+        assert_eq!(pruned.slots["sku"].domain, None);
+        assert_eq!(
+            pruned.slots["placed_by"].domain.as_deref(),
+            Some("Order"),
+            "a domain the subset keeps is left alone"
+        );
+    }
+
+    #[test]
+    fn the_default_range_is_kept() {
+        let mut schema = shop();
+        schema.default_range = Some("Sku".to_string());
+        schema.slots.get_mut("sku").unwrap().range = None;
+
+        let pruned = prune_to_subset(&schema, "public").unwrap();
+        assert_eq!(pruned.types.keys().collect::<Vec<_>>(), vec!["Sku"]);
+    }
+}
@@ -0,0 +1,121 @@
+//! Static SVG export of the schema graph
+//!
+//! [`GraphWriter`]'s node/edge model, laid out on the CPU and drawn by
+//! `panschema-viz` ([`panschema_viz::svg`]) — the layout engine, symbol
+//! encoding and legend of the HTML page's graph canvas, without a
+//! browser. The static layouts are deterministic, so a regenerated image
+//! diffs cleanly; `auto` picks the layout the page would open with.
+
+use std::path::Path;
+
+use crate::graph_writer::{GraphData, GraphWriter};
+use crate::io::{IoError, IoResult, Writer};
+use crate::linkml::SchemaDefinition;
+
+/// Writes the schema graph as a standalone SVG image.
+pub struct SvgWriter {
+    layout: String,
+}
+
+impl SvgWriter {
+    /// An `auto`-layout writer.
+    pub fn new() -> Self {
+        Self {
+            layout: "auto".to_string(),
+        }
+    }
+
+    /// Lay the graph out with `layout`: a layout identifier
+    /// (`kamada-kawai`, `hierarchical`, …) or `auto`.
+    pub fn with_layout(mut self, layout: impl Into<String>) -> Self {
+        self.layout = layout.into();
+        self
+    }
+
+    /// Render the SVG document for `schema`.
+    pub fn render(&self, schema: &SchemaDefinition) -> IoResult<String> {
+        render_graph(&GraphWriter::new().schema_to_graph(schema), &self.layout)
+    }
+}
+
+/// Draw `graph` — a whole schema's, or a part of one such as a
+/// [`GraphData::neighborhood`] — as a standalone SVG document laid out
+/// with `layout`.
+pub fn render_graph(graph: &GraphData, layout: &str) -> IoResult<String> {
+    let json = serde_json::to_string(graph)
+        .map_err(|e| IoError::Write(format!("Failed to serialize graph: {}", e)))?;
+    // The HTML page's default graph aspect, so the image matches what
+    // the page first shows.
+    panschema_viz::svg::render_svg(&json, layout, 16, 8).map_err(IoError::Write)
+}
+
+impl Default for SvgWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer for SvgWriter {
+    fn write(&self, schema: &SchemaDefinition, output: &Path) -> IoResult<()> {
+        let svg = self.render(schema)?;
+        crate::io::ensure_output_parent(output)?;
+        std::fs::write(output, svg).map_err(IoError::Io)?;
+        Ok(())
+    }
+
+    fn format_id(&self) -> &str {
+        "svg"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkml::{ClassDefinition, SlotDefinition};
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("zoo");
+        schema
+            .classes
+            .insert("Animal".to_string(), ClassDefinition::new("Animal"));
+        let mut dog = ClassDefinition::new("Dog");
+        dog.is_a = Some("Animal".to_string());
+        dog.slots = vec!["owner".to_string()];
+        schema.classes.insert("Dog".to_string(), dog);
+        let mut owner = SlotDefinition::new("owner");
+        owner.range = Some("Animal".to_string());
+        schema.slots.insert("owner".to_string(), owner);
+        schema
+    }
+
+    #[test]
+    fn renders_the_schema_graph_as_svg() {
+        let svg = SvgWriter::new()
+            .with_layout("hierarchical")
+            .render(&schema())
+            .unwrap();
+        assert!(svg.starts_with("<svg"), "{svg}");
+        assert!(svg.contains("<title>zoo</title>"), "{svg}");
+        assert!(svg.contains(">Dog<") && svg.contains(">Animal<"), "{svg}");
+        assert_eq!(
+            svg,
+            SvgWriter::new()
+                .with_layout("hierarchical")
+                .render(&schema())
+                .unwrap(),
+            "a static layout renders the same bytes every time"
+        );
+    }
+
+    #[test]
+    fn unknown_layout_is_a_write_error() {
+        let err = SvgWriter::new()
+            .with_layout("spiral")
+            .render(&schema())
+            .unwrap_err();
+        assert!(
+            matches!(err, IoError::Write(ref m) if m.contains("spiral")),
+            "{err}"
+        );
+    }
+}
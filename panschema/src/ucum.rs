@@ -0,0 +1,261 @@
+//! UCUM (Unified Code for Units of Measure) syntax check for `unit.ucum_code`.
+//!
+//! Parses the case-sensitive UCUM grammar — terms joined by `.` and `/`,
+//! parenthesized sub-terms, integer exponents, `{annotations}`, integer
+//! factors — and checks every unbracketed atom against the UCUM tables of
+//! base, derived and customary units, with an SI prefix allowed only on a
+//! metric atom. Square-bracketed atoms (`[in_i]`, `[ppm]`, `[degF]`, …) are
+//! accepted by shape: UCUM reserves brackets for exactly these special and
+//! customary units, and there are too many to be worth mirroring here.
+//! This is a syntax check, not a unit-conversion engine: `kg/m3` and
+//! `m3/kg` both pass.
+//!
+//! Reference: <https://ucum.org/ucum>
+
+/// Prefix symbols, longest first so `da` wins over `d`.
+const PREFIXES: &[&str] = &[
+    "da", "Ki", "Mi", "Gi", "Ti", "Y", "Z", "E", "P", "T", "G", "M", "k", "h", "d", "c", "m", "u",
+    "n", "p", "f", "a", "z", "y",
+];
+
+/// Atoms that accept a prefix.
+const METRIC_ATOMS: &[&str] = &[
+    "m", "s", "g", "rad", "K", "C", "cd", "mol", "sr", "Hz", "N", "Pa", "J", "W", "A", "V", "F",
+    "Ohm", "S", "Wb", "Cel", "T", "H", "lm", "lx", "Bq", "Gy", "Sv", "l", "L", "ar", "t", "bar",
+    "u", "eV", "pc", "kat", "U", "bit", "By", "Bd", "eq", "osm", "g%", "Np", "B", "B[SPL]", "B[V]",
+    "B[mV]", "B[uV]", "B[10.nV]", "B[W]", "B[kW]", "cal", "cal_[15]", "cal_[20]", "cal_m",
+    "cal_IT", "cal_th", "Ci", "R", "RAD", "REM", "G", "Gal", "dyn", "erg", "P", "St", "Mx", "Oe",
+    "Gb", "sb", "Lmb", "ph", "Ky", "mho", "st", "tex", "m[Hg]", "m[H2O]", "gf", "[iU]", "[IU]",
+];
+
+/// Atoms that do not accept a prefix.
+const NON_METRIC_ATOMS: &[&str] = &[
+    "1", "10*", "10^", "%", "min", "h", "d", "a", "a_t", "a_j", "a_g", "wk", "mo", "mo_s", "mo_j",
+    "mo_g", "deg", "'", "''", "gon", "circ", "sph", "AU", "Ao", "b", "atm", "Den",
+];
+
+/// Check a UCUM code's syntax, returning a short reason on failure.
+pub fn check(code: &str) -> Result<(), String> {
+    if code.is_empty() {
+        return Err("empty code".to_string());
+    }
+    if code.chars().any(|c| c.is_whitespace()) {
+        return Err("contains whitespace".to_string());
+    }
+    let mut parser = Parser {
+        chars: code.chars().collect(),
+        pos: 0,
+    };
+    // A leading `/` is UCUM's unary division: `/min` is "per minute".
+    if parser.peek() == Some('/') {
+        parser.pos += 1;
+    }
+    parser.term()?;
+    match parser.peek() {
+        None => Ok(()),
+        Some(c) => Err(format!("unexpected `{c}` at position {}", parser.pos + 1)),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// term := component (('.' | '/') component)*
+    fn term(&mut self) -> Result<(), String> {
+        self.component()?;
+        while let Some(op @ ('.' | '/')) = self.peek() {
+            self.pos += 1;
+            if self.peek().is_none() {
+                return Err(format!("`{op}` is not followed by a unit"));
+            }
+            self.component()?;
+        }
+        Ok(())
+    }
+
+    /// component := '(' term ')' | annotation | simple-unit [exponent] [annotation]
+    fn component(&mut self) -> Result<(), String> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                self.term()?;
+                if self.peek() != Some(')') {
+                    return Err("unbalanced `(`".to_string());
+                }
+                self.pos += 1;
+                Ok(())
+            }
+            Some('{') => self.annotation(),
+            Some(_) => {
+                let token = self.unit_token()?;
+                check_simple_unit(&token)?;
+                if self.peek() == Some('{') {
+                    self.annotation()?;
+                }
+                Ok(())
+            }
+            None => Err("missing unit".to_string()),
+        }
+    }
+
+    /// annotation := '{' printable-except-braces* '}'
+    fn annotation(&mut self) -> Result<(), String> {
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '}' => return Ok(()),
+                '{' => return Err("nested `{` in annotation".to_string()),
+                _ => {}
+            }
+        }
+        Err("unterminated `{` annotation".to_string())
+    }
+
+    /// Read one simple unit with its exponent: everything up to the next
+    /// operator, parenthesis or annotation, with `[...]` read as a unit
+    /// whatever it contains.
+    fn unit_token(&mut self) -> Result<String, String> {
+        let mut token = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '.' | '/' | '(' | ')' | '{' | '}' => break,
+                '[' => {
+                    let start = self.pos;
+                    while let Some(b) = self.peek() {
+                        token.push(b);
+                        self.pos += 1;
+                        if b == ']' {
+                            break;
+                        }
+                    }
+                    if !token.ends_with(']') {
+                        return Err(format!("unbalanced `[` at position {}", start + 1));
+                    }
+                }
+                ']' => return Err(format!("unbalanced `]` at position {}", self.pos + 1)),
+                _ => {
+                    token.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        if token.is_empty() {
+            return Err(format!("missing unit at position {}", self.pos + 1));
+        }
+        Ok(token)
+    }
+}
+
+/// A simple unit is an integer factor, or an atom (optionally prefixed)
+/// followed by an optional signed integer exponent.
+fn check_simple_unit(token: &str) -> Result<(), String> {
+    if token.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(());
+    }
+    for (unit, exponent) in splits(token) {
+        if exponent.is_some_and(|e| !is_exponent(e)) {
+            continue;
+        }
+        if is_atom(unit) {
+            return Ok(());
+        }
+    }
+    Err(format!("`{token}` is not a UCUM unit"))
+}
+
+/// Candidate `(unit, exponent)` splits: the whole token, then each split
+/// before a trailing run of digits (with an optional sign).
+fn splits(token: &str) -> Vec<(&str, Option<&str>)> {
+    let mut out = vec![(token, None)];
+    let digits_start = token
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_digit())
+        .last()
+        .map(|(i, _)| i);
+    if let Some(start) = digits_start {
+        let signed = token[..start]
+            .strip_suffix(['+', '-'])
+            .map(|rest| rest.len());
+        for at in [signed, Some(start)].into_iter().flatten() {
+            if at > 0 {
+                out.push((&token[..at], Some(&token[at..])));
+            }
+        }
+    }
+    out
+}
+
+fn is_exponent(text: &str) -> bool {
+    let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_atom(unit: &str) -> bool {
+    if NON_METRIC_ATOMS.contains(&unit) || METRIC_ATOMS.contains(&unit) {
+        return true;
+    }
+    if unit.starts_with('[') && unit.ends_with(']') && unit.len() > 2 {
+        return true;
+    }
+    PREFIXES.iter().any(|prefix| {
+        unit.strip_prefix(prefix)
+            .is_some_and(|rest| METRIC_ATOMS.contains(&rest))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_well_formed_codes() {
+        for code in [
+            "kg",
+            "m/s2",
+            "m.s-2",
+            "/min",
+            "mm[Hg]",
+            "10*3/uL",
+            "[in_i]",
+            "kg{dry}",
+            "{count}/h",
+            "Cel",
+            "%",
+            "(kg.m)/s2",
+            "dB[SPL]",
+            "1",
+            "mL/(24.h)",
+            "umol/L",
+            "KiBy",
+        ] {
+            assert_eq!(check(code), Ok(()), "`{code}` is valid UCUM");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_codes_with_a_reason() {
+        for (code, reason) in [
+            ("", "empty code"),
+            ("kg m", "contains whitespace"),
+            ("kgs", "`kgs` is not a UCUM unit"),
+            ("m/", "`/` is not followed by a unit"),
+            ("(kg", "unbalanced `(`"),
+            ("kg)", "unexpected `)` at position 3"),
+            ("kg{dry", "unterminated `{` annotation"),
+            ("[in_i", "unbalanced `[` at position 1"),
+            ("degC", "`degC` is not a UCUM unit"),
+            ("kmin", "`kmin` is not a UCUM unit"),
+        ] {
+            assert_eq!(check(code), Err(reason.to_string()), "`{code}`");
+        }
+    }
+}
@@ -10,13 +10,18 @@
 //! checks — fidelity the display-oriented `literals` (stringified) and the
 //! still-incomplete JSON-Schema projection can't provide.
 
+use crate::expression::{Expression, Value as ExprValue, record_value};
 use crate::instances::{InstanceSet, InstanceValue, ScalarValue, scalar_to_display};
 use crate::linkml::{
-    EnumDefinition, RuleConditions, SchemaDefinition, SlotCondition, ValuePresence,
+    ArrayExpression, ClassExpression, EnumDefinition, RuleConditions, SchemaDefinition,
+    SlotCondition, SlotDefinition, ValuePresence,
+};
+use crate::linkml_resolve::{
+    ancestors_or_self, effective_cardinality, resolve_effective_slots_with_provenance,
 };
-use crate::linkml_resolve::{effective_cardinality, resolve_effective_slots_with_provenance};
 use regex::Regex;
 use serde_norway::Value;
+use std::collections::BTreeSet;
 use std::fmt;
 
 /// A single way the data fails to conform to the schema.
@@ -184,9 +189,23 @@ pub fn validate_instances(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<V
             } else {
                 Vec::new()
             };
-            for value in authored.map(|sv| sv.values.as_slice()).unwrap_or_default() {
+            // An array slot's value is shape-checked whole; its elements
+            // then face the per-value checks like any other value.
+            let values = authored.map(|sv| sv.values.as_slice()).unwrap_or_default();
+            if let Some(array) = &slot.array {
+                for value in values {
+                    if let Some(reason) = array_shape_failure(array, value) {
+                        push(format!(
+                            "array slot `{slot_name}` (class `{class_name}`) {reason}"
+                        ));
+                    }
+                }
+            }
+            for value in array_elements(values) {
                 let scalar = match value {
                     InstanceValue::Scalar(s) => s,
+                    // Flattened into its elements by `array_elements`.
+                    InstanceValue::Array(_) => continue,
                     // A value the reader couldn't fit to the slot's range kind
                     // (an object where a scalar is declared, or a non-reference
                     // scalar where a class is) — a range-kind mismatch.
@@ -314,6 +333,42 @@ pub fn validate_instances(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<V
                 }
             }
 
+            // Slot-level `all_of` / `exactly_one_of` / `none_of`: each value
+            // is tested against every branch (its range, pattern, and
+            // bounds). `any_of` needs no pass of its own — its branches are
+            // the induced ranges the checks above already hold values to.
+            for value in slot_values(inst, slot_name) {
+                if matches!(value, InstanceValue::Unexpected(_)) {
+                    continue;
+                }
+                let admits =
+                    |branch: &SlotDefinition| branch_admits(schema, branch, value, &class_of);
+                let shown = value_display(value);
+                for branch in slot.all_of.iter().filter(|b| !admits(b)) {
+                    push(format!(
+                        "slot `{slot_name}` (class `{class_name}`) value `{shown}` does not \
+                         satisfy `all_of` branch {}",
+                        branch_label(branch)
+                    ));
+                }
+                if !slot.exactly_one_of.is_empty() {
+                    let n = slot.exactly_one_of.iter().filter(|b| admits(b)).count();
+                    if n != 1 {
+                        push(format!(
+                            "slot `{slot_name}` (class `{class_name}`) value `{shown}` satisfies \
+                             {n} of the `exactly_one_of` branches, not exactly one"
+                        ));
+                    }
+                }
+                for branch in slot.none_of.iter().filter(|b| admits(b)) {
+                    push(format!(
+                        "slot `{slot_name}` (class `{class_name}`) value `{shown}` satisfies {}, \
+                         which the slot rules out with `none_of`",
+                        branch_label(branch)
+                    ));
+                }
+            }
+
             // Slot-level `is_a` states a subset: every value here must also
             // be a value of the parent slot on this record. No reasoner runs
             // on this path — without the check, citing a value outside the
@@ -342,6 +397,37 @@ pub fn validate_instances(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<V
                     ));
                 }
             }
+
+            // A computed slot's stored value must equal its
+            // `equals_expression` evaluated over the record. An expression
+            // that doesn't parse is a schema-load warning, not a per-record
+            // violation; one reading an absent slot evaluates to `None` and
+            // has nothing to check.
+            if let Some(source) = slot.equals_expression.as_deref()
+                && let Ok(expression) = Expression::parse(source)
+            {
+                match expression.evaluate(|name| record_value(inst, name)) {
+                    Ok(ExprValue::Null) => {}
+                    Ok(computed) => {
+                        for value in slot_values(inst, slot_name) {
+                            let InstanceValue::Scalar(scalar) = value else {
+                                continue;
+                            };
+                            if !ExprValue::from(scalar).agrees_with(&computed) {
+                                push(format!(
+                                    "slot `{slot_name}` (class `{class_name}`) value `{}` does \
+                                     not equal `{source}` (computed `{computed}`)",
+                                    scalar_to_display(scalar)
+                                ));
+                            }
+                        }
+                    }
+                    Err(err) => push(format!(
+                        "slot `{slot_name}` (class `{class_name}`) `equals_expression` \
+                         `{source}` cannot be evaluated for this record: {err}"
+                    )),
+                }
+            }
         }
 
         // Conditional constraints: a rule whose precondition holds imposes its
@@ -382,6 +468,70 @@ pub fn validate_instances(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<V
                 });
             }
         }
+        // Class expressions, read off the class directly like its rules. A
+        // member's `is_a` asks what the record *is*, so each record's
+        // ancestry (through `is_a` and mixins) is computed once here.
+        let lineage = ancestors_or_self(schema, class_name);
+        let holds = |expr: &ClassExpression| expression_holds(expr, &lineage, inst);
+        let mut push = |detail: String| {
+            out.push(Violation {
+                record: inst.id.clone(),
+                detail,
+            })
+        };
+        if !class.any_of.is_empty() && !class.any_of.iter().any(holds) {
+            push(format!(
+                "the record satisfies none of the `any_of` alternatives of class `{class_name}`"
+            ));
+        }
+        for expr in class.all_of.iter().filter(|e| !holds(e)) {
+            push(format!(
+                "the record does not satisfy `all_of` member {} of class `{class_name}`",
+                expression_label(expr)
+            ));
+        }
+        if !class.exactly_one_of.is_empty() {
+            let n = class.exactly_one_of.iter().filter(|e| holds(e)).count();
+            if n != 1 {
+                push(format!(
+                    "the record satisfies {n} of the `exactly_one_of` alternatives of class \
+                     `{class_name}`, not exactly one"
+                ));
+            }
+        }
+        for expr in class.none_of.iter().filter(|e| holds(e)) {
+            push(format!(
+                "the record satisfies {}, which class `{class_name}` rules out with `none_of`",
+                expression_label(expr)
+            ));
+        }
+        // `disjoint_with` and `union_of` constrain every class in the
+        // record's ancestry, not only its own: a disjointness is broken by a
+        // record that is both classes however it came to be each.
+        for ancestor in &lineage {
+            let Some(def) = schema.classes.get(*ancestor) else {
+                continue;
+            };
+            for other in def
+                .disjoint_with
+                .iter()
+                .filter(|d| lineage.contains(d.as_str()))
+            {
+                push(format!(
+                    "the record is both a `{ancestor}` and a `{other}`, which `{ancestor}` \
+                     declares disjoint"
+                ));
+            }
+            if !def.union_of.is_empty()
+                && !def.union_of.iter().any(|m| lineage.contains(m.as_str()))
+            {
+                push(format!(
+                    "class `{ancestor}` is the union of `{}`, but the record (class \
+                     `{class_name}`) is none of them",
+                    def.union_of.join("`, `")
+                ));
+            }
+        }
     }
 
     // Cross-record reference integrity: a typed reference to an id no record
@@ -473,6 +623,42 @@ pub fn validate_instances(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<V
     out
 }
 
+/// Every record missing a slot its class marks `recommended` — LinkML's
+/// soft requirement. These are warnings, not violations: the data still
+/// conforms, so they are kept apart from [`validate_instances`] and never
+/// fail a run. Ordered like violations: by record, then slot.
+pub fn missing_recommended(schema: &SchemaDefinition, set: &InstanceSet) -> Vec<Violation> {
+    let mut out = Vec::new();
+    let mut resolved_by_class: std::collections::BTreeMap<&str, _> =
+        std::collections::BTreeMap::new();
+    for inst in &set.instances {
+        let Some(class_name) = inst.types.first() else {
+            continue;
+        };
+        let Some(class) = schema.classes.get(class_name) else {
+            continue;
+        };
+        let resolved = resolved_by_class
+            .entry(class_name.as_str())
+            .or_insert_with(|| resolve_effective_slots_with_provenance(class, schema));
+        for (slot_name, rs) in resolved.iter() {
+            // A required slot's absence is already a violation.
+            if rs.definition.recommended
+                && !effective_cardinality(&rs.definition).required
+                && slot_values(inst, slot_name).is_empty()
+            {
+                out.push(Violation {
+                    record: inst.id.clone(),
+                    detail: format!(
+                        "recommended slot `{slot_name}` (class `{class_name}`) is absent"
+                    ),
+                });
+            }
+        }
+    }
+    out
+}
+
 /// Read a LinkML instance-data tree into the instance model and validate it —
 /// the per-format adapter over [`validate_instances`] (ADR-008). A data file
 /// that isn't a container mapping is a single structural violation rather than
@@ -520,6 +706,38 @@ fn conditions_hold(cond: &RuleConditions, inst: &crate::instances::Instance) ->
         .all(|(slot, sc)| slot_condition_failure(sc, slot_values(inst, slot)).is_none())
 }
 
+/// Whether a record (whose ancestry is `lineage`) satisfies an anonymous
+/// class expression: it is the expression's `is_a`, every slot condition
+/// holds, and each nested combinator holds.
+fn expression_holds(
+    expr: &ClassExpression,
+    lineage: &BTreeSet<&str>,
+    inst: &crate::instances::Instance,
+) -> bool {
+    let holds = |e: &ClassExpression| expression_holds(e, lineage, inst);
+    expr.is_a
+        .as_deref()
+        .is_none_or(|class| lineage.contains(class))
+        && expr
+            .slot_conditions
+            .iter()
+            .all(|(slot, sc)| slot_condition_failure(sc, slot_values(inst, slot)).is_none())
+        && (expr.any_of.is_empty() || expr.any_of.iter().any(holds))
+        && expr.all_of.iter().all(holds)
+        && (expr.exactly_one_of.is_empty()
+            || expr.exactly_one_of.iter().filter(|e| holds(e)).count() == 1)
+        && !expr.none_of.iter().any(holds)
+}
+
+/// A class-expression member as a violation names it: its own summary
+/// (see [`crate::rules::class_expression_summary`]) in parentheses.
+fn expression_label(expr: &ClassExpression) -> String {
+    match crate::rules::class_expression_summary(expr) {
+        Some(summary) => format!("({summary})"),
+        None => "(an empty expression)".to_string(),
+    }
+}
+
 /// Why `cond` does not hold for a slot's `values`, phrased as a clause that
 /// completes "slot `x` …", or `None` when it holds.
 ///
@@ -622,6 +840,144 @@ fn slot_condition_failure(cond: &SlotCondition, values: &[InstanceValue]) -> Opt
     None
 }
 
+/// Whether `value` satisfies one branch of a slot-level combinator: the
+/// branch's `range` (a class by the referenced record's class, an enum by
+/// membership, a type by primitive kind), `pattern`, and value bounds. A
+/// reference whose target names no record passes the range test — the
+/// dangling-reference report is the one that problem gets.
+fn branch_admits(
+    schema: &SchemaDefinition,
+    branch: &SlotDefinition,
+    value: &InstanceValue,
+    class_of: &std::collections::BTreeMap<&str, &str>,
+) -> bool {
+    let in_range = branch.range.as_deref().is_none_or(|range| match value {
+        InstanceValue::Reference { target, .. } => class_of
+            .get(target.as_str())
+            .is_none_or(|actual| crate::linkml_resolve::class_satisfies(schema, actual, range)),
+        InstanceValue::Scalar(scalar) => match schema.enums.get(range) {
+            Some(enum_def) => enum_permits(enum_def, scalar),
+            None if schema.classes.contains_key(range) => false,
+            None => crate::primitives::effective_primitive(schema, range)
+                .is_none_or(|primitive| kind_matches(primitive, scalar)),
+        },
+        InstanceValue::Unexpected(_) | InstanceValue::Array(_) => true,
+    });
+    if !in_range {
+        return false;
+    }
+    let InstanceValue::Scalar(scalar) = value else {
+        return true;
+    };
+    if let Some(p) = &branch.pattern
+        && Regex::new(p).is_ok_and(|re| !re.is_match(&scalar_to_display(scalar)))
+    {
+        return false;
+    }
+    let n = numeric(scalar);
+    branch
+        .minimum_value
+        .is_none_or(|min| n.is_some_and(|n| n >= min))
+        && branch
+            .maximum_value
+            .is_none_or(|max| n.is_some_and(|n| n <= max))
+}
+
+/// Values with every `array` value replaced by its elements, depth-first,
+/// so per-value checks see the numbers in a matrix rather than the matrix.
+fn array_elements(values: &[InstanceValue]) -> Vec<&InstanceValue> {
+    let mut out = Vec::new();
+    for value in values {
+        match value {
+            InstanceValue::Array(items) => out.extend(array_elements(items)),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Why an `array` slot's value has the wrong shape, or `None` when it fits:
+/// ragged nesting, a dimension count outside the expression's bounds, or
+/// an axis length outside its dimension's cardinality. A scalar where an
+/// array belongs has zero dimensions.
+fn array_shape_failure(array: &ArrayExpression, value: &InstanceValue) -> Option<String> {
+    let Some((shape, open)) = array_shape(value) else {
+        return Some("is ragged: lists at the same depth differ in length or nesting".to_string());
+    };
+    let ndims = shape.len() as u32;
+    let (min, max) = array.dimension_bounds();
+    // An empty innermost list hides how deep the value would go, so it
+    // only fails on having too many dimensions.
+    if (ndims < min && !open) || max.is_some_and(|max| ndims > max) {
+        let expected = match (min, max) {
+            (min, Some(max)) if min == max => min.to_string(),
+            (min, Some(max)) => format!("{min} to {max}"),
+            (min, None) => format!("at least {min}"),
+        };
+        return Some(format!(
+            "has {ndims} dimension(s), but the slot expects {expected}"
+        ));
+    }
+    for (i, &len) in shape.iter().enumerate() {
+        let (lo, hi) = array.axis_bounds(i);
+        if lo.is_some_and(|lo| len < lo as usize) || hi.is_some_and(|hi| len > hi as usize) {
+            let dim = &array.dimensions[i];
+            let axis = dim
+                .alias
+                .as_ref()
+                .map_or_else(|| format!("axis {}", i + 1), |a| format!("axis `{a}`"));
+            return Some(format!(
+                "has length {len} along {axis}, which expects {}",
+                dim.describe_length()
+            ));
+        }
+    }
+    None
+}
+
+/// The length along each axis of a rectangular nested-list value,
+/// outermost first, and whether it ends in an empty list; `None` when
+/// lists at one depth differ in shape.
+fn array_shape(value: &InstanceValue) -> Option<(Vec<usize>, bool)> {
+    let InstanceValue::Array(items) = value else {
+        return Some((Vec::new(), false));
+    };
+    let mut inner: Option<(Vec<usize>, bool)> = None;
+    for item in items {
+        let shape = array_shape(item)?;
+        match &inner {
+            Some(prev) if *prev != shape => return None,
+            _ => inner = Some(shape),
+        }
+    }
+    let (rest, open) = inner.unwrap_or((Vec::new(), items.is_empty()));
+    let mut shape = vec![items.len()];
+    shape.extend(rest);
+    Some((shape, open))
+}
+
+/// A combinator branch as it reads in a violation: the facets it states.
+fn branch_label(branch: &SlotDefinition) -> String {
+    let mut parts = Vec::new();
+    if let Some(range) = &branch.range {
+        parts.push(format!("range `{range}`"));
+    }
+    if let Some(pattern) = &branch.pattern {
+        parts.push(format!("pattern `{pattern}`"));
+    }
+    if let Some(min) = branch.minimum_value {
+        parts.push(format!("minimum {min}"));
+    }
+    if let Some(max) = branch.maximum_value {
+        parts.push(format!("maximum {max}"));
+    }
+    if parts.is_empty() {
+        "(no range or value facets)".to_string()
+    } else {
+        format!("({})", parts.join(", "))
+    }
+}
+
 /// Whether `scalar`'s string form is one of the enum's permissible values —
 /// matched against either the value key or its `text`.
 fn enum_permits(enum_def: &EnumDefinition, scalar: &ScalarValue) -> bool {
@@ -656,7 +1012,9 @@ fn values_match(a: &InstanceValue, b: &InstanceValue) -> bool {
 fn numeric_value(value: &InstanceValue) -> Option<f64> {
     match value {
         InstanceValue::Scalar(s) => numeric(s),
-        InstanceValue::Reference { .. } | InstanceValue::Unexpected(_) => None,
+        InstanceValue::Reference { .. }
+        | InstanceValue::Array(_)
+        | InstanceValue::Unexpected(_) => None,
     }
 }
 
@@ -667,6 +1025,7 @@ fn value_display(value: &InstanceValue) -> String {
     match value {
         InstanceValue::Scalar(s) => scalar_to_display(s),
         InstanceValue::Reference { target, .. } => target.clone(),
+        InstanceValue::Array(_) => crate::instances::array_to_display(value),
         InstanceValue::Unexpected(kind) => kind.to_string(),
     }
 }
@@ -706,7 +1065,7 @@ fn numeric(scalar: &ScalarValue) -> Option<f64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linkml::{ClassDefinition, SlotDefinition};
+    use crate::linkml::ClassDefinition;
 
     const SCHEMA: &str = "\
 name: WineCatalog
@@ -964,6 +1323,130 @@ pets:
         );
     }
 
+    /// A stored computed value is checked against its `equals_expression`;
+    /// a record missing an input has nothing to check.
+    #[test]
+    fn computed_slots_must_equal_their_expression() {
+        let schema: crate::linkml::SchemaDefinition = serde_norway::from_str(
+            "name: s\nclasses:\n  Rectangle:\n    tree_root: true\n    attributes:\n      id:\n        identifier: true\n      width: {range: float}\n      height: {range: float}\n      area:\n        range: float\n        equals_expression: '{width} * {height}'\n",
+        )
+        .expect("parse schema");
+        let violations = |yaml: &str| -> Vec<String> {
+            let data: serde_norway::Value = serde_norway::from_str(yaml).expect("parse data");
+            let set = crate::instances::InstanceSet::from_linkml_data(&schema, &data);
+            validate_instances(&schema, &set)
+                .iter()
+                .map(|v| v.to_string())
+                .collect()
+        };
+
+        assert!(violations("id: r\nwidth: 3\nheight: 4\narea: 12.0\n").is_empty());
+        assert!(
+            violations("id: r\nwidth: 3\narea: 12\n").is_empty(),
+            "`height` is absent, so the expression is None"
+        );
+        assert_eq!(
+            violations("id: r\nwidth: 3\nheight: 4\narea: 13\n"),
+            vec![
+                "instance `r`: slot `area` (class `Rectangle`) value `13` does not equal \
+                 `{width} * {height}` (computed `12`)"
+                    .to_string()
+            ]
+        );
+    }
+
+    /// An array slot's value is checked for its dimension count, each
+    /// axis length and rectangularity, and its elements for the range.
+    #[test]
+    fn array_slots_are_shape_checked() {
+        let schema: crate::linkml::SchemaDefinition = serde_norway::from_str(
+            "name: s\nclasses:\n  Series:\n    tree_root: true\n    attributes:\n      id:\n        identifier: true\n      samples:\n        range: float\n        array:\n          dimensions:\n            - alias: time\n              minimum_cardinality: 1\n            - alias: channel\n              exact_cardinality: 2\n",
+        )
+        .expect("parse schema");
+        let violations = |yaml: &str| -> Vec<String> {
+            let data: serde_norway::Value = serde_norway::from_str(yaml).expect("parse data");
+            let set = crate::instances::InstanceSet::from_linkml_data(&schema, &data);
+            validate_instances(&schema, &set)
+                .iter()
+                .map(|v| v.detail.clone())
+                .collect()
+        };
+
+        assert_eq!(
+            violations("id: s\nsamples: [[0.5, 1], [1.5, 2]]\n"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            violations("id: s\nsamples: [0.5, 1.5]\n"),
+            vec![
+                "array slot `samples` (class `Series`) has 1 dimension(s), but the slot \
+                 expects 2"
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            violations("id: s\nsamples: [[0.5, 1, 2]]\n"),
+            vec![
+                "array slot `samples` (class `Series`) has length 3 along axis `channel`, \
+                 which expects 2"
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            violations("id: s\nsamples: [[0.5, 1], [2]]\n"),
+            vec![
+                "array slot `samples` (class `Series`) is ragged: lists at the same depth \
+                 differ in length or nesting"
+                    .to_string()
+            ]
+        );
+        let found = violations("id: s\nsamples: [[0.5, high]]\n");
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert!(found[0].contains("value `high` is a string"), "{found:?}");
+    }
+
+    #[test]
+    fn slot_combinators_test_each_value_against_every_branch() {
+        let schema: crate::linkml::SchemaDefinition = serde_norway::from_str(
+            "name: s\ndefault_range: string\nclasses:\n  Item:\n    tree_root: true\n    attributes:\n      id:\n        identifier: true\n      codes:\n        multivalued: true\n        all_of:\n          - pattern: '^[A-Z]'\n        exactly_one_of:\n          - pattern: '^A'\n          - pattern: '1$'\n      score:\n        range: integer\n        none_of:\n          - minimum_value: 90\n            maximum_value: 100\n",
+        )
+        .expect("parse schema");
+        let violations = |yaml: &str| -> Vec<String> {
+            let data: serde_norway::Value = serde_norway::from_str(yaml).expect("parse data");
+            let set = crate::instances::InstanceSet::from_linkml_data(&schema, &data);
+            validate_instances(&schema, &set)
+                .iter()
+                .map(|v| v.to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            violations("id: x1\ncodes: [AB, B1]\nscore: 42\n"),
+            Vec::<String>::new(),
+            "each value matches exactly one branch and no excluded one"
+        );
+        let found = violations("id: x1\ncodes: [A1, b]\nscore: 95\n");
+        assert_eq!(found.len(), 4, "got: {found:?}");
+        assert!(
+            found
+                .iter()
+                .any(|v| v.contains("`A1` satisfies 2 of the `exactly_one_of`"))
+        );
+        assert!(
+            found
+                .iter()
+                .any(|v| v.contains("`b` satisfies 0 of the `exactly_one_of`"))
+        );
+        assert!(
+            found
+                .iter()
+                .any(|v| v.contains("`b` does not satisfy `all_of` branch (pattern `^[A-Z]`)"))
+        );
+        assert!(found.iter().any(|v| v.contains(
+            "`95` satisfies (minimum 90, maximum 100), which the slot rules out with `none_of`"
+        )));
+    }
+
     /// The kind-mismatch report is grammatical for every primitive: the
     /// expected range takes its own indefinite article.
     #[test]
@@ -1435,6 +1918,31 @@ wineries:
         assert!(validate_instance_data(&schema(), &d).is_empty());
     }
 
+    #[test]
+    fn missing_recommended_slot_is_a_warning_not_a_violation() {
+        // `produced_by` is recommended: a wine without it still conforms,
+        // and the gap is reported separately as a warning.
+        let mut schema = schema();
+        let wine = schema.classes.get_mut("Wine").expect("Wine");
+        wine.attributes
+            .get_mut("produced_by")
+            .expect("produced_by")
+            .recommended = true;
+        let d = data(
+            "wines:\n  - id: w1\n    name: A\n  - id: w2\n    name: B\n    produced_by: e\nwineries:\n  - id: e\n    name: E\n",
+        );
+        assert!(validate_instance_data(&schema, &d).is_empty());
+        let set = instance_set_for(&schema, &d).expect("set");
+        let warnings = missing_recommended(&schema, &set);
+        assert_eq!(warnings.len(), 1, "only w1 omits it; got: {warnings:?}");
+        assert_eq!(warnings[0].record, "w1");
+        assert!(
+            warnings[0]
+                .detail
+                .contains("recommended slot `produced_by`")
+        );
+    }
+
     #[test]
     fn missing_required_slot_is_a_violation_naming_record_and_slot() {
         // The wine omits its required `name`.
@@ -2563,4 +3071,102 @@ classes:
             "the precondition does not match, so the rule does not apply; got: {v:?}"
         );
     }
+
+    /// Class expressions and class-level set axioms: a `Pet` is identified
+    /// by exactly one of a chip or a tattoo and is never a stray; a
+    /// `Robodog` is a `Dog` mixing in `Machine`, which `Dog` declares
+    /// disjoint; and an `Animal` is the union of `Cat` and `Dog`.
+    const EXPRESSION_SCHEMA: &str = "
+id: https://example.org/expressions
+name: expressions
+default_range: string
+classes:
+  Registry:
+    tree_root: true
+    attributes:
+      pets: {range: Pet, multivalued: true}
+      robodogs: {range: Robodog, multivalued: true}
+      animals: {range: Animal, multivalued: true}
+  Pet:
+    attributes:
+      id: {identifier: true}
+      chip: {range: string}
+      tattoo: {range: string}
+      status: {range: string}
+    exactly_one_of:
+      - slot_conditions: {chip: {required: true}}
+      - slot_conditions: {tattoo: {required: true}}
+    none_of:
+      - slot_conditions: {status: {equals_string: stray}}
+  Animal:
+    union_of: [Cat, Dog]
+    attributes:
+      id: {identifier: true}
+  Cat: {}
+  Machine:
+    mixin: true
+  Dog:
+    disjoint_with: [Machine]
+  Robodog:
+    is_a: Dog
+    mixins: [Machine]
+    attributes:
+      id: {identifier: true}
+";
+
+    fn expression_violations(yaml: &str) -> Vec<Violation> {
+        let schema: SchemaDefinition =
+            serde_norway::from_str(EXPRESSION_SCHEMA).expect("parse expression schema");
+        validate_instance_data(&schema, &data(yaml))
+    }
+
+    #[test]
+    fn exactly_one_of_counts_the_alternatives_a_record_satisfies() {
+        assert!(expression_violations("pets:\n  - id: p1\n    chip: c1\n").is_empty());
+        let both = expression_violations("pets:\n  - id: p1\n    chip: c1\n    tattoo: t1\n");
+        assert_eq!(both.len(), 1, "got: {both:?}");
+        assert!(
+            both[0]
+                .detail
+                .contains("satisfies 2 of the `exactly_one_of` alternatives of class `Pet`"),
+            "got: {}",
+            both[0].detail
+        );
+        let neither = expression_violations("pets:\n  - id: p1\n");
+        assert!(
+            neither[0].detail.contains("satisfies 0 of"),
+            "got: {neither:?}"
+        );
+    }
+
+    #[test]
+    fn a_record_satisfying_a_none_of_member_is_a_violation() {
+        let v = expression_violations("pets:\n  - id: p1\n    chip: c1\n    status: stray\n");
+        assert_eq!(v.len(), 1, "got: {v:?}");
+        assert_eq!(
+            v[0].detail,
+            "the record satisfies (`status` has value `stray`), which class `Pet` rules out \
+             with `none_of`"
+        );
+    }
+
+    #[test]
+    fn disjointness_and_unions_are_checked_across_the_records_ancestry() {
+        let v = expression_violations("robodogs:\n  - id: r1\n");
+        assert_eq!(v.len(), 1, "got: {v:?}");
+        assert!(
+            v[0].detail
+                .contains("is both a `Dog` and a `Machine`, which `Dog` declares disjoint"),
+            "got: {}",
+            v[0].detail
+        );
+
+        let v = expression_violations("animals:\n  - id: a1\n");
+        assert_eq!(v.len(), 1, "got: {v:?}");
+        assert!(
+            v[0].detail.contains("is the union of `Cat`, `Dog`"),
+            "got: {}",
+            v[0].detail
+        );
+    }
 }
@@ -7,7 +7,8 @@ use std::fs;
 use std::path::Path;
 
 use crate::io::{IoError, IoResult, Reader};
-use crate::linkml::{SchemaDefinition, SlotDefinition};
+use crate::linkml::{EnumExpression, SchemaDefinition, SlotDefinition};
+use crate::source_map::SourceMap;
 
 /// Reader for native LinkML YAML schemas
 pub struct YamlReader;
@@ -31,6 +32,7 @@ impl Reader for YamlReader {
         let mut schema: SchemaDefinition =
             serde_norway::from_str(&content).map_err(|e| IoError::Parse(e.to_string()))?;
         backfill_names(&mut schema)?;
+        schema.source_map = SourceMap::from_yaml(input, &content);
         // LinkML's derivation rules give a schema that omits `default_range`
         // the default `string`, so this document means the same thing here
         // as through linkml-runtime. Set at read time — per file, before
@@ -50,7 +52,7 @@ impl Reader for YamlReader {
 
 /// Apply LinkML's "dict key is the canonical name" rule to all metaobjects.
 ///
-/// For each `name` field in classes, slots, enums, types, and inline
+/// For each `name` field in classes, slots, enums, types, subsets, and inline
 /// attributes/slot_usage inside classes:
 ///
 /// - If `name` is empty, fill it from the dict key.
@@ -68,10 +70,28 @@ fn backfill_names(schema: &mut SchemaDefinition) -> IoResult<()> {
         for (pv_key, pv) in enum_def.permissible_values.iter_mut() {
             backfill_one(&format!("enum '{key}' value"), pv_key, &mut pv.text)?;
         }
+        for expr in enum_def.include.iter_mut().chain(enum_def.minus.iter_mut()) {
+            backfill_expression_values(key, expr)?;
+        }
     }
     for (key, type_def) in schema.types.iter_mut() {
         backfill_one("type", key, &mut type_def.name)?;
     }
+    for (key, subset) in schema.subsets.iter_mut() {
+        backfill_one("subset", key, &mut subset.name)?;
+    }
+    Ok(())
+}
+
+/// Permissible values nested in an enum's `include` / `minus` expressions
+/// are keyed by text just like the enum's own.
+fn backfill_expression_values(enum_key: &str, expr: &mut EnumExpression) -> IoResult<()> {
+    for (pv_key, pv) in expr.permissible_values.iter_mut() {
+        backfill_one(&format!("enum '{enum_key}' value"), pv_key, &mut pv.text)?;
+    }
+    for nested in expr.include.iter_mut().chain(expr.minus.iter_mut()) {
+        backfill_expression_values(enum_key, nested)?;
+    }
     Ok(())
 }
 
@@ -155,6 +175,25 @@ mod tests {
         assert_eq!(schema.default_range.as_deref(), Some("integer"));
     }
 
+    #[test]
+    fn subsets_read_with_names_and_element_membership() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("schema.yaml");
+        fs::write(
+            &path,
+            "name: s\nsubsets:\n  public:\n    description: Partner-facing.\n\
+             classes:\n  Order:\n    in_subset: [public]\n",
+        )
+        .expect("write");
+        let schema = YamlReader::new().read(&path).expect("read");
+        assert_eq!(schema.subsets["public"].name, "public");
+        assert_eq!(schema.classes["Order"].in_subset, vec!["public"]);
+        assert!(
+            schema.classes["Order"].unmodeled.is_empty(),
+            "in_subset is modeled, not captured as an unmodeled key"
+        );
+    }
+
     #[test]
     fn yaml_reader_parses_sample_schema() {
         let reader = YamlReader::new();
@@ -491,6 +530,34 @@ enums:
         assert_eq!(color.permissible_values.get("blue").unwrap().text, "blue");
     }
 
+    #[test]
+    fn dynamic_enum_expressions_read_with_nested_value_texts() {
+        let schema = parse_yaml(
+            r#"
+id: https://example.org/x
+name: x
+enums:
+  Pet:
+    reachable_from:
+      source_ontology: obo:ncbitaxon.owl
+      source_nodes: [NCBITaxon:40674]
+      is_direct: true
+    concepts: [NCBITaxon:9615]
+    minus:
+      - permissible_values:
+          unknown: {}
+"#,
+        )
+        .expect("dynamic enum metaslots parse");
+        let pet = &schema.enums["Pet"];
+        assert!(pet.is_dynamic());
+        let query = pet.reachable_from.as_ref().unwrap();
+        assert_eq!(query.source_nodes, vec!["NCBITaxon:40674"]);
+        assert!(query.is_direct && !query.include_self);
+        assert_eq!(pet.concepts, vec!["NCBITaxon:9615"]);
+        assert_eq!(pet.minus[0].permissible_values["unknown"].text, "unknown");
+    }
+
     // ---- Types ----
 
     #[test]
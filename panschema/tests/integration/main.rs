@@ -920,6 +920,94 @@ fn instances_flag_warns_only_for_formats_that_ignore_it() {
     let _ = fs::remove_dir_all(dir);
 }
 
+#[test]
+fn generate_subset_prunes_the_schema_before_the_writer_runs() {
+    let dir = std::env::temp_dir().join("panschema_subset_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("mkdir");
+    let schema = dir.join("shop.yaml");
+    fs::write(
+        &schema,
+        r#"id: https://example.org/shop
+name: shop
+default_range: string
+subsets:
+  public:
+    description: Partner-facing elements.
+classes:
+  Order:
+    in_subset: [public]
+    attributes:
+      id:
+        identifier: true
+      customer:
+        range: Customer
+  Customer:
+    attributes:
+      name: {}
+  AuditLog:
+    attributes:
+      note: {}
+"#,
+    )
+    .expect("write schema");
+
+    // Only the member and what it references reach the writer.
+    let out_path = dir.join("public.schema.json");
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args([
+            "generate",
+            "--schema",
+            schema.to_str().unwrap(),
+            "--format",
+            "json-schema",
+            "--subset",
+            "public",
+            "--output",
+            out_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("run panschema");
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let doc: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+    let defs = doc["$defs"].as_object().expect("$defs");
+    assert!(defs.contains_key("Order") && defs.contains_key("Customer"));
+    assert!(
+        !defs.contains_key("AuditLog"),
+        "an element outside the subset's closure must not be emitted: {doc}"
+    );
+
+    // A subset the schema does not declare fails rather than emitting the
+    // whole schema.
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args([
+            "generate",
+            "--schema",
+            schema.to_str().unwrap(),
+            "--format",
+            "json-schema",
+            "--subset",
+            "partner",
+            "--output",
+            dir.join("partner.schema.json").to_str().unwrap(),
+        ])
+        .output()
+        .expect("run panschema");
+    assert!(!out.status.success(), "an unknown subset must fail");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("unknown subset `partner`") && stderr.contains("public"),
+        "the error should name the declared subsets; got: {stderr}"
+    );
+
+    let _ = fs::remove_dir_all(dir);
+}
+
 #[test]
 fn validate_command_exit_code_reflects_conformance() {
     // Conforming data validates clean and exits zero.
@@ -964,6 +1052,380 @@ fn validate_command_exit_code_reflects_conformance() {
     );
 }
 
+#[test]
+fn validate_with_only_a_schema_checks_it_against_the_metamodel() {
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema", "tests/fixtures/wine_catalog.yaml"])
+        .output()
+        .expect("run panschema");
+    assert!(
+        out.status.success(),
+        "a well-formed schema should exit zero; stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(String::from_utf8_lossy(&out.stdout).contains("conforms to the LinkML metamodel"));
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let schema = dir.path().join("typo.yaml");
+    fs::write(
+        &schema,
+        "id: https://example.org/typo\nname: typo\nslots:\n  tags:\n    mulitvalued: true\n",
+    )
+    .expect("write schema");
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema"])
+        .arg(&schema)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(!out.status.success(), "a misspelled metaslot must fail");
+    assert!(
+        stderr.contains("did you mean `multivalued`?") && stderr.contains("typo.yaml:5:5"),
+        "the issue should suggest the fix and point at the key; got: {stderr}"
+    );
+}
+
+#[test]
+fn structured_pattern_interpolates_imported_settings_and_is_enforced() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let dir = tmp.path();
+    fs::write(
+        dir.join("fragments.yaml"),
+        "name: fragments\nsettings:\n  prefix: ORD\n  digits: '[0-9]{6}'\n",
+    )
+    .expect("write fragments");
+    let schema = dir.join("orders.yaml");
+    fs::write(
+        &schema,
+        r#"name: orders
+imports: [fragments]
+default_range: string
+classes:
+  Orders:
+    tree_root: true
+    attributes:
+      orders:
+        range: Order
+        multivalued: true
+        inlined_as_list: true
+  Order:
+    attributes:
+      id:
+        identifier: true
+        structured_pattern:
+          syntax: "{prefix}-{digits}"
+          interpolated: true
+"#,
+    )
+    .expect("write schema");
+    let data = dir.join("orders-data.yaml");
+    fs::write(&data, "orders:\n  - id: ORD-000042\n  - id: ORD-42\n").expect("write data");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema"])
+        .arg(&schema)
+        .arg("--data")
+        .arg(&data)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        !out.status.success(),
+        "a short id must fail; stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("`ORD-42` does not match pattern `^(?:ORD-[0-9]{6})$`")
+            && !stderr.contains("ORD-000042"),
+        "only the short id violates the interpolated pattern; got: {stderr}"
+    );
+}
+
+#[test]
+fn generate_lang_renders_the_page_in_a_translated_language() {
+    let dir = std::env::temp_dir().join("panschema_lang_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("mkdir");
+    let schema = dir.join("people.yaml");
+    fs::write(
+        &schema,
+        r#"id: https://example.org/people
+name: people
+in_language: en
+default_range: string
+classes:
+  Person:
+    description: A human being.
+    local_names:
+      fr: Personne
+    alt_descriptions:
+      fr: Un être humain.
+"#,
+    )
+    .expect("write schema");
+
+    let run = |lang: &str, out: &std::path::Path| {
+        Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args([
+                "generate",
+                "--schema",
+                schema.to_str().unwrap(),
+                "--no-graph",
+                "--offline",
+                "--lang",
+                lang,
+                "--output",
+                out.to_str().unwrap(),
+            ])
+            .output()
+            .expect("run panschema")
+    };
+
+    let out_dir = dir.join("fr");
+    let out = run("fr", &out_dir);
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let html = fs::read_to_string(out_dir.join("index.html")).unwrap();
+    assert!(html.contains("<html lang=\"fr\">"), "got: {html}");
+    assert!(html.contains("Personne"), "got: {html}");
+    assert!(
+        html.contains("<option value=\"en\">en</option>"),
+        "the source language stays switchable: {html}"
+    );
+
+    // A language the schema never uses renders the source text, loudly.
+    let out = run("ja", &dir.join("ja"));
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("translates nothing into `ja`") && stderr.contains("fr"),
+        "the warning should name the languages on offer; got: {stderr}"
+    );
+
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn equals_expression_is_checked_by_validate_and_filled_by_generate_infer() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let dir = tmp.path();
+    let schema = dir.join("plots.yaml");
+    fs::write(
+        &schema,
+        r#"name: plots
+id: https://example.org/plots
+prefixes:
+  plots: https://example.org/plots/
+default_prefix: plots
+default_range: string
+classes:
+  Plots:
+    tree_root: true
+    attributes:
+      plots:
+        range: Plot
+        multivalued: true
+        inlined_as_list: true
+  Plot:
+    attributes:
+      id:
+        identifier: true
+      width:
+        range: integer
+      depth:
+        range: integer
+      area:
+        range: integer
+        equals_expression: "{width} * {depth}"
+"#,
+    )
+    .expect("write schema");
+    let data = dir.join("plots-data.yaml");
+    fs::write(
+        &data,
+        "plots:\n  - {id: north, width: 3, depth: 4, area: 13}\n  - {id: south, width: 5, depth: 7}\n",
+    )
+    .expect("write data");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema"])
+        .arg(&schema)
+        .arg("--data")
+        .arg(&data)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        !out.status.success(),
+        "a wrong area must fail; stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("value `13` does not equal `{width} * {depth}` (computed `12`)")
+            && !stderr.contains("south"),
+        "only the stored mismatch is reported, not the missing value; got: {stderr}"
+    );
+
+    let graph = dir.join("plots.json");
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args([
+            "generate",
+            "--infer",
+            "--format",
+            "instance-graph-json",
+            "--schema",
+        ])
+        .arg(&schema)
+        .arg("--instances")
+        .arg(&data)
+        .arg("--output")
+        .arg(&graph)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(out.status.success(), "generate succeeds; stderr: {stderr}");
+    assert!(
+        stderr.contains("filled 1 computed slot value(s)"),
+        "the fill is reported; got: {stderr}"
+    );
+    let doc = fs::read_to_string(&graph).expect("read graph");
+    assert!(
+        doc.contains("\"35\""),
+        "south's area is filled from width * depth; got: {doc}"
+    );
+}
+
+#[test]
+fn array_slots_are_shape_checked_and_projected_to_nested_json_schema_arrays() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let dir = tmp.path();
+    let schema = dir.join("spectra.yaml");
+    fs::write(
+        &schema,
+        r#"name: spectra
+default_range: string
+classes:
+  Spectra:
+    tree_root: true
+    attributes:
+      readings:
+        range: Reading
+        multivalued: true
+        inlined_as_list: true
+  Reading:
+    attributes:
+      id:
+        identifier: true
+      intensity:
+        range: float
+        array:
+          dimensions:
+            - alias: sample
+            - alias: band
+              exact_cardinality: 3
+"#,
+    )
+    .expect("write schema");
+    let data = dir.join("spectra-data.yaml");
+    fs::write(
+        &data,
+        "readings:\n  - id: good\n    intensity: [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]]\n  - id: short\n    intensity: [[0.1, 0.2]]\n",
+    )
+    .expect("write data");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema"])
+        .arg(&schema)
+        .arg("--data")
+        .arg(&data)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        !out.status.success(),
+        "a short band axis must fail; stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("instance `short`: array slot `intensity` (class `Reading`) has length 2 along axis `band`, which expects 3")
+            && !stderr.contains("instance `good`"),
+        "only the short reading is reported; got: {stderr}"
+    );
+
+    let json = dir.join("spectra.schema.json");
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["generate", "--format", "json-schema", "--schema"])
+        .arg(&schema)
+        .arg("--output")
+        .arg(&json)
+        .output()
+        .expect("run panschema");
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let doc: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&json).expect("read schema")).expect("json");
+    let intensity = &doc["$defs"]["Reading"]["properties"]["intensity"];
+    assert_eq!(intensity["type"], "array");
+    assert_eq!(intensity["items"]["maxItems"], 3);
+    assert_eq!(intensity["items"]["items"]["type"], "number");
+}
+
+#[test]
+fn validate_warns_on_a_missing_recommended_slot_without_failing() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let dir = tmp.path();
+    let schema = dir.join("people.yaml");
+    fs::write(
+        &schema,
+        r#"name: people
+default_range: string
+classes:
+  Registry:
+    tree_root: true
+    attributes:
+      people:
+        range: Person
+        multivalued: true
+        inlined_as_list: true
+  Person:
+    attributes:
+      id:
+        identifier: true
+      email:
+        recommended: true
+"#,
+    )
+    .expect("write schema");
+    let data = dir.join("people-data.yaml");
+    fs::write(
+        &data,
+        "people:\n  - id: ada\n    email: ada@example.org\n  - id: alan\n",
+    )
+    .expect("write data");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args(["validate", "--schema"])
+        .arg(&schema)
+        .arg("--data")
+        .arg(&data)
+        .output()
+        .expect("run panschema");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        out.status.success(),
+        "a recommended gap is not an error; stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains(
+            "warning: instance `alan`: recommended slot `email` (class `Person`) is absent"
+        ) && !stderr.contains("`ada`"),
+        "only alan is warned about; got: {stderr}"
+    );
+}
+
 #[test]
 fn validate_reports_ids_that_mint_one_iri_across_two_data_files() {
     let out = Command::new(env!("CARGO_BIN_EXE_panschema"))
@@ -1696,6 +2158,34 @@ html_schema_sections = false
 [schemas]
 sample_schema = { path = "./sample-pkg" }
 
+[generate.sample_schema]
+html = "docs/"
+html_site_mode = "multi-page"
+"#,
+    )
+    .expect("write manifest");
+    let status = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .arg("generate")
+        .current_dir(consumer)
+        .status()
+        .expect("Failed to execute panschema");
+    assert!(status.success(), "multi-page generate exited with error");
+    let docs = consumer.join("docs");
+    assert!(
+        docs.join("sitemap.html").is_file(),
+        "multi-page writes a sitemap"
+    );
+    let class_pages = fs::read_dir(docs.join("classes"))
+        .expect("multi-page writes a classes/ directory")
+        .count();
+    assert!(class_pages > 0, "every class gets a page");
+
+    fs::write(
+        consumer.join("panschema.toml"),
+        r#"
+[schemas]
+sample_schema = { path = "./sample-pkg" }
+
 [generate.sample_schema]
 html = "docs/"
 html_page_layout = "sideways"
@@ -3980,6 +4470,367 @@ rust = "sample.rs"
     );
 }
 
+/// The manifest's `markdown` key writes a directory of pages, and
+/// `--check` compares it page by page: a page an earlier run wrote for an
+/// element the schema no longer has is drift, like an edited one, and a
+/// hand-written page beside them is not.
+#[test]
+fn markdown_output_is_a_page_directory_checked_page_by_page() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let consumer = tmp.path();
+    write_sample_pkg(consumer, "sample-pkg");
+    fs::write(
+        consumer.join("panschema.toml"),
+        r#"
+[schemas]
+sample_schema = { path = "./sample-pkg" }
+
+[generate.sample_schema]
+markdown = "docs"
+"#,
+    )
+    .expect("write manifest");
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args(args)
+            .current_dir(consumer)
+            .output()
+            .expect("run panschema")
+    };
+
+    assert!(run(&["generate"]).status.success());
+    let docs = consumer.join("docs");
+    let index = fs::read_to_string(docs.join("index.md")).expect("read index.md");
+    assert!(
+        index.contains("[Person](classes/Person.md)"),
+        "the index links each class page; got:\n{index}"
+    );
+    let person = fs::read_to_string(docs.join("classes/Person.md")).expect("read Person.md");
+    assert!(
+        person.contains("| `name` | `string` | 1 |"),
+        "the class page tables its resolved slots; got:\n{person}"
+    );
+    let out = run(&["generate", "--check"]);
+    assert!(
+        out.status.success(),
+        "fresh pages pass --check; stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    // A page an earlier run wrote, for an element since removed.
+    let list = docs.join(".panschema-pages");
+    let written = fs::read_to_string(&list).expect("read the page list");
+    fs::write(&list, format!("classes/Retired.md\n{written}")).expect("write page list");
+    fs::write(docs.join("classes/Retired.md"), "# Retired\n").expect("write stale page");
+    assert!(
+        !run(&["generate", "--check"]).status.success(),
+        "a page the writer would prune is drift"
+    );
+    assert!(run(&["generate"]).status.success());
+    assert!(!docs.join("classes/Retired.md").exists());
+
+    fs::write(docs.join("classes/Guide.md"), "# Guide\n").expect("write own page");
+    assert!(
+        run(&["generate", "--check"]).status.success(),
+        "a hand-written page the writer never listed is not drift"
+    );
+    assert!(run(&["generate"]).status.success());
+    assert!(docs.join("classes/Guide.md").is_file());
+
+    fs::write(docs.join("SUMMARY.md"), "# Summary\n").expect("write book summary");
+    assert!(
+        run(&["generate", "--check"]).status.success(),
+        "a file beside index.md that the writer never owned is not drift"
+    );
+}
+
+/// The diagram formats draw the class diagram from the schema graph, and
+/// `--root` / `--depth` cut it down to one class's neighborhood.
+#[test]
+fn mermaid_class_diagram_narrows_to_a_root_and_depth() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let render = |extra: &[&str]| {
+        let out = tmp.path().join("diagram.mmd");
+        let run = Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args([
+                "generate",
+                "--schema",
+                "tests/fixtures/class_card_dogfood.yaml",
+                "--format",
+                "mermaid",
+                "--output",
+                out.to_str().unwrap(),
+            ])
+            .args(extra)
+            .output()
+            .expect("run panschema");
+        (run, fs::read_to_string(&out).unwrap_or_default())
+    };
+
+    let (run, whole) = render(&[]);
+    assert!(
+        run.status.success(),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    assert!(whole.starts_with("classDiagram\n"), "{whole}");
+    assert!(whole.contains("    Document <|-- Report\n"), "{whole}");
+    assert!(whole.contains("    Auditable <|.. Document\n"), "{whole}");
+    assert!(whole.contains("        title : string [1]\n"), "{whole}");
+
+    let (run, near) = render(&["--root", "Report", "--depth", "1"]);
+    assert!(
+        run.status.success(),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    assert!(near.contains("class Document"), "{near}");
+    assert!(
+        !near.contains("class Auditable"),
+        "a mixin of the parent is two hops out; got:\n{near}"
+    );
+
+    let (run, _) = render(&["--root", "Nobody"]);
+    assert!(!run.status.success());
+    assert!(String::from_utf8_lossy(&run.stderr).contains("`Nobody`"));
+}
+
+/// `--theme` brands the page from a directory of overrides; a
+/// placeholder outside a component's contract fails before writing.
+#[test]
+fn html_theme_overrides_the_header_and_ships_its_stylesheet() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let theme = tmp.path().join("theme");
+    fs::create_dir_all(&theme).unwrap();
+    fs::write(
+        theme.join("header.html"),
+        r#"<div class="acme-bar">Acme · {{ title }}</div>{{ default }}"#,
+    )
+    .unwrap();
+    fs::write(theme.join("theme.css"), ":root { --color-primary: #c00; }").unwrap();
+    let out = tmp.path().join("site");
+    let run = |out: &Path| {
+        Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args([
+                "generate",
+                "--schema",
+                "tests/fixtures/class_card_dogfood.yaml",
+                "--no-graph",
+                "--theme",
+                theme.to_str().unwrap(),
+                "--output",
+                out.to_str().unwrap(),
+            ])
+            .output()
+            .expect("run panschema")
+    };
+
+    let ran = run(&out);
+    assert!(
+        ran.status.success(),
+        "{}",
+        String::from_utf8_lossy(&ran.stderr)
+    );
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    assert!(
+        index.contains(r#"<div class="acme-bar">Acme · "#),
+        "{index}"
+    );
+    assert!(
+        index.contains("site-header"),
+        "`default` keeps the stock header"
+    );
+    assert!(index.contains(r#"href="theme/theme.css""#));
+    assert!(out.join("theme/theme.css").is_file());
+
+    fs::write(theme.join("footer.html"), "{{ copyright }}").unwrap();
+    let rejected = tmp.path().join("rejected");
+    let ran = run(&rejected);
+    assert!(!ran.status.success());
+    assert!(String::from_utf8_lossy(&ran.stderr).contains("`copyright`"));
+    assert!(!rejected.join("index.html").exists());
+}
+
+/// `--self-contained` writes one `index.html` that carries the viz bundle
+/// and the theme's stylesheet and assets inline, with nothing beside it.
+#[test]
+fn self_contained_html_is_a_single_file() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let theme = tmp.path().join("theme");
+    fs::create_dir_all(theme.join("assets")).unwrap();
+    fs::write(
+        theme.join("header.html"),
+        r#"<img class="acme-logo" src="{{ assets }}logo.svg">{{ default }}"#,
+    )
+    .unwrap();
+    fs::write(
+        theme.join("theme.css"),
+        "@font-face { font-family: Acme; src: url(assets/acme.woff2); }",
+    )
+    .unwrap();
+    fs::write(theme.join("assets/logo.svg"), "<svg/>").unwrap();
+    fs::write(theme.join("assets/acme.woff2"), [0u8, 1, 2]).unwrap();
+    let out = tmp.path().join("site");
+    let ran = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .args([
+            "generate",
+            "--schema",
+            "tests/fixtures/class_card_dogfood.yaml",
+            "--self-contained",
+            "--theme",
+            theme.to_str().unwrap(),
+            "--output",
+            out.to_str().unwrap(),
+        ])
+        .output()
+        .expect("run panschema");
+    assert!(
+        ran.status.success(),
+        "{}",
+        String::from_utf8_lossy(&ran.stderr)
+    );
+    let written: Vec<_> = fs::read_dir(&out)
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    assert_eq!(written, ["index.html"], "nothing beside the page");
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    assert!(index.contains("window.__PANSCHEMA_VIZ_BUNDLE__ ="));
+    assert!(index.contains("window.__PANSCHEMA_GRAPH_DATA__"));
+    assert!(
+        index.contains(r#"<img class="acme-logo" src="data:image/svg+xml;base64,PHN2Zy8+">"#),
+        "the header's asset is inlined"
+    );
+    assert!(
+        index.contains("src: url(data:font/woff2;base64,AAEC);"),
+        "the stylesheet is inlined with its font"
+    );
+    assert!(!index.contains("theme/theme.css") && !index.contains("theme/assets/"));
+}
+
+/// `--format svg` draws the schema graph with a chosen static layout,
+/// byte-identically across runs; an unknown layout fails before writing.
+#[cfg(feature = "svg")]
+#[test]
+fn svg_graph_renders_deterministically_with_a_chosen_layout() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let render = |name: &str, layout: &str| {
+        let out = tmp.path().join(name);
+        let run = Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args([
+                "generate",
+                "--schema",
+                "tests/fixtures/class_card_dogfood.yaml",
+                "--format",
+                "svg",
+                "--layout",
+                layout,
+                "--output",
+                out.to_str().unwrap(),
+            ])
+            .output()
+            .expect("run panschema");
+        (run, fs::read_to_string(&out).unwrap_or_default())
+    };
+
+    let (run, first) = render("a.svg", "kamada-kawai");
+    assert!(
+        run.status.success(),
+        "{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    assert!(first.starts_with("<svg"), "{first}");
+    assert!(first.contains(">Report<"), "{first}");
+    let (_, second) = render("b.svg", "kamada-kawai");
+    assert_eq!(first, second, "a static layout renders the same bytes");
+
+    let (run, _) = render("c.svg", "spiral");
+    assert!(!run.status.success());
+    assert!(String::from_utf8_lossy(&run.stderr).contains("spiral"));
+}
+
+/// `mdbook-panschema` speaks mdbook's preprocessor protocol: it claims
+/// the HTML renderer, rewrites a chapter's directives from the book JSON
+/// on stdin, and fails the build on a directive naming a missing element.
+#[test]
+fn mdbook_preprocessor_embeds_elements_and_rejects_unknown_ones() {
+    use std::io::Write;
+    let book_dir = tempfile::tempdir().expect("tempdir");
+    let supports = |renderer: &str| {
+        Command::new(env!("CARGO_BIN_EXE_mdbook-panschema"))
+            .args(["supports", renderer])
+            .status()
+            .expect("run mdbook-panschema supports")
+            .success()
+    };
+    assert!(supports("html"));
+    assert!(!supports("epub"));
+
+    let schema = std::path::absolute("tests/fixtures/sample_schema.yaml").expect("schema path");
+    let run = |content: &str| {
+        let input = serde_json::json!([
+            {
+                "root": book_dir.path(),
+                "config": {"preprocessor": {"panschema": {"schema": schema}}},
+                "renderer": "html",
+                "mdbook_version": "0.4.40"
+            },
+            {"sections": [{"Chapter": {"name": "Model", "content": content, "sub_items": []}}]}
+        ]);
+        let mut child = Command::new(env!("CARGO_BIN_EXE_mdbook-panschema"))
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn mdbook-panschema");
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.to_string().as_bytes())
+            .expect("write book");
+        child.wait_with_output().expect("wait for mdbook-panschema")
+    };
+
+    let content = |out: &std::process::Output| {
+        assert!(
+            out.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let book: serde_json::Value = serde_json::from_slice(&out.stdout).expect("book JSON");
+        book["sections"][0]["Chapter"]["content"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    };
+    let card = content(&run("# Model\n\n{{#panschema class Person}}\n"));
+    assert!(card.contains("## Person"), "{card}");
+    assert!(!card.contains("{{#panschema"), "{card}");
+
+    // `graph` is drawn by the `svg` feature's renderer.
+    let out = run("{{#panschema graph Person}}\n");
+    if cfg!(feature = "svg") {
+        let graph = content(&out);
+        assert!(
+            graph.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""),
+            "{graph}"
+        );
+    } else {
+        assert!(!out.status.success(), "no renderer, no graph");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("`svg` feature"), "{stderr}");
+    }
+
+    let out = run("{{#panschema class Persn}}");
+    assert!(!out.status.success(), "an unknown element fails the build");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("chapter `Model`") && stderr.contains("Persn"),
+        "{stderr}"
+    );
+}
+
 /// `panschema fetch` writes a lockfile with one entry per manifested schema;
 /// `panschema verify` then succeeds against the unchanged on-disk content.
 #[test]
@@ -4333,6 +5184,113 @@ a = { path = "./a-pkg" }
     );
 }
 
+/// A dynamic enum expands at load against its ontology, `fetch` pins the
+/// expansion in the lockfile, and `verify` and `generate` catch the value
+/// set drifting when the ontology changes underneath it.
+#[test]
+fn dynamic_enum_expands_and_its_value_set_is_pinned_by_the_lockfile() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let consumer = tmp.path();
+    let pkg = consumer.join("zoo-pkg");
+    write_pkg(
+        &pkg,
+        "zoo",
+        "0.1.0",
+        "zoo.yaml",
+        r#"id: https://example.org/zoo
+name: zoo
+prefixes:
+  ex: http://example.org/onto/
+enums:
+  AnimalKind:
+    reachable_from:
+      source_ontology: animals.ttl
+      source_nodes: [ex:Animal]
+"#,
+    );
+    let ontology = pkg.join("animals.ttl");
+    let animals = "@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n\
+                   @prefix ex: <http://example.org/onto/> .\n\
+                   ex:Dog rdfs:subClassOf ex:Animal ; rdfs:label \"dog\" .\n\
+                   ex:Cat rdfs:subClassOf ex:Animal .\n";
+    fs::write(&ontology, animals).expect("write ontology");
+    fs::write(
+        consumer.join("panschema.toml"),
+        "[schemas]\nzoo = { path = \"./zoo-pkg\" }\n\n\
+         [generate.zoo]\njson_schema = \"zoo.manifest.schema.json\"\n",
+    )
+    .expect("write manifest");
+    let cache = consumer.join("cache");
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_panschema"))
+            .args(args)
+            .current_dir(consumer)
+            .env("PANSCHEMA_CACHE_ROOT", &cache)
+            .output()
+            .expect("run panschema")
+    };
+
+    let fetch = run(&["fetch"]);
+    assert!(
+        fetch.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&fetch.stderr)
+    );
+    let lock = fs::read_to_string(consumer.join("panschema.lock")).expect("lockfile");
+    assert!(
+        lock.contains("[[enum]]") && lock.contains("name = \"AnimalKind\""),
+        "the expansion is pinned: {lock}"
+    );
+
+    let out_path = consumer.join("zoo.schema.json");
+    let generate = run(&[
+        "generate",
+        "--schema",
+        pkg.join("zoo.yaml").to_str().unwrap(),
+        "--format",
+        "json-schema",
+        "--output",
+        out_path.to_str().unwrap(),
+    ]);
+    assert!(
+        generate.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&generate.stderr)
+    );
+    let doc = fs::read_to_string(&out_path).expect("json schema");
+    assert!(
+        doc.contains("ex:Dog") && doc.contains("ex:Cat"),
+        "the writer sees the materialized values: {doc}"
+    );
+
+    assert!(run(&["verify"]).status.success());
+    fs::write(
+        &ontology,
+        format!("{animals}ex:Fish rdfs:subClassOf ex:Animal .\n"),
+    )
+    .expect("grow ontology");
+    let verify = run(&["verify"]);
+    assert!(!verify.status.success(), "a grown value set is drift");
+    let stderr = String::from_utf8_lossy(&verify.stderr);
+    assert!(
+        stderr.contains("enum `AnimalKind` of `zoo`"),
+        "stderr should name the drifted enum; got: {stderr}"
+    );
+
+    let generate = run(&["generate"]);
+    assert!(
+        generate.status.success(),
+        "drift warns without --strict: {}",
+        String::from_utf8_lossy(&generate.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&generate.stderr);
+    assert!(
+        stderr.contains("warning: enum `AnimalKind` of `zoo`"),
+        "generate should warn about the drifted enum; got: {stderr}"
+    );
+    assert!(!run(&["generate", "--strict"]).status.success());
+}
+
 /// `panschema verify` errors when no lockfile exists.
 #[test]
 fn verify_errors_when_no_lockfile() {
@@ -5804,6 +6762,103 @@ output_dir = "site"
     );
 }
 
+#[test]
+fn cli_publish_renders_a_changes_page_between_releases() {
+    fn git(cwd: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(cwd)
+            .args(args)
+            .status()
+            .expect("git on PATH");
+        assert!(status.success(), "git {args:?} failed");
+    }
+    fn release(repo: &Path, version: &str, classes: &str) {
+        fs::write(
+            repo.join("schema.yaml"),
+            format!(
+                "id: https://example.org/changes\n\
+                 name: changes_fixture\n\
+                 version: {version}\n\
+                 prefixes:\n  schema: https://example.org/\n\
+                 default_prefix: schema\n\
+                 classes:\n{classes}"
+            ),
+        )
+        .unwrap();
+        git(repo, &["add", "schema.yaml"]);
+        git(repo, &["commit", "-m", "release", "--quiet"]);
+        git(repo, &["tag", &format!("v{version}")]);
+    }
+
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let repo = tmp.path();
+    git(repo, &["init", "--initial-branch=main", "--quiet"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test"]);
+    git(repo, &["config", "commit.gpgsign", "false"]);
+    release(
+        repo,
+        "0.1.0",
+        "  Thing:\n    description: a thing\n  Gone:\n    description: dropped later\n",
+    );
+    release(
+        repo,
+        "0.2.0",
+        "  Thing:\n    description: a thing\n    abstract: true\n  Fresh:\n    description: new\n",
+    );
+    fs::write(
+        repo.join("panschema-publish.toml"),
+        r#"[schema]
+name = "changes_fixture"
+version = "0.2.0"
+linkml = "1.7.0"
+
+[files]
+main = "schema.yaml"
+
+[publishing]
+versions = ["v0.1.0", "v0.2.0"]
+current = "v0.2.0"
+output_dir = "site"
+"#,
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_panschema"))
+        .arg("publish")
+        .current_dir(repo)
+        .status()
+        .expect("panschema");
+    assert!(status.success(), "panschema publish exited with error");
+
+    assert!(
+        !repo.join("site/v0.1.0/changes.html").exists(),
+        "the first release has nothing to compare against"
+    );
+    let changes = fs::read_to_string(repo.join("site/v0.2.0/changes.html")).unwrap();
+    assert!(changes.contains(r#"<li id="class-Fresh" class="change change-added">"#));
+    assert!(changes.contains(r#"<li id="class-Gone" class="change change-removed">"#));
+    assert!(changes.contains(r#"<a href="../v0.1.0/index.html#class-Gone">v0.1.0</a>"#));
+    assert!(changes.contains(r#"<li id="class-Thing" class="change change-modified">"#));
+    assert!(changes.contains("<th><code>abstract</code></th>"));
+
+    let index = fs::read_to_string(repo.join("site/v0.2.0/index.html")).unwrap();
+    assert!(index.contains("Changes since v0.1.0"));
+    assert!(index.contains(
+        r#"<a class="changed-badge" href="changes.html#class-Thing">changed in v0.2.0</a>"#
+    ));
+    assert!(
+        index.contains(
+            r#"<a class="changed-badge" href="changes.html#class-Fresh">new in v0.2.0</a>"#
+        )
+    );
+    assert!(
+        repo.join("site/current/changes.html").is_file(),
+        "the current alias copies the Changes page too"
+    );
+}
+
 /// CLI exit-code contract: `panschema publish` against a manifest
 /// without a `[publishing]` section fails fast and the error message
 /// names the missing section.
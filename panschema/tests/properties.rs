@@ -109,6 +109,7 @@ fn arb_enum() -> impl Strategy<Value = (String, EnumDefinition)> {
                     text: key,
                     description: None,
                     meaning: None,
+                    metadata: Default::default(),
                 },
             );
         }
@@ -148,6 +148,7 @@ fn every_subcommand_is_documented() {
     for sub in [
         "generate",
         "validate",
+        "lint",
         "migrate",
         "publish",
         "serve",
@@ -33,4 +33,4 @@ echo "==> Rebuilding panschema-viz bundle ($bundle_profile)"
 wasm-pack build panschema-viz --target web "$bundle_profile" --features webgpu
 
 echo "==> Installing panschema"
-cargo install --path panschema --force "${install_flags[@]}"
+cargo install --path panschema --force --features svg "${install_flags[@]}"
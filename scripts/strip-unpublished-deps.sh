@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+#
+# Drop panschema's path dependency on panschema-viz, and the `svg`
+# feature that enables it, from panschema/Cargo.toml before `cargo
+# publish`.
+#
+# `cargo publish` refuses a path dependency without a version, optional
+# or not, and panschema-viz can't be published for it to name: its
+# layout crates are git-only. The published crate is built without
+# `svg`, so `--format svg` reports the missing feature there; release
+# binaries and `cargo install --git … --features svg` keep it. The
+# release job runs this before publishing, and the lint job before its
+# `cargo publish --dry-run`, so a new unpublishable dependency fails CI
+# rather than the release.
+#
+# Usage:
+#   ./scripts/strip-unpublished-deps.sh   # edits panschema/Cargo.toml in place
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+manifest=panschema/Cargo.toml
+sed -i -e '/^panschema-viz = /d' -e '/^svg = /d' "$manifest"
+if grep -Eq '^panschema-viz|dep:panschema-viz' "$manifest"; then
+  echo "error: $manifest still depends on panschema-viz" >&2
+  exit 1
+fi